@@ -773,9 +773,10 @@ impl AppRuntime {
             Self::spawn_orphaned_image_cleanup(image.clone());
         }
 
+        let events_msg_store = Arc::new(MsgStore::new());
         let events = EventService::new(
             db.clone(),
-            Arc::new(MsgStore::new()),
+            events_msg_store.clone(),
             Arc::new(RwLock::new(0)),
             shutdown_token.clone(),
         );
@@ -783,6 +784,7 @@ impl AppRuntime {
         let container = LocalContainerService::new(
             db.clone(),
             core.msg_stores.clone(),
+            events_msg_store,
             config,
             core.git.clone(),
             image.clone(),
@@ -1,9 +1,9 @@
 use std::sync::{Arc, OnceLock};
 
 use async_trait::async_trait;
-use config::{Config, NotificationConfig, SoundFile};
+use config::{Config, NotificationConfig, NotificationSound, SoundFile};
 use tokio::sync::RwLock;
-use utils_core::notifications::Notifier;
+use utils_core::notifications::{NotificationEvent, Notifier};
 
 #[derive(Debug, Clone)]
 pub struct NotificationService {
@@ -17,9 +17,18 @@ impl NotificationService {
         Self { config }
     }
 
-    async fn send_notification(config: &NotificationConfig, title: &str, message: &str) {
+    async fn send_notification(
+        config: &NotificationConfig,
+        event: Option<NotificationEvent>,
+        title: &str,
+        message: &str,
+    ) {
         if config.sound_enabled {
-            Self::play_sound_notification(&config.sound_file).await;
+            let sound = match event {
+                Some(event) => config.sound_for(event),
+                None => NotificationSound::Builtin(config.sound_file.clone()),
+            };
+            Self::play_sound_notification(&sound, &config.sound_file).await;
         }
 
         if config.push_enabled {
@@ -27,8 +36,8 @@ impl NotificationService {
         }
     }
 
-    async fn play_sound_notification(sound_file: &SoundFile) {
-        let file_path = match sound_file.get_path().await {
+    async fn play_sound_notification(sound: &NotificationSound, fallback: &SoundFile) {
+        let file_path = match sound.get_path(fallback).await {
             Ok(path) => path,
             Err(error) => {
                 tracing::error!("Failed to create cached sound file: {}", error);
@@ -211,6 +220,11 @@ impl NotificationService {
 impl Notifier for NotificationService {
     async fn notify(&self, title: &str, message: &str) {
         let config = self.config.read().await.notifications.clone();
-        Self::send_notification(&config, title, message).await;
+        Self::send_notification(&config, None, title, message).await;
+    }
+
+    async fn notify_event(&self, event: NotificationEvent, title: &str, message: &str) {
+        let config = self.config.read().await.notifications.clone();
+        Self::send_notification(&config, Some(event), title, message).await;
     }
 }
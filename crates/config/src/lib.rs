@@ -13,11 +13,13 @@ mod yaml_schema;
 
 pub use editor::{EditorConfig, EditorOpenError, EditorType};
 pub use schema::{
-    AccessControlConfig, AccessControlMode, CURRENT_CONFIG_VERSION, Config, DiffPreviewGuardPreset,
-    GitHubConfig, NotificationConfig, ProjectConfig, ProjectMcpExecutorPolicyMode,
-    ProjectRepoConfig, ProjectsFile, ShowcaseState, SoundFile, ThemeMode, UiLanguage,
-    WorkspaceLifecycleHookConfig, WorkspaceLifecycleHookFailurePolicy,
-    WorkspaceLifecycleHookRunMode,
+    AccessControlConfig, AccessControlMode, AccessScope, CURRENT_CONFIG_VERSION, Config,
+    DiffPreviewGuardPreset, ExecutionCapacityPolicy, GitHubConfig, NotificationConfig,
+    NotificationEventSounds, NotificationSound, ProjectConfig, ProjectMcpExecutorPolicyMode,
+    ProjectRepoConfig, ProjectsFile, ProxyConfig, ScopedAccessToken, ShowcaseState, SoundFile,
+    ThemeMode, UiLanguage, WORKTREE_ROOT_SUBDIR, WorkspaceLifecycleHookConfig,
+    WorkspaceLifecycleHookFailurePolicy, WorkspaceLifecycleHookRunMode, custom_sound_path,
+    custom_sounds_dir, is_valid_custom_sound_name,
 };
 pub use yaml_schema::{
     ConfigSchemaError, generate_config_schema_json, generate_projects_schema_json,
@@ -291,6 +293,12 @@ fn is_template_allowed_for_path(path: &[TemplatePathSegment]) -> bool {
         [Key(a), Key(b)] if a == "github" && b == "pat" => true,
         [Key(a), Key(b)] if a == "github" && b == "oauth_token" => true,
         [Key(a), Key(b)] if a == "access_control" && b == "token" => true,
+        [Key(a), Key(b)] if a == "access_control" && b == "webhook_secret" => true,
+        [Key(a), Key(b), Index(_), Key(c)]
+            if a == "access_control" && b == "tokens" && c == "token" =>
+        {
+            true
+        }
 
         [Key(a), Index(_), Key(b)] if a == "projects" && b == "dev_script" => true,
         [Key(a), Index(_), Key(b), Index(_), Key(c)]
@@ -317,6 +325,7 @@ fn is_template_allowed_for_path(path: &[TemplatePathSegment]) -> bool {
             Key(c),
             Key(_env_key),
         ] if a == "executor_profiles" && b == "executors" && c == "env" => true,
+        [Key(a), Key(_executor), Key(_env_key)] if a == "executor_env" => true,
 
         _ => false,
     }
@@ -356,12 +365,15 @@ const TEMPLATE_WHITELIST_DOCS: &str = concat!(
     "- github.pat\n",
     "- github.oauth_token\n",
     "- access_control.token\n",
+    "- access_control.tokens[*].token\n",
+    "- access_control.webhook_secret\n",
     "- projects[*].dev_script\n",
     "- projects[*].repos[*].setup_script\n",
     "- projects[*].repos[*].cleanup_script\n",
     "- projects[*].after_prepare_hook.command\n",
     "- projects[*].before_cleanup_hook.command\n",
     "- executor_profiles.executors.<EXECUTOR>.<VARIANT>.<EXECUTOR>.env.<NAME>\n",
+    "- executor_env.<EXECUTOR>.<NAME>\n",
 );
 
 fn validate_templates_are_whitelisted(config: &Config) -> Result<(), ConfigError> {
@@ -638,10 +650,19 @@ fn resolve_whitelisted_templates(
     resolve_templates_in_option_string(&mut config.github.pat, env)?;
     resolve_templates_in_option_string(&mut config.github.oauth_token, env)?;
     resolve_templates_in_option_string(&mut config.access_control.token, env)?;
+    resolve_templates_in_option_string(&mut config.access_control.webhook_secret, env)?;
+    for scoped in config.access_control.tokens.iter_mut() {
+        scoped.token = resolve_templates_in_string(&scoped.token, env)?;
+    }
 
     if let Some(profiles) = config.executor_profiles.as_mut() {
         resolve_templates_in_executor_profiles_env(profiles, env)?;
     }
+    for env_map in config.executor_env.values_mut() {
+        for value in env_map.values_mut() {
+            *value = resolve_templates_in_string(value, env)?;
+        }
+    }
 
     for project in config.projects.iter_mut() {
         resolve_templates_in_option_string(&mut project.dev_script, env)?;
@@ -757,6 +778,9 @@ pub fn try_load_config_from_file(config_path: &Path) -> Result<Config, ConfigErr
     config
         .validate_projects(&profiles)
         .map_err(ConfigError::ValidationError)?;
+    config
+        .validate_worktree_root()
+        .map_err(ConfigError::ValidationError)?;
 
     Ok(config)
 }
@@ -854,6 +878,9 @@ fn build_runtime_config_from_disk(
     config
         .validate_projects(&profiles)
         .map_err(ConfigError::ValidationError)?;
+    config
+        .validate_worktree_root()
+        .map_err(ConfigError::ValidationError)?;
 
     Ok(config)
 }
@@ -1223,6 +1250,30 @@ github:
         }
     }
 
+    #[test]
+    fn worktree_root_must_be_absolute_and_is_created_when_missing() {
+        let temp_root = TempRoot::new("vk-config-test-");
+        let config_path = temp_root.join("config.yaml");
+
+        write_file(&config_path, "worktree_root: relative/worktrees\n");
+        let err = try_load_config_from_file(&config_path).expect_err("expected error");
+        match err {
+            ConfigError::ValidationError(message) => {
+                assert!(message.contains("worktree_root"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let root = temp_root.join("scratch").join("worktrees");
+        write_file(
+            &config_path,
+            &format!("worktree_root: \"{}\"\n", root.display()),
+        );
+        let loaded = try_load_config_from_file(&config_path).expect("load config");
+        assert_eq!(loaded.worktree_root_dir(), Some(root.clone()));
+        assert!(root.is_dir());
+    }
+
     #[test]
     fn reload_keeps_last_known_good_on_error() {
         let _env = EnvVarGuard::set_optional("GITHUB_PAT", None);
@@ -1300,6 +1351,54 @@ projects:
         }
     }
 
+    #[test]
+    fn repos_with_the_same_directory_name_are_rejected() {
+        let temp_root = TempRoot::new("vk-config-test-");
+        let config_path = temp_root.join("config.yaml");
+
+        write_file(
+            &config_path,
+            r#"
+projects:
+  - id: 11111111-1111-1111-1111-111111111111
+    name: a
+    repos:
+      - path: /tmp/a/app
+      - path: /tmp/b/app
+  - id: 22222222-2222-2222-2222-222222222222
+    name: b
+    repos:
+      - path: /tmp/c/app
+"#,
+        );
+
+        let err = try_load_config_from_file(&config_path).expect_err("expected error");
+        match err {
+            ConfigError::ValidationError(message) => {
+                assert!(message.contains("projects[0].repos[1]"));
+                assert!(message.contains("'app'"));
+                assert!(message.contains("projects[0].repos[0]"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        write_file(
+            &config_path,
+            r#"
+projects:
+  - id: 11111111-1111-1111-1111-111111111111
+    name: a
+    repos:
+      - path: /tmp/a/app
+  - id: 22222222-2222-2222-2222-222222222222
+    name: b
+    repos:
+      - path: /tmp/c/app
+"#,
+        );
+        try_load_config_from_file(&config_path).expect("same name across projects is allowed");
+    }
+
     #[test]
     fn projects_ui_overlay_appends_repo_to_existing_project() {
         let temp_root = TempRoot::new("vk-config-test-");
@@ -1,6 +1,7 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     path::{Component, Path, PathBuf},
+    time::Duration,
 };
 
 use anyhow::Error;
@@ -10,12 +11,15 @@ use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 use ts_rs::TS;
 use utils_assets::SoundAssets;
-use utils_core::cache_dir;
+use utils_core::{cache_dir, notifications::NotificationEvent};
 
 use super::editor::EditorConfig;
 
 pub const CURRENT_CONFIG_VERSION: &str = "v10";
 
+/// Subdirectory of a configured `worktree_root` that holds the workspaces this app creates.
+pub const WORKTREE_ROOT_SUBDIR: &str = "vibe-kanban-worktrees";
+
 fn default_executor_profile() -> ExecutorProfileId {
     ExecutorProfileId::new(BaseCodingAgent::ClaudeCode)
 }
@@ -77,6 +81,17 @@ pub enum DiffPreviewGuardPreset {
     Off,
 }
 
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TS, Default, schemars::JsonSchema,
+)]
+#[ts(use_ts_enum)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionCapacityPolicy {
+    #[default]
+    Queue,
+    Reject,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, TS, Default, schemars::JsonSchema)]
 pub struct ShowcaseState {
     #[serde(default)]
@@ -116,6 +131,11 @@ pub struct NotificationConfig {
     #[serde(alias = "soundFile")]
     #[schemars(description = "声音文件预设。")]
     pub sound_file: SoundFile,
+    #[serde(alias = "eventSounds")]
+    #[schemars(
+        description = "按事件覆盖提示音（可选）：值为内置预设（如 `ROOSTER`）或已上传的自定义声音（`{ custom: chime.wav }`）；未设置的事件使用 `sound_file`。"
+    )]
+    pub event_sounds: NotificationEventSounds,
 }
 
 impl Default for NotificationConfig {
@@ -124,10 +144,94 @@ impl Default for NotificationConfig {
             sound_enabled: true,
             push_enabled: true,
             sound_file: SoundFile::CowMooing,
+            event_sounds: NotificationEventSounds::default(),
         }
     }
 }
 
+impl NotificationConfig {
+    /// Sound configured for `event`, falling back to `sound_file`.
+    pub fn sound_for(&self, event: NotificationEvent) -> NotificationSound {
+        let sounds = &self.event_sounds;
+        let configured = match event {
+            NotificationEvent::TaskCompleted => &sounds.task_completed,
+            NotificationEvent::TaskFailed => &sounds.task_failed,
+            NotificationEvent::ApprovalNeeded => &sounds.approval_needed,
+        };
+        configured
+            .clone()
+            .unwrap_or_else(|| NotificationSound::Builtin(self.sound_file.clone()))
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS, schemars::JsonSchema)]
+#[serde(default)]
+pub struct NotificationEventSounds {
+    #[schemars(description = "任务执行成功时的提示音。")]
+    pub task_completed: Option<NotificationSound>,
+    #[schemars(description = "任务执行失败时的提示音。")]
+    pub task_failed: Option<NotificationSound>,
+    #[schemars(description = "工具调用等待审批时的提示音。")]
+    pub approval_needed: Option<NotificationSound>,
+}
+
+/// A built-in preset or a custom sound uploaded via `POST /api/sounds/custom`.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, schemars::JsonSchema)]
+#[serde(untagged)]
+pub enum NotificationSound {
+    Builtin(SoundFile),
+    Custom { custom: String },
+}
+
+impl NotificationSound {
+    /// Local file to play. A custom sound that is missing or badly named falls back to
+    /// `fallback`.
+    pub async fn get_path(
+        &self,
+        fallback: &SoundFile,
+    ) -> Result<PathBuf, Box<dyn std::error::Error + Send + Sync>> {
+        match self {
+            NotificationSound::Builtin(sound) => sound.get_path().await,
+            NotificationSound::Custom { custom } => {
+                match custom_sound_path(custom).filter(|path| path.is_file()) {
+                    Some(path) => Ok(path),
+                    None => {
+                        tracing::warn!(
+                            "Custom sound '{}' not found in {}, using default",
+                            custom,
+                            custom_sounds_dir().display()
+                        );
+                        fallback.get_path().await
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Directory holding uploaded notification sounds.
+pub fn custom_sounds_dir() -> PathBuf {
+    utils_assets::asset_dir().join("sounds")
+}
+
+/// Custom sounds are stored flat as `<name>.wav`; anything else (paths, hidden files, other
+/// extensions) is rejected.
+pub fn is_valid_custom_sound_name(name: &str) -> bool {
+    let Some(stem) = name.strip_suffix(".wav") else {
+        return false;
+    };
+    name.len() <= 128
+        && !stem.is_empty()
+        && !stem.starts_with('.')
+        && stem
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+pub fn custom_sound_path(name: &str) -> Option<PathBuf> {
+    is_valid_custom_sound_name(name).then(|| custom_sounds_dir().join(name))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS, schemars::JsonSchema)]
 #[serde(default)]
 pub struct GitHubConfig {
@@ -253,6 +357,64 @@ pub enum AccessControlMode {
     Token,
 }
 
+/// Permission carried by a scoped access token. Each scope only grants its own class of
+/// requests; `admin` grants everything.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS, schemars::JsonSchema,
+)]
+pub enum AccessScope {
+    /// Read-only requests (GET/HEAD/OPTIONS and read-style POSTs such as patch previews).
+    #[serde(rename = "read")]
+    Read,
+    /// Create, update and delete tasks, tags and task images.
+    #[serde(rename = "tasks:write")]
+    TasksWrite,
+    /// Start new attempts (`POST /api/task-attempts`, `POST /api/tasks/create-and-start`).
+    #[serde(rename = "attempts:start")]
+    AttemptsStart,
+    /// Act on existing attempts: follow-ups, stop, merge, push, approvals, etc.
+    #[serde(rename = "attempts:write")]
+    AttemptsWrite,
+    /// Everything, including config, projects, repos and filesystem routes.
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl AccessScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccessScope::Read => "read",
+            AccessScope::TasksWrite => "tasks:write",
+            AccessScope::AttemptsStart => "attempts:start",
+            AccessScope::AttemptsWrite => "attempts:write",
+            AccessScope::Admin => "admin",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, TS, schemars::JsonSchema)]
+#[serde(default)]
+pub struct ScopedAccessToken {
+    #[schemars(description = "token 名称（仅用于日志与错误提示）。")]
+    pub name: String,
+    #[schemars(
+        description = "token 值（支持模板 `{{secret.NAME}}` / `{{env.NAME}}`；推荐通过 secret.env 注入）。"
+    )]
+    pub token: String,
+    #[schemars(
+        description = "授予的权限：`read` / `tasks:write` / `attempts:start` / `attempts:write` / `admin`。各权限互不包含（除 `admin` 外），只读访问需显式授予 `read`。"
+    )]
+    pub scopes: Vec<AccessScope>,
+}
+
+impl ScopedAccessToken {
+    pub fn grants(&self, required: AccessScope) -> bool {
+        self.scopes
+            .iter()
+            .any(|scope| *scope == required || *scope == AccessScope::Admin)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, TS, schemars::JsonSchema)]
 #[serde(default)]
 pub struct AccessControlConfig {
@@ -265,6 +427,14 @@ pub struct AccessControlConfig {
     #[serde(alias = "allowLocalhostBypass")]
     #[schemars(description = "是否允许 localhost 绕过 token 校验（仅当 mode=TOKEN 时有意义）。")]
     pub allow_localhost_bypass: bool,
+    #[schemars(
+        description = "带权限范围的具名 token 列表（mode=TOKEN 时生效）。`token` 字段仍作为拥有全部权限的超级 token；两者至少配置其一。"
+    )]
+    pub tokens: Vec<ScopedAccessToken>,
+    #[schemars(
+        description = "入站 webhook（`POST /api/webhooks/tasks`）的 HMAC-SHA256 共享密钥（支持模板 `{{secret.NAME}}` / `{{env.NAME}}`）。请求需携带 `X-VK-Timestamp`（Unix 秒）、`X-VK-Delivery`（投递 id）与 `X-VK-Signature: sha256=<hex>`（对 `<timestamp>.<delivery>.<body>` 签名），不需要 API token；时间戳偏差超过 5 分钟会被拒绝，同一投递 id 重复投递返回首次创建的任务；未设置时该入口关闭。"
+    )]
+    pub webhook_secret: Option<String>,
 }
 
 impl Default for AccessControlConfig {
@@ -273,7 +443,55 @@ impl Default for AccessControlConfig {
             mode: AccessControlMode::Disabled,
             token: None,
             allow_localhost_bypass: true,
+            tokens: Vec::new(),
+            webhook_secret: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, TS, schemars::JsonSchema)]
+#[serde(default)]
+pub struct ProxyConfig {
+    #[serde(alias = "httpProxy")]
+    #[schemars(description = "HTTP 代理地址（例如 `http://proxy.local:3128`）。")]
+    pub http_proxy: Option<String>,
+    #[serde(alias = "httpsProxy")]
+    #[schemars(description = "HTTPS 代理地址（例如 `http://proxy.local:3128`）。")]
+    pub https_proxy: Option<String>,
+    #[serde(alias = "noProxy")]
+    #[schemars(description = "不走代理的主机列表（逗号分隔，例如 `localhost,127.0.0.1,.corp`）。")]
+    pub no_proxy: Option<String>,
+}
+
+impl ProxyConfig {
+    /// Standard proxy variables for child processes, in both upper- and lowercase spellings since
+    /// CLIs disagree on which one they read.
+    pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+        let mut vars = Vec::new();
+        for (upper, lower, value) in [
+            ("HTTP_PROXY", "http_proxy", &self.http_proxy),
+            ("HTTPS_PROXY", "https_proxy", &self.https_proxy),
+            ("NO_PROXY", "no_proxy", &self.no_proxy),
+        ] {
+            if let Some(value) = value {
+                vars.push((upper, value.clone()));
+                vars.push((lower, value.clone()));
+            }
+        }
+        vars
+    }
+
+    fn normalized(mut self) -> Self {
+        for value in [
+            &mut self.http_proxy,
+            &mut self.https_proxy,
+            &mut self.no_proxy,
+        ] {
+            if matches!(value.as_deref(), Some(v) if v.trim().is_empty()) {
+                *value = None;
+            }
         }
+        self
     }
 }
 
@@ -372,10 +590,19 @@ pub struct ProjectConfig {
     pub dev_script_working_dir: Option<String>,
     #[schemars(description = "可选：默认 agent 工作目录（相对 workspace root）。")]
     pub default_agent_working_dir: Option<String>,
+    #[schemars(
+        description = "可选：项目上下文文件（相对 workspace root，例如 `repo/CONVENTIONS.md`）。\n\n启动 attempt 或发送 follow-up 时，文件内容会作为前言插入到 agent prompt 之前；路径不能逃逸出 workspace。"
+    )]
+    pub context_file: Option<String>,
     #[schemars(
         description = "项目级 git hooks 跳过策略。\n\n- null/未设置：继承全局 `git_no_verify`\n- true/false：覆盖全局设置"
     )]
     pub git_no_verify_override: Option<bool>,
+    #[serde(default)]
+    #[schemars(
+        description = "可选：项目默认 executor profile（executor + 可选 variant）。\n\n启动 attempt 时未指定 executor 则使用该值；未设置时回退到全局 `executor_profile`。"
+    )]
+    pub default_executor_profile: Option<ExecutorProfileId>,
     #[serde(default = "default_scheduler_max_concurrent")]
     pub scheduler_max_concurrent: i32,
     #[serde(default = "default_scheduler_max_retries")]
@@ -409,6 +636,38 @@ pub struct Config {
         description = "Executor profiles 覆盖（可选，按需配置）。\n\n该字段会与内置 defaults 合并后作为运行时可用 profiles。\n当某个 executor/variant 在本次构建中不可用时，引用会导致配置校验失败。\n\n模板支持：仅允许在 profile 的 env map 值中使用 `{{env.*}}/{{secret.*}}`（例如 `executor_profiles.executors.CLAUDE_CODE.DEFAULT.CLAUDE_CODE.env.OPENAI_API_KEY: \"{{secret.OPENAI_API_KEY}}\"`）。其它字段不支持模板。"
     )]
     pub executor_profiles: Option<ExecutorConfigs>,
+    #[serde(default)]
+    #[serde(alias = "executorEnv")]
+    #[schemars(
+        description = "按 executor 注入到 agent 子进程的环境变量（键为 executor 名，例如 `CODEX`）。\n\nVK_* 运行时变量与 profile 的 env 优先级更高。值支持模板 `{{secret.*}}` / `{{env.*}}`（例如 `executor_env.CODEX.OPENAI_API_KEY: \"{{secret.OPENAI_API_KEY}}\"`）。"
+    )]
+    pub executor_env: HashMap<BaseCodingAgent, HashMap<String, String>>,
+    #[serde(alias = "executionTimeoutSecs")]
+    #[schemars(
+        description = "coding agent 执行进程的最长运行时间（秒）。超时后进程会被终止，并记录 `failure_kind=timed_out`。\n\nnull/0 表示不限制。"
+    )]
+    pub execution_timeout_secs: Option<u64>,
+    #[serde(default)]
+    #[serde(alias = "executorTimeoutSecs")]
+    #[schemars(
+        description = "按 executor 覆盖 `execution_timeout_secs`（键为 executor 名，例如 `CODEX`）。0 表示该 executor 不限制。"
+    )]
+    pub executor_timeout_secs: HashMap<BaseCodingAgent, u64>,
+    #[serde(alias = "executionIdleTimeoutSecs")]
+    #[schemars(
+        description = "coding agent 执行进程无输出（没有新的日志条目）的最长时间（秒），常用于处理卡在等待输入的 agent。超时后进程会被终止，并记录 `failure_kind=idle_timeout`；与 `execution_timeout_secs` 相互独立。\n\nnull/0 表示不限制。"
+    )]
+    pub execution_idle_timeout_secs: Option<u64>,
+    #[serde(alias = "maxConcurrentExecutions")]
+    #[schemars(
+        description = "同时运行的 coding agent 执行进程上限（全局）。script/dev server 进程不计入。\n\nnull/0 表示不限制。"
+    )]
+    pub max_concurrent_executions: Option<u32>,
+    #[serde(alias = "executionCapacityPolicy")]
+    #[schemars(
+        description = "达到 `max_concurrent_executions` 上限后的处理策略。QUEUE 表示排队等待空闲名额；REJECT 表示直接拒绝并返回 `capacity_exceeded` 错误。"
+    )]
+    pub execution_capacity_policy: ExecutionCapacityPolicy,
     #[serde(alias = "disclaimerAcknowledged")]
     pub disclaimer_acknowledged: bool,
     #[serde(alias = "onboardingAcknowledged")]
@@ -418,6 +677,11 @@ pub struct Config {
     pub github: GitHubConfig,
     #[serde(alias = "workspaceDir")]
     pub workspace_dir: Option<String>,
+    #[serde(alias = "worktreeRoot")]
+    #[schemars(
+        description = "可选：attempt worktree 的存放根目录（绝对路径，例如更快的 scratch 盘）。加载配置时会校验目录可写（不存在则创建）。\n\nworktree 实际创建在该目录下的 `vibe-kanban-worktrees` 子目录中，孤儿清理只会扫描这个子目录。\n\nnull/未设置时使用默认的临时目录；修改只影响之后新建的 worktree，旧根目录下已有的 worktree 不会被迁移或自动清理。"
+    )]
+    pub worktree_root: Option<String>,
     #[serde(alias = "lastAppVersion")]
     pub last_app_version: Option<String>,
     #[serde(alias = "showReleaseNotes")]
@@ -441,6 +705,20 @@ pub struct Config {
     pub diff_preview_guard: DiffPreviewGuardPreset,
     #[serde(alias = "accessControl")]
     pub access_control: AccessControlConfig,
+    #[schemars(
+        description = "出站代理配置：注入到 agent 子进程环境变量（HTTP_PROXY/HTTPS_PROXY/NO_PROXY），并用于服务端自身的 HTTP 请求。"
+    )]
+    pub proxy: ProxyConfig,
+    #[serde(alias = "taskStatusTransitions")]
+    #[schemars(
+        description = "MCP `transition_task` 允许的任务状态迁移：键为当前状态，值为可迁移到的状态列表（todo / inprogress / inreview / done / cancelled）。\n\n留空使用内置默认矩阵；一旦配置则完全替换默认矩阵。`update_task` 不受此限制。"
+    )]
+    pub task_status_transitions: HashMap<String, Vec<String>>,
+    #[serde(alias = "turnSummaryLlmEnabled")]
+    #[schemars(
+        description = "每个 coding agent turn 结束时，调用 OpenAI 兼容接口生成一行摘要（用于 MCP `tail_session_messages`）。\n\n复用翻译功能的 `KANBAN_OPENAI_API_BASE` / `KANBAN_OPENAI_API_KEY` / `KANBAN_OPENAI_DEFAULT_MODEL`（回退 `OPENAI_*`）。关闭或调用失败时使用最后一条 assistant 消息。"
+    )]
+    pub turn_summary_llm_enabled: bool,
     #[serde(default)]
     #[schemars(
         description = "Projects 与 repos 配置（file-first）。\n\n- 推荐写入 `projects.yaml`（或拆分到 `projects.d/*.yaml`）\n- 若存在 `projects.yaml` / `projects.d/*`，会覆盖 `config.yaml` 中的 inline `projects`\n- projects 的 `id` 必须显式提供且全局唯一\n- repo `path` 必须为绝对路径\n- 修改后调用 `POST /api/config/reload`（或启用 watcher 自动 reload）"
@@ -459,6 +737,41 @@ pub struct ProjectsFile {
 }
 
 impl Config {
+    /// Wall-clock limit for a coding agent run by `executor`; the per-executor override wins
+    /// over the global value. `None` means no limit.
+    pub fn execution_timeout(&self, executor: BaseCodingAgent) -> Option<Duration> {
+        let secs = self
+            .executor_timeout_secs
+            .get(&executor)
+            .copied()
+            .or(self.execution_timeout_secs)?;
+        (secs > 0).then(|| Duration::from_secs(secs))
+    }
+
+    /// How long a coding agent run may go without producing output. `None` means no limit.
+    pub fn execution_idle_timeout(&self) -> Option<Duration> {
+        self.execution_idle_timeout_secs
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+    }
+
+    /// Executor profile used when an attempt for `project_id` is started without one: the
+    /// project's `default_executor_profile`, else the global `executor_profile`.
+    pub fn executor_profile_for_project(&self, project_id: uuid::Uuid) -> ExecutorProfileId {
+        self.projects
+            .iter()
+            .find(|project| project.id == Some(project_id))
+            .and_then(|project| project.default_executor_profile.clone())
+            .unwrap_or_else(|| self.executor_profile.clone())
+    }
+
+    /// Maximum number of coding agent executions allowed to run at once. `None` means no limit.
+    pub fn max_concurrent_executions(&self) -> Option<usize> {
+        self.max_concurrent_executions
+            .filter(|limit| *limit > 0)
+            .map(|limit| limit as usize)
+    }
+
     pub fn normalized(mut self) -> Self {
         if self.config_version.trim().is_empty() {
             self.config_version = CURRENT_CONFIG_VERSION.to_string();
@@ -493,6 +806,13 @@ impl Config {
             self.workspace_dir = None;
         }
 
+        self.worktree_root = self
+            .worktree_root
+            .as_deref()
+            .map(str::trim)
+            .filter(|root| !root.is_empty())
+            .map(str::to_string);
+
         if matches!(
             self.access_control.token.as_deref(),
             Some(token) if token.trim().is_empty()
@@ -500,6 +820,8 @@ impl Config {
             self.access_control.token = None;
         }
 
+        self.proxy = self.proxy.normalized();
+
         self
     }
 
@@ -513,6 +835,42 @@ impl Config {
         Ok(())
     }
 
+    /// Configured root for new attempt worktrees, if any.
+    pub fn worktree_root_dir(&self) -> Option<PathBuf> {
+        self.worktree_root.as_deref().map(PathBuf::from)
+    }
+
+    /// Directory new workspaces are created in when `worktree_root` is set. Workspaces are nested
+    /// in a dedicated subdirectory so orphan cleanup never scans unrelated content of the root.
+    /// Workspaces left under a previously configured root are not migrated or reclaimed.
+    pub fn worktree_base_dir(&self) -> Option<PathBuf> {
+        self.worktree_root_dir()
+            .map(|root| root.join(WORKTREE_ROOT_SUBDIR))
+    }
+
+    /// Checks that `worktree_root` is an absolute, writable directory, creating it if needed.
+    pub fn validate_worktree_root(&self) -> Result<(), String> {
+        let Some(root) = self.worktree_root_dir() else {
+            return Ok(());
+        };
+        if !root.is_absolute() {
+            return Err(format!(
+                "worktree_root must be an absolute path, got '{}'",
+                root.display()
+            ));
+        }
+        std::fs::create_dir_all(&root).map_err(|err| {
+            format!(
+                "worktree_root '{}' cannot be created: {err}",
+                root.display()
+            )
+        })?;
+        let probe = root.join(format!(".vk-write-check-{}", std::process::id()));
+        std::fs::write(&probe, b"")
+            .and_then(|()| std::fs::remove_file(&probe))
+            .map_err(|err| format!("worktree_root '{}' is not writable: {err}", root.display()))
+    }
+
     pub fn validate_projects(&self, profiles: &ExecutorConfigs) -> Result<(), String> {
         fn validate_single_command_text(label: &str, script: &str) -> Result<(), String> {
             let trimmed = script.trim();
@@ -629,6 +987,9 @@ impl Config {
             }
 
             let mut seen_repo_paths = HashSet::new();
+            // Repos are named after their directory, and repo-prefixed attempt paths resolve by
+            // that name, so two repos with the same basename would be ambiguous.
+            let mut seen_repo_names = HashMap::new();
             for (repo_index, repo) in project.repos.iter().enumerate() {
                 let path = repo.path.trim();
                 if path.is_empty() {
@@ -646,6 +1007,16 @@ impl Config {
                         "projects[{project_index}].repos[{repo_index}] duplicate path: {path}"
                     ));
                 }
+                if let Some(name) = Path::new(path).file_name() {
+                    let name = name.to_string_lossy().to_string();
+                    if let Some(first_index) = seen_repo_names.insert(name.clone(), repo_index) {
+                        return Err(format!(
+                            "projects[{project_index}].repos[{repo_index}] repo name '{name}' \
+                             collides with projects[{project_index}].repos[{first_index}]; \
+                             repo directories in a project must have distinct names"
+                        ));
+                    }
+                }
 
                 if let Some(script) = repo.setup_script.as_deref() {
                     validate_single_command_text(
@@ -683,6 +1054,20 @@ impl Config {
                 )?;
             }
 
+            if let Some(context_file) = project.context_file.as_deref() {
+                let path = Path::new(context_file.trim());
+                if path.as_os_str().is_empty()
+                    || path.is_absolute()
+                    || path
+                        .components()
+                        .any(|component| matches!(component, Component::ParentDir))
+                {
+                    return Err(format!(
+                        "projects[{project_index}].context_file must be a file path inside the workspace"
+                    ));
+                }
+            }
+
             if project.scheduler_max_concurrent < 1 {
                 return Err(format!(
                     "projects[{project_index}].scheduler_max_concurrent must be at least 1"
@@ -701,6 +1086,14 @@ impl Config {
                 ));
             }
 
+            if let Some(profile) = project.default_executor_profile.as_ref() {
+                profiles.require_coding_agent(profile).map_err(|err| {
+                    format!(
+                        "projects[{project_index}].default_executor_profile invalid executor profile: {err}"
+                    )
+                })?;
+            }
+
             if matches!(
                 project.mcp_auto_executor_policy_mode,
                 ProjectMcpExecutorPolicyMode::AllowList
@@ -746,12 +1139,19 @@ impl Default for Config {
             theme: ThemeMode::System,
             executor_profile: default_executor_profile(),
             executor_profiles: None,
+            executor_env: HashMap::new(),
+            execution_timeout_secs: None,
+            executor_timeout_secs: HashMap::new(),
+            execution_idle_timeout_secs: None,
+            max_concurrent_executions: None,
+            execution_capacity_policy: ExecutionCapacityPolicy::default(),
             disclaimer_acknowledged: false,
             onboarding_acknowledged: false,
             notifications: NotificationConfig::default(),
             editor: EditorConfig::default(),
             github: GitHubConfig::default(),
             workspace_dir: None,
+            worktree_root: None,
             last_app_version: None,
             show_release_notes: false,
             language: UiLanguage::default(),
@@ -763,6 +1163,9 @@ impl Default for Config {
             llman_claude_code_path: None,
             diff_preview_guard: default_diff_preview_guard(),
             access_control: AccessControlConfig::default(),
+            proxy: ProxyConfig::default(),
+            task_status_transitions: HashMap::new(),
+            turn_summary_llm_enabled: false,
             projects: Vec::new(),
         }
     }
@@ -788,6 +1191,95 @@ mod tests {
         assert!(!config.disclaimer_acknowledged);
     }
 
+    #[test]
+    fn execution_timeout_prefers_executor_override() {
+        let raw = r#"
+execution_timeout_secs: 600
+executor_timeout_secs:
+  CODEX: 60
+  GEMINI: 0
+execution_idle_timeout_secs: 900
+"#;
+        let config = serde_yaml::from_str::<Config>(raw).expect("YAML parse should succeed");
+
+        assert_eq!(
+            config.execution_timeout(BaseCodingAgent::Codex),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(config.execution_timeout(BaseCodingAgent::Gemini), None);
+        assert_eq!(
+            config.execution_timeout(BaseCodingAgent::ClaudeCode),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(
+            Config::default().execution_timeout(BaseCodingAgent::ClaudeCode),
+            None
+        );
+        assert_eq!(
+            config.execution_idle_timeout(),
+            Some(Duration::from_secs(900))
+        );
+        assert_eq!(Config::default().execution_idle_timeout(), None);
+    }
+
+    #[test]
+    fn executor_profile_for_project_prefers_project_default() {
+        let with_default = uuid::Uuid::new_v4();
+        let without_default = uuid::Uuid::new_v4();
+        let raw = format!(
+            r#"
+executor_profile:
+  executor: CLAUDE_CODE
+projects:
+  - id: {with_default}
+    name: Codex project
+    default_executor_profile:
+      executor: CODEX
+      variant: HIGH
+  - id: {without_default}
+    name: Plain project
+"#
+        );
+        let config = serde_yaml::from_str::<Config>(&raw).expect("YAML parse should succeed");
+
+        assert_eq!(
+            config.executor_profile_for_project(with_default),
+            ExecutorProfileId {
+                executor: BaseCodingAgent::Codex,
+                variant: Some("HIGH".to_string()),
+            }
+        );
+        assert_eq!(
+            config.executor_profile_for_project(without_default),
+            ExecutorProfileId::new(BaseCodingAgent::ClaudeCode)
+        );
+        assert_eq!(
+            config.executor_profile_for_project(uuid::Uuid::new_v4()),
+            ExecutorProfileId::new(BaseCodingAgent::ClaudeCode)
+        );
+    }
+
+    #[test]
+    fn max_concurrent_executions_treats_zero_as_unlimited() {
+        let config = serde_yaml::from_str::<Config>("max_concurrent_executions: 0")
+            .expect("YAML parse should succeed");
+        assert_eq!(config.max_concurrent_executions(), None);
+        assert_eq!(
+            config.execution_capacity_policy,
+            ExecutionCapacityPolicy::Queue
+        );
+
+        let config = serde_yaml::from_str::<Config>(
+            "maxConcurrentExecutions: 2\nexecutionCapacityPolicy: REJECT",
+        )
+        .expect("YAML parse should succeed");
+        assert_eq!(config.max_concurrent_executions(), Some(2));
+        assert_eq!(
+            config.execution_capacity_policy,
+            ExecutionCapacityPolicy::Reject
+        );
+    }
+
     #[test]
     fn aliases_and_normalization_are_applied() {
         let raw = r#"
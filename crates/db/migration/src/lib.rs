@@ -20,6 +20,12 @@ mod m20260311000000_milestone_plan_applications;
 mod m20260312000000_task_turn_continuation;
 mod m20260312000001_project_mcp_executor_policy;
 mod m20260329000000_event_outbox_unpublished_index;
+mod m20260401000000_execution_process_failure_kind;
+mod m20260402000000_task_schedules;
+mod m20260403000000_workspace_labels;
+mod m20260404000000_execution_process_last_activity;
+mod m20260406000000_task_soft_delete;
+mod m20260407000000_workspace_repo_sparse_paths;
 
 pub struct Migrator;
 
@@ -47,6 +53,12 @@ impl MigratorTrait for Migrator {
             Box::new(m20260312000000_task_turn_continuation::Migration),
             Box::new(m20260312000001_project_mcp_executor_policy::Migration),
             Box::new(m20260329000000_event_outbox_unpublished_index::Migration),
+            Box::new(m20260401000000_execution_process_failure_kind::Migration),
+            Box::new(m20260402000000_task_schedules::Migration),
+            Box::new(m20260403000000_workspace_labels::Migration),
+            Box::new(m20260404000000_execution_process_last_activity::Migration),
+            Box::new(m20260406000000_task_soft_delete::Migration),
+            Box::new(m20260407000000_workspace_repo_sparse_paths::Migration),
         ]
     }
 }
@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // NULL = no specific failure kind recorded.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ExecutionProcesses::Table)
+                    .add_column(ColumnDef::new(ExecutionProcesses::FailureKind).string_len(32))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ExecutionProcesses::Table)
+                    .drop_column(ExecutionProcesses::FailureKind)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ExecutionProcesses {
+    Table,
+    FailureKind,
+}
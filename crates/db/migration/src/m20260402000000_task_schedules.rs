@@ -0,0 +1,137 @@
+use sea_orm_migration::{prelude::*, sea_orm::DatabaseBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(TaskSchedules::Table)
+                    .col(pk_id_col(manager, TaskSchedules::Id))
+                    .col(
+                        ColumnDef::new(TaskSchedules::TaskId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskSchedules::Cron)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskSchedules::ExecutorProfileId)
+                            .json()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TaskSchedules::Repos).json().not_null())
+                    .col(
+                        ColumnDef::new(TaskSchedules::Enabled)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(ColumnDef::new(TaskSchedules::NextRunAt).timestamp())
+                    .col(ColumnDef::new(TaskSchedules::LastRunAt).timestamp())
+                    .col(ColumnDef::new(TaskSchedules::LastWorkspaceId).uuid())
+                    .col(ColumnDef::new(TaskSchedules::LastError).text())
+                    .col(timestamp_col(TaskSchedules::CreatedAt))
+                    .col(timestamp_col(TaskSchedules::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_schedules_task_id")
+                            .from(TaskSchedules::Table, TaskSchedules::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_task_schedules_task_id")
+                    .table(TaskSchedules::Table)
+                    .col(TaskSchedules::TaskId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_task_schedules_enabled_next_run")
+                    .table(TaskSchedules::Table)
+                    .col(TaskSchedules::Enabled)
+                    .col(TaskSchedules::NextRunAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .if_exists()
+                    .table(TaskSchedules::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn pk_id_col<T: Iden>(manager: &SchemaManager, col: T) -> ColumnDef {
+    let mut col = ColumnDef::new(col);
+    match manager.get_database_backend() {
+        DatabaseBackend::Sqlite => {
+            col.integer();
+        }
+        _ => {
+            col.big_integer();
+        }
+    }
+    col.not_null().auto_increment().primary_key().to_owned()
+}
+
+fn timestamp_col<T: Iden>(col: T) -> ColumnDef {
+    ColumnDef::new(col)
+        .timestamp()
+        .not_null()
+        .default(Expr::current_timestamp())
+        .to_owned()
+}
+
+#[derive(Iden)]
+enum TaskSchedules {
+    Table,
+    Id,
+    TaskId,
+    Cron,
+    ExecutorProfileId,
+    Repos,
+    Enabled,
+    NextRunAt,
+    LastRunAt,
+    LastWorkspaceId,
+    LastError,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Tasks {
+    Table,
+    Id,
+}
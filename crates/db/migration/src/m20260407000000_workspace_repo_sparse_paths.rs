@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // JSON array of sparse-checkout patterns; NULL = full checkout.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WorkspaceRepos::Table)
+                    .add_column(ColumnDef::new(WorkspaceRepos::SparsePaths).json())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WorkspaceRepos::Table)
+                    .drop_column(WorkspaceRepos::SparsePaths)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum WorkspaceRepos {
+    Table,
+    SparsePaths,
+}
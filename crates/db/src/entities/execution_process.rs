@@ -1,6 +1,8 @@
 use sea_orm::{JsonValue, entity::prelude::*};
 
-use crate::types::{ExecutionProcessRunReason, ExecutionProcessStatus};
+use crate::types::{
+    ExecutionProcessFailureKind, ExecutionProcessRunReason, ExecutionProcessStatus,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "execution_processes")]
@@ -13,9 +15,11 @@ pub struct Model {
     pub executor_action: JsonValue,
     pub status: ExecutionProcessStatus,
     pub exit_code: Option<i64>,
+    pub failure_kind: Option<ExecutionProcessFailureKind>,
     pub dropped: bool,
     pub started_at: DateTimeUtc,
     pub completed_at: Option<DateTimeUtc>,
+    pub last_activity_at: Option<DateTimeUtc>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
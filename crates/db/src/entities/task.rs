@@ -21,6 +21,7 @@ pub struct Model {
     pub continuation_turns_override: Option<i32>,
     pub shared_task_id: Option<i64>,
     pub archived_kanban_id: Option<i64>,
+    pub deleted_at: Option<DateTimeUtc>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
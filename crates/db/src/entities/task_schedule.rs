@@ -0,0 +1,24 @@
+use sea_orm::{JsonValue, entity::prelude::*};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "task_schedules")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub task_id: i64,
+    pub cron: String,
+    pub executor_profile_id: JsonValue,
+    pub repos: JsonValue,
+    pub enabled: bool,
+    pub next_run_at: Option<DateTimeUtc>,
+    pub last_run_at: Option<DateTimeUtc>,
+    pub last_workspace_id: Option<Uuid>,
+    pub last_error: Option<String>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,4 +1,4 @@
-use sea_orm::entity::prelude::*;
+use sea_orm::{JsonValue, entity::prelude::*};
 
 use crate::types::WorkspaceLifecycleHookStatus;
 
@@ -19,6 +19,7 @@ pub struct Model {
     pub before_cleanup_hook_status: Option<WorkspaceLifecycleHookStatus>,
     pub before_cleanup_hook_ran_at: Option<DateTimeUtc>,
     pub before_cleanup_hook_error_summary: Option<String>,
+    pub labels: Option<JsonValue>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
@@ -1,4 +1,4 @@
-use sea_orm::entity::prelude::*;
+use sea_orm::{JsonValue, entity::prelude::*};
 
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "workspace_repos")]
@@ -9,6 +9,7 @@ pub struct Model {
     pub workspace_id: i64,
     pub repo_id: i64,
     pub target_branch: String,
+    pub sparse_paths: Option<JsonValue>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
@@ -151,4 +151,21 @@ impl DBService {
         }
         Ok(DBService { pool })
     }
+
+    /// Size of the SQLite database in bytes (page count times page size).
+    pub async fn size_bytes(&self) -> Result<u64, DbErr> {
+        let backend = self.pool.get_database_backend();
+        let row = self
+            .pool
+            .query_one_raw(Statement::from_string(
+                backend,
+                "SELECT page_count * page_size AS size_bytes FROM pragma_page_count(), pragma_page_size()",
+            ))
+            .await?;
+        let size: i64 = match row {
+            Some(row) => row.try_get("", "size_bytes")?,
+            None => 0,
+        };
+        Ok(u64::try_from(size).unwrap_or(0))
+    }
 }
@@ -91,6 +91,21 @@ pub async fn find_pending_by_execution_tool_call<C: ConnectionTrait>(
     record.map(Approval::from_model).transpose()
 }
 
+/// Oldest approval of `execution_process_id` that is still pending and not yet past its timeout.
+pub async fn find_open_by_execution_process<C: ConnectionTrait>(
+    db: &C,
+    execution_process_id: Uuid,
+) -> Result<Option<Approval>, DbErr> {
+    let record = approval::Entity::find()
+        .filter(approval::Column::ExecutionProcessId.eq(execution_process_id))
+        .filter(approval::Column::Status.eq("pending"))
+        .filter(approval::Column::TimeoutAt.gt(Utc::now()))
+        .order_by_asc(approval::Column::Id)
+        .one(db)
+        .await?;
+    record.map(Approval::from_model).transpose()
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn insert_pending<C: ConnectionTrait>(
     db: &C,
@@ -21,6 +21,8 @@ pub struct EventOutboxEntry {
     pub payload: Value,
     pub created_at: DateTime<Utc>,
     pub published_at: Option<DateTime<Utc>>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
 }
 
 impl EventOutboxEntry {
@@ -34,6 +36,8 @@ impl EventOutboxEntry {
             payload: model.payload,
             created_at: model.created_at.into(),
             published_at: model.published_at.map(Into::into),
+            attempts: model.attempts,
+            last_error: model.last_error,
         }
     }
 }
@@ -120,6 +124,20 @@ impl EventOutbox {
         Ok(())
     }
 
+    /// Deletes rows that were published before `cutoff`. Unpublished rows are kept regardless of
+    /// age so the dispatcher can still retry them.
+    pub async fn prune_events_before<C: ConnectionTrait>(
+        db: &C,
+        cutoff: DateTime<Utc>,
+    ) -> Result<u64, DbErr> {
+        let result = event_outbox::Entity::delete_many()
+            .filter(event_outbox::Column::PublishedAt.is_not_null())
+            .filter(event_outbox::Column::PublishedAt.lt(cutoff))
+            .exec(db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
     pub async fn tail_after<C: ConnectionTrait>(
         db: &C,
         after_id: i64,
@@ -138,6 +156,34 @@ impl EventOutbox {
             .collect())
     }
 
+    /// Oldest-first page of rows after `cursor` (exclusive). Returns the id to pass as the next
+    /// cursor and whether more rows follow it.
+    pub async fn page_after<C: ConnectionTrait>(
+        db: &C,
+        cursor: Option<i64>,
+        limit: u64,
+    ) -> Result<(Vec<EventOutboxEntry>, Option<i64>, bool), DbErr> {
+        let limit = limit.clamp(1, 200);
+        let mut query = event_outbox::Entity::find().order_by_asc(event_outbox::Column::Id);
+        if let Some(cursor) = cursor {
+            query = query.filter(event_outbox::Column::Id.gt(cursor));
+        }
+
+        let mut records = query.limit(limit + 1).all(db).await?;
+        let has_more = records.len() as u64 > limit;
+        records.truncate(limit as usize);
+        let next_cursor = records.last().map(|r| r.id).or(cursor);
+
+        Ok((
+            records
+                .into_iter()
+                .map(EventOutboxEntry::from_model)
+                .collect(),
+            next_cursor,
+            has_more,
+        ))
+    }
+
     pub async fn page_older<C: ConnectionTrait>(
         db: &C,
         cursor: Option<i64>,
@@ -261,4 +307,86 @@ mod tests {
                 .is_empty()
         );
     }
+
+    #[tokio::test]
+    async fn page_after_walks_rows_oldest_first() {
+        let db = setup_db().await;
+
+        for value in 0..5 {
+            EventOutbox::enqueue(
+                &db,
+                "test.event",
+                "test",
+                Uuid::new_v4(),
+                serde_json::json!({ "value": value }),
+            )
+            .await
+            .unwrap();
+        }
+
+        let (first, cursor, has_more) = EventOutbox::page_after(&db, None, 2).await.unwrap();
+        assert_eq!(first.len(), 2);
+        assert!(has_more);
+        assert_eq!(first[0].payload["value"], 0);
+        assert_eq!(first[1].payload["value"], 1);
+        assert_eq!(cursor, Some(first[1].id));
+
+        let (second, cursor, has_more) = EventOutbox::page_after(&db, cursor, 2).await.unwrap();
+        assert_eq!(second.len(), 2);
+        assert!(has_more);
+        assert_eq!(second[0].payload["value"], 2);
+
+        let (third, last_cursor, has_more) = EventOutbox::page_after(&db, cursor, 2).await.unwrap();
+        assert_eq!(third.len(), 1);
+        assert!(!has_more);
+        assert_eq!(third[0].payload["value"], 4);
+
+        let (empty, cursor, has_more) = EventOutbox::page_after(&db, last_cursor, 2).await.unwrap();
+        assert!(empty.is_empty());
+        assert!(!has_more);
+        assert_eq!(cursor, last_cursor);
+    }
+
+    #[tokio::test]
+    async fn prune_events_before_removes_only_old_published_rows() {
+        let db = setup_db().await;
+
+        for event_type in ["old.published", "recent.published", "old.unpublished"] {
+            EventOutbox::enqueue(
+                &db,
+                event_type,
+                "test",
+                Uuid::new_v4(),
+                serde_json::json!({}),
+            )
+            .await
+            .unwrap();
+        }
+
+        let old = Utc::now() - chrono::Duration::days(30);
+        for model in EventOutbox::fetch_unpublished(&db, 10).await.unwrap() {
+            let published_at = match model.event_type.as_str() {
+                "old.published" => Some(old),
+                "recent.published" => Some(Utc::now()),
+                _ => None,
+            };
+            let mut active: event_outbox::ActiveModel = model.into();
+            active.created_at = Set(old.into());
+            active.published_at = Set(published_at.map(Into::into));
+            active.update(&db).await.unwrap();
+        }
+
+        let removed = EventOutbox::prune_events_before(&db, Utc::now() - chrono::Duration::days(7))
+            .await
+            .unwrap();
+        assert_eq!(removed, 1);
+
+        let (remaining, _, _) = EventOutbox::page_after(&db, None, 10).await.unwrap();
+        let mut remaining_types: Vec<_> = remaining
+            .into_iter()
+            .map(|entry| entry.event_type)
+            .collect();
+        remaining_types.sort();
+        assert_eq!(remaining_types, vec!["old.unpublished", "recent.published"]);
+    }
 }
@@ -22,7 +22,9 @@ use super::{
     workspace::Workspace,
     workspace_repo::WorkspaceRepo,
 };
-pub use crate::types::{ExecutionProcessRunReason, ExecutionProcessStatus};
+pub use crate::types::{
+    ExecutionProcessFailureKind, ExecutionProcessRunReason, ExecutionProcessStatus,
+};
 use crate::{
     entities::{
         coding_agent_turn, execution_process, execution_process_repo_state, repo, session, task,
@@ -59,12 +61,16 @@ pub struct ExecutionProcess {
     pub executor_action: ExecutorAction,
     pub status: ExecutionProcessStatus,
     pub exit_code: Option<i64>,
+    pub failure_kind: Option<ExecutionProcessFailureKind>,
     /// dropped: true if this process is excluded from the current
     /// history view (due to restore/trimming). Hidden from logs/timeline;
     /// still listed in the Processes tab.
     pub dropped: bool,
     pub started_at: DateTime<Utc>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// When the process last produced a normalized log entry; persisted at most every
+    /// few seconds while it runs.
+    pub last_activity_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -99,9 +105,11 @@ pub struct ExecutionProcessPublic {
     pub executor_action: ExecutorAction,
     pub status: ExecutionProcessStatus,
     pub exit_code: Option<i64>,
+    pub failure_kind: Option<ExecutionProcessFailureKind>,
     pub dropped: bool,
     pub started_at: DateTime<Utc>,
     pub completed_at: Option<DateTime<Utc>>,
+    pub last_activity_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -115,9 +123,11 @@ impl ExecutionProcessPublic {
             executor_action: redact_executor_action_for_public(&process.executor_action),
             status: process.status.clone(),
             exit_code: process.exit_code,
+            failure_kind: process.failure_kind.clone(),
             dropped: process.dropped,
             started_at: process.started_at,
             completed_at: process.completed_at,
+            last_activity_at: process.last_activity_at,
             created_at: process.created_at,
             updated_at: process.updated_at,
         }
@@ -183,9 +193,11 @@ impl ExecutionProcess {
             executor_action,
             status: model.status,
             exit_code: model.exit_code,
+            failure_kind: model.failure_kind,
             dropped: model.dropped,
             started_at: model.started_at.into(),
             completed_at: model.completed_at.map(Into::into),
+            last_activity_at: model.last_activity_at.map(Into::into),
             created_at: model.created_at.into(),
             updated_at: model.updated_at.into(),
         })
@@ -698,6 +710,61 @@ impl ExecutionProcess {
         Ok(())
     }
 
+    /// Records why the execution process failed.
+    pub async fn set_failure_kind<C: ConnectionTrait>(
+        db: &C,
+        id: Uuid,
+        failure_kind: ExecutionProcessFailureKind,
+    ) -> Result<(), DbErr> {
+        let record = execution_process::Entity::find()
+            .filter(execution_process::Column::Uuid.eq(id))
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound(
+                "Execution process not found".to_string(),
+            ))?;
+
+        let session_uuid = ids::session_uuid_by_id(db, record.session_id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Session not found".to_string()))?;
+        let mut active: execution_process::ActiveModel = record.into();
+        active.failure_kind = Set(Some(failure_kind));
+        active.updated_at = Set(Utc::now().into());
+        active.update(db).await?;
+        let payload = serde_json::to_value(ExecutionProcessEventPayload {
+            process_id: id,
+            session_id: session_uuid,
+        })
+        .map_err(|err| DbErr::Custom(err.to_string()))?;
+        EventOutbox::enqueue(
+            db,
+            EVENT_EXECUTION_PROCESS_UPDATED,
+            "execution_process",
+            id,
+            payload,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Records output activity. Deliberately leaves `updated_at` alone and emits no event: it is
+    /// a heartbeat, written often while the process runs.
+    pub async fn record_activity<C: ConnectionTrait>(
+        db: &C,
+        id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<(), DbErr> {
+        execution_process::Entity::update_many()
+            .col_expr(
+                execution_process::Column::LastActivityAt,
+                Expr::value(Some(at)),
+            )
+            .filter(execution_process::Column::Uuid.eq(id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
     pub fn executor_action(&self) -> &ExecutorAction {
         &self.executor_action
     }
@@ -1,13 +1,19 @@
+use std::collections::HashSet;
+
 use chrono::{DateTime, Utc};
 use sea_orm::{
     ColumnTrait, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect, Set,
+    QuerySelect, Set, sea_query::Expr,
 };
 use serde::{Deserialize, Serialize};
 use utils_core::log_entries::LogEntryChannel;
 use uuid::Uuid;
 
-use crate::{entities::execution_process_log_entry, models::ids};
+use crate::{
+    entities::{execution_process, execution_process_log, execution_process_log_entry},
+    models::ids,
+    types::ExecutionProcessStatus,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionProcessLogEntry {
@@ -115,6 +121,20 @@ impl ExecutionProcessLogEntry {
         Ok(exists)
     }
 
+    /// Total bytes of stored entry JSON across all executions and channels.
+    pub async fn total_json_bytes<C: ConnectionTrait>(db: &C) -> Result<i64, DbErr> {
+        let total: Option<i64> = execution_process_log_entry::Entity::find()
+            .select_only()
+            .column_as(
+                Expr::cust("COALESCE(SUM(LENGTH(CAST(entry_json AS BLOB))), 0)"),
+                "total",
+            )
+            .into_tuple()
+            .one(db)
+            .await?;
+        Ok(total.unwrap_or(0))
+    }
+
     pub async fn fetch_page<C: ConnectionTrait>(
         db: &C,
         execution_id: Uuid,
@@ -307,14 +327,241 @@ impl ExecutionProcessLogEntry {
 
         Ok(())
     }
+
+    /// Deletes every entry of `channel` for the execution process. Returns the number of
+    /// deleted rows.
+    pub async fn delete_channel<C: ConnectionTrait>(
+        db: &C,
+        execution_id: Uuid,
+        channel: LogEntryChannel,
+    ) -> Result<u64, DbErr> {
+        let execution_row_id = ids::execution_process_id_by_uuid(db, execution_id)
+            .await?
+            .ok_or(DbErr::RecordNotFound(
+                "Execution process not found".to_string(),
+            ))?;
+        let channel_value = to_db_channel(channel);
+
+        let result = execution_process_log_entry::Entity::delete_many()
+            .filter(execution_process_log_entry::Column::ExecutionProcessId.eq(execution_row_id))
+            .filter(execution_process_log_entry::Column::Channel.eq(channel_value))
+            .exec(db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Trims the oldest raw entries of every execution process whose raw rows exceed
+    /// `max_bytes` of serialized JSON (in UTF-8 bytes), keeping the newest rows within the cap
+    /// and always at least the newest row, even when it alone is over the cap. Normalized
+    /// entries are never touched. Running processes are skipped since they are still appending,
+    /// and so are processes that still have legacy JSONL logs, otherwise the startup backfill
+    /// would restore the trimmed rows. Returns the number of deleted rows.
+    pub async fn trim_raw_to_max_bytes<C: ConnectionTrait>(
+        db: &C,
+        max_bytes: u64,
+    ) -> Result<u64, DbErr> {
+        let max_bytes_value = i64::try_from(max_bytes).unwrap_or(i64::MAX);
+        let process_ids: Vec<i64> = execution_process_log_entry::Entity::find()
+            .select_only()
+            .column(execution_process_log_entry::Column::ExecutionProcessId)
+            .filter(
+                execution_process_log_entry::Column::Channel
+                    .eq(execution_process_log_entry::LogChannel::Raw),
+            )
+            .group_by(execution_process_log_entry::Column::ExecutionProcessId)
+            .having(Expr::cust_with_values(
+                "SUM(LENGTH(CAST(entry_json AS BLOB))) > ?",
+                [max_bytes_value],
+            ))
+            .into_tuple()
+            .all(db)
+            .await?;
+        if process_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let mut skipped_process_ids: HashSet<i64> = execution_process_log::Entity::find()
+            .select_only()
+            .column(execution_process_log::Column::ExecutionProcessId)
+            .into_tuple::<i64>()
+            .all(db)
+            .await?
+            .into_iter()
+            .collect();
+        skipped_process_ids.extend(
+            execution_process::Entity::find()
+                .select_only()
+                .column(execution_process::Column::Id)
+                .filter(execution_process::Column::Status.eq(ExecutionProcessStatus::Running))
+                .into_tuple::<i64>()
+                .all(db)
+                .await?,
+        );
+
+        let mut deleted = 0u64;
+        for execution_row_id in process_ids {
+            if skipped_process_ids.contains(&execution_row_id) {
+                continue;
+            }
+            deleted += Self::trim_raw_for_process(db, execution_row_id, max_bytes).await?;
+        }
+        Ok(deleted)
+    }
+
+    async fn trim_raw_for_process<C: ConnectionTrait>(
+        db: &C,
+        execution_row_id: i64,
+        max_bytes: u64,
+    ) -> Result<u64, DbErr> {
+        const PAGE_SIZE: u64 = 500;
+
+        let channel_value = execution_process_log_entry::LogChannel::Raw;
+        let mut retained_bytes = 0u64;
+        let mut cursor: Option<i64> = None;
+
+        // Walk newest-to-oldest until the budget is exceeded; that entry and everything older
+        // gets dropped, except the newest entry, which is always kept. Sizes are measured the
+        // same way as the candidate query above; the BLOB cast makes LENGTH count bytes rather
+        // than characters.
+        let mut newest = true;
+        let cutoff = 'scan: loop {
+            let mut query = execution_process_log_entry::Entity::find()
+                .select_only()
+                .column(execution_process_log_entry::Column::EntryIndex)
+                .column_as(
+                    Expr::cust("LENGTH(CAST(entry_json AS BLOB))"),
+                    "entry_bytes",
+                )
+                .filter(
+                    execution_process_log_entry::Column::ExecutionProcessId.eq(execution_row_id),
+                )
+                .filter(execution_process_log_entry::Column::Channel.eq(channel_value.clone()));
+            if let Some(cursor) = cursor {
+                query = query.filter(execution_process_log_entry::Column::EntryIndex.lt(cursor));
+            }
+
+            let rows: Vec<(i64, i64)> = query
+                .order_by_desc(execution_process_log_entry::Column::EntryIndex)
+                .limit(PAGE_SIZE)
+                .into_tuple()
+                .all(db)
+                .await?;
+            let Some((last_index, _)) = rows.last() else {
+                break None;
+            };
+            cursor = Some(*last_index);
+
+            for (entry_index, entry_bytes) in &rows {
+                retained_bytes = retained_bytes.saturating_add(*entry_bytes as u64);
+                if retained_bytes > max_bytes && !newest {
+                    break 'scan Some(*entry_index);
+                }
+                newest = false;
+            }
+        };
+
+        let Some(cutoff) = cutoff else {
+            return Ok(0);
+        };
+
+        let result = execution_process_log_entry::Entity::delete_many()
+            .filter(execution_process_log_entry::Column::ExecutionProcessId.eq(execution_row_id))
+            .filter(execution_process_log_entry::Column::Channel.eq(channel_value))
+            .filter(execution_process_log_entry::Column::EntryIndex.lte(cutoff))
+            .exec(db)
+            .await?;
+        Ok(result.rows_affected)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use sea_orm::Database;
+    use sea_orm::{ActiveModelTrait, Database, IntoActiveModel};
     use sea_orm_migration::MigratorTrait;
 
     use super::*;
+    use crate::{
+        models::{
+            project::{CreateProject, Project},
+            session::{CreateSession, Session},
+            task::{CreateTask, Task},
+            workspace::{CreateWorkspace, Workspace},
+        },
+        types::ExecutionProcessRunReason,
+    };
+
+    async fn create_execution_process(db: &sea_orm::DatabaseConnection) -> Uuid {
+        let project_id = Uuid::new_v4();
+        Project::create(
+            db,
+            &CreateProject {
+                name: "Test project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let task_id = Uuid::new_v4();
+        Task::create(
+            db,
+            &CreateTask::from_title_description(project_id, "Test task".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let workspace_id = Uuid::new_v4();
+        Workspace::create(
+            db,
+            &CreateWorkspace {
+                branch: "main".to_string(),
+                agent_working_dir: None,
+            },
+            workspace_id,
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let session_id = Uuid::new_v4();
+        Session::create(
+            db,
+            &CreateSession {
+                executor: Some("test".to_string()),
+            },
+            session_id,
+            workspace_id,
+        )
+        .await
+        .unwrap();
+        let session_row_id = ids::session_id_by_uuid(db, session_id)
+            .await
+            .unwrap()
+            .expect("session row id");
+
+        let execution_id = Uuid::new_v4();
+        let now = Utc::now();
+        execution_process::ActiveModel {
+            uuid: Set(execution_id),
+            session_id: Set(session_row_id),
+            run_reason: Set(ExecutionProcessRunReason::CodingAgent),
+            executor_action: Set(serde_json::json!({})),
+            status: Set(ExecutionProcessStatus::Completed),
+            exit_code: Set(Some(0)),
+            dropped: Set(false),
+            started_at: Set(now.into()),
+            completed_at: Set(Some(now.into())),
+            created_at: Set(now.into()),
+            updated_at: Set(now.into()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .unwrap();
+        execution_id
+    }
 
     #[tokio::test]
     async fn table_available_detects_missing_schema() {
@@ -324,4 +571,136 @@ mod tests {
         db_migration::Migrator::up(&db, None).await.unwrap();
         assert!(ExecutionProcessLogEntry::table_available(&db).await);
     }
+
+    #[tokio::test]
+    async fn trim_raw_to_max_bytes_drops_oldest_raw_entries_only() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db_migration::Migrator::up(&db, None).await.unwrap();
+        let execution_id = create_execution_process(&db).await;
+
+        let raw_entry = r#"{"type":"STDOUT","content":"0123456789"}"#;
+        let entry_bytes = serde_json::from_str::<serde_json::Value>(raw_entry)
+            .unwrap()
+            .to_string()
+            .len() as u64;
+        let raw_rows: Vec<LogEntryRow> = (0..10)
+            .map(|entry_index| LogEntryRow {
+                entry_index,
+                entry_json: raw_entry.to_string(),
+            })
+            .collect();
+        ExecutionProcessLogEntry::upsert_entries(
+            &db,
+            execution_id,
+            LogEntryChannel::Raw,
+            &raw_rows,
+        )
+        .await
+        .unwrap();
+        ExecutionProcessLogEntry::upsert_entry(
+            &db,
+            execution_id,
+            LogEntryChannel::Normalized,
+            0,
+            r#"{"type":"NORMALIZED_ENTRY","content":{}}"#,
+        )
+        .await
+        .unwrap();
+
+        let deleted = ExecutionProcessLogEntry::trim_raw_to_max_bytes(&db, entry_bytes * 4)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 6);
+
+        let raw = ExecutionProcessLogEntry::stats(&db, execution_id, LogEntryChannel::Raw)
+            .await
+            .unwrap()
+            .expect("raw entries");
+        assert_eq!((raw.count, raw.min_index, raw.max_index), (4, 6, 9));
+        let normalized =
+            ExecutionProcessLogEntry::stats(&db, execution_id, LogEntryChannel::Normalized)
+                .await
+                .unwrap()
+                .expect("normalized entries");
+        assert_eq!(normalized.count, 1);
+
+        let deleted = ExecutionProcessLogEntry::trim_raw_to_max_bytes(&db, entry_bytes * 4)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 0);
+
+        // A running process is still appending and must not be trimmed.
+        let running_id = create_execution_process(&db).await;
+        ExecutionProcessLogEntry::upsert_entries(&db, running_id, LogEntryChannel::Raw, &raw_rows)
+            .await
+            .unwrap();
+        let mut running = execution_process::Entity::find()
+            .filter(execution_process::Column::Uuid.eq(running_id))
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap()
+            .into_active_model();
+        running.status = Set(ExecutionProcessStatus::Running);
+        running.update(&db).await.unwrap();
+        let deleted = ExecutionProcessLogEntry::trim_raw_to_max_bytes(&db, entry_bytes * 4)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 0);
+        let raw = ExecutionProcessLogEntry::stats(&db, running_id, LogEntryChannel::Raw)
+            .await
+            .unwrap()
+            .expect("raw entries");
+        assert_eq!(raw.count, 10);
+    }
+
+    #[tokio::test]
+    async fn trim_raw_to_max_bytes_counts_bytes_and_keeps_the_newest_entry() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db_migration::Migrator::up(&db, None).await.unwrap();
+        let execution_id = create_execution_process(&db).await;
+
+        // 20 characters but 40 bytes of content: a character count would fit both rows in the
+        // cap below, a byte count only the newest.
+        let raw_entry =
+            serde_json::json!({ "type": "STDOUT", "content": "é".repeat(20) }).to_string();
+        let entry_bytes = raw_entry.len() as u64;
+        let raw_rows: Vec<LogEntryRow> = (0..2)
+            .map(|entry_index| LogEntryRow {
+                entry_index,
+                entry_json: raw_entry.clone(),
+            })
+            .collect();
+        ExecutionProcessLogEntry::upsert_entries(
+            &db,
+            execution_id,
+            LogEntryChannel::Raw,
+            &raw_rows,
+        )
+        .await
+        .unwrap();
+
+        let cap = raw_entry.chars().count() as u64 * 2;
+        assert!(cap < entry_bytes * 2);
+        let deleted = ExecutionProcessLogEntry::trim_raw_to_max_bytes(&db, cap)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 1);
+        let raw = ExecutionProcessLogEntry::stats(&db, execution_id, LogEntryChannel::Raw)
+            .await
+            .unwrap()
+            .expect("raw entries");
+        assert_eq!((raw.count, raw.min_index, raw.max_index), (1, 1, 1));
+
+        // The newest entry alone is over the cap and is still kept.
+        let deleted = ExecutionProcessLogEntry::trim_raw_to_max_bytes(&db, entry_bytes / 2)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 0);
+        let raw = ExecutionProcessLogEntry::stats(&db, execution_id, LogEntryChannel::Raw)
+            .await
+            .unwrap()
+            .expect("raw entries");
+        assert_eq!(raw.count, 1);
+    }
 }
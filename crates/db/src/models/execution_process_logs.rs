@@ -94,6 +94,17 @@ impl ExecutionProcessLogs {
         Ok(summaries.into_iter().map(|(_, summary)| summary).collect())
     }
 
+    /// Total bytes stored across all legacy JSONL log rows.
+    pub async fn total_bytes<C: ConnectionTrait>(db: &C) -> Result<i64, DbErr> {
+        let total: Option<Option<i64>> = execution_process_log::Entity::find()
+            .select_only()
+            .column_as(execution_process_log::Column::ByteSize.sum(), "total")
+            .into_tuple()
+            .one(db)
+            .await?;
+        Ok(total.flatten().unwrap_or(0))
+    }
+
     /// Find logs by execution process ID
     pub async fn find_by_execution_id<C: ConnectionTrait>(
         db: &C,
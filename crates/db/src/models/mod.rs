@@ -25,5 +25,6 @@ pub mod tag;
 pub mod task;
 pub mod task_dispatch_state;
 pub mod task_orchestration_state;
+pub mod task_schedule;
 pub mod workspace;
 pub mod workspace_repo;
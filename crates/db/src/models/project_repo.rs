@@ -1,8 +1,9 @@
-use std::path::Path;
+use std::{collections::HashSet, path::Path};
 
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QuerySelect,
+    Set,
 };
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -23,6 +24,19 @@ pub enum ProjectRepoError {
     NotFound,
     #[error("Repository already exists in this project")]
     AlreadyExists,
+    #[error("Repository name '{name}' is already used in this project (try '{suggestion}')")]
+    NameConflict { name: String, suggestion: String },
+}
+
+/// Returns `name` if it is free, otherwise the first free `name-N` (N >= 2).
+pub fn disambiguate_repo_name(name: &str, taken: &HashSet<String>) -> String {
+    if !taken.contains(name) {
+        return name.to_string();
+    }
+    (2..)
+        .map(|suffix| format!("{name}-{suffix}"))
+        .find(|candidate| !taken.contains(candidate))
+        .expect("an unused suffix exists")
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -202,6 +216,29 @@ impl ProjectRepo {
         Ok(record.map(|model| Self::from_model(model, project_id, repo_id)))
     }
 
+    /// Names of the repos already in the project, read from `repos` so renames are reflected.
+    async fn repo_names_in_project<C: ConnectionTrait>(
+        db: &C,
+        project_row_id: i64,
+    ) -> Result<HashSet<String>, DbErr> {
+        let repo_row_ids: Vec<i64> = project_repo::Entity::find()
+            .select_only()
+            .column(project_repo::Column::RepoId)
+            .filter(project_repo::Column::ProjectId.eq(project_row_id))
+            .into_tuple()
+            .all(db)
+            .await?;
+        Ok(repo::Entity::find()
+            .filter(repo::Column::Id.is_in(repo_row_ids))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|model| model.name)
+            .collect())
+    }
+
+    /// Adds the repo at `repo_path` to the project. Fails with `NameConflict` when another repo
+    /// of the project already has the same name, since repo-prefixed paths would be ambiguous.
     pub async fn add_repo_to_project<C: ConnectionTrait>(
         db: &C,
         project_id: Uuid,
@@ -224,6 +261,14 @@ impl ProjectRepo {
             .await?
             .ok_or(DbErr::RecordNotFound("Repo not found".to_string()))?;
 
+        let taken = Self::repo_names_in_project(db, project_row_id).await?;
+        if taken.contains(&repo.name) {
+            return Err(ProjectRepoError::NameConflict {
+                suggestion: disambiguate_repo_name(&repo.name, &taken),
+                name: repo.name,
+            });
+        }
+
         let active = project_repo::ActiveModel {
             uuid: Set(Uuid::new_v4()),
             project_id: Set(project_row_id),
@@ -25,8 +25,13 @@ pub enum ScratchError {
     Database(#[from] DbErr),
     #[error("Scratch type mismatch: expected '{expected}' but got '{actual}'")]
     TypeMismatch { expected: String, actual: String },
+    #[error("Scratch payload too large: {size} bytes (max {max} bytes)")]
+    TooLarge { size: usize, max: usize },
 }
 
+/// Default upper bound for a single serialized scratch payload.
+pub const DEFAULT_SCRATCH_MAX_BYTES: usize = 256 * 1024;
+
 /// Data for a draft follow-up scratch
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct DraftFollowUpData {
@@ -66,6 +71,18 @@ impl ScratchPayload {
         }
         Ok(())
     }
+
+    /// Validates that the serialized payload does not exceed `max_bytes`.
+    pub fn validate_size(&self, max_bytes: usize) -> Result<(), ScratchError> {
+        let size = serde_json::to_vec(self)?.len();
+        if size > max_bytes {
+            return Err(ScratchError::TooLarge {
+                size,
+                max: max_bytes,
+            });
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -261,4 +278,155 @@ impl Scratch {
         }
         Ok(result.rows_affected)
     }
+
+    /// Deletes scratches that have not been updated since `cutoff`.
+    pub async fn prune_updated_before<C: ConnectionTrait>(
+        db: &C,
+        cutoff: DateTime<Utc>,
+    ) -> Result<u64, DbErr> {
+        let records = scratch::Entity::find()
+            .filter(scratch::Column::UpdatedAt.lt(cutoff))
+            .all(db)
+            .await?;
+
+        let mut removed = 0u64;
+        for record in records {
+            let result = scratch::Entity::delete_by_id(record.id).exec(db).await?;
+            if result.rows_affected == 0 {
+                continue;
+            }
+            removed += result.rows_affected;
+
+            let Some(session_id) = ids::session_uuid_by_id(db, record.session_id).await? else {
+                continue;
+            };
+            let payload = serde_json::to_value(ScratchEventPayload {
+                scratch_id: session_id,
+                scratch_type: record.scratch_type,
+            })
+            .map_err(|err| DbErr::Custom(err.to_string()))?;
+            EventOutbox::enqueue(db, EVENT_SCRATCH_DELETED, "scratch", session_id, payload).await?;
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::Database;
+    use sea_orm_migration::MigratorTrait;
+
+    use super::*;
+    use crate::models::{
+        project::{CreateProject, Project},
+        session::{CreateSession, Session},
+        task::{CreateTask, Task},
+        workspace::{CreateWorkspace, Workspace},
+    };
+
+    async fn setup_db() -> sea_orm::DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db_migration::Migrator::up(&db, None).await.unwrap();
+        db
+    }
+
+    async fn create_session(db: &sea_orm::DatabaseConnection) -> Uuid {
+        let project_id = Uuid::new_v4();
+        Project::create(
+            db,
+            &CreateProject {
+                name: "Scratch project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let task_id = Uuid::new_v4();
+        Task::create(
+            db,
+            &CreateTask::from_title_description(project_id, "Scratch".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let workspace_id = Uuid::new_v4();
+        Workspace::create(
+            db,
+            &CreateWorkspace {
+                branch: "main".to_string(),
+                agent_working_dir: None,
+            },
+            workspace_id,
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let session_id = Uuid::new_v4();
+        Session::create(
+            db,
+            &CreateSession { executor: None },
+            session_id,
+            workspace_id,
+        )
+        .await
+        .unwrap();
+        session_id
+    }
+
+    #[test]
+    fn oversized_payload_is_rejected() {
+        let payload = ScratchPayload::DraftTask("x".repeat(64));
+        assert!(payload.validate_size(1024).is_ok());
+
+        let err = payload.validate_size(16).unwrap_err();
+        match err {
+            ScratchError::TooLarge { size, max } => {
+                assert!(size > 64);
+                assert_eq!(max, 16);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn prune_removes_only_expired_scratches() {
+        let db = setup_db().await;
+        let session_id = create_session(&db).await;
+
+        Scratch::create(
+            &db,
+            session_id,
+            &CreateScratch {
+                payload: ScratchPayload::DraftTask("draft".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+        let removed = Scratch::prune_updated_before(&db, Utc::now() - chrono::Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(removed, 0);
+        assert!(
+            Scratch::find_by_id(&db, session_id, &ScratchType::DraftTask)
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        let removed = Scratch::prune_updated_before(&db, Utc::now() + chrono::Duration::minutes(1))
+            .await
+            .unwrap();
+        assert_eq!(removed, 1);
+        assert!(
+            Scratch::find_by_id(&db, session_id, &ScratchType::DraftTask)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
 }
@@ -4,11 +4,30 @@ use sea_orm::{
     Set,
 };
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use ts_rs::TS;
 use uuid::Uuid;
 
 use crate::entities::tag;
 
+#[derive(Debug, Error)]
+pub enum TagError {
+    #[error(transparent)]
+    Database(#[from] DbErr),
+    #[error("Invalid tag name '{0}': tag names must be non-empty and contain no whitespace or '@'")]
+    InvalidName(String),
+    #[error("A tag named '{0}' already exists")]
+    DuplicateName(String),
+}
+
+/// Tag names are referenced as `@name` in prompts, so they must match `@([^\s@]+)` verbatim.
+pub fn validate_tag_name(tag_name: &str) -> Result<(), TagError> {
+    if tag_name.is_empty() || tag_name.chars().any(|c| c.is_whitespace() || c == '@') {
+        return Err(TagError::InvalidName(tag_name.to_string()));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct Tag {
     pub id: Uuid,
@@ -57,7 +76,34 @@ impl Tag {
         Ok(record.map(Self::from_model))
     }
 
-    pub async fn create<C: ConnectionTrait>(db: &C, data: &CreateTag) -> Result<Self, DbErr> {
+    pub async fn find_by_name<C: ConnectionTrait>(
+        db: &C,
+        tag_name: &str,
+    ) -> Result<Option<Self>, DbErr> {
+        let record = tag::Entity::find()
+            .filter(tag::Column::TagName.eq(tag_name))
+            .one(db)
+            .await?;
+        Ok(record.map(Self::from_model))
+    }
+
+    async fn ensure_name_available<C: ConnectionTrait>(
+        db: &C,
+        tag_name: &str,
+        exclude_id: Option<Uuid>,
+    ) -> Result<(), TagError> {
+        validate_tag_name(tag_name)?;
+        if let Some(existing) = Self::find_by_name(db, tag_name).await?
+            && Some(existing.id) != exclude_id
+        {
+            return Err(TagError::DuplicateName(tag_name.to_string()));
+        }
+        Ok(())
+    }
+
+    pub async fn create<C: ConnectionTrait>(db: &C, data: &CreateTag) -> Result<Self, TagError> {
+        Self::ensure_name_available(db, &data.tag_name, None).await?;
+
         let now = Utc::now();
         let active = tag::ActiveModel {
             uuid: Set(Uuid::new_v4()),
@@ -75,12 +121,15 @@ impl Tag {
         db: &C,
         id: Uuid,
         data: &UpdateTag,
-    ) -> Result<Self, DbErr> {
+    ) -> Result<Self, TagError> {
         let record = tag::Entity::find()
             .filter(tag::Column::Uuid.eq(id))
             .one(db)
             .await?
             .ok_or(DbErr::RecordNotFound("Tag not found".to_string()))?;
+        if let Some(tag_name) = data.tag_name.as_deref() {
+            Self::ensure_name_available(db, tag_name, Some(id)).await?;
+        }
 
         let mut active: tag::ActiveModel = record.into();
         if let Some(tag_name) = data.tag_name.clone() {
@@ -103,3 +152,73 @@ impl Tag {
         Ok(result.rows_affected)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::Database;
+    use sea_orm_migration::MigratorTrait;
+
+    use super::*;
+
+    async fn setup_db() -> sea_orm::DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db_migration::Migrator::up(&db, None).await.unwrap();
+        db
+    }
+
+    fn create_tag(tag_name: &str) -> CreateTag {
+        CreateTag {
+            tag_name: tag_name.to_string(),
+            content: "content".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_rejects_whitespace_and_at_in_tag_name() {
+        let db = setup_db().await;
+
+        for name in ["two words", "tab\tname", "", "@mention", "a@b"] {
+            let err = Tag::create(&db, &create_tag(name)).await.unwrap_err();
+            assert!(
+                matches!(err, TagError::InvalidName(_)),
+                "expected InvalidName for {name:?}, got {err:?}"
+            );
+        }
+        assert!(Tag::find_all(&db).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn create_and_rename_reject_duplicate_tag_name() {
+        let db = setup_db().await;
+
+        Tag::create(&db, &create_tag("review")).await.unwrap();
+        let other = Tag::create(&db, &create_tag("plan")).await.unwrap();
+
+        let err = Tag::create(&db, &create_tag("review")).await.unwrap_err();
+        assert!(matches!(err, TagError::DuplicateName(_)));
+
+        let err = Tag::update(
+            &db,
+            other.id,
+            &UpdateTag {
+                tag_name: Some("review".to_string()),
+                content: None,
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, TagError::DuplicateName(_)));
+
+        // Renaming a tag to its own name is not a conflict.
+        Tag::update(
+            &db,
+            other.id,
+            &UpdateTag {
+                tag_name: Some("plan".to_string()),
+                content: Some("updated".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    }
+}
@@ -196,6 +196,10 @@ pub struct UpdateTask {
     pub image_ids: Option<Vec<Uuid>>,
     #[serde(deserialize_with = "deserialize_optional_i32_as_double_option")]
     pub continuation_turns_override: Option<Option<i32>>,
+    /// The `updated_at` the client last observed; the update is rejected if the row changed since.
+    #[serde(default)]
+    #[ts(optional)]
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone)]
@@ -206,6 +210,9 @@ pub struct TaskUpdateParams {
     pub status: TaskStatus,
     pub parent_workspace_id: Option<Uuid>,
     pub continuation_turns_override: Option<Option<i32>>,
+    /// When set, the update only applies while the row's `updated_at` still matches; otherwise
+    /// it fails with `DbErr::RecordNotUpdated`.
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
 fn deserialize_optional_i32_as_double_option<'de, D>(
@@ -1400,6 +1407,7 @@ impl Task {
         let models = task::Entity::find()
             .filter(task::Column::ProjectId.eq(project_row_id))
             .filter(task::Column::ArchivedKanbanId.is_null())
+            .filter(task::Column::DeletedAt.is_null())
             .order_by_desc(task::Column::CreatedAt)
             .all(db)
             .await?;
@@ -1433,6 +1441,7 @@ impl Task {
     ) -> Result<Vec<TaskWithAttemptStatus>, DbErr> {
         let models = task::Entity::find()
             .filter(task::Column::ArchivedKanbanId.is_null())
+            .filter(task::Column::DeletedAt.is_null())
             .order_by_desc(task::Column::CreatedAt)
             .all(db)
             .await?;
@@ -1465,7 +1474,9 @@ impl Task {
             None => None,
         };
 
-        let mut query = task::Entity::find().order_by_desc(task::Column::CreatedAt);
+        let mut query = task::Entity::find()
+            .filter(task::Column::DeletedAt.is_null())
+            .order_by_desc(task::Column::CreatedAt);
 
         if let Some(project_row_id) = project_row_id {
             query = query.filter(task::Column::ProjectId.eq(project_row_id));
@@ -1483,6 +1494,24 @@ impl Task {
     }
 
     pub async fn find_by_id<C: ConnectionTrait>(db: &C, id: Uuid) -> Result<Option<Self>, DbErr> {
+        let record = task::Entity::find()
+            .filter(task::Column::Uuid.eq(id))
+            .filter(task::Column::DeletedAt.is_null())
+            .one(db)
+            .await?;
+
+        match record {
+            Some(model) => Ok(Some(Self::from_model(db, model).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Like `find_by_id`, but also finds soft-deleted tasks, whose attempts keep their
+    /// worktrees until the task is purged.
+    pub async fn find_by_id_including_deleted<C: ConnectionTrait>(
+        db: &C,
+        id: Uuid,
+    ) -> Result<Option<Self>, DbErr> {
         let record = task::Entity::find()
             .filter(task::Column::Uuid.eq(id))
             .one(db)
@@ -1500,6 +1529,7 @@ impl Task {
     ) -> Result<Option<TaskWithAttemptStatus>, DbErr> {
         let record = task::Entity::find()
             .filter(task::Column::Uuid.eq(id))
+            .filter(task::Column::DeletedAt.is_null())
             .one(db)
             .await?;
 
@@ -1553,6 +1583,7 @@ impl Task {
 
         let models = task::Entity::find()
             .filter(task::Column::OriginTaskId.eq(origin_task_row_id))
+            .filter(task::Column::DeletedAt.is_null())
             .order_by_desc(task::Column::CreatedAt)
             .all(db)
             .await?;
@@ -1693,6 +1724,7 @@ impl Task {
             status,
             parent_workspace_id,
             continuation_turns_override,
+            expected_updated_at,
         } = params;
         let project_row_id = ids::project_id_by_uuid(db, project_id)
             .await?
@@ -1712,6 +1744,7 @@ impl Task {
             return Err(Self::archived_task_write_error());
         }
 
+        let record_id = record.id;
         let status_changed = record.status != status;
         let milestone_id = record.milestone_id;
         let task_kind = record.task_kind.clone();
@@ -1733,7 +1766,21 @@ impl Task {
         }
         active.updated_at = Set(Utc::now().into());
 
-        let updated = active.update(db).await?;
+        // The `updated_at` check is part of the UPDATE so a concurrent writer between the read
+        // above and this statement is still detected.
+        let mut update = task::Entity::update_many()
+            .set(active)
+            .filter(task::Column::Id.eq(record_id));
+        if let Some(expected) = expected_updated_at {
+            update = update.filter(task::Column::UpdatedAt.eq(expected));
+        }
+        if update.exec(db).await?.rows_affected == 0 {
+            return Err(DbErr::RecordNotUpdated);
+        }
+        let updated = task::Entity::find_by_id(record_id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Task not found".to_string()))?;
         let payload = serde_json::to_value(TaskEventPayload {
             task_id: id,
             project_id,
@@ -1871,6 +1918,47 @@ impl Task {
         Ok(())
     }
 
+    /// Reassigns a task to another project. Its attempts stay attached to the task; the parent
+    /// workspace link is cleared because it points into the source project.
+    pub async fn move_to_project<C: ConnectionTrait>(
+        db: &C,
+        task_id: Uuid,
+        target_project_id: Uuid,
+    ) -> Result<Self, DbErr> {
+        let target_project_row_id = ids::project_id_by_uuid(db, target_project_id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Project not found".to_string()))?;
+
+        let record = task::Entity::find()
+            .filter(task::Column::Uuid.eq(task_id))
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        if record.archived_kanban_id.is_some() {
+            return Err(Self::archived_task_write_error());
+        }
+
+        if record.project_id == target_project_row_id {
+            return Self::from_model(db, record).await;
+        }
+
+        let mut active: task::ActiveModel = record.into();
+        active.project_id = Set(target_project_row_id);
+        active.parent_workspace_id = Set(None);
+        active.updated_at = Set(Utc::now().into());
+        let updated = active.update(db).await?;
+
+        let payload = serde_json::to_value(TaskEventPayload {
+            task_id,
+            project_id: target_project_id,
+        })
+        .map_err(|err| DbErr::Custom(err.to_string()))?;
+        EventOutbox::enqueue(db, EVENT_TASK_UPDATED, "task", task_id, payload).await?;
+
+        Self::from_model(db, updated).await
+    }
+
     pub async fn nullify_children_by_workspace_id<C: ConnectionTrait>(
         db: &C,
         workspace_id: Uuid,
@@ -1975,6 +2063,104 @@ impl Task {
         Self::delete_allow_archived(db, id).await
     }
 
+    /// Hides a task from every listing until it is restored or pruned. Returns 0 when the task
+    /// does not exist or is already soft-deleted.
+    pub async fn soft_delete<C: ConnectionTrait>(db: &C, id: Uuid) -> Result<u64, DbErr> {
+        let record = task::Entity::find()
+            .filter(task::Column::Uuid.eq(id))
+            .filter(task::Column::DeletedAt.is_null())
+            .one(db)
+            .await?;
+
+        let Some(record) = record else {
+            return Ok(0);
+        };
+
+        if record.archived_kanban_id.is_some() {
+            return Err(DbErr::Custom(
+                "Task is archived. Delete its archive to remove it.".to_string(),
+            ));
+        }
+
+        let project_id = ids::project_uuid_by_id(db, record.project_id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Project not found".to_string()))?;
+
+        let now = Utc::now();
+        let mut active: task::ActiveModel = record.into();
+        active.deleted_at = Set(Some(now.into()));
+        active.updated_at = Set(now.into());
+        active.update(db).await?;
+
+        let payload = serde_json::to_value(TaskEventPayload {
+            task_id: id,
+            project_id,
+        })
+        .map_err(|err| DbErr::Custom(err.to_string()))?;
+        EventOutbox::enqueue(db, EVENT_TASK_DELETED, "task", id, payload).await?;
+
+        Ok(1)
+    }
+
+    /// Brings back a soft-deleted task, provided it was deleted at or after `deleted_after`.
+    pub async fn restore_deleted<C: ConnectionTrait>(
+        db: &C,
+        id: Uuid,
+        deleted_after: DateTime<Utc>,
+    ) -> Result<Self, DbErr> {
+        let record = task::Entity::find()
+            .filter(task::Column::Uuid.eq(id))
+            .filter(task::Column::DeletedAt.is_not_null())
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Deleted task not found".to_string()))?;
+
+        if record
+            .deleted_at
+            .is_some_and(|deleted_at| deleted_at < deleted_after)
+        {
+            return Err(DbErr::Custom(
+                "Task recovery window has passed.".to_string(),
+            ));
+        }
+
+        let project_id = ids::project_uuid_by_id(db, record.project_id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Project not found".to_string()))?;
+
+        let mut active: task::ActiveModel = record.into();
+        active.deleted_at = Set(None);
+        active.updated_at = Set(Utc::now().into());
+        let restored = active.update(db).await?;
+
+        let payload = serde_json::to_value(TaskEventPayload {
+            task_id: id,
+            project_id,
+        })
+        .map_err(|err| DbErr::Custom(err.to_string()))?;
+        EventOutbox::enqueue(db, EVENT_TASK_CREATED, "task", id, payload).await?;
+
+        Self::from_model(db, restored).await
+    }
+
+    /// Soft-deleted tasks whose recovery window ended before `cutoff`, oldest first.
+    pub async fn find_deleted_before<C: ConnectionTrait>(
+        db: &C,
+        cutoff: DateTime<Utc>,
+    ) -> Result<Vec<Self>, DbErr> {
+        let models = task::Entity::find()
+            .filter(task::Column::DeletedAt.lt(cutoff))
+            .order_by_asc(task::Column::DeletedAt)
+            .all(db)
+            .await?;
+
+        let mut tasks = Vec::with_capacity(models.len());
+        for model in models {
+            tasks.push(Self::from_model(db, model).await?);
+        }
+        Ok(tasks)
+    }
+
     pub async fn delete_allow_archived<C: ConnectionTrait>(db: &C, id: Uuid) -> Result<u64, DbErr> {
         let record = task::Entity::find()
             .filter(task::Column::Uuid.eq(id))
@@ -2570,4 +2756,59 @@ mod tests {
             super::TaskContinuationBudgetSource::ProjectDefault
         ));
     }
+
+    #[tokio::test]
+    async fn soft_deleted_task_is_hidden_until_restored() {
+        let db = setup_db().await;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            &db,
+            &CreateProject {
+                name: "Soft delete".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let task_id = Uuid::new_v4();
+        Task::create(
+            &db,
+            &CreateTask::from_title_description(project_id, "Oops".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(Task::soft_delete(&db, task_id).await.unwrap(), 1);
+        assert_eq!(Task::soft_delete(&db, task_id).await.unwrap(), 0);
+        assert!(Task::find_by_id(&db, task_id).await.unwrap().is_none());
+        assert!(
+            Task::find_by_project_id_with_attempt_status(&db, project_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let outside_window = chrono::Utc::now() + chrono::Duration::seconds(60);
+        let err = Task::restore_deleted(&db, task_id, outside_window)
+            .await
+            .expect_err("recovery window has passed");
+        assert!(matches!(err, sea_orm::DbErr::Custom(_)));
+
+        let within_window = chrono::Utc::now() - chrono::Duration::seconds(60);
+        let restored = Task::restore_deleted(&db, task_id, within_window)
+            .await
+            .unwrap();
+        assert_eq!(restored.id, task_id);
+        assert!(Task::find_by_id(&db, task_id).await.unwrap().is_some());
+        assert!(
+            Task::find_deleted_before(&db, chrono::Utc::now() + chrono::Duration::seconds(60))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
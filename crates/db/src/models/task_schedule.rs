@@ -0,0 +1,230 @@
+use chrono::{DateTime, Utc};
+use executors_protocol::ExecutorProfileId;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{entities::task_schedule, models::ids};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+pub struct TaskScheduleRepo {
+    pub repo_id: Uuid,
+    pub target_branch: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct TaskSchedule {
+    pub task_id: Uuid,
+    pub cron: String,
+    pub executor_profile_id: ExecutorProfileId,
+    pub repos: Vec<TaskScheduleRepo>,
+    pub enabled: bool,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_workspace_id: Option<Uuid>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpsertTaskSchedule {
+    pub cron: String,
+    pub executor_profile_id: ExecutorProfileId,
+    pub repos: Vec<TaskScheduleRepo>,
+    pub enabled: bool,
+    pub next_run_at: Option<DateTime<Utc>>,
+}
+
+/// Outcome of a scheduler tick for a single schedule.
+#[derive(Debug, Clone)]
+pub enum TaskScheduleRunOutcome {
+    Started { workspace_id: Uuid },
+    Skipped { reason: String },
+    Failed { error: String },
+}
+
+impl TaskSchedule {
+    fn from_model(model: task_schedule::Model, task_id: Uuid) -> Result<Self, DbErr> {
+        let executor_profile_id = serde_json::from_value(model.executor_profile_id)
+            .map_err(|err| DbErr::Custom(err.to_string()))?;
+        let repos =
+            serde_json::from_value(model.repos).map_err(|err| DbErr::Custom(err.to_string()))?;
+
+        Ok(Self {
+            task_id,
+            cron: model.cron,
+            executor_profile_id,
+            repos,
+            enabled: model.enabled,
+            next_run_at: model.next_run_at.map(Into::into),
+            last_run_at: model.last_run_at.map(Into::into),
+            last_workspace_id: model.last_workspace_id,
+            last_error: model.last_error,
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        })
+    }
+
+    async fn find_model_by_task_id<C: ConnectionTrait>(
+        db: &C,
+        task_id: Uuid,
+    ) -> Result<(i64, Option<task_schedule::Model>), DbErr> {
+        let task_row_id = ids::task_id_by_uuid(db, task_id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        let record = task_schedule::Entity::find()
+            .filter(task_schedule::Column::TaskId.eq(task_row_id))
+            .one(db)
+            .await?;
+
+        Ok((task_row_id, record))
+    }
+
+    pub async fn find_by_task_id<C: ConnectionTrait>(
+        db: &C,
+        task_id: Uuid,
+    ) -> Result<Option<Self>, DbErr> {
+        let (_, record) = Self::find_model_by_task_id(db, task_id).await?;
+        record
+            .map(|model| Self::from_model(model, task_id))
+            .transpose()
+    }
+
+    /// Enabled schedules whose `next_run_at` is at or before `now`, oldest first.
+    pub async fn find_due<C: ConnectionTrait>(
+        db: &C,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Self>, DbErr> {
+        let records = task_schedule::Entity::find()
+            .filter(task_schedule::Column::Enabled.eq(true))
+            .filter(task_schedule::Column::NextRunAt.is_not_null())
+            .filter(task_schedule::Column::NextRunAt.lte(now))
+            .order_by_asc(task_schedule::Column::NextRunAt)
+            .all(db)
+            .await?;
+
+        let mut schedules = Vec::with_capacity(records.len());
+        for model in records {
+            let Some(task_id) = ids::task_uuid_by_id(db, model.task_id).await? else {
+                continue;
+            };
+            schedules.push(Self::from_model(model, task_id)?);
+        }
+        Ok(schedules)
+    }
+
+    pub async fn upsert<C: ConnectionTrait>(
+        db: &C,
+        task_id: Uuid,
+        data: &UpsertTaskSchedule,
+    ) -> Result<Self, DbErr> {
+        let (task_row_id, existing) = Self::find_model_by_task_id(db, task_id).await?;
+        let executor_profile_id = serde_json::to_value(&data.executor_profile_id)
+            .map_err(|err| DbErr::Custom(err.to_string()))?;
+        let repos =
+            serde_json::to_value(&data.repos).map_err(|err| DbErr::Custom(err.to_string()))?;
+        let now = Utc::now();
+
+        let model = if let Some(existing) = existing {
+            let mut active: task_schedule::ActiveModel = existing.into();
+            active.cron = Set(data.cron.clone());
+            active.executor_profile_id = Set(executor_profile_id);
+            active.repos = Set(repos);
+            active.enabled = Set(data.enabled);
+            active.next_run_at = Set(data.next_run_at.map(Into::into));
+            active.last_error = Set(None);
+            active.updated_at = Set(now.into());
+            active.update(db).await?
+        } else {
+            let active = task_schedule::ActiveModel {
+                task_id: Set(task_row_id),
+                cron: Set(data.cron.clone()),
+                executor_profile_id: Set(executor_profile_id),
+                repos: Set(repos),
+                enabled: Set(data.enabled),
+                next_run_at: Set(data.next_run_at.map(Into::into)),
+                last_run_at: Set(None),
+                last_workspace_id: Set(None),
+                last_error: Set(None),
+                created_at: Set(now.into()),
+                updated_at: Set(now.into()),
+                ..Default::default()
+            };
+            active.insert(db).await?
+        };
+
+        Self::from_model(model, task_id)
+    }
+
+    pub async fn delete_by_task_id<C: ConnectionTrait>(
+        db: &C,
+        task_id: Uuid,
+    ) -> Result<u64, DbErr> {
+        let (task_row_id, _) = Self::find_model_by_task_id(db, task_id).await?;
+        let result = task_schedule::Entity::delete_many()
+            .filter(task_schedule::Column::TaskId.eq(task_row_id))
+            .exec(db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Turn off the task's schedule, if it has one, so it stops firing. Re-enabling it goes
+    /// through [`Self::upsert`], which computes a fresh `next_run_at`.
+    pub async fn disable_by_task_id<C: ConnectionTrait>(
+        db: &C,
+        task_id: Uuid,
+    ) -> Result<(), DbErr> {
+        let (_, existing) = Self::find_model_by_task_id(db, task_id).await?;
+        let Some(existing) = existing.filter(|model| model.enabled) else {
+            return Ok(());
+        };
+
+        let mut active: task_schedule::ActiveModel = existing.into();
+        active.enabled = Set(false);
+        active.next_run_at = Set(None);
+        active.updated_at = Set(Utc::now().into());
+        active.update(db).await?;
+        Ok(())
+    }
+
+    /// Record the outcome of a due run and advance the schedule to `next_run_at`.
+    pub async fn record_run<C: ConnectionTrait>(
+        db: &C,
+        task_id: Uuid,
+        ran_at: DateTime<Utc>,
+        outcome: &TaskScheduleRunOutcome,
+        next_run_at: Option<DateTime<Utc>>,
+    ) -> Result<(), DbErr> {
+        let (_, existing) = Self::find_model_by_task_id(db, task_id).await?;
+        let Some(existing) = existing else {
+            return Ok(());
+        };
+
+        let mut active: task_schedule::ActiveModel = existing.into();
+        match outcome {
+            TaskScheduleRunOutcome::Started { workspace_id } => {
+                active.last_run_at = Set(Some(ran_at.into()));
+                active.last_workspace_id = Set(Some(*workspace_id));
+                active.last_error = Set(None);
+            }
+            TaskScheduleRunOutcome::Skipped { reason } => {
+                active.last_error = Set(Some(reason.clone()));
+            }
+            TaskScheduleRunOutcome::Failed { error } => {
+                active.last_run_at = Set(Some(ran_at.into()));
+                active.last_error = Set(Some(error.clone()));
+            }
+        }
+        active.next_run_at = Set(next_run_at.map(Into::into));
+        active.updated_at = Set(Utc::now().into());
+        active.update(db).await?;
+
+        Ok(())
+    }
+}
@@ -51,6 +51,7 @@ pub struct Workspace {
     pub container_ref: Option<String>,
     pub branch: String,
     pub agent_working_dir: Option<String>,
+    pub labels: Vec<String>,
     pub setup_completed_at: Option<DateTime<Utc>>,
     pub latest_hook_run: Option<WorkspaceLifecycleHookRunSummary>,
     pub after_prepare_hook_status: Option<WorkspaceLifecycleHookStatus>,
@@ -111,6 +112,18 @@ pub struct CreateWorkspace {
     pub agent_working_dir: Option<String>,
 }
 
+/// Trim labels, drop empty ones and de-duplicate while keeping the caller's order.
+pub fn normalize_labels(labels: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(labels.len());
+    for label in labels {
+        let label = label.trim();
+        if !label.is_empty() && !normalized.iter().any(|existing| existing == label) {
+            normalized.push(label.to_string());
+        }
+    }
+    normalized
+}
+
 fn latest_hook_run_from_model(
     model: &workspace::Model,
 ) -> Option<WorkspaceLifecycleHookRunSummary> {
@@ -157,12 +170,18 @@ fn latest_hook_run_from_model(
 impl Workspace {
     fn from_model(model: workspace::Model, task_id: Uuid) -> Self {
         let latest_hook_run = latest_hook_run_from_model(&model);
+        let labels = model
+            .labels
+            .clone()
+            .and_then(|value| serde_json::from_value(value).ok())
+            .unwrap_or_default();
         Self {
             id: model.uuid,
             task_id,
             container_ref: model.container_ref.clone(),
             branch: model.branch,
             agent_working_dir: model.agent_working_dir,
+            labels,
             setup_completed_at: model.setup_completed_at.map(Into::into),
             latest_hook_run,
             after_prepare_hook_status: model.after_prepare_hook_status,
@@ -176,6 +195,11 @@ impl Workspace {
         }
     }
 
+    pub fn has_label(&self, label: &str) -> bool {
+        let label = label.trim();
+        self.labels.iter().any(|existing| existing == label)
+    }
+
     pub async fn parent_task<C: ConnectionTrait>(&self, db: &C) -> Result<Option<Task>, DbErr> {
         Task::find_by_id(db, self.task_id).await
     }
@@ -496,6 +520,7 @@ impl Workspace {
             before_cleanup_hook_status: Set(None),
             before_cleanup_hook_ran_at: Set(None),
             before_cleanup_hook_error_summary: Set(None),
+            labels: Set(None),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
             ..Default::default()
@@ -560,6 +585,48 @@ impl Workspace {
         Ok(())
     }
 
+    /// Replace the workspace labels with the normalized `labels`.
+    pub async fn set_labels<C: ConnectionTrait>(
+        db: &C,
+        workspace_id: Uuid,
+        labels: &[String],
+    ) -> Result<Self, WorkspaceError> {
+        let record = workspace::Entity::find()
+            .filter(workspace::Column::Uuid.eq(workspace_id))
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Workspace not found".to_string()))?;
+
+        let task_id = ids::task_uuid_by_id(db, record.task_id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Task not found".to_string()))?;
+        let labels = normalize_labels(labels);
+        let labels = if labels.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_value(&labels).map_err(|err| DbErr::Custom(err.to_string()))?)
+        };
+
+        let mut active: workspace::ActiveModel = record.into();
+        active.labels = Set(labels);
+        active.updated_at = Set(Utc::now().into());
+        let model = active.update(db).await?;
+        let payload = serde_json::to_value(WorkspaceEventPayload {
+            workspace_id,
+            task_id,
+        })
+        .map_err(|err| DbErr::Custom(err.to_string()))?;
+        EventOutbox::enqueue(
+            db,
+            EVENT_WORKSPACE_UPDATED,
+            "workspace",
+            workspace_id,
+            payload,
+        )
+        .await?;
+        Ok(Self::from_model(model, task_id))
+    }
+
     pub async fn update_branch_name<C: ConnectionTrait>(
         db: &C,
         workspace_id: Uuid,
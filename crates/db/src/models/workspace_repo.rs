@@ -21,6 +21,8 @@ pub struct WorkspaceRepo {
     pub workspace_id: Uuid,
     pub repo_id: Uuid,
     pub target_branch: String,
+    /// Sparse-checkout patterns for this repo's worktree; empty = full checkout.
+    pub sparse_paths: Vec<String>,
     #[ts(type = "Date")]
     pub created_at: DateTime<Utc>,
     #[ts(type = "Date")]
@@ -31,6 +33,8 @@ pub struct WorkspaceRepo {
 pub struct CreateWorkspaceRepo {
     pub repo_id: Uuid,
     pub target_branch: String,
+    #[serde(default)]
+    pub sparse_paths: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -39,6 +43,7 @@ pub struct RepoWithTargetBranch {
     #[serde(flatten)]
     pub repo: Repo,
     pub target_branch: String,
+    pub sparse_paths: Vec<String>,
 }
 
 /// Repo info with copy_files configuration from project_repos.
@@ -50,13 +55,35 @@ pub struct RepoWithCopyFiles {
     pub copy_files: Option<String>,
 }
 
+/// Trim sparse-checkout patterns, drop empty ones and de-duplicate while keeping the caller's order.
+pub fn normalize_sparse_paths(paths: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(paths.len());
+    for path in paths {
+        let path = path.trim();
+        if !path.is_empty() && !normalized.iter().any(|existing| existing == path) {
+            normalized.push(path.to_string());
+        }
+    }
+    normalized
+}
+
+fn sparse_paths_from_model(model: &workspace_repo::Model) -> Vec<String> {
+    model
+        .sparse_paths
+        .clone()
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
 impl WorkspaceRepo {
     fn from_model(model: workspace_repo::Model, workspace_id: Uuid, repo_id: Uuid) -> Self {
+        let sparse_paths = sparse_paths_from_model(&model);
         Self {
             id: model.uuid,
             workspace_id,
             repo_id,
             target_branch: model.target_branch,
+            sparse_paths,
             created_at: model.created_at.into(),
             updated_at: model.updated_at.into(),
         }
@@ -76,11 +103,21 @@ impl WorkspaceRepo {
             let repo_row_id = ids::repo_id_by_uuid(db, repo.repo_id)
                 .await?
                 .ok_or(DbErr::RecordNotFound("Repo not found".to_string()))?;
+            let sparse_paths = normalize_sparse_paths(&repo.sparse_paths);
+            let sparse_paths = if sparse_paths.is_empty() {
+                None
+            } else {
+                Some(
+                    serde_json::to_value(&sparse_paths)
+                        .map_err(|err| DbErr::Custom(err.to_string()))?,
+                )
+            };
             let active = workspace_repo::ActiveModel {
                 uuid: Set(Uuid::new_v4()),
                 workspace_id: Set(workspace_row_id),
                 repo_id: Set(repo_row_id),
                 target_branch: Set(repo.target_branch.clone()),
+                sparse_paths: Set(sparse_paths),
                 created_at: Set(Utc::now().into()),
                 updated_at: Set(Utc::now().into()),
                 ..Default::default()
@@ -156,9 +193,11 @@ impl WorkspaceRepo {
                 .one(db)
                 .await?
                 .ok_or(DbErr::RecordNotFound("Repo not found".to_string()))?;
+            let sparse_paths = sparse_paths_from_model(&model);
             repos.push(RepoWithTargetBranch {
                 repo: Repo::from(repo_model),
                 target_branch: model.target_branch,
+                sparse_paths,
             });
         }
         repos.sort_by(|a, b| a.repo.display_name.cmp(&b.repo.display_name));
@@ -88,6 +88,8 @@ pub enum TaskCreatedByKind {
     AgentFollowup,
     #[sea_orm(string_value = "milestone_planner")]
     MilestonePlanner,
+    #[sea_orm(string_value = "webhook")]
+    Webhook,
 }
 
 #[derive(
@@ -398,6 +400,18 @@ pub enum ExecutionProcessStatus {
     Killed,
 }
 
+/// Why an execution process failed, when the status alone does not say.
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, TS)]
+#[sea_orm(rs_type = "String", db_type = "Text")]
+#[serde(rename_all = "snake_case")]
+#[ts(use_ts_enum)]
+pub enum ExecutionProcessFailureKind {
+    #[sea_orm(string_value = "timed_out")]
+    TimedOut,
+    #[sea_orm(string_value = "idle_timeout")]
+    IdleTimeout,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, TS)]
 #[sea_orm(rs_type = "String", db_type = "Text")]
 #[serde(rename_all = "lowercase")]
@@ -1,6 +1,6 @@
 use std::{
     collections::HashSet,
-    fs,
+    fs, io,
     path::{Path, PathBuf},
 };
 
@@ -9,6 +9,84 @@ use globwalk::GlobWalkerBuilder;
 
 use super::ContainerError;
 
+/// How a file ended up at its target path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CopyMethod {
+    /// Copy-on-write clone sharing extents with the source.
+    Reflink,
+    /// Regular byte copy.
+    Copy,
+}
+
+/// Copy `source` to `target`, preferring a copy-on-write clone (APFS `clonefile`, Btrfs/XFS
+/// `FICLONE`) and falling back to a regular copy when the filesystem or platform can't clone.
+pub(crate) fn clone_or_copy_file(source: &Path, target: &Path) -> io::Result<CopyMethod> {
+    match reflink_file(source, target) {
+        Ok(()) => return Ok(CopyMethod::Reflink),
+        Err(e) => tracing::trace!(
+            "Reflink of {} unavailable, copying instead: {e}",
+            source.display()
+        ),
+    }
+    fs::copy(source, target)?;
+    Ok(CopyMethod::Copy)
+}
+
+/// Clone `source` into a new file at `target`. On failure no file is left at `target`.
+#[cfg(target_os = "linux")]
+fn reflink_file(source: &Path, target: &Path) -> io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    // _IOW(0x94, 9, int) from linux/fs.h.
+    const FICLONE: u64 = 0x4004_9409;
+
+    let source_file = fs::File::open(source)?;
+    let target_file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(target)?;
+    // SAFETY: both descriptors are open for the duration of the call.
+    let rc = unsafe {
+        nix::libc::ioctl(
+            target_file.as_raw_fd(),
+            FICLONE as _,
+            source_file.as_raw_fd(),
+        )
+    };
+    let result = if rc == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        source_file
+            .metadata()
+            .and_then(|metadata| target_file.set_permissions(metadata.permissions()))
+    };
+    if result.is_err() {
+        drop(target_file);
+        let _ = fs::remove_file(target);
+    }
+    result
+}
+
+/// Clone `source` into a new file at `target`. On failure no file is left at `target`.
+#[cfg(target_os = "macos")]
+fn reflink_file(source: &Path, target: &Path) -> io::Result<()> {
+    use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+    let source_c = CString::new(source.as_os_str().as_bytes())?;
+    let target_c = CString::new(target.as_os_str().as_bytes())?;
+    // SAFETY: both pointers come from live NUL-terminated strings.
+    let rc = unsafe { nix::libc::clonefile(source_c.as_ptr(), target_c.as_ptr(), 0) };
+    if rc == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn reflink_file(_source: &Path, _target: &Path) -> io::Result<()> {
+    Err(io::Error::from(io::ErrorKind::Unsupported))
+}
+
 /// Normalize pattern for cross-platform glob matching (convert backslashes to forward slashes)
 fn normalize_pattern(pattern: &str) -> String {
     pattern.replace('\\', "/")
@@ -110,7 +188,7 @@ fn copy_single_file(
     {
         fs::create_dir_all(parent)?;
     }
-    fs::copy(source_file, &target_file)?;
+    clone_or_copy_file(source_file, &target_file)?;
 
     Ok(true)
 }
@@ -310,6 +388,37 @@ mod tests {
         assert_eq!(fs::read_to_string(target_file).unwrap(), "library code");
     }
 
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[test]
+    fn test_clone_or_copy_file_attempts_reflink_and_falls_back() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("source.bin");
+        fs::write(&source, "copy-on-write contents").unwrap();
+
+        // Whether the clone succeeds depends on the filesystem backing the temp dir; either way a
+        // failed attempt must not leave a partial file behind.
+        let probe = dir.path().join("probe.bin");
+        let reflink_supported = reflink_file(&source, &probe).is_ok();
+        assert_eq!(probe.exists(), reflink_supported);
+
+        let target = dir.path().join("target.bin");
+        let method = clone_or_copy_file(&source, &target).unwrap();
+        let expected = if reflink_supported {
+            CopyMethod::Reflink
+        } else {
+            CopyMethod::Copy
+        };
+        assert_eq!(method, expected);
+        assert_eq!(
+            fs::read_to_string(&target).unwrap(),
+            "copy-on-write contents"
+        );
+        assert_eq!(
+            fs::metadata(&target).unwrap().permissions(),
+            fs::metadata(&source).unwrap().permissions()
+        );
+    }
+
     #[cfg(unix)]
     #[test]
     fn test_symlink_loop_is_skipped() {
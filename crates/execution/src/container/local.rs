@@ -1,8 +1,8 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
     io,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, OnceLock},
     time::Duration,
 };
 
@@ -10,7 +10,7 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use chrono::{Duration as ChronoDuration, Utc};
 use command_group::AsyncGroupChild;
-use config::Config;
+use config::{Config, ExecutionCapacityPolicy};
 use db::{
     DBService, DbErr,
     models::{
@@ -18,6 +18,7 @@ use db::{
         execution_process::{
             ExecutionContext, ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus,
         },
+        execution_process_log_entries::ExecutionProcessLogEntry,
         execution_process_logs::ExecutionProcessLogs,
         execution_process_repo_state::ExecutionProcessRepoState,
         milestone::Milestone,
@@ -72,7 +73,10 @@ use tasks::{
         strip_vk_next_lines,
     },
 };
-use tokio::{sync::RwLock, task::JoinHandle};
+use tokio::{
+    sync::{Notify, RwLock},
+    task::JoinHandle,
+};
 use tokio_util::{io::ReaderStream, sync::CancellationToken};
 use utils_core::{
     diff::DiffSummary,
@@ -81,11 +85,15 @@ use utils_core::{
 };
 use uuid::Uuid;
 
-use super::{ContainerError, ContainerRef, ContainerService, DiffStreamOptions, command, copy};
+use super::{
+    ContainerError, ContainerRef, ContainerService, DiffStreamOptions, ExecutionCapacity, command,
+    copy, startup_progress::StartupProgressReporter,
+};
 use crate::{
     diff_stream::{self, DiffStreamHandle},
     image::ImageService,
     queued_message::QueuedMessageService,
+    turn_summary::{SharedTurnSummarizer, resolve_turn_summary},
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -108,6 +116,116 @@ impl FinalizationTracker {
     }
 }
 
+/// Coding agent executions holding a concurrency slot, plus the FIFO of starts waiting for one.
+#[derive(Clone, Default)]
+struct ExecutionSlots {
+    state: Arc<std::sync::Mutex<ExecutionSlotsState>>,
+    released: Arc<Notify>,
+}
+
+#[derive(Default)]
+struct ExecutionSlotsState {
+    running: HashSet<Uuid>,
+    queued: VecDeque<Uuid>,
+}
+
+impl ExecutionSlots {
+    fn lock(&self) -> std::sync::MutexGuard<'_, ExecutionSlotsState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Take a slot if one is free and no earlier start is queued ahead of this one.
+    fn try_acquire(&self, execution_process_id: Uuid, limit: Option<usize>) -> bool {
+        let mut state = self.lock();
+        if state.running.contains(&execution_process_id) {
+            return true;
+        }
+        let position = state
+            .queued
+            .iter()
+            .position(|queued| *queued == execution_process_id);
+        let ahead = position.unwrap_or(state.queued.len());
+        if limit.is_some_and(|limit| state.running.len() + ahead >= limit) {
+            return false;
+        }
+        if let Some(position) = position {
+            state.queued.remove(position);
+            // Let the next waiter re-check in case more than one slot was freed.
+            self.released.notify_waiters();
+        }
+        state.running.insert(execution_process_id);
+        true
+    }
+
+    fn check(
+        &self,
+        limit: Option<usize>,
+        policy: ExecutionCapacityPolicy,
+    ) -> Result<(), ContainerError> {
+        let Some(limit) = limit else {
+            return Ok(());
+        };
+        let running = self.lock().running.len();
+        if policy == ExecutionCapacityPolicy::Reject && running >= limit {
+            return Err(ContainerError::CapacityExceeded { running, limit });
+        }
+        Ok(())
+    }
+
+    /// Returns `Ok(false)` when the start was queued behind the limit.
+    fn admit(
+        &self,
+        execution_process_id: Uuid,
+        limit: Option<usize>,
+        policy: ExecutionCapacityPolicy,
+    ) -> Result<bool, ContainerError> {
+        if self.try_acquire(execution_process_id, limit) {
+            return Ok(true);
+        }
+        match policy {
+            ExecutionCapacityPolicy::Queue => {
+                let mut state = self.lock();
+                if !state.queued.contains(&execution_process_id) {
+                    state.queued.push_back(execution_process_id);
+                }
+                Ok(false)
+            }
+            ExecutionCapacityPolicy::Reject => Err(ContainerError::CapacityExceeded {
+                running: self.lock().running.len(),
+                limit: limit.unwrap_or_default(),
+            }),
+        }
+    }
+
+    fn is_queued(&self, execution_process_id: Uuid) -> bool {
+        self.lock().queued.contains(&execution_process_id)
+    }
+
+    fn cancel(&self, execution_process_id: Uuid) {
+        let mut state = self.lock();
+        let before = state.queued.len();
+        state
+            .queued
+            .retain(|queued| *queued != execution_process_id);
+        if state.queued.len() != before {
+            self.released.notify_waiters();
+        }
+    }
+
+    fn release(&self, execution_process_id: Uuid) {
+        if self.lock().running.remove(&execution_process_id) {
+            self.released.notify_waiters();
+        }
+    }
+
+    fn counts(&self) -> (usize, usize) {
+        let state = self.lock();
+        (state.running.len(), state.queued.len())
+    }
+}
+
 #[derive(Debug, Clone)]
 struct WorkspaceHookProject {
     id: Uuid,
@@ -126,8 +244,24 @@ const DEFAULT_WORKSPACE_CLEANUP_INTERVAL_SECS: u64 = 60 * 30; // 30 minutes
 const MIN_WORKSPACE_EXPIRED_TTL_SECS: i64 = 60; // 1 minute
 const MIN_WORKSPACE_CLEANUP_INTERVAL_SECS: u64 = 10; // 10 seconds
 
+/// Orphaned workspace directories younger than this are left alone by the periodic reclaim so an
+/// attempt that is still creating its worktrees is never swept.
+const ORPHAN_WORKSPACE_MIN_AGE: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+const RAW_LOG_MAX_BYTES_ENV: &str = "VK_RAW_LOG_MAX_BYTES_PER_PROCESS";
+const RAW_LOG_RETENTION_INTERVAL_ENV: &str = "VK_RAW_LOG_RETENTION_INTERVAL_SECS";
+
+const DEFAULT_RAW_LOG_MAX_BYTES: u64 = 64 * 1024 * 1024; // 64 MiB
+const DEFAULT_RAW_LOG_RETENTION_INTERVAL_SECS: u64 = 60 * 60; // 1 hour
+const MIN_RAW_LOG_RETENTION_INTERVAL_SECS: u64 = 10; // 10 seconds
+
 const HOOK_OUTPUT_SUMMARY_LIMIT: usize = 4_000;
 
+const PROJECT_CONTEXT_FILE_LIMIT: usize = 64 * 1024;
+
+// Fallback poll so queued executions notice a raised `max_concurrent_executions`.
+const QUEUED_EXECUTION_RECHECK_INTERVAL: Duration = Duration::from_secs(5);
+
 fn summarize_hook_failure(output: &[u8]) -> Option<String> {
     let text = String::from_utf8_lossy(output);
     let trimmed = text.trim();
@@ -146,6 +280,71 @@ fn resolve_hook_working_dir(workspace_dir: &Path, hook: &WorkspaceLifecycleHookC
         .unwrap_or_else(|| workspace_dir.to_path_buf())
 }
 
+/// Starts the child env from the configured proxy and the `executor_env` entries for `executor`
+/// (which may override the proxy). VK_* runtime variables and profile env are applied on top, so
+/// they take precedence.
+fn configured_executor_env(config: &Config, executor: Option<BaseCodingAgent>) -> ExecutionEnv {
+    let mut env = ExecutionEnv::new();
+    for (key, value) in config.proxy.env_vars() {
+        env.insert(key, value);
+    }
+    if let Some(vars) = executor.and_then(|executor| config.executor_env.get(&executor)) {
+        env.merge(vars);
+    }
+    env
+}
+
+/// Reads the project context file at `context_file` (relative to the workspace root). Files that
+/// are missing, empty or resolve outside the workspace are skipped.
+fn read_project_context_file(workspace_dir: &Path, context_file: &str) -> Option<String> {
+    let trimmed = context_file.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let resolved = match workspace_dir.join(trimmed).canonicalize() {
+        Ok(path) => path,
+        Err(err) => {
+            tracing::warn!("Project context file '{}' not readable: {}", trimmed, err);
+            return None;
+        }
+    };
+    let root = workspace_dir.canonicalize().ok()?;
+    if !resolved.starts_with(&root) || !resolved.is_file() {
+        tracing::warn!(
+            "Project context file '{}' is not a file inside the workspace; skipping",
+            trimmed
+        );
+        return None;
+    }
+
+    let bytes = std::fs::read(&resolved)
+        .inspect_err(|err| {
+            tracing::warn!("Failed to read project context file '{}': {}", trimmed, err)
+        })
+        .ok()?;
+    let text = String::from_utf8_lossy(&bytes);
+    let text = truncate_to_char_boundary(text.trim(), PROJECT_CONTEXT_FILE_LIMIT);
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Prepends the project context to the prompt of a coding agent request. The stored action is
+/// left untouched so retries and replays do not inject the preamble twice.
+fn with_project_context(action: &ExecutorAction, context: &str) -> ExecutorAction {
+    let prepend = |prompt: &str| format!("Project context:\n{context}\n\n---\n\n{prompt}");
+    let mut action = action.clone();
+    match &mut action.typ {
+        ExecutorActionType::CodingAgentInitialRequest(request) => {
+            request.prompt = prepend(&request.prompt);
+        }
+        ExecutorActionType::CodingAgentFollowUpRequest(request) => {
+            request.prompt = prepend(&request.prompt);
+        }
+        ExecutorActionType::ScriptRequest(_) => {}
+    }
+    action
+}
+
 fn should_run_after_prepare_hook(
     workspace: &Workspace,
     hook: &WorkspaceLifecycleHookConfig,
@@ -248,14 +447,18 @@ pub struct LocalContainerService {
     child_store: Arc<RwLock<HashMap<Uuid, Arc<RwLock<AsyncGroupChild>>>>>,
     interrupt_senders: Arc<RwLock<HashMap<Uuid, InterruptSender>>>,
     msg_stores: Arc<RwLock<HashMap<Uuid, Arc<MsgStore>>>>,
+    /// Global event stream store; receives workspace startup progress patches.
+    events_msg_store: Arc<MsgStore>,
     auto_retry_states: Arc<RwLock<HashMap<Uuid, AutoRetryState>>>,
     finalization_tracker: FinalizationTracker,
+    execution_slots: ExecutionSlots,
     config: Arc<RwLock<Config>>,
     git: GitService,
     image_service: ImageService,
     approvals: Approvals,
     queued_message_service: QueuedMessageService,
     notification_service: SharedNotifier,
+    turn_summarizer: Arc<OnceLock<SharedTurnSummarizer>>,
     shutdown_token: CancellationToken,
 }
 
@@ -264,6 +467,7 @@ impl LocalContainerService {
     pub async fn new(
         db: DBService,
         msg_stores: Arc<RwLock<HashMap<Uuid, Arc<MsgStore>>>>,
+        events_msg_store: Arc<MsgStore>,
         config: Arc<RwLock<Config>>,
         git: GitService,
         image_service: ImageService,
@@ -282,22 +486,34 @@ impl LocalContainerService {
             child_store,
             interrupt_senders,
             msg_stores,
+            events_msg_store,
             auto_retry_states,
             finalization_tracker,
+            execution_slots: ExecutionSlots::default(),
             config,
             git,
             image_service,
             approvals,
             queued_message_service,
             notification_service,
+            turn_summarizer: Arc::new(OnceLock::new()),
             shutdown_token,
         };
 
         container.spawn_workspace_cleanup().await;
+        container.spawn_raw_log_retention();
 
         container
     }
 
+    /// Installs the summarizer used for finished turns when `turn_summary_llm_enabled` is set.
+    /// Only the first call takes effect.
+    pub fn set_turn_summarizer(&self, summarizer: SharedTurnSummarizer) {
+        if self.turn_summarizer.set(summarizer).is_err() {
+            tracing::warn!("Turn summarizer already installed; ignoring");
+        }
+    }
+
     pub async fn get_child_from_store(&self, id: &Uuid) -> Option<Arc<RwLock<AsyncGroupChild>>> {
         let map = self.child_store.read().await;
         map.get(id).cloned()
@@ -311,6 +527,7 @@ impl LocalContainerService {
     pub async fn remove_child_from_store(&self, id: &Uuid) {
         let mut map = self.child_store.write().await;
         map.remove(id);
+        self.execution_slots.release(*id);
     }
 
     async fn add_interrupt_sender(&self, id: Uuid, sender: InterruptSender) {
@@ -331,8 +548,19 @@ impl LocalContainerService {
         self.finalization_tracker.end(execution_process_id).await;
     }
 
+    /// Base directory for new workspaces: the dedicated subdirectory of the configured
+    /// `worktree_root`, else the default.
+    async fn workspace_base_dir(config: &Arc<RwLock<Config>>) -> PathBuf {
+        config
+            .read()
+            .await
+            .worktree_base_dir()
+            .unwrap_or_else(WorkspaceManager::get_workspace_base_dir)
+    }
+
     async fn workspace_dir_for(
         db: &DBService,
+        config: &Arc<RwLock<Config>>,
         workspace: &Workspace,
     ) -> Result<PathBuf, ContainerError> {
         if let Some(container_ref) = &workspace.container_ref {
@@ -344,7 +572,7 @@ impl LocalContainerService {
             .await?
             .ok_or_else(|| anyhow!("Task not found for workspace"))?;
         let dir_name = Self::dir_name_from_workspace(&workspace.id, &task.title);
-        Ok(WorkspaceManager::get_workspace_base_dir().join(dir_name))
+        Ok(Self::workspace_base_dir(config).await.join(dir_name))
     }
 
     async fn load_workspace_hook_context(
@@ -399,7 +627,7 @@ impl LocalContainerService {
             after_prepare_hook,
             before_cleanup_hook,
         };
-        let workspace_dir = Self::workspace_dir_for(db, &fresh_workspace).await?;
+        let workspace_dir = Self::workspace_dir_for(db, config, &fresh_workspace).await?;
         Ok((fresh_workspace, task, project, workspace_dir))
     }
 
@@ -668,6 +896,62 @@ impl LocalContainerService {
                     .unwrap_or_else(|e| {
                         tracing::error!("Failed to clean up expired workspaces: {}", e)
                     });
+                // Workspaces created before `worktree_root` was set still live in the default
+                // base dir, so keep reclaiming there as well.
+                let mut base_dirs = vec![WorkspaceManager::get_workspace_base_dir()];
+                let configured_base_dir = Self::workspace_base_dir(&config).await;
+                if !base_dirs.contains(&configured_base_dir) {
+                    base_dirs.push(configured_base_dir);
+                }
+                for workspace_base_dir in &base_dirs {
+                    WorkspaceManager::reclaim_orphan_workspaces(
+                        &db.pool,
+                        workspace_base_dir,
+                        ORPHAN_WORKSPACE_MIN_AGE,
+                    )
+                    .await;
+                }
+            }
+        });
+    }
+
+    /// Periodically trims the oldest raw log rows of each execution process down to
+    /// `VK_RAW_LOG_MAX_BYTES_PER_PROCESS`. Normalized entries are kept. A limit of 0 disables
+    /// the loop.
+    pub fn spawn_raw_log_retention(&self) {
+        let max_bytes = read_env_u64(RAW_LOG_MAX_BYTES_ENV, DEFAULT_RAW_LOG_MAX_BYTES, 0);
+        if max_bytes == 0 {
+            tracing::info!("Raw log retention disabled via {}", RAW_LOG_MAX_BYTES_ENV);
+            return;
+        }
+        let interval_secs = read_env_u64(
+            RAW_LOG_RETENTION_INTERVAL_ENV,
+            DEFAULT_RAW_LOG_RETENTION_INTERVAL_SECS,
+            MIN_RAW_LOG_RETENTION_INTERVAL_SECS,
+        );
+
+        let db = self.db.clone();
+        let shutdown_token = self.shutdown_token.clone();
+        let mut retention_interval =
+            tokio::time::interval(tokio::time::Duration::from_secs(interval_secs));
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = shutdown_token.cancelled() => {
+                        tracing::info!("Stopping periodic raw log retention");
+                        break;
+                    }
+                    _ = retention_interval.tick() => {}
+                }
+                match ExecutionProcessLogEntry::trim_raw_to_max_bytes(&db.pool, max_bytes).await {
+                    Ok(0) => {}
+                    Ok(deleted) => tracing::info!(
+                        "Raw log retention trimmed {} rows (max_bytes_per_process={})",
+                        deleted,
+                        max_bytes
+                    ),
+                    Err(err) => tracing::error!("Failed to apply raw log retention: {}", err),
+                }
             }
         });
     }
@@ -1004,6 +1288,8 @@ impl LocalContainerService {
                 executor_profile_id: executor_profile_id.clone(),
                 working_dir: working_dir.clone(),
                 image_paths: image_paths.clone(),
+                model: None,
+                reasoning_effort: None,
             })
         } else {
             ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
@@ -1011,6 +1297,8 @@ impl LocalContainerService {
                 executor_profile_id: executor_profile_id.clone(),
                 working_dir,
                 image_paths,
+                model: None,
+                reasoning_effort: None,
             })
         };
 
@@ -1167,6 +1455,260 @@ impl LocalContainerService {
         Ok(true)
     }
 
+    /// Spawn a background task that kills the execution process once it has been running for
+    /// longer than `timeout`.
+    pub fn spawn_execution_timeout(&self, execution_id: Uuid, timeout: Duration) -> JoinHandle<()> {
+        let container = self.clone();
+        let shutdown_token = self.shutdown_token.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = shutdown_token.cancelled() => {}
+                result = container.enforce_execution_timeout(execution_id, timeout) => {
+                    if let Err(err) = result {
+                        tracing::error!(
+                            "Failed to enforce timeout for execution process {}: {}",
+                            execution_id,
+                            err
+                        );
+                    }
+                }
+            }
+        })
+    }
+
+    /// Spawn a background task that kills the execution process once it has produced no output
+    /// for `idle_timeout`.
+    pub fn spawn_execution_idle_timeout(
+        &self,
+        execution_id: Uuid,
+        idle_timeout: Duration,
+    ) -> JoinHandle<()> {
+        let container = self.clone();
+        let shutdown_token = self.shutdown_token.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = shutdown_token.cancelled() => {}
+                result = container.enforce_execution_idle_timeout(execution_id, idle_timeout) => {
+                    if let Err(err) = result {
+                        tracing::error!(
+                            "Failed to enforce idle timeout for execution process {}: {}",
+                            execution_id,
+                            err
+                        );
+                    }
+                }
+            }
+        })
+    }
+
+    /// Spawn the executor child for `execution_process` and start its log, exit and timeout
+    /// monitors.
+    async fn spawn_execution(
+        &self,
+        workspace: &Workspace,
+        execution_process: &ExecutionProcess,
+        executor_action: &ExecutorAction,
+    ) -> Result<(), ContainerError> {
+        // Get the worktree path
+        let container_ref = workspace
+            .container_ref
+            .as_ref()
+            .ok_or(ContainerError::Other(anyhow!(
+                "Container ref not found for workspace"
+            )))?;
+        let current_dir = PathBuf::from(container_ref);
+
+        let approvals_service: Arc<dyn ExecutorApprovalService> =
+            match executor_action.base_executor() {
+                Some(
+                    BaseCodingAgent::Codex
+                    | BaseCodingAgent::ClaudeCode
+                    | BaseCodingAgent::Gemini
+                    | BaseCodingAgent::QwenCode
+                    | BaseCodingAgent::Opencode,
+                ) => ExecutorApprovalBridge::new(
+                    self.approvals.clone(),
+                    self.db.clone(),
+                    self.notification_service.clone(),
+                    execution_process.id,
+                ),
+                _ => Arc::new(NoopExecutorApprovalService {}),
+            };
+
+        // Build ExecutionEnv with configured executor env and VK_* variables
+        let mut env = {
+            let config = self.config.read().await;
+            configured_executor_env(&config, executor_action.base_executor())
+        };
+
+        // Load task and project context for environment variables
+        let task = workspace
+            .parent_task(&self.db.pool)
+            .await?
+            .ok_or(ContainerError::Other(anyhow!(
+                "Task not found for workspace"
+            )))?;
+        let project_id = task.project_id;
+        let config_project_name = {
+            let config = self.config.read().await;
+            config
+                .projects
+                .iter()
+                .find(|project| project.id == Some(project_id))
+                .map(|project| project.name.clone())
+        };
+        let project_name = if let Some(name) = config_project_name {
+            name
+        } else {
+            task.parent_project(&self.db.pool)
+                .await?
+                .map(|project| project.name)
+                .unwrap_or_else(|| "Unknown project".to_string())
+        };
+
+        env.insert("VK_PROJECT_NAME", &project_name);
+        env.insert("VK_PROJECT_ID", project_id.to_string());
+        env.insert("VK_TASK_ID", task.id.to_string());
+        env.insert("VK_WORKSPACE_ID", workspace.id.to_string());
+        env.insert("VK_WORKSPACE_BRANCH", &workspace.branch);
+
+        let context_file = if executor_action.base_executor().is_some() {
+            let config = self.config.read().await;
+            config
+                .projects
+                .iter()
+                .find(|project| project.id == Some(project_id))
+                .and_then(|project| project.context_file.clone())
+        } else {
+            None
+        };
+        let executor_action = match context_file
+            .as_deref()
+            .and_then(|path| read_project_context_file(&current_dir, path))
+        {
+            Some(context) => with_project_context(executor_action, &context),
+            None => executor_action.clone(),
+        };
+
+        // Create the child and stream, add to execution tracker with timeout
+        let mut spawned = tokio::time::timeout(
+            Duration::from_secs(30),
+            executor_action.spawn(&current_dir, approvals_service, &env),
+        )
+        .await
+        .map_err(|_| {
+            ContainerError::Other(anyhow!(
+                "Timeout: process took more than 30 seconds to start"
+            ))
+        })??;
+
+        self.track_child_msgs_in_store(execution_process.id, &mut spawned.child)
+            .await;
+
+        self.add_child_to_store(execution_process.id, spawned.child)
+            .await;
+
+        // Store interrupt sender for graceful shutdown
+        if let Some(interrupt_sender) = spawned.interrupt_sender {
+            self.add_interrupt_sender(execution_process.id, interrupt_sender)
+                .await;
+        }
+
+        // Spawn unified exit monitor: watches OS exit and optional executor signal
+        let _hn = self.spawn_exit_monitor(&execution_process.id, spawned.exit_signal);
+
+        let (timeout, idle_timeout) = match executor_action.base_executor() {
+            Some(executor) => {
+                let config = self.config.read().await;
+                (
+                    config.execution_timeout(executor),
+                    config.execution_idle_timeout(),
+                )
+            }
+            None => (None, None),
+        };
+        if let Some(timeout) = timeout {
+            self.spawn_execution_timeout(execution_process.id, timeout);
+        }
+        if let Some(idle_timeout) = idle_timeout {
+            self.spawn_execution_idle_timeout(execution_process.id, idle_timeout);
+        }
+
+        Ok(())
+    }
+
+    /// Wait for a concurrency slot, then spawn the queued execution. The process stays
+    /// `running` while queued, so stopping it works like any other execution.
+    fn spawn_queued_execution(
+        &self,
+        workspace: Workspace,
+        execution_process: ExecutionProcess,
+        executor_action: ExecutorAction,
+    ) -> JoinHandle<()> {
+        let container = self.clone();
+        tokio::spawn(async move {
+            let id = execution_process.id;
+            let released = container.execution_slots.released.clone();
+            loop {
+                let notified = released.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                if !container.execution_slots.is_queued(id) {
+                    // Stopped while waiting.
+                    return;
+                }
+                let limit = container.config.read().await.max_concurrent_executions();
+                if container.execution_slots.try_acquire(id, limit) {
+                    break;
+                }
+
+                tokio::select! {
+                    _ = container.shutdown_token.cancelled() => {
+                        container.execution_slots.cancel(id);
+                        return;
+                    }
+                    _ = &mut notified => {}
+                    _ = tokio::time::sleep(QUEUED_EXECUTION_RECHECK_INTERVAL) => {}
+                }
+            }
+
+            match ExecutionProcess::find_by_id(&container.db.pool, id).await {
+                Ok(Some(process)) if process.status == ExecutionProcessStatus::Running => {}
+                _ => {
+                    container.execution_slots.release(id);
+                    return;
+                }
+            }
+
+            tracing::info!("Starting queued execution process {}", id);
+            if let Err(err) = container
+                .spawn_execution(&workspace, &execution_process, &executor_action)
+                .await
+            {
+                container.execution_slots.release(id);
+                tracing::error!("Failed to start queued execution process {}: {}", id, err);
+                if let Err(e) = ExecutionProcess::update_completion(
+                    &container.db.pool,
+                    id,
+                    ExecutionProcessStatus::Failed,
+                    None,
+                )
+                .await
+                {
+                    tracing::error!("Failed to mark queued execution {} as failed: {}", id, e);
+                }
+                if let Ok(ctx) = ExecutionProcess::load_context(&container.db.pool, id).await
+                    && let Err(e) =
+                        Task::update_status(&container.db.pool, ctx.task.id, TaskStatus::InReview)
+                            .await
+                {
+                    tracing::error!("Failed to update task status to InReview: {e}");
+                }
+            }
+        })
+    }
+
     /// Spawn a background task that polls the child process for completion and
     /// cleans up the execution entry when it exits.
     pub fn spawn_exit_monitor(
@@ -1236,6 +1778,9 @@ impl LocalContainerService {
                 tracing::error!("Failed to update execution process completion: {}", e);
             }
 
+            // Free the slot before finalization so follow-up actions can start right away.
+            container.execution_slots.release(exec_id);
+
             let owns_finalization = container.begin_finalization(exec_id).await;
             if !owns_finalization {
                 tracing::debug!(
@@ -1412,9 +1957,13 @@ impl LocalContainerService {
                                     }
                                 }
                             } else {
-                                // Execution failed or was killed - discard the queued message and finalize
+                                // Execution failed or was killed - discard the queue and finalize
+                                let discarded = 1 + container
+                                    .queued_message_service
+                                    .clear_queue(ctx.session.id);
                                 tracing::info!(
-                                    "Discarding queued message for session {} due to execution status {:?}",
+                                    "Discarding {} queued message(s) for session {} due to execution status {:?}",
+                                    discarded,
                                     ctx.session.id,
                                     ctx.execution_process.status
                                 );
@@ -1599,7 +2148,40 @@ impl LocalContainerService {
                 // Only update if summary is not already set.
                 // Even when summary exists, we still want VK_NEXT for continuation.
                 if turn.summary.is_none() {
-                    CodingAgentTurn::update_summary(&self.db.pool, exec_id, &summary).await?;
+                    let summarizer = if self.config.read().await.turn_summary_llm_enabled {
+                        self.turn_summarizer.get().cloned()
+                    } else {
+                        None
+                    };
+                    match summarizer {
+                        // The LLM call can take seconds; keep it off the exit path.
+                        Some(summarizer) => {
+                            let db = self.db.clone();
+                            let prompt = turn.prompt.clone();
+                            tokio::spawn(async move {
+                                let summary = resolve_turn_summary(
+                                    Some(summarizer.as_ref()),
+                                    prompt.as_deref(),
+                                    summary,
+                                )
+                                .await;
+                                if let Err(err) =
+                                    CodingAgentTurn::update_summary(&db.pool, exec_id, &summary)
+                                        .await
+                                {
+                                    tracing::warn!(
+                                        "Failed to store turn summary for {}: {}",
+                                        exec_id,
+                                        err
+                                    );
+                                }
+                            });
+                        }
+                        None => {
+                            CodingAgentTurn::update_summary(&self.db.pool, exec_id, &summary)
+                                .await?;
+                        }
+                    }
                 }
 
                 let (vk_next_action, vk_next_invalid_raw) = match vk_next {
@@ -1838,6 +2420,8 @@ impl LocalContainerService {
                 executor_profile_id: executor_profile_id.clone(),
                 working_dir: working_dir.clone(),
                 image_paths: image_paths.clone(),
+                model: None,
+                reasoning_effort: None,
             })
         } else {
             ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
@@ -1845,6 +2429,8 @@ impl LocalContainerService {
                 executor_profile_id: executor_profile_id.clone(),
                 working_dir,
                 image_paths,
+                model: None,
+                reasoning_effort: None,
             })
         };
 
@@ -1923,6 +2509,8 @@ impl LocalContainerService {
                 executor_profile_id: executor_profile_id.clone(),
                 working_dir: working_dir.clone(),
                 image_paths: image_paths.clone(),
+                model: None,
+                reasoning_effort: None,
             })
         } else {
             ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
@@ -1930,6 +2518,8 @@ impl LocalContainerService {
                 executor_profile_id: executor_profile_id.clone(),
                 working_dir,
                 image_paths,
+                model: None,
+                reasoning_effort: None,
             })
         };
 
@@ -2231,7 +2821,9 @@ impl ContainerService for LocalContainerService {
 
         let workspace_dir_name =
             LocalContainerService::dir_name_from_workspace(&workspace.id, &task.title);
-        let workspace_dir = WorkspaceManager::get_workspace_base_dir().join(&workspace_dir_name);
+        let workspace_dir = Self::workspace_base_dir(&self.config)
+            .await
+            .join(&workspace_dir_name);
 
         let repos_with_target_branches =
             WorkspaceRepo::find_repos_with_target_branch_for_workspace(&self.db.pool, workspace.id)
@@ -2247,13 +2839,22 @@ impl ContainerService for LocalContainerService {
             .collect();
         let workspace_inputs: Vec<RepoWorkspaceInput> = repos_with_target_branches
             .iter()
-            .map(|row| RepoWorkspaceInput::new(row.repo.clone(), row.target_branch.clone()))
+            .map(|row| {
+                RepoWorkspaceInput::new(row.repo.clone(), row.target_branch.clone())
+                    .with_sparse_paths(row.sparse_paths.clone())
+            })
             .collect();
 
-        let created_workspace = WorkspaceManager::create_workspace(
+        let progress = StartupProgressReporter::new(
+            self.events_msg_store.clone(),
+            workspace.id,
+            &workspace_inputs,
+        );
+        let created_workspace = WorkspaceManager::create_workspace_with_progress(
             &workspace_dir,
             &workspace_inputs,
             &workspace.branch,
+            &|repo, stage| progress.report(repo, stage),
         )
         .await?;
 
@@ -2305,7 +2906,10 @@ impl ContainerService for LocalContainerService {
 
         let workspace_inputs: Vec<RepoWorkspaceInput> = repos_with_target_branches
             .iter()
-            .map(|row| RepoWorkspaceInput::new(row.repo.clone(), row.target_branch.clone()))
+            .map(|row| {
+                RepoWorkspaceInput::new(row.repo.clone(), row.target_branch.clone())
+                    .with_sparse_paths(row.sparse_paths.clone())
+            })
             .collect();
 
         let workspace_dir = if let Some(container_ref) = &workspace.container_ref {
@@ -2317,7 +2921,9 @@ impl ContainerService for LocalContainerService {
                 .ok_or(DbErr::RecordNotFound("Task not found".to_string()))?;
             let workspace_dir_name =
                 LocalContainerService::dir_name_from_workspace(&workspace.id, &task.title);
-            WorkspaceManager::get_workspace_base_dir().join(&workspace_dir_name)
+            Self::workspace_base_dir(&self.config)
+                .await
+                .join(&workspace_dir_name)
         };
 
         WorkspaceManager::ensure_workspace_exists(
@@ -2375,94 +2981,63 @@ impl ContainerService for LocalContainerService {
         execution_process: &ExecutionProcess,
         executor_action: &ExecutorAction,
     ) -> Result<(), ContainerError> {
-        // Get the worktree path
-        let container_ref = workspace
-            .container_ref
-            .as_ref()
-            .ok_or(ContainerError::Other(anyhow!(
-                "Container ref not found for workspace"
-            )))?;
-        let current_dir = PathBuf::from(container_ref);
-
-        let approvals_service: Arc<dyn ExecutorApprovalService> =
-            match executor_action.base_executor() {
-                Some(
-                    BaseCodingAgent::Codex
-                    | BaseCodingAgent::ClaudeCode
-                    | BaseCodingAgent::Gemini
-                    | BaseCodingAgent::QwenCode
-                    | BaseCodingAgent::Opencode,
-                ) => ExecutorApprovalBridge::new(
-                    self.approvals.clone(),
-                    self.db.clone(),
-                    self.notification_service.clone(),
-                    execution_process.id,
-                ),
-                _ => Arc::new(NoopExecutorApprovalService {}),
+        if executor_action.base_executor().is_some() {
+            let (limit, policy) = {
+                let config = self.config.read().await;
+                (
+                    config.max_concurrent_executions(),
+                    config.execution_capacity_policy,
+                )
             };
+            if !self
+                .execution_slots
+                .admit(execution_process.id, limit, policy)?
+            {
+                tracing::info!(
+                    "Execution process {} queued: max concurrent executions reached",
+                    execution_process.id
+                );
+                self.spawn_queued_execution(
+                    workspace.clone(),
+                    execution_process.clone(),
+                    executor_action.clone(),
+                );
+                return Ok(());
+            }
+        }
 
-        // Build ExecutionEnv with VK_* variables
-        let mut env = ExecutionEnv::new();
-
-        // Load task and project context for environment variables
-        let task = workspace
-            .parent_task(&self.db.pool)
-            .await?
-            .ok_or(ContainerError::Other(anyhow!(
-                "Task not found for workspace"
-            )))?;
-        let project_id = task.project_id;
-        let config_project_name = {
-            let config = self.config.read().await;
-            config
-                .projects
-                .iter()
-                .find(|project| project.id == Some(project_id))
-                .map(|project| project.name.clone())
-        };
-        let project_name = if let Some(name) = config_project_name {
-            name
-        } else {
-            task.parent_project(&self.db.pool)
-                .await?
-                .map(|project| project.name)
-                .unwrap_or_else(|| "Unknown project".to_string())
-        };
-
-        env.insert("VK_PROJECT_NAME", &project_name);
-        env.insert("VK_PROJECT_ID", project_id.to_string());
-        env.insert("VK_TASK_ID", task.id.to_string());
-        env.insert("VK_WORKSPACE_ID", workspace.id.to_string());
-        env.insert("VK_WORKSPACE_BRANCH", &workspace.branch);
-
-        // Create the child and stream, add to execution tracker with timeout
-        let mut spawned = tokio::time::timeout(
-            Duration::from_secs(30),
-            executor_action.spawn(&current_dir, approvals_service, &env),
-        )
-        .await
-        .map_err(|_| {
-            ContainerError::Other(anyhow!(
-                "Timeout: process took more than 30 seconds to start"
-            ))
-        })??;
-
-        self.track_child_msgs_in_store(execution_process.id, &mut spawned.child)
-            .await;
-
-        self.add_child_to_store(execution_process.id, spawned.child)
+        let result = self
+            .spawn_execution(workspace, execution_process, executor_action)
             .await;
-
-        // Store interrupt sender for graceful shutdown
-        if let Some(interrupt_sender) = spawned.interrupt_sender {
-            self.add_interrupt_sender(execution_process.id, interrupt_sender)
-                .await;
+        if result.is_err() {
+            self.execution_slots.release(execution_process.id);
         }
+        result
+    }
 
-        // Spawn unified exit monitor: watches OS exit and optional executor signal
-        let _hn = self.spawn_exit_monitor(&execution_process.id, spawned.exit_signal);
+    async fn check_execution_capacity(
+        &self,
+        executor_action: &ExecutorAction,
+    ) -> Result<(), ContainerError> {
+        if executor_action.base_executor().is_none() {
+            return Ok(());
+        }
+        let config = self.config.read().await;
+        self.execution_slots.check(
+            config.max_concurrent_executions(),
+            config.execution_capacity_policy,
+        )
+    }
 
-        Ok(())
+    async fn execution_capacity(&self) -> ExecutionCapacity {
+        let (running, queued) = self.execution_slots.counts();
+        let config = self.config.read().await;
+        ExecutionCapacity {
+            running,
+            queued,
+            limit: config.max_concurrent_executions(),
+            policy: config.execution_capacity_policy,
+        }
     }
 
     async fn stop_execution(
@@ -2619,6 +3194,7 @@ impl ContainerService for LocalContainerService {
 
             let _ = self.take_interrupt_sender(&execution_process.id).await;
             self.remove_child_from_store(&execution_process.id).await;
+            self.execution_slots.cancel(execution_process.id);
 
             if let Some(msg) = self.msg_stores.write().await.remove(&execution_process.id) {
                 msg.push_finished();
@@ -2941,6 +3517,7 @@ mod tests {
             container_ref: None,
             branch: "test-branch".to_string(),
             agent_working_dir: None,
+            labels: Vec::new(),
             setup_completed_at: None,
             latest_hook_run: None,
             after_prepare_hook_status: None,
@@ -2954,6 +3531,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn configured_executor_env_only_includes_matching_executor() {
+        let mut config = Config::default();
+        config.executor_env.insert(
+            BaseCodingAgent::Codex,
+            HashMap::from([("OPENAI_API_KEY".to_string(), "sk-codex".to_string())]),
+        );
+        config.executor_env.insert(
+            BaseCodingAgent::ClaudeCode,
+            HashMap::from([("ANTHROPIC_API_KEY".to_string(), "sk-claude".to_string())]),
+        );
+
+        let env = configured_executor_env(&config, Some(BaseCodingAgent::Codex));
+        assert_eq!(
+            env.vars.get("OPENAI_API_KEY").map(String::as_str),
+            Some("sk-codex")
+        );
+        assert!(!env.contains_key("ANTHROPIC_API_KEY"));
+
+        assert!(
+            configured_executor_env(&config, Some(BaseCodingAgent::Gemini))
+                .vars
+                .is_empty()
+        );
+        assert!(configured_executor_env(&config, None).vars.is_empty());
+    }
+
+    #[test]
+    fn configured_executor_env_injects_proxy_vars() {
+        let mut config = Config::default();
+        config.proxy.https_proxy = Some("http://proxy.local:3128".to_string());
+        config.proxy.no_proxy = Some("localhost".to_string());
+
+        let env = configured_executor_env(&config, Some(BaseCodingAgent::Codex));
+        for key in ["HTTPS_PROXY", "https_proxy"] {
+            assert_eq!(
+                env.vars.get(key).map(String::as_str),
+                Some("http://proxy.local:3128")
+            );
+        }
+        assert_eq!(
+            env.vars.get("NO_PROXY").map(String::as_str),
+            Some("localhost")
+        );
+        assert!(!env.contains_key("HTTP_PROXY"));
+    }
+
     #[test]
     fn after_prepare_hook_once_per_workspace_skips_after_success() {
         let mut workspace = sample_workspace();
@@ -3014,6 +3638,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn project_context_is_prepended_to_launched_prompt() {
+        let workspace_dir =
+            std::env::temp_dir().join(format!("vk-context-file-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(workspace_dir.join("repo")).unwrap();
+        std::fs::write(
+            workspace_dir.join("repo/CONVENTIONS.md"),
+            "Use snake_case for file names.\n",
+        )
+        .unwrap();
+
+        let action = ExecutorAction::new(
+            ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
+                prompt: "Fix the login flow".to_string(),
+                executor_profile_id: ExecutorProfileId::new(BaseCodingAgent::FakeAgent),
+                working_dir: None,
+                image_paths: None,
+                model: None,
+                reasoning_effort: None,
+            }),
+            None,
+        );
+        let context =
+            read_project_context_file(&workspace_dir, "repo/CONVENTIONS.md").expect("context file");
+        let launched = with_project_context(&action, &context);
+
+        let ExecutorActionType::CodingAgentInitialRequest(request) = launched.typ() else {
+            panic!("expected initial request");
+        };
+        assert!(
+            request
+                .prompt
+                .starts_with("Project context:\nUse snake_case")
+        );
+        assert!(request.prompt.ends_with("Fix the login flow"));
+
+        assert_eq!(
+            read_project_context_file(&workspace_dir, "../etc/passwd"),
+            None
+        );
+        assert_eq!(read_project_context_file(&workspace_dir, "repo"), None);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
     #[tokio::test]
     async fn finalization_tracker_allows_single_owner_during_race() {
         let tracker = FinalizationTracker::default();
@@ -3050,4 +3719,59 @@ mod tests {
         tracker.end(execution_process_id).await;
         assert!(tracker.begin(execution_process_id).await);
     }
+
+    #[test]
+    fn execution_slots_queue_or_reject_beyond_limit() {
+        let slots = ExecutionSlots::default();
+        let limit = Some(2);
+        let [first, second, third, fourth] = [(); 4].map(|_| Uuid::new_v4());
+
+        assert!(
+            slots
+                .admit(first, limit, ExecutionCapacityPolicy::Queue)
+                .unwrap()
+        );
+        assert!(
+            slots
+                .admit(second, limit, ExecutionCapacityPolicy::Reject)
+                .unwrap()
+        );
+
+        assert!(matches!(
+            slots.admit(third, limit, ExecutionCapacityPolicy::Reject),
+            Err(ContainerError::CapacityExceeded {
+                running: 2,
+                limit: 2
+            })
+        ));
+        assert!(slots.check(limit, ExecutionCapacityPolicy::Reject).is_err());
+        assert_eq!(slots.counts(), (2, 0));
+
+        assert!(
+            !slots
+                .admit(third, limit, ExecutionCapacityPolicy::Queue)
+                .unwrap()
+        );
+        assert!(
+            !slots
+                .admit(fourth, limit, ExecutionCapacityPolicy::Queue)
+                .unwrap()
+        );
+        assert!(slots.check(limit, ExecutionCapacityPolicy::Queue).is_ok());
+        assert_eq!(slots.counts(), (2, 2));
+
+        // Queued starts get freed slots in FIFO order.
+        slots.release(first);
+        assert!(!slots.try_acquire(fourth, limit));
+        assert!(slots.try_acquire(third, limit));
+        assert_eq!(slots.counts(), (2, 1));
+
+        slots.cancel(fourth);
+        assert!(!slots.is_queued(fourth));
+        assert!(
+            slots
+                .admit(Uuid::new_v4(), None, ExecutionCapacityPolicy::Reject)
+                .unwrap()
+        );
+    }
 }
@@ -8,14 +8,18 @@ use std::{
 use anyhow::{Error as AnyhowError, anyhow};
 use async_trait::async_trait;
 use chrono::{Duration, Utc};
-use config::cache_budget::{CacheBudgetConfig, cache_budgets};
+use config::{
+    ExecutionCapacityPolicy,
+    cache_budget::{CacheBudgetConfig, cache_budgets},
+};
 use db::{
-    DBService, DbErr,
+    DBService, DbErr, TransactionTrait,
     models::{
+        approval as approval_model,
         coding_agent_turn::{CodingAgentTurn, CreateCodingAgentTurn},
         execution_process::{
-            CreateExecutionProcess, ExecutionContext, ExecutionProcess, ExecutionProcessRunReason,
-            ExecutionProcessStatus,
+            CreateExecutionProcess, ExecutionContext, ExecutionProcess,
+            ExecutionProcessFailureKind, ExecutionProcessRunReason, ExecutionProcessStatus,
         },
         execution_process_log_entries::{ExecutionProcessLogEntry, LogEntryRow},
         execution_process_logs::ExecutionProcessLogs,
@@ -56,11 +60,13 @@ use repos::{
     workspace_manager::WorkspaceError as WorkspaceManagerError,
     worktree_manager::WorktreeError,
 };
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio::{sync::RwLock, task::JoinHandle};
+use ts_rs::TS;
 use utils_core::{
     log_entries::LogEntryChannel,
-    notifications::SharedNotifier,
+    notifications::{NotificationEvent, SharedNotifier},
     text::{git_branch_id, short_uuid},
 };
 use uuid::Uuid;
@@ -71,8 +77,10 @@ pub type ContainerRef = String;
 mod command;
 mod copy;
 mod local;
+mod startup_progress;
 
 pub use local::LocalContainerService;
+pub use startup_progress::{RepoStartupProgress, RepoStartupStatus, WorkspaceStartupProgress};
 
 static LOG_ENTRY_BACKFILL_CACHE: Lazy<Cache<String, ()>> =
     Lazy::new(|| build_log_backfill_cache(cache_budgets()));
@@ -98,6 +106,19 @@ pub fn log_backfill_completion_cache_len() -> u64 {
 
 const DEFAULT_LOG_BACKFILL_CONCURRENCY: usize = 4;
 
+/// Minimum gap between `last_activity_at` writes while normalized entries keep arriving.
+const ACTIVITY_PERSIST_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+async fn persist_activity(db: &DBService, execution_id: Uuid, at: chrono::DateTime<Utc>) {
+    if let Err(err) = ExecutionProcess::record_activity(&db.pool, execution_id, at).await {
+        tracing::warn!(
+            "Failed to record activity for execution {}: {}",
+            execution_id,
+            err
+        );
+    }
+}
+
 pub(super) async fn find_config_project_by_id(
     config: &Arc<RwLock<config::Config>>,
     project_id: Uuid,
@@ -351,6 +372,15 @@ async fn resolve_log_persistence_config(pool: &db::DbPool) -> LogPersistenceConf
     }
 }
 
+/// Snapshot of the coding agent concurrency gate (`max_concurrent_executions`).
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ExecutionCapacity {
+    pub running: usize,
+    pub queued: usize,
+    pub limit: Option<usize>,
+    pub policy: ExecutionCapacityPolicy,
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct DiffStreamOptions {
     pub stats_only: bool,
@@ -384,6 +414,8 @@ pub enum ContainerError {
     Io(#[from] std::io::Error),
     #[error("Failed to kill process: {0}")]
     KillFailed(std::io::Error),
+    #[error("capacity_exceeded: {running} of {limit} concurrent executions are already running")]
+    CapacityExceeded { running: usize, limit: usize },
     #[error(transparent)]
     Other(#[from] AnyhowError), // Catches any unclassified errors
 }
@@ -492,14 +524,20 @@ pub trait ContainerService {
         }
 
         let title = format!("Task Complete: {}", ctx.task.title);
-        let message = match ctx.execution_process.status {
-            ExecutionProcessStatus::Completed => format!(
-                "✅ '{}' completed successfully\nBranch: {:?}\nExecutor: {:?}",
-                ctx.task.title, ctx.workspace.branch, ctx.session.executor
+        let (event, message) = match ctx.execution_process.status {
+            ExecutionProcessStatus::Completed => (
+                NotificationEvent::TaskCompleted,
+                format!(
+                    "✅ '{}' completed successfully\nBranch: {:?}\nExecutor: {:?}",
+                    ctx.task.title, ctx.workspace.branch, ctx.session.executor
+                ),
             ),
-            ExecutionProcessStatus::Failed => format!(
-                "❌ '{}' execution failed\nBranch: {:?}\nExecutor: {:?}",
-                ctx.task.title, ctx.workspace.branch, ctx.session.executor
+            ExecutionProcessStatus::Failed => (
+                NotificationEvent::TaskFailed,
+                format!(
+                    "❌ '{}' execution failed\nBranch: {:?}\nExecutor: {:?}",
+                    ctx.task.title, ctx.workspace.branch, ctx.session.executor
+                ),
             ),
             _ => {
                 tracing::warn!(
@@ -509,7 +547,9 @@ pub trait ContainerService {
                 return;
             }
         };
-        self.notification_service().notify(&title, &message).await;
+        self.notification_service()
+            .notify_event(event, &title, &message)
+            .await;
     }
 
     /// Cleanup executions marked as running in the db, call at startup
@@ -1178,6 +1218,15 @@ pub trait ContainerService {
         executor_action: &ExecutorAction,
     ) -> Result<(), ContainerError>;
 
+    /// Fail fast with `ContainerError::CapacityExceeded` when `executor_action` would exceed
+    /// the concurrency limit and the policy rejects instead of queueing.
+    async fn check_execution_capacity(
+        &self,
+        executor_action: &ExecutorAction,
+    ) -> Result<(), ContainerError>;
+
+    async fn execution_capacity(&self) -> ExecutionCapacity;
+
     async fn stop_execution(
         &self,
         execution_process: &ExecutionProcess,
@@ -1194,6 +1243,101 @@ pub trait ContainerService {
 
     async fn try_commit_changes(&self, ctx: &ExecutionContext) -> Result<bool, ContainerError>;
 
+    /// Force-stops a running execution process and records `failure_kind`. Returns whether
+    /// this call stopped it.
+    async fn kill_with_failure_kind(
+        &self,
+        process: &ExecutionProcess,
+        failure_kind: ExecutionProcessFailureKind,
+    ) -> Result<bool, ContainerError> {
+        let pool = &self.db().pool;
+        self.stop_execution_force(process, ExecutionProcessStatus::Killed)
+            .await?;
+
+        // Another finalizer may have won the race and completed the process normally.
+        let stopped = ExecutionProcess::find_by_id(pool, process.id)
+            .await?
+            .is_some_and(|process| process.status == ExecutionProcessStatus::Killed);
+        if stopped {
+            ExecutionProcess::set_failure_kind(pool, process.id, failure_kind).await?;
+        }
+        Ok(stopped)
+    }
+
+    /// Waits for `timeout` and, if the execution process is still running by then, kills it
+    /// and records it as timed out. Returns whether the process was killed.
+    async fn enforce_execution_timeout(
+        &self,
+        execution_id: Uuid,
+        timeout: std::time::Duration,
+    ) -> Result<bool, ContainerError> {
+        tokio::time::sleep(timeout).await;
+
+        let pool = &self.db().pool;
+        let Some(process) = ExecutionProcess::find_by_id(pool, execution_id).await? else {
+            return Ok(false);
+        };
+        if process.status != ExecutionProcessStatus::Running {
+            return Ok(false);
+        }
+
+        tracing::warn!(
+            "Execution process {} exceeded its {}s timeout; killing it",
+            execution_id,
+            timeout.as_secs()
+        );
+        self.kill_with_failure_kind(&process, ExecutionProcessFailureKind::TimedOut)
+            .await
+    }
+
+    /// Kills the execution process once it has produced no output (`last_activity_at`) for
+    /// `idle_timeout`, recording it as idle-timed-out. Idle time is counted from the later of
+    /// the last activity and the call, so time spent queued does not count. A process waiting on
+    /// an open approval is never idle; the count restarts once the approval is answered. Returns
+    /// whether the process was killed.
+    async fn enforce_execution_idle_timeout(
+        &self,
+        execution_id: Uuid,
+        idle_timeout: std::time::Duration,
+    ) -> Result<bool, ContainerError> {
+        let mut watch_started = Utc::now();
+        let pool = &self.db().pool;
+        loop {
+            let Some(process) = ExecutionProcess::find_by_id(pool, execution_id).await? else {
+                return Ok(false);
+            };
+            if process.status != ExecutionProcessStatus::Running {
+                return Ok(false);
+            }
+            if approval_model::find_open_by_execution_process(pool, execution_id)
+                .await?
+                .is_some()
+            {
+                watch_started = Utc::now();
+                tokio::time::sleep(idle_timeout.min(ACTIVITY_PERSIST_INTERVAL)).await;
+                continue;
+            }
+
+            let last_activity = process
+                .last_activity_at
+                .map_or(watch_started, |at| at.max(watch_started));
+            let idle_for = (Utc::now() - last_activity).to_std().unwrap_or_default();
+            if idle_for < idle_timeout {
+                tokio::time::sleep(idle_timeout - idle_for).await;
+                continue;
+            }
+
+            tracing::warn!(
+                "Execution process {} produced no output for {}s; killing it",
+                execution_id,
+                idle_timeout.as_secs()
+            );
+            return self
+                .kill_with_failure_kind(&process, ExecutionProcessFailureKind::IdleTimeout)
+                .await;
+        }
+    }
+
     async fn copy_project_files(
         &self,
         source_dir: &Path,
@@ -1437,6 +1581,142 @@ pub trait ContainerService {
         }
     }
 
+    /// Re-runs the executor's log normalizer over the persisted raw entries of a finished
+    /// execution process and replaces its stored normalized entries with the result. Returns
+    /// the number of normalized entries written. Nothing is written if there are no raw entries,
+    /// if they do not fit the in-memory log budget, or if normalization does not settle. Callers
+    /// should refuse processes whose raw history was trimmed by retention, since the result would
+    /// be missing the trimmed part.
+    async fn renormalize_log_entries(
+        &self,
+        execution_process: &ExecutionProcess,
+    ) -> Result<usize, ContainerError> {
+        const PAGE_SIZE: usize = 1000;
+
+        let pool = &self.db().pool;
+        let execution_id = execution_process.id;
+
+        let executor_profile_id = match execution_process.executor_action().typ() {
+            ExecutorActionType::CodingAgentInitialRequest(request) => &request.executor_profile_id,
+            ExecutorActionType::CodingAgentFollowUpRequest(request) => &request.executor_profile_id,
+            ExecutorActionType::ScriptRequest(_) => {
+                return Err(ContainerError::Other(anyhow!(
+                    "Execution process {execution_id} does not produce normalized logs"
+                )));
+            }
+        };
+        let executor = ExecutorConfigs::get_cached()
+            .require_coding_agent(executor_profile_id)
+            .map_err(|err| ContainerError::Other(anyhow!(err)))?;
+
+        let (workspace, _session) = execution_process
+            .parent_workspace_and_session(pool)
+            .await?
+            .ok_or_else(|| {
+                ContainerError::Other(anyhow!(
+                    "No workspace/session found for session ID: {}",
+                    execution_process.session_id
+                ))
+            })?;
+        let current_dir = self.workspace_to_current_dir(&workspace);
+
+        let temp_store = Arc::new(MsgStore::new());
+        let mut after_index = -1;
+        loop {
+            let rows = ExecutionProcessLogEntry::fetch_after(
+                pool,
+                execution_id,
+                LogEntryChannel::Raw,
+                PAGE_SIZE,
+                after_index,
+            )
+            .await?;
+            let Some(last) = rows.last() else {
+                break;
+            };
+            after_index = last.entry_index;
+
+            for row in &rows {
+                let entry: serde_json::Value = serde_json::from_str(&row.entry_json)
+                    .map_err(|e| ContainerError::Other(anyhow!("Failed to decode entry: {e}")))?;
+                let content = entry
+                    .get("content")
+                    .and_then(|content| content.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+                match entry.get("type").and_then(|typ| typ.as_str()) {
+                    Some("STDOUT") => temp_store.push(LogMsg::Stdout(content)),
+                    Some("STDERR") => temp_store.push(LogMsg::Stderr(content)),
+                    _ => {}
+                }
+            }
+
+            if rows.len() < PAGE_SIZE {
+                break;
+            }
+        }
+        if after_index < 0 {
+            return Err(ContainerError::Other(anyhow!(
+                "Execution process {execution_id} has no raw log entries to renormalize"
+            )));
+        }
+        temp_store.push_finished();
+        // The normalizer replays the store's bounded history, so raw input it already evicted
+        // would be missing from the result.
+        if temp_store.sequenced_history_metadata().evicted {
+            return Err(ContainerError::Other(anyhow!(
+                "Raw history for {execution_id} exceeds the in-memory log budget"
+            )));
+        }
+
+        executor.normalize_logs(temp_store.clone(), &current_dir);
+        // A partial result would replace complete stored entries, so keep them instead.
+        if !wait_for_normalization_idle(&temp_store).await {
+            return Err(ContainerError::Other(anyhow!(
+                "Normalization of {execution_id} did not finish in time; stored entries were kept"
+            )));
+        }
+        if temp_store.normalized_history_metadata().evicted {
+            return Err(ContainerError::Other(anyhow!(
+                "Normalized history for {execution_id} exceeds the in-memory log budget"
+            )));
+        }
+
+        let entries: Vec<LogEntryRow> = temp_store
+            .normalized_history_page(usize::MAX, None)
+            .0
+            .into_iter()
+            .map(|snapshot| {
+                serde_json::to_string(snapshot.entry_json.as_ref()).map(|entry_json| LogEntryRow {
+                    entry_index: snapshot.entry_index as i64,
+                    entry_json,
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| ContainerError::Other(anyhow!("Failed to encode entry: {e}")))?;
+
+        let tx = pool.begin().await?;
+        ExecutionProcessLogEntry::delete_channel(&tx, execution_id, LogEntryChannel::Normalized)
+            .await?;
+        ExecutionProcessLogEntry::upsert_entries(
+            &tx,
+            execution_id,
+            LogEntryChannel::Normalized,
+            &entries,
+        )
+        .await?;
+        tx.commit().await?;
+
+        // The stored entries no longer mirror the legacy JSONL logs; keep the backfill from
+        // overwriting them.
+        LOG_ENTRY_BACKFILL_CACHE.insert(
+            format!("{execution_id}:{}", LogEntryChannel::Normalized),
+            (),
+        );
+
+        Ok(entries.len())
+    }
+
     async fn stream_raw_log_entries(
         &self,
         id: &Uuid,
@@ -1637,7 +1917,8 @@ pub trait ContainerService {
         after_index: i64,
     ) -> Result<(Vec<LogEntrySnapshot>, bool), ContainerError> {
         let limit = limit.clamp(1, usize::MAX);
-        let after_usize = usize::try_from(after_index).unwrap_or(usize::MAX);
+        // A negative `after_index` reads from the first entry.
+        let after_usize = usize::try_from(after_index).ok();
 
         if execution_process.status != ExecutionProcessStatus::Running {
             self.backfill_log_entries_if_incomplete(execution_process.id, channel)
@@ -1867,6 +2148,11 @@ pub trait ContainerService {
                 return;
             };
 
+            // Each entry is a heartbeat; writes are throttled and the latest one is flushed when
+            // the stream ends.
+            let mut last_persisted: Option<Instant> = None;
+            let mut pending_activity = None;
+
             let mut stream = store.normalized_history_plus_stream();
             while let Some(item) = stream.next().await {
                 match item {
@@ -1901,6 +2187,16 @@ pub trait ContainerService {
                                 err
                             );
                         }
+
+                        let now = Utc::now();
+                        if last_persisted.is_none_or(|at| at.elapsed() >= ACTIVITY_PERSIST_INTERVAL)
+                        {
+                            persist_activity(&db, execution_id, now).await;
+                            last_persisted = Some(Instant::now());
+                            pending_activity = None;
+                        } else {
+                            pending_activity = Some(now);
+                        }
                     }
                     Ok(LogEntryEvent::Finished) => break,
                     Err(err) => {
@@ -1909,6 +2205,10 @@ pub trait ContainerService {
                     }
                 }
             }
+
+            if let Some(at) = pending_activity {
+                persist_activity(&db, execution_id, at).await;
+            }
         })
     }
 
@@ -1917,6 +2217,7 @@ pub trait ContainerService {
         workspace: &Workspace,
         executor_profile_id: ExecutorProfileId,
         prompt_override: Option<String>,
+        reasoning_effort: Option<String>,
     ) -> Result<ExecutionProcess, ContainerError> {
         // Create container
         self.create(workspace).await?;
@@ -2102,6 +2403,8 @@ pub trait ContainerService {
                 executor_profile_id: executor_profile_id.clone(),
                 working_dir,
                 image_paths,
+                model: None,
+                reasoning_effort,
             }),
             cleanup_action.map(Box::new),
         );
@@ -2151,6 +2454,8 @@ pub trait ContainerService {
         executor_action: &ExecutorAction,
         run_reason: &ExecutionProcessRunReason,
     ) -> Result<ExecutionProcess, ContainerError> {
+        self.check_execution_capacity(executor_action).await?;
+
         // Update task status to InProgress when starting an execution
         let task = workspace
             .parent_task(&self.db().pool)
@@ -2423,6 +2728,30 @@ fn run_reason_for_action(typ: &ExecutorActionType) -> ExecutionProcessRunReason
     }
 }
 
+/// Normalizers run as detached tasks, so wait until the store stops growing before reading
+/// the normalized entries back. Returns false if it was still growing when the wait timed out.
+async fn wait_for_normalization_idle(store: &MsgStore) -> bool {
+    const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+    const IDLE_WINDOW: std::time::Duration = std::time::Duration::from_millis(250);
+    const MAX_WAIT: std::time::Duration = std::time::Duration::from_secs(30);
+
+    let started = Instant::now();
+    let mut last_seq = store.max_seq();
+    let mut last_change = Instant::now();
+    while started.elapsed() < MAX_WAIT {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        let seq = store.max_seq();
+        if seq != last_seq {
+            last_seq = seq;
+            last_change = Instant::now();
+        } else if last_change.elapsed() >= IDLE_WINDOW {
+            return true;
+        }
+    }
+    tracing::warn!("Timed out waiting for log normalization to settle");
+    false
+}
+
 fn extract_normalized_patch_entries(patch: &json_patch::Patch) -> Vec<LogEntryRow> {
     patch
         .iter()
@@ -2524,6 +2853,8 @@ mod tests {
             executor_profile_id: ExecutorProfileId::new(BaseCodingAgent::FakeAgent),
             working_dir: None,
             image_paths: None,
+            model: None,
+            reasoning_effort: None,
         });
 
         assert_eq!(
@@ -2592,7 +2923,9 @@ mod tests {
             dev_script: None,
             dev_script_working_dir: None,
             default_agent_working_dir: None,
+            context_file: None,
             git_no_verify_override: None,
+            default_executor_profile: None,
             scheduler_max_concurrent: 1,
             scheduler_max_retries: 0,
             default_continuation_turns: 0,
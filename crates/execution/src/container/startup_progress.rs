@@ -0,0 +1,117 @@
+use std::sync::{Arc, Mutex};
+
+use db::models::repo::Repo;
+use json_patch::{AddOperation, Patch, PatchOperation};
+use logs_store::MsgStore;
+use repos::workspace_manager::{RepoSetupStage, RepoWorkspaceInput};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum RepoStartupStatus {
+    Pending,
+    Cloning,
+    Ready,
+    Failed,
+}
+
+impl From<RepoSetupStage> for RepoStartupStatus {
+    fn from(stage: RepoSetupStage) -> Self {
+        match stage {
+            RepoSetupStage::Cloning => Self::Cloning,
+            RepoSetupStage::Ready => Self::Ready,
+            RepoSetupStage::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct RepoStartupProgress {
+    pub repo_id: Uuid,
+    pub repo_name: String,
+    pub status: RepoStartupStatus,
+}
+
+/// Snapshot pushed to the global event stream at `/workspace_startup/{workspace_id}` whenever a
+/// repo's worktree changes stage while an attempt's workspace is being created.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct WorkspaceStartupProgress {
+    pub workspace_id: Uuid,
+    pub repos: Vec<RepoStartupProgress>,
+    /// Repos whose worktree is ready.
+    pub completed: usize,
+    pub total: usize,
+}
+
+impl WorkspaceStartupProgress {
+    fn new(workspace_id: Uuid, inputs: &[RepoWorkspaceInput]) -> Self {
+        Self {
+            workspace_id,
+            repos: inputs
+                .iter()
+                .map(|input| RepoStartupProgress {
+                    repo_id: input.repo.id,
+                    repo_name: input.repo.name.clone(),
+                    status: RepoStartupStatus::Pending,
+                })
+                .collect(),
+            completed: 0,
+            total: inputs.len(),
+        }
+    }
+
+    fn patch(&self) -> Patch {
+        Patch(vec![PatchOperation::Add(AddOperation {
+            path: format!("/workspace_startup/{}", self.workspace_id)
+                .try_into()
+                .expect("Workspace startup path should be valid"),
+            value: serde_json::to_value(self)
+                .expect("Workspace startup progress serialization should not fail"),
+        })])
+    }
+}
+
+/// Tracks per-repo startup stages for one workspace and publishes each change as a patch.
+pub(crate) struct StartupProgressReporter {
+    events: Arc<MsgStore>,
+    progress: Mutex<WorkspaceStartupProgress>,
+}
+
+impl StartupProgressReporter {
+    pub(crate) fn new(
+        events: Arc<MsgStore>,
+        workspace_id: Uuid,
+        inputs: &[RepoWorkspaceInput],
+    ) -> Self {
+        let reporter = Self {
+            events,
+            progress: Mutex::new(WorkspaceStartupProgress::new(workspace_id, inputs)),
+        };
+        reporter.publish(&reporter.progress.lock().unwrap());
+        reporter
+    }
+
+    pub(crate) fn report(&self, repo: &Repo, stage: RepoSetupStage) {
+        let mut progress = self.progress.lock().unwrap();
+        let Some(entry) = progress
+            .repos
+            .iter_mut()
+            .find(|entry| entry.repo_id == repo.id)
+        else {
+            return;
+        };
+        entry.status = stage.into();
+        progress.completed = progress
+            .repos
+            .iter()
+            .filter(|entry| entry.status == RepoStartupStatus::Ready)
+            .count();
+        self.publish(&progress);
+    }
+
+    fn publish(&self, progress: &WorkspaceStartupProgress) {
+        self.events.push_patch(progress.patch());
+    }
+}
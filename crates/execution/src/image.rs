@@ -11,6 +11,57 @@ use db::{
 use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
+pub const IMAGE_MAX_BYTES_ENV: &str = "VK_IMAGE_MAX_BYTES";
+pub const IMAGE_MAX_DIMENSION_ENV: &str = "VK_IMAGE_MAX_DIMENSION";
+pub const IMAGE_STRIP_EXIF_ENV: &str = "VK_IMAGE_STRIP_EXIF";
+
+const DEFAULT_IMAGE_MAX_BYTES: u64 = 20 * 1024 * 1024;
+const DEFAULT_IMAGE_MAX_DIMENSION: u64 = 16_384;
+
+/// Reads a positive limit from `name`; `0` disables the limit and invalid values fall back to
+/// `default`.
+fn read_limit_env(name: &str, default: u64) -> Option<u64> {
+    let Ok(raw) = std::env::var(name) else {
+        return Some(default);
+    };
+    match raw.trim().parse::<u64>() {
+        Ok(0) => None,
+        Ok(value) => Some(value),
+        Err(err) => {
+            tracing::warn!(
+                "Invalid {}='{}': {}. Using default {}",
+                name,
+                raw,
+                err,
+                default
+            );
+            Some(default)
+        }
+    }
+}
+
+/// Maximum accepted upload size in bytes; `None` disables the limit.
+pub fn image_max_bytes() -> Option<u64> {
+    read_limit_env(IMAGE_MAX_BYTES_ENV, DEFAULT_IMAGE_MAX_BYTES)
+}
+
+/// Maximum accepted width or height in pixels; `None` disables the limit.
+pub fn image_max_dimension() -> Option<u32> {
+    read_limit_env(IMAGE_MAX_DIMENSION_ENV, DEFAULT_IMAGE_MAX_DIMENSION)
+        .map(|value| value.min(u32::MAX as u64) as u32)
+}
+
+/// Whether EXIF metadata is removed from uploads; on unless the env var is set to a false value.
+fn image_strip_exif() -> bool {
+    match std::env::var(IMAGE_STRIP_EXIF_ENV) {
+        Ok(value) => !matches!(
+            value.trim().to_ascii_lowercase().as_str(),
+            "0" | "false" | "no" | "off"
+        ),
+        Err(_) => true,
+    }
+}
+
 fn mime_type_for_extension(extension: &str) -> Option<&'static str> {
     match extension.to_ascii_lowercase().as_str() {
         "png" => Some("image/png"),
@@ -22,6 +73,204 @@ fn mime_type_for_extension(extension: &str) -> Option<&'static str> {
     }
 }
 
+/// Detects the image format from its leading bytes.
+fn sniff_mime_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else if data.starts_with(b"BM") {
+        Some("image/bmp")
+    } else {
+        None
+    }
+}
+
+fn be_u16(data: &[u8], at: usize) -> Option<u32> {
+    let bytes = data.get(at..at + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]) as u32)
+}
+
+fn le_u16(data: &[u8], at: usize) -> Option<u32> {
+    let bytes = data.get(at..at + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]) as u32)
+}
+
+fn le_u24(data: &[u8], at: usize) -> Option<u32> {
+    let bytes = data.get(at..at + 3)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]))
+}
+
+/// Reads `(width, height)` from the header of an image of the given sniffed type.
+fn image_dimensions(mime_type: &str, data: &[u8]) -> Option<(u32, u32)> {
+    match mime_type {
+        "image/png" => {
+            if data.get(12..16)? != b"IHDR" {
+                return None;
+            }
+            let width = u32::from_be_bytes(data.get(16..20)?.try_into().ok()?);
+            let height = u32::from_be_bytes(data.get(20..24)?.try_into().ok()?);
+            Some((width, height))
+        }
+        "image/gif" => Some((le_u16(data, 6)?, le_u16(data, 8)?)),
+        "image/bmp" => {
+            let header_size = u32::from_le_bytes(data.get(14..18)?.try_into().ok()?);
+            if header_size == 12 {
+                return Some((le_u16(data, 18)?, le_u16(data, 20)?));
+            }
+            let width = i32::from_le_bytes(data.get(18..22)?.try_into().ok()?);
+            let height = i32::from_le_bytes(data.get(22..26)?.try_into().ok()?);
+            Some((width.unsigned_abs(), height.unsigned_abs()))
+        }
+        "image/webp" => match data.get(12..16)? {
+            b"VP8 " => {
+                if data.get(23..26)? != [0x9D, 0x01, 0x2A] {
+                    return None;
+                }
+                Some((le_u16(data, 26)? & 0x3FFF, le_u16(data, 28)? & 0x3FFF))
+            }
+            b"VP8L" => {
+                if *data.get(20)? != 0x2F {
+                    return None;
+                }
+                let bits = u32::from_le_bytes(data.get(21..25)?.try_into().ok()?);
+                Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+            }
+            b"VP8X" => Some((le_u24(data, 24)? + 1, le_u24(data, 27)? + 1)),
+            _ => None,
+        },
+        "image/jpeg" => {
+            let mut pos = 2;
+            loop {
+                while *data.get(pos)? == 0xFF && *data.get(pos + 1)? == 0xFF {
+                    pos += 1;
+                }
+                if *data.get(pos)? != 0xFF {
+                    return None;
+                }
+                let marker = *data.get(pos + 1)?;
+                match marker {
+                    0xD0..=0xD9 | 0x01 => pos += 2,
+                    0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                        return Some((be_u16(data, pos + 7)?, be_u16(data, pos + 5)?));
+                    }
+                    _ => pos += 2 + be_u16(data, pos + 2)? as usize,
+                }
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns a copy of `data` without its EXIF block, or `None` when there is nothing to remove or
+/// the container cannot be walked. Pixel data and other metadata are copied unchanged.
+fn strip_exif(mime_type: &str, data: &[u8]) -> Option<Vec<u8>> {
+    match mime_type {
+        "image/jpeg" => strip_jpeg_exif(data),
+        "image/png" => strip_png_exif(data),
+        "image/webp" => strip_webp_exif(data),
+        _ => None,
+    }
+}
+
+fn strip_jpeg_exif(data: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(data.get(..2)?);
+    let mut pos = 2;
+    let mut stripped = false;
+    loop {
+        if *data.get(pos)? != 0xFF {
+            return None;
+        }
+        let marker = *data.get(pos + 1)?;
+        if marker == 0xFF {
+            out.push(0xFF);
+            pos += 1;
+            continue;
+        }
+        if matches!(marker, 0xD0..=0xD9 | 0x01) {
+            out.extend_from_slice(&data[pos..pos + 2]);
+            pos += 2;
+            if marker == 0xD9 {
+                break;
+            }
+            continue;
+        }
+        let end = pos + 2 + be_u16(data, pos + 2)? as usize;
+        let segment = data.get(pos..end)?;
+        if marker == 0xE1 && segment.get(4..10) == Some(b"Exif\0\0".as_slice()) {
+            stripped = true;
+        } else {
+            out.extend_from_slice(segment);
+        }
+        pos = end;
+        // Entropy-coded data follows the start-of-scan header; copy the rest verbatim.
+        if marker == 0xDA {
+            break;
+        }
+    }
+    out.extend_from_slice(&data[pos..]);
+    stripped.then_some(out)
+}
+
+fn strip_png_exif(data: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(data.get(..8)?);
+    let mut pos = 8;
+    let mut stripped = false;
+    while pos < data.len() {
+        let len = u32::from_be_bytes(data.get(pos..pos + 4)?.try_into().ok()?) as usize;
+        let end = pos + 12 + len;
+        let chunk = data.get(pos..end)?;
+        if &chunk[4..8] == b"eXIf" {
+            stripped = true;
+        } else {
+            out.extend_from_slice(chunk);
+        }
+        pos = end;
+    }
+    stripped.then_some(out)
+}
+
+fn strip_webp_exif(data: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(data.get(..12)?);
+    let mut pos = 12;
+    let mut stripped = false;
+    let mut vp8x_flags_at = None;
+    while pos < data.len() {
+        let len = u32::from_le_bytes(data.get(pos + 4..pos + 8)?.try_into().ok()?) as usize;
+        let end = pos + 8 + len + (len & 1);
+        let chunk = data.get(pos..end.min(data.len()))?;
+        match &chunk[..4] {
+            b"EXIF" => stripped = true,
+            fourcc => {
+                if fourcc == b"VP8X" {
+                    vp8x_flags_at = Some(out.len() + 8);
+                }
+                out.extend_from_slice(chunk);
+            }
+        }
+        pos = end;
+    }
+    if !stripped {
+        return None;
+    }
+    // Clear the "has EXIF" flag and fix up the RIFF size for the shorter file.
+    if let Some(at) = vp8x_flags_at
+        && let Some(flags) = out.get_mut(at)
+    {
+        *flags &= !0x08;
+    }
+    let riff_size = (out.len() - 8) as u32;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Some(out)
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ImageError {
     #[error("IO error: {0}")]
@@ -36,6 +285,12 @@ pub enum ImageError {
     #[error("Image too large: {0} bytes (max: {1} bytes)")]
     TooLarge(u64, u64),
 
+    #[error("Image content does not match declared type {0}")]
+    ContentMismatch(String),
+
+    #[error("Image dimensions too large: {width}x{height} (max: {max} pixels per side)")]
+    DimensionsTooLarge { width: u32, height: u32, max: u32 },
+
     #[error("Image not found")]
     NotFound,
 
@@ -47,7 +302,9 @@ pub enum ImageError {
 pub struct ImageService {
     cache_dir: PathBuf,
     pool: db::DbPool,
-    max_size_bytes: u64,
+    max_size_bytes: Option<u64>,
+    max_dimension: Option<u32>,
+    strip_exif: bool,
 }
 
 impl ImageService {
@@ -57,7 +314,9 @@ impl ImageService {
         Ok(Self {
             cache_dir,
             pool,
-            max_size_bytes: 20 * 1024 * 1024, // 20MB default
+            max_size_bytes: image_max_bytes(),
+            max_dimension: image_max_dimension(),
+            strip_exif: image_strip_exif(),
         })
     }
 
@@ -68,23 +327,41 @@ impl ImageService {
     ) -> Result<Image, ImageError> {
         let file_size = data.len() as u64;
 
-        if file_size > self.max_size_bytes {
-            return Err(ImageError::TooLarge(file_size, self.max_size_bytes));
+        if let Some(max) = self.max_size_bytes
+            && file_size > max
+        {
+            return Err(ImageError::TooLarge(file_size, max));
         }
 
-        let hash = format!("{:x}", Sha256::digest(data));
-
         // Extract extension from original filename
         let extension = Path::new(original_filename)
             .extension()
             .and_then(|e| e.to_str())
             .unwrap_or("png");
 
-        let mime_type = mime_type_for_extension(extension).map(|mime| mime.to_string());
-
-        if mime_type.is_none() {
+        let Some(declared_mime) = mime_type_for_extension(extension) else {
             return Err(ImageError::InvalidFormat);
+        };
+
+        if sniff_mime_type(data) != Some(declared_mime) {
+            return Err(ImageError::ContentMismatch(declared_mime.to_string()));
         }
+        let Some((width, height)) = image_dimensions(declared_mime, data) else {
+            return Err(ImageError::ContentMismatch(declared_mime.to_string()));
+        };
+        if let Some(max) = self.max_dimension
+            && (width > max || height > max)
+        {
+            return Err(ImageError::DimensionsTooLarge { width, height, max });
+        }
+        let mime_type = Some(declared_mime.to_string());
+
+        let stripped = self
+            .strip_exif
+            .then(|| strip_exif(declared_mime, data))
+            .flatten();
+        let data = stripped.as_deref().unwrap_or(data);
+        let hash = format!("{:x}", Sha256::digest(data));
 
         let existing_image = Image::find_by_hash(&self.pool, &hash).await?;
 
@@ -103,7 +380,7 @@ impl ImageService {
                 file_path: new_filename,
                 original_name: original_filename.to_string(),
                 mime_type,
-                size_bytes: file_size as i64,
+                size_bytes: data.len() as i64,
                 hash,
             },
         )
@@ -253,7 +530,7 @@ impl ImageService {
 
 #[cfg(test)]
 mod tests {
-    use super::mime_type_for_extension;
+    use super::{image_dimensions, mime_type_for_extension, sniff_mime_type, strip_exif};
 
     #[test]
     fn svg_is_not_an_allowed_upload_format() {
@@ -270,4 +547,40 @@ mod tests {
         assert_eq!(mime_type_for_extension("webp"), Some("image/webp"));
         assert_eq!(mime_type_for_extension("bmp"), Some("image/bmp"));
     }
+
+    #[test]
+    fn sniffs_type_and_dimensions_from_headers() {
+        let mut gif = b"GIF89a".to_vec();
+        gif.extend_from_slice(&[0x40, 0x01, 0xF0, 0x00]);
+        assert_eq!(sniff_mime_type(&gif), Some("image/gif"));
+        assert_eq!(image_dimensions("image/gif", &gif), Some((320, 240)));
+
+        let jpeg = [
+            0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, // SOI + APP0
+            0xFF, 0xC0, 0x00, 0x0B, 0x08, 0x00, 0x20, 0x00, 0x40, // SOF0 64x32
+        ];
+        assert_eq!(sniff_mime_type(&jpeg), Some("image/jpeg"));
+        assert_eq!(image_dimensions("image/jpeg", &jpeg), Some((64, 32)));
+
+        assert_eq!(sniff_mime_type(b"<html></html>"), None);
+        assert_eq!(image_dimensions("image/png", b"\x89PNG\r\n\x1a\n"), None);
+    }
+
+    #[test]
+    fn strips_exif_segment_from_jpeg() {
+        let soi = [0xFF, 0xD8];
+        let exif = [
+            0xFF, 0xE1, 0x00, 0x0A, b'E', b'x', b'i', b'f', 0x00, 0x00, b'G', b'P',
+        ];
+        let rest = [
+            0xFF, 0xC0, 0x00, 0x0B, 0x08, 0x00, 0x01, 0x00, 0x01, 0x01, 0x01, 0x11,
+            0x00, // SOF0
+            0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9, // SOS, scan data, EOI
+        ];
+        let jpeg = [&soi[..], &exif[..], &rest[..]].concat();
+
+        let stripped = strip_exif("image/jpeg", &jpeg).expect("EXIF segment should be removed");
+        assert_eq!(stripped, [&soi[..], &rest[..]].concat());
+        assert_eq!(strip_exif("image/jpeg", &stripped), None);
+    }
 }
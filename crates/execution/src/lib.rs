@@ -3,3 +3,4 @@ pub mod diff_stream;
 pub mod fs_watch;
 pub mod image;
 pub mod queued_message;
+pub mod turn_summary;
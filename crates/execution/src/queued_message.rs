@@ -1,4 +1,4 @@
-use std::{sync::Arc, time::Duration};
+use std::{collections::VecDeque, sync::Arc, time::Duration};
 
 use chrono::{DateTime, Utc};
 use config::cache_budget::{cache_budgets, should_warn};
@@ -28,16 +28,37 @@ pub struct QueuedMessage {
 pub enum QueueStatus {
     /// No message queued
     Empty,
-    /// Message is queued and waiting for execution to complete
-    Queued { message: QueuedMessage },
+    /// Messages are queued and will run in order as executions complete
+    Queued {
+        /// The next message to be dispatched
+        message: QueuedMessage,
+        /// All queued messages in dispatch order (including `message`)
+        messages: Vec<QueuedMessage>,
+        /// Number of queued messages
+        depth: usize,
+    },
+}
+
+impl QueueStatus {
+    fn from_messages(messages: Vec<QueuedMessage>) -> Self {
+        match messages.first().cloned() {
+            Some(message) => QueueStatus::Queued {
+                message,
+                depth: messages.len(),
+                messages,
+            },
+            None => QueueStatus::Empty,
+        }
+    }
 }
 
 /// In-memory service for managing queued follow-up messages.
-/// One queued message per session.
+/// Each session holds an ordered queue that is dispatched FIFO, one message per completed
+/// execution.
 #[derive(Clone)]
 pub struct QueuedMessageService {
-    queue: Arc<DashMap<Uuid, QueuedMessage>>,
-    idempotency: Arc<DashMap<Uuid, QueueIdempotencyRecord>>,
+    queue: Arc<DashMap<Uuid, VecDeque<QueuedMessage>>>,
+    idempotency: Arc<DashMap<Uuid, Vec<QueueIdempotencyRecord>>>,
     ttl: Duration,
 }
 
@@ -45,6 +66,7 @@ pub struct QueuedMessageService {
 struct QueueIdempotencyRecord {
     key: String,
     request_hash: String,
+    queued_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Error)]
@@ -62,8 +84,9 @@ impl QueuedMessageService {
         }
     }
 
+    /// Total number of queued messages across all sessions.
     pub fn queue_len(&self) -> usize {
-        self.queue.len()
+        self.queue.iter().map(|entry| entry.value().len()).sum()
     }
 
     fn is_expired(&self, queued_at: DateTime<Utc>) -> bool {
@@ -75,70 +98,103 @@ impl QueuedMessageService {
         Utc::now() - queued_at > ttl
     }
 
-    fn prune_expired(&self) -> usize {
+    /// Drop expired messages for one session, returning how many were removed.
+    fn prune_session(&self, session_id: &Uuid) -> usize {
         if self.ttl.is_zero() {
             return 0;
         }
 
-        let mut expired = Vec::new();
-        for entry in self.queue.iter() {
-            if self.is_expired(entry.value().queued_at) {
-                expired.push(*entry.key());
+        let removed = match self.queue.get_mut(session_id) {
+            Some(mut messages) => {
+                let before = messages.len();
+                messages.retain(|message| !self.is_expired(message.queued_at));
+                before - messages.len()
             }
+            None => return 0,
+        };
+        self.queue
+            .remove_if(session_id, |_, messages| messages.is_empty());
+        if removed > 0 {
+            self.forget_missing_idempotency(session_id);
         }
+        removed
+    }
 
-        for key in &expired {
-            self.queue.remove(key);
-            self.idempotency.remove(key);
+    fn prune_expired(&self) -> usize {
+        if self.ttl.is_zero() {
+            return 0;
         }
 
-        if !expired.is_empty() && should_warn("queued_messages") {
+        let sessions: Vec<Uuid> = self.queue.iter().map(|entry| *entry.key()).collect();
+        let removed: usize = sessions
+            .iter()
+            .map(|session_id| self.prune_session(session_id))
+            .sum();
+
+        if removed > 0 && should_warn("queued_messages") {
             tracing::warn!(
                 "Removed {} expired queued messages (ttl={}s)",
-                expired.len(),
+                removed,
                 self.ttl.as_secs()
             );
         }
 
-        expired.len()
+        removed
     }
 
     fn prune_if_expired(&self, session_id: &Uuid) -> bool {
-        if let Some(entry) = self.queue.get(session_id) {
-            let expired = self.is_expired(entry.queued_at);
-            drop(entry);
-            if expired {
-                self.queue.remove(session_id);
-                self.idempotency.remove(session_id);
-                if should_warn("queued_messages") {
-                    tracing::warn!(
-                        "Queued message expired for session {session_id} (ttl={}s)",
-                        self.ttl.as_secs()
-                    );
-                }
-                return true;
-            }
+        let removed = self.prune_session(session_id);
+        if removed > 0 && should_warn("queued_messages") {
+            tracing::warn!(
+                "{removed} queued message(s) expired for session {session_id} (ttl={}s)",
+                self.ttl.as_secs()
+            );
         }
-        false
+        removed > 0
     }
 
-    /// Queue a message for a session. Replaces any existing queued message.
-    pub fn queue_message(&self, session_id: Uuid, data: DraftFollowUpData) -> QueuedMessage {
-        self.prune_expired();
-        self.idempotency.remove(&session_id);
+    /// Drop idempotency records whose message is no longer queued.
+    fn forget_missing_idempotency(&self, session_id: &Uuid) {
+        let queued_at: Vec<DateTime<Utc>> = self
+            .queue
+            .get(session_id)
+            .map(|messages| messages.iter().map(|message| message.queued_at).collect())
+            .unwrap_or_default();
+        if let Some(mut records) = self.idempotency.get_mut(session_id) {
+            records.retain(|record| queued_at.contains(&record.queued_at));
+        }
+        self.idempotency
+            .remove_if(session_id, |_, records| records.is_empty());
+    }
+
+    fn push(&self, session_id: Uuid, data: DraftFollowUpData) -> QueuedMessage {
+        let mut messages = self.queue.entry(session_id).or_default();
+        // Keep `queued_at` unique within a session; it identifies the message for idempotency.
+        let mut queued_at = Utc::now();
+        if let Some(last) = messages.back()
+            && queued_at <= last.queued_at
+        {
+            queued_at = last.queued_at + chrono::Duration::microseconds(1);
+        }
         let queued = QueuedMessage {
             session_id,
             data,
-            queued_at: Utc::now(),
+            queued_at,
         };
-        self.queue.insert(session_id, queued.clone());
+        messages.push_back(queued.clone());
         queued
     }
 
+    /// Append a message to the end of a session's queue.
+    pub fn queue_message(&self, session_id: Uuid, data: DraftFollowUpData) -> QueuedMessage {
+        self.prune_expired();
+        self.push(session_id, data)
+    }
+
     /// Queue a message for a session, using an idempotency key for safe retries.
-    /// If the same idempotency key is reused with the same payload, this returns the existing
-    /// queued message without modifying timestamps. If the key is reused with a different payload,
-    /// this returns a Conflict error.
+    /// If the same idempotency key is reused with the same payload while that message is still
+    /// queued, this returns it without queueing a duplicate. If the key is reused with a different
+    /// payload, this returns a Conflict error.
     pub fn queue_message_idempotent(
         &self,
         session_id: Uuid,
@@ -148,76 +204,98 @@ impl QueuedMessageService {
     ) -> Result<QueuedMessage, QueueMessageIdempotencyError> {
         self.prune_expired();
 
-        if let Some(entry) = self.idempotency.get(&session_id) {
-            // If the same key is reused, ensure the payload matches and return the existing message.
-            if entry.key == idempotency_key {
-                if entry.request_hash != request_hash {
-                    return Err(QueueMessageIdempotencyError::Conflict);
-                }
-                if let Some(existing) = self.queue.get(&session_id) {
-                    return Ok(existing.clone());
-                }
+        let existing = self.idempotency.get(&session_id).and_then(|records| {
+            records
+                .iter()
+                .find(|record| record.key == idempotency_key)
+                .cloned()
+        });
+        if let Some(record) = existing {
+            if record.request_hash != request_hash {
+                return Err(QueueMessageIdempotencyError::Conflict);
+            }
+            if let Some(existing) = self.queue.get(&session_id).and_then(|messages| {
+                messages
+                    .iter()
+                    .find(|message| message.queued_at == record.queued_at)
+                    .cloned()
+            }) {
+                return Ok(existing);
             }
         }
 
-        let queued_at = Utc::now();
-        let queued = QueuedMessage {
-            session_id,
-            data,
-            queued_at,
-        };
-        self.queue.insert(session_id, queued.clone());
-        self.idempotency.insert(
-            session_id,
-            QueueIdempotencyRecord {
-                key: idempotency_key,
-                request_hash,
-            },
-        );
+        let queued = self.push(session_id, data);
+        let mut records = self.idempotency.entry(session_id).or_default();
+        records.retain(|record| record.key != idempotency_key);
+        records.push(QueueIdempotencyRecord {
+            key: idempotency_key,
+            request_hash,
+            queued_at: queued.queued_at,
+        });
         Ok(queued)
     }
 
-    /// Cancel/remove a queued message for a session
+    /// Cancel/remove the next queued message for a session, keeping the rest of the queue.
     pub fn cancel_queued(&self, session_id: Uuid) -> Option<QueuedMessage> {
+        self.take_queued(session_id)
+    }
+
+    /// Remove every queued message for a session, returning how many were dropped.
+    pub fn clear_queue(&self, session_id: Uuid) -> usize {
         self.idempotency.remove(&session_id);
-        self.queue.remove(&session_id).map(|(_, v)| v)
+        self.queue
+            .remove(&session_id)
+            .map(|(_, messages)| messages.len())
+            .unwrap_or(0)
     }
 
-    /// Get the queued message for a session (if any)
+    /// Get the next queued message for a session (if any)
     pub fn get_queued(&self, session_id: Uuid) -> Option<QueuedMessage> {
-        if self.prune_if_expired(&session_id) {
-            return None;
-        }
-        self.queue.get(&session_id).map(|r| r.clone())
+        self.prune_if_expired(&session_id);
+        self.queue
+            .get(&session_id)
+            .and_then(|messages| messages.front().cloned())
     }
 
-    /// Take (remove and return) the queued message for a session.
-    /// Used by finalization flow to consume the queued message.
+    /// Take (remove and return) the next queued message for a session.
+    /// Used by finalization flow to consume the queue one message per completed execution.
     pub fn take_queued(&self, session_id: Uuid) -> Option<QueuedMessage> {
-        if self.prune_if_expired(&session_id) {
-            return None;
+        self.prune_if_expired(&session_id);
+        let taken = self
+            .queue
+            .get_mut(&session_id)
+            .and_then(|mut messages| messages.pop_front());
+        self.queue
+            .remove_if(&session_id, |_, messages| messages.is_empty());
+        if taken.is_some() {
+            self.forget_missing_idempotency(&session_id);
         }
-        self.idempotency.remove(&session_id);
-        self.queue.remove(&session_id).map(|(_, v)| v)
+        taken
+    }
+
+    /// Number of messages queued for a session
+    pub fn queue_depth(&self, session_id: Uuid) -> usize {
+        self.prune_if_expired(&session_id);
+        self.queue
+            .get(&session_id)
+            .map(|messages| messages.len())
+            .unwrap_or(0)
     }
 
     /// Check if a session has a queued message
     pub fn has_queued(&self, session_id: Uuid) -> bool {
-        if self.prune_if_expired(&session_id) {
-            return false;
-        }
-        self.queue.contains_key(&session_id)
+        self.queue_depth(session_id) > 0
     }
 
     /// Get queue status for frontend display
     pub fn get_status(&self, session_id: Uuid) -> QueueStatus {
-        if self.prune_if_expired(&session_id) {
-            return QueueStatus::Empty;
-        }
-        match self.get_queued(session_id) {
-            Some(msg) => QueueStatus::Queued { message: msg },
-            None => QueueStatus::Empty,
-        }
+        self.prune_if_expired(&session_id);
+        let messages = self
+            .queue
+            .get(&session_id)
+            .map(|messages| messages.iter().cloned().collect())
+            .unwrap_or_default();
+        QueueStatus::from_messages(messages)
     }
 }
 
@@ -249,8 +327,10 @@ mod tests {
         service.queue_message(session_id, data);
 
         if let Some(mut entry) = service.queue.get_mut(&session_id) {
-            entry.queued_at =
-                Utc::now() - ChronoDuration::seconds((service.ttl.as_secs() + 1) as i64);
+            for message in entry.iter_mut() {
+                message.queued_at =
+                    Utc::now() - ChronoDuration::seconds((service.ttl.as_secs() + 1) as i64);
+            }
         }
 
         assert!(service.get_queued(session_id).is_none());
@@ -311,4 +391,83 @@ mod tests {
 
         assert!(matches!(err, QueueMessageIdempotencyError::Conflict));
     }
+
+    #[test]
+    fn queue_message_appends_in_order() {
+        let service = QueuedMessageService::new();
+        let session_id = Uuid::new_v4();
+
+        service.queue_message(session_id, follow_up("first"));
+        service.queue_message(session_id, follow_up("second"));
+
+        assert_eq!(service.queue_depth(session_id), 2);
+        match service.get_status(session_id) {
+            QueueStatus::Queued {
+                message,
+                messages,
+                depth,
+            } => {
+                assert_eq!(message.data.message, "first");
+                assert_eq!(depth, 2);
+                let order: Vec<_> = messages.iter().map(|m| m.data.message.as_str()).collect();
+                assert_eq!(order, ["first", "second"]);
+            }
+            QueueStatus::Empty => panic!("expected queued status"),
+        }
+    }
+
+    #[test]
+    fn take_queued_dispatches_fifo() {
+        let service = QueuedMessageService::new();
+        let session_id = Uuid::new_v4();
+
+        service.queue_message(session_id, follow_up("first"));
+        service
+            .queue_message_idempotent(
+                session_id,
+                "req-2".to_string(),
+                "hash-2".to_string(),
+                follow_up("second"),
+            )
+            .unwrap();
+
+        assert_eq!(
+            service.take_queued(session_id).unwrap().data.message,
+            "first"
+        );
+        assert_eq!(service.queue_depth(session_id), 1);
+        assert_eq!(
+            service.take_queued(session_id).unwrap().data.message,
+            "second"
+        );
+        assert!(service.take_queued(session_id).is_none());
+        assert!(matches!(service.get_status(session_id), QueueStatus::Empty));
+        assert!(!service.idempotency.contains_key(&session_id));
+    }
+
+    fn follow_up(message: &str) -> DraftFollowUpData {
+        DraftFollowUpData {
+            message: message.to_string(),
+            variant: None,
+        }
+    }
+
+    #[test]
+    fn cancel_removes_next_message_and_clear_empties_queue() {
+        let service = QueuedMessageService::new();
+        let session_id = Uuid::new_v4();
+
+        for message in ["first", "second", "third"] {
+            service.queue_message(session_id, follow_up(message));
+        }
+
+        let cancelled = service.cancel_queued(session_id).unwrap();
+        assert_eq!(cancelled.data.message, "first");
+        assert_eq!(service.queue_depth(session_id), 2);
+
+        assert_eq!(service.clear_queue(session_id), 2);
+        assert_eq!(service.queue_depth(session_id), 0);
+        assert!(matches!(service.get_status(session_id), QueueStatus::Empty));
+        assert_eq!(service.clear_queue(session_id), 0);
+    }
 }
@@ -0,0 +1,94 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+
+/// Upper bound on how long a turn's completion waits for a generated summary.
+const TURN_SUMMARY_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Produces a one-line summary for a finished coding agent turn.
+#[async_trait]
+pub trait TurnSummarizer: Send + Sync {
+    async fn summarize(&self, prompt: Option<&str>, last_message: &str) -> anyhow::Result<String>;
+}
+
+pub type SharedTurnSummarizer = Arc<dyn TurnSummarizer>;
+
+/// Summary to store for a finished turn. Uses the summarizer's first non-empty line when one is
+/// given and succeeds in time; otherwise keeps the heuristic summary.
+pub async fn resolve_turn_summary(
+    summarizer: Option<&dyn TurnSummarizer>,
+    prompt: Option<&str>,
+    heuristic: String,
+) -> String {
+    let Some(summarizer) = summarizer else {
+        return heuristic;
+    };
+
+    match tokio::time::timeout(
+        TURN_SUMMARY_TIMEOUT,
+        summarizer.summarize(prompt, &heuristic),
+    )
+    .await
+    {
+        Ok(Ok(summary)) => match summary.lines().map(str::trim).find(|line| !line.is_empty()) {
+            Some(line) => line.to_string(),
+            None => heuristic,
+        },
+        Ok(Err(err)) => {
+            tracing::warn!(error = %err, "Turn summary generation failed; using last message");
+            heuristic
+        }
+        Err(_) => {
+            tracing::warn!("Turn summary generation timed out; using last message");
+            heuristic
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the given summary, or fails when `None`.
+    struct FixedSummarizer(Option<&'static str>);
+
+    #[async_trait]
+    impl TurnSummarizer for FixedSummarizer {
+        async fn summarize(
+            &self,
+            _prompt: Option<&str>,
+            _last_message: &str,
+        ) -> anyhow::Result<String> {
+            self.0
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("summarizer unavailable"))
+        }
+    }
+
+    #[tokio::test]
+    async fn summarizer_output_replaces_heuristic() {
+        let summarizer = FixedSummarizer(Some("\nFixed the login redirect\nextra detail"));
+        let summary = resolve_turn_summary(
+            Some(&summarizer),
+            Some("fix login"),
+            "Long last message".to_string(),
+        )
+        .await;
+        assert_eq!(summary, "Fixed the login redirect");
+    }
+
+    #[tokio::test]
+    async fn disabled_or_failing_summarizer_keeps_heuristic() {
+        let heuristic = "Long last message".to_string();
+        assert_eq!(
+            resolve_turn_summary(None, None, heuristic.clone()).await,
+            heuristic
+        );
+
+        let failing = FixedSummarizer(None);
+        assert_eq!(
+            resolve_turn_summary(Some(&failing), None, heuristic.clone()).await,
+            heuristic
+        );
+    }
+}
@@ -617,6 +617,7 @@ impl ClaudeLogProcessor {
                             &new_string.clone().unwrap_or_default(),
                         ),
                         has_line_numbers: false,
+                        language: None,
                     }]
                 } else {
                     vec![]
@@ -637,6 +638,7 @@ impl ClaudeLogProcessor {
                             &edit.new_string.clone().unwrap_or_default(),
                         ),
                         has_line_numbers: false,
+                        language: None,
                     })
                     .collect();
                 ActionType::FileEdit {
@@ -373,17 +373,111 @@ impl AppServerClient {
                 send_server_response(peer, request_id, response).await
             }
             ServerRequest::McpServerElicitationRequest { request_id, params } => {
-                tracing::warn!(
-                    thread_id = %params.thread_id,
-                    server_name = %params.server_name,
-                    "McpServerElicitationRequest is unsupported; declining"
-                );
+                if self.auto_approve {
+                    tracing::warn!(
+                        thread_id = %params.thread_id,
+                        server_name = %params.server_name,
+                        "declining McpServerElicitationRequest in auto-approve mode"
+                    );
+                    let response = McpServerElicitationRequestResponse {
+                        action: McpServerElicitationAction::Decline,
+                        content: None,
+                        meta: None,
+                    };
+                    return send_server_response(peer, request_id, response).await;
+                }
+
+                let input = serde_json::to_value(&params)
+                    .map_err(|err| ExecutorError::Io(io::Error::other(err.to_string())))?;
+                let call_id = elicitation_call_id(&request_id);
+                let message = input
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|message| !message.is_empty())
+                    .unwrap_or("MCP server requested input")
+                    .to_string();
+                let Some(accept_content) = elicitation_accept_content(&input) else {
+                    tracing::warn!(
+                        thread_id = %params.thread_id,
+                        server_name = %params.server_name,
+                        "declining McpServerElicitationRequest whose form has required fields"
+                    );
+                    let reason = format!(
+                        "Declined {} input request \"{message}\": it needs form fields ({}) that \
+                         cannot be filled in through an approval.",
+                        params.server_name,
+                        elicitation_required_fields(&input).join(", ")
+                    );
+                    self.log_writer
+                        .log_raw(
+                            &Approval::elicitation_declined(
+                                call_id,
+                                params.server_name.clone(),
+                                reason,
+                            )
+                            .raw(),
+                        )
+                        .await?;
+                    let response = McpServerElicitationRequestResponse {
+                        action: McpServerElicitationAction::Decline,
+                        content: None,
+                        meta: None,
+                    };
+                    return send_server_response(peer, request_id, response).await;
+                };
+
+                // Surface the prompt as a tool entry so the approval can attach to it.
+                self.log_writer
+                    .log_raw(
+                        &Approval::elicitation_request(
+                            call_id.clone(),
+                            params.server_name.clone(),
+                            message,
+                        )
+                        .raw(),
+                    )
+                    .await?;
+
+                let status = match self
+                    .request_tool_approval(ELICITATION_TOOL_NAME, input, &call_id)
+                    .await
+                {
+                    Ok(status) => status,
+                    Err(err) => {
+                        tracing::error!("failed to request elicitation response: {err}");
+                        ApprovalStatus::Denied {
+                            reason: Some("approval service error".to_string()),
+                        }
+                    }
+                };
+                self.log_writer
+                    .log_raw(
+                        &Approval::approval_response(
+                            call_id,
+                            ELICITATION_TOOL_NAME.to_string(),
+                            status.clone(),
+                        )
+                        .raw(),
+                    )
+                    .await?;
+
+                let (action, feedback) = elicitation_action(&status);
+                let content = match action {
+                    McpServerElicitationAction::Accept => accept_content,
+                    _ => None,
+                };
                 let response = McpServerElicitationRequestResponse {
-                    action: McpServerElicitationAction::Decline,
-                    content: None,
+                    action,
+                    content,
                     meta: None,
                 };
-                send_server_response(peer, request_id, response).await
+                send_server_response(peer, request_id, response).await?;
+                if let Some(message) = feedback {
+                    tracing::debug!("queueing elicitation feedback: {message}");
+                    self.enqueue_feedback(message).await;
+                }
+                Ok(())
             }
             ServerRequest::PermissionsRequestApproval { request_id, params } => {
                 tracing::warn!(
@@ -776,6 +870,57 @@ impl JsonRpcCallbacks for AppServerClient {
     }
 }
 
+const ELICITATION_TOOL_NAME: &str = "codex.elicitation";
+
+/// Elicitations carry no item id, so derive a stable call id from the JSON-RPC request id.
+fn elicitation_call_id(request_id: &RequestId) -> String {
+    match request_id {
+        RequestId::String(id) => format!("elicitation-{id}"),
+        RequestId::Integer(id) => format!("elicitation-{id}"),
+    }
+}
+
+/// Maps the user's response to an elicitation prompt. A denial with a reason declines the
+/// elicitation and forwards the reason to the agent as feedback.
+fn elicitation_action(status: &ApprovalStatus) -> (McpServerElicitationAction, Option<String>) {
+    match status {
+        ApprovalStatus::Approved => (McpServerElicitationAction::Accept, None),
+        ApprovalStatus::Denied { reason } => (
+            McpServerElicitationAction::Decline,
+            reason
+                .as_deref()
+                .map(str::trim)
+                .filter(|reason| !reason.is_empty())
+                .map(str::to_string),
+        ),
+        ApprovalStatus::TimedOut | ApprovalStatus::Pending => {
+            (McpServerElicitationAction::Decline, None)
+        }
+    }
+}
+
+/// Content to send when an elicitation is accepted. Approvals carry no form values, so a form is
+/// only answerable with `{}` when its schema requires nothing; URL elicitations take no content.
+/// `None` means the form cannot be satisfied and must be declined.
+fn elicitation_accept_content(input: &Value) -> Option<Option<Value>> {
+    if input.get("mode").and_then(Value::as_str) == Some("url") {
+        return Some(None);
+    }
+    elicitation_required_fields(input)
+        .is_empty()
+        .then(|| Some(Value::Object(Default::default())))
+}
+
+/// Names listed in a form elicitation's `requestedSchema.required`.
+fn elicitation_required_fields(input: &Value) -> Vec<&str> {
+    input
+        .get("requestedSchema")
+        .and_then(|schema| schema.get("required"))
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
 async fn send_server_response<T>(
     peer: &JsonRpcPeer,
     request_id: RequestId,
@@ -810,9 +955,10 @@ mod tests {
     use std::{process::Stdio, sync::Arc};
 
     use async_trait::async_trait;
-    use codex_app_server_protocol::DynamicToolCallParams;
+    use codex_app_server_protocol::{DynamicToolCallParams, McpServerElicitationRequestParams};
     use codex_protocol::protocol::{ExitedReviewModeEvent, ReviewRequest, ReviewTarget};
     use tokio::{
+        io::{AsyncBufReadExt, BufReader},
         process::Command,
         sync::{Mutex, oneshot},
         time::{Duration, timeout},
@@ -1429,6 +1575,193 @@ mod tests {
         );
         assert_eq!(calls[0].1, "call-1");
     }
+
+    #[test]
+    fn elicitation_action_maps_approval_status() {
+        assert!(matches!(
+            elicitation_action(&ApprovalStatus::Approved),
+            (McpServerElicitationAction::Accept, None)
+        ));
+        assert!(matches!(
+            elicitation_action(&ApprovalStatus::TimedOut),
+            (McpServerElicitationAction::Decline, None)
+        ));
+        let (action, feedback) = elicitation_action(&ApprovalStatus::Denied {
+            reason: Some(" use the staging token ".to_string()),
+        });
+        assert!(matches!(action, McpServerElicitationAction::Decline));
+        assert_eq!(feedback.as_deref(), Some("use the staging token"));
+    }
+
+    #[test]
+    fn elicitation_accept_content_only_answers_forms_without_required_fields() {
+        let url = serde_json::json!({ "mode": "url", "url": "https://example.com/login" });
+        assert_eq!(elicitation_accept_content(&url), Some(None));
+
+        let confirm = serde_json::json!({
+            "mode": "form",
+            "requestedSchema": { "type": "object", "properties": {} },
+        });
+        assert_eq!(
+            elicitation_accept_content(&confirm),
+            Some(Some(serde_json::json!({})))
+        );
+
+        let login = serde_json::json!({
+            "mode": "form",
+            "requestedSchema": {
+                "type": "object",
+                "properties": { "token": { "type": "string" } },
+                "required": ["token"],
+            },
+        });
+        assert_eq!(elicitation_accept_content(&login), None);
+    }
+
+    #[tokio::test]
+    async fn elicitation_request_waits_for_approval_and_accepts() {
+        #[derive(Default)]
+        struct RecordingApprovalService {
+            calls: Mutex<Vec<(String, String, Value)>>,
+        }
+
+        #[async_trait]
+        impl ExecutorApprovalService for RecordingApprovalService {
+            async fn request_tool_approval(
+                &self,
+                tool_name: &str,
+                tool_input: Value,
+                tool_call_id: &str,
+            ) -> Result<ApprovalStatus, ExecutorApprovalError> {
+                self.calls.lock().await.push((
+                    tool_name.to_string(),
+                    tool_call_id.to_string(),
+                    tool_input,
+                ));
+                Ok(ApprovalStatus::Approved)
+            }
+        }
+
+        let approvals = Arc::new(RecordingApprovalService::default());
+        let state = Arc::new(Mutex::new(ResponseState::default()));
+        let peer = spawn_peer(state.clone()).await;
+        let client = AppServerClient::new(
+            LogWriter::new(tokio::io::sink()),
+            Some(approvals.clone()),
+            false,
+            VkDynamicToolContext::new(std::env::temp_dir()),
+        );
+
+        let params: McpServerElicitationRequestParams = serde_json::from_value(serde_json::json!({
+            "threadId": "thread-1",
+            "turnId": "turn-1",
+            "serverName": "docs",
+            "mode": "url",
+            "message": "Sign in to continue",
+            "url": "https://example.com/login",
+            "elicitationId": "elicit-1",
+        }))
+        .expect("elicitation params");
+
+        client
+            .handle_server_request(
+                &peer,
+                ServerRequest::McpServerElicitationRequest {
+                    request_id: RequestId::Integer(7),
+                    params,
+                },
+            )
+            .await
+            .expect("handle elicitation");
+
+        let response = wait_for_response(state).await;
+        let parsed: McpServerElicitationRequestResponse =
+            serde_json::from_value(response.result).expect("decode response");
+        assert!(matches!(parsed.action, McpServerElicitationAction::Accept));
+        assert!(parsed.content.is_none());
+
+        let calls = approvals.calls.lock().await.clone();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, ELICITATION_TOOL_NAME);
+        assert_eq!(calls[0].1, "elicitation-7");
+        assert_eq!(calls[0].2["message"], "Sign in to continue");
+    }
+
+    #[tokio::test]
+    async fn elicitation_form_with_required_fields_is_declined_visibly() {
+        struct PanickingApprovalService;
+
+        #[async_trait]
+        impl ExecutorApprovalService for PanickingApprovalService {
+            async fn request_tool_approval(
+                &self,
+                _tool_name: &str,
+                _tool_input: Value,
+                _tool_call_id: &str,
+            ) -> Result<ApprovalStatus, ExecutorApprovalError> {
+                panic!("a form with required fields must not be sent for approval");
+            }
+        }
+
+        let state = Arc::new(Mutex::new(ResponseState::default()));
+        let peer = spawn_peer(state.clone()).await;
+        let (log_tx, log_rx) = tokio::io::duplex(64 * 1024);
+        let client = AppServerClient::new(
+            LogWriter::new(log_tx),
+            Some(Arc::new(PanickingApprovalService)),
+            false,
+            VkDynamicToolContext::new(std::env::temp_dir()),
+        );
+
+        let params: McpServerElicitationRequestParams = serde_json::from_value(serde_json::json!({
+            "threadId": "thread-1",
+            "turnId": "turn-1",
+            "serverName": "docs",
+            "mode": "form",
+            "message": "Paste your API token",
+            "requestedSchema": {
+                "type": "object",
+                "properties": { "token": { "type": "string" } },
+                "required": ["token"],
+            },
+        }))
+        .expect("elicitation params");
+
+        client
+            .handle_server_request(
+                &peer,
+                ServerRequest::McpServerElicitationRequest {
+                    request_id: RequestId::Integer(9),
+                    params,
+                },
+            )
+            .await
+            .expect("handle elicitation");
+
+        let response = wait_for_response(state).await;
+        let parsed: McpServerElicitationRequestResponse =
+            serde_json::from_value(response.result).expect("decode response");
+        assert!(matches!(parsed.action, McpServerElicitationAction::Decline));
+
+        let mut line = String::new();
+        BufReader::new(log_rx)
+            .read_line(&mut line)
+            .await
+            .expect("read logged line");
+        match serde_json::from_str::<Approval>(&line).expect("approval log line") {
+            Approval::ElicitationDeclined {
+                call_id,
+                server_name,
+                reason,
+            } => {
+                assert_eq!(call_id, "elicitation-9");
+                assert_eq!(server_name, "docs");
+                assert!(reason.contains("Paste your API token"));
+                assert!(reason.contains("(token)"));
+            }
+            other => panic!("unexpected approval log: {other:?}"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -37,7 +37,7 @@ use regex::Regex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use strum_macros::AsRefStr;
+use strum_macros::{AsRefStr, EnumString};
 use tokio::process::Command;
 use ts_rs::TS;
 
@@ -78,7 +78,7 @@ pub enum AskForApproval {
 }
 
 /// Reasoning effort for the underlying model
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS, JsonSchema, AsRefStr)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS, JsonSchema, AsRefStr, EnumString)]
 #[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub enum ReasoningEffort {
@@ -754,6 +754,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn thread_start_params_forward_reasoning_effort() {
+        let mut executor: Codex = serde_json::from_value(json!({})).expect("default config");
+        executor.model_reasoning_effort = Some("xhigh".parse().expect("known effort"));
+        let params = executor.build_thread_start_params(std::path::Path::new("/tmp"));
+        let config = params.config.expect("config overrides");
+        assert_eq!(config.get("model_reasoning_effort"), Some(&json!("xhigh")));
+    }
+
     #[test]
     fn thread_start_params_skips_dynamic_tools_when_disabled() {
         let mut executor: Codex = serde_json::from_value(json!({})).expect("default config");
@@ -2,6 +2,7 @@ use std::{
     collections::HashMap,
     path::{Path, PathBuf},
     sync::{Arc, LazyLock},
+    time::Duration,
 };
 
 use codex_app_server_protocol::{JSONRPCNotification, JSONRPCResponse, ServerNotification};
@@ -29,6 +30,8 @@ use executors_core::{
         plain_text_processor::PlainTextLogProcessor,
         utils::{
             ConversationPatch, EntryIndexProvider,
+            ansi::ansi_to_html,
+            language::language_for_path,
             patch::{add_normalized_entry, replace_normalized_entry, upsert_normalized_entry},
         },
     },
@@ -42,6 +45,8 @@ use utils_core::{
     approvals::ApprovalStatus, diff::normalize_unified_diff, path::make_path_relative,
 };
 
+use crate::codex::session::SessionHandler;
+
 trait ToNormalizedEntry {
     fn to_normalized_entry(&self) -> NormalizedEntry;
 }
@@ -74,17 +79,82 @@ struct StreamingText {
     content: String,
 }
 
+/// One stream of command output. Once more than `max_bytes` arrive, only the first and last
+/// `max_bytes / 2` bytes are kept and the rest is counted as omitted.
+#[derive(Default)]
+struct CommandOutput {
+    head: String,
+    tail: String,
+    omitted_bytes: usize,
+}
+
+impl CommandOutput {
+    fn push(&mut self, chunk: &str, max_bytes: usize) {
+        if max_bytes == 0 {
+            self.head.push_str(chunk);
+            return;
+        }
+
+        let mut chunk = chunk;
+        if self.tail.is_empty() && self.omitted_bytes == 0 {
+            let room = (max_bytes / 2).saturating_sub(self.head.len());
+            let split = floor_char_boundary(chunk, room);
+            self.head.push_str(&chunk[..split]);
+            chunk = &chunk[split..];
+        }
+        self.tail.push_str(chunk);
+
+        let tail_budget = max_bytes - max_bytes / 2;
+        if self.tail.len() > tail_budget {
+            let cut = ceil_char_boundary(&self.tail, self.tail.len() - tail_budget);
+            self.tail.drain(..cut);
+            self.omitted_bytes += cut;
+        }
+    }
+
+    fn render(&self) -> String {
+        if self.omitted_bytes == 0 {
+            format!("{}{}", self.head, self.tail)
+        } else {
+            format!(
+                "{}\n[... {} bytes omitted ...]\n{}",
+                self.head, self.omitted_bytes, self.tail
+            )
+        }
+    }
+}
+
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn ceil_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
 #[derive(Default)]
 struct CommandState {
     index: Option<usize>,
     command: String,
-    stdout: String,
-    stderr: String,
+    stdout: CommandOutput,
+    stderr: CommandOutput,
     formatted_output: Option<String>,
     status: ToolStatus,
     exit_code: Option<i32>,
+    signal: Option<i32>,
     awaiting_approval: bool,
     call_id: String,
+    retry_of: Option<String>,
+    output_format: CommandOutputFormat,
+    output_ansi: CommandOutputAnsi,
 }
 
 impl ToNormalizedEntry for CommandState {
@@ -98,14 +168,22 @@ impl ToNormalizedEntry for CommandState {
                 action_type: ActionType::CommandRun {
                     command: self.command.clone(),
                     result: Some(CommandRunResult {
-                        exit_status: self
-                            .exit_code
-                            .map(|code| CommandExitStatus::ExitCode { code }),
-                        output: if self.formatted_output.is_some() {
-                            self.formatted_output.clone()
-                        } else {
-                            build_command_output(Some(&self.stdout), Some(&self.stderr))
+                        exit_status: match self.signal {
+                            Some(signal) => Some(CommandExitStatus::Signal { signal }),
+                            None => self
+                                .exit_code
+                                .map(|code| CommandExitStatus::ExitCode { code }),
                         },
+                        output: match (&self.formatted_output, self.output_format) {
+                            (Some(formatted), CommandOutputFormat::Formatted) => {
+                                Some(formatted.clone())
+                            }
+                            _ => build_command_output(
+                                Some(&self.stdout.render()),
+                                Some(&self.stderr.render()),
+                            ),
+                        }
+                        .map(|output| self.output_ansi.render(&output)),
                     }),
                 },
                 status: self.status.clone(),
@@ -113,6 +191,7 @@ impl ToNormalizedEntry for CommandState {
             content,
             metadata: serde_json::to_value(ToolCallMetadata {
                 tool_call_id: self.call_id.clone(),
+                retry_of_tool_call_id: self.retry_of.clone(),
             })
             .ok(),
         }
@@ -213,6 +292,7 @@ impl ToNormalizedEntry for DynamicToolState {
             content,
             metadata: serde_json::to_value(ToolCallMetadata {
                 tool_call_id: self.call_id.clone(),
+                ..Default::default()
             })
             .ok(),
         }
@@ -272,6 +352,7 @@ impl ToNormalizedEntry for WebSearchState {
 #[derive(Default)]
 struct PatchState {
     entries: Vec<PatchEntry>,
+    retry_of: Option<String>,
 }
 
 struct PatchEntry {
@@ -281,6 +362,7 @@ struct PatchEntry {
     status: ToolStatus,
     awaiting_approval: bool,
     call_id: String,
+    retry_of: Option<String>,
 }
 
 impl ToNormalizedEntry for PatchEntry {
@@ -300,12 +382,17 @@ impl ToNormalizedEntry for PatchEntry {
             content,
             metadata: serde_json::to_value(ToolCallMetadata {
                 tool_call_id: self.call_id.clone(),
+                retry_of_tool_call_id: self.retry_of.clone(),
             })
             .ok(),
         }
     }
 }
 
+/// Approval tool names used by the Codex client for commands and patches.
+const EXEC_COMMAND_APPROVAL_TOOL: &str = "codex.exec_command";
+const APPLY_PATCH_APPROVAL_TOOL: &str = "codex.apply_patch";
+
 struct LogState {
     entry_index: EntryIndexProvider,
     assistant: Option<StreamingText>,
@@ -315,8 +402,13 @@ struct LogState {
     dynamic_tools: HashMap<String, DynamicToolState>,
     patches: HashMap<String, PatchState>,
     web_searches: HashMap<String, WebSearchState>,
+    /// Latest denied call per approval tool name, waiting for the agent's retry.
+    denied_calls: HashMap<String, String>,
     token_usage_info: Option<TokenUsageInfo>,
     agent_session_id: Option<String>,
+    command_output_max_bytes: usize,
+    command_output_format: CommandOutputFormat,
+    command_output_ansi: CommandOutputAnsi,
 }
 
 enum StreamingTextKind {
@@ -335,11 +427,77 @@ impl LogState {
             dynamic_tools: HashMap::new(),
             patches: HashMap::new(),
             web_searches: HashMap::new(),
+            denied_calls: HashMap::new(),
             token_usage_info: None,
             agent_session_id: None,
+            command_output_max_bytes: command_output_max_bytes(),
+            command_output_format: command_output_format(),
+            command_output_ansi: command_output_ansi(),
         }
     }
 
+    fn record_approval(&mut self, approval: &Approval) {
+        if let Approval::ApprovalResponse {
+            call_id,
+            tool_name,
+            approval_status: ApprovalStatus::Denied { .. },
+        } = approval
+        {
+            self.denied_calls.insert(tool_name.clone(), call_id.clone());
+        }
+    }
+
+    /// Links a new `tool_name` call to the pending denial it retries, consuming the denial. Only
+    /// calls for which `is_retry` holds against the denied call id are linked; anything else
+    /// leaves the denial pending.
+    fn take_denied_call(
+        &mut self,
+        tool_name: &str,
+        call_id: &str,
+        is_retry: impl FnOnce(&Self, &str) -> bool,
+    ) -> Option<String> {
+        match self.denied_calls.get(tool_name) {
+            Some(denied_call_id) if denied_call_id != call_id && is_retry(self, denied_call_id) => {
+                self.denied_calls.remove(tool_name)
+            }
+            _ => None,
+        }
+    }
+
+    /// A command retries a denied one when it runs the same command text.
+    fn take_denied_command(&mut self, call_id: &str, command: &str) -> Option<String> {
+        self.take_denied_call(
+            EXEC_COMMAND_APPROVAL_TOOL,
+            call_id,
+            |state, denied_call_id| {
+                state
+                    .commands
+                    .get(denied_call_id)
+                    .is_some_and(|denied| denied.command == command)
+            },
+        )
+    }
+
+    /// A patch retries a denied one when it touches at least one of the same paths.
+    fn take_denied_patch(
+        &mut self,
+        call_id: &str,
+        changes: &[(String, Vec<FileChange>)],
+    ) -> Option<String> {
+        self.take_denied_call(
+            APPLY_PATCH_APPROVAL_TOOL,
+            call_id,
+            |state, denied_call_id| {
+                state.patches.get(denied_call_id).is_some_and(|denied| {
+                    denied
+                        .entries
+                        .iter()
+                        .any(|entry| changes.iter().any(|(path, _)| *path == entry.path))
+                })
+            },
+        )
+    }
+
     fn streaming_text_update(
         &mut self,
         content: String,
@@ -437,11 +595,16 @@ fn normalize_file_changes(
                         let dest_rel =
                             make_path_relative(dest.to_string_lossy().as_ref(), worktree_path);
                         edits.push(FileChange::Rename { new_path: dest_rel });
+                        // A pure move carries no hunks; an empty Edit would only add noise.
+                        if !has_diff_hunks(unified_diff) {
+                            return (relative, edits);
+                        }
                     }
                     let diff = normalize_unified_diff(&relative, unified_diff);
                     edits.push(FileChange::Edit {
                         unified_diff: diff,
                         has_line_numbers: true,
+                        language: language_for_path(&relative).map(str::to_string),
                     });
                     edits
                 }
@@ -451,6 +614,10 @@ fn normalize_file_changes(
         .collect()
 }
 
+fn has_diff_hunks(unified_diff: &str) -> bool {
+    unified_diff.lines().any(|line| line.starts_with("@@"))
+}
+
 fn format_todo_status(status: &StepStatus) -> String {
     match status {
         StepStatus::Pending => "pending",
@@ -483,6 +650,7 @@ fn emit_normalization_error(
             metadata: call_id.and_then(|call_id| {
                 serde_json::to_value(ToolCallMetadata {
                     tool_call_id: call_id.to_string(),
+                    ..Default::default()
                 })
                 .ok()
             }),
@@ -490,7 +658,44 @@ fn emit_normalization_error(
     );
 }
 
+const NORMALIZE_DEBUG_ENV: &str = "VK_CODEX_NORMALIZE_DEBUG";
+const MALFORMED_EVENT_SNIPPET_CHARS: usize = 200;
+
+fn normalize_debug_enabled() -> bool {
+    match std::env::var(NORMALIZE_DEBUG_ENV) {
+        Ok(value) => matches!(
+            value.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes"
+        ),
+        Err(_) => false,
+    }
+}
+
+/// Builds a low-severity entry describing a line that looked like a codex event but could not
+/// be parsed. Only emitted when `VK_CODEX_NORMALIZE_DEBUG` is enabled.
+fn malformed_codex_event_entry(line: &str, reason: &str) -> NormalizedEntry {
+    let trimmed = line.trim();
+    let mut snippet: String = trimmed
+        .chars()
+        .take(MALFORMED_EVENT_SNIPPET_CHARS)
+        .collect();
+    if snippet.len() < trimmed.len() {
+        snippet.push('…');
+    }
+
+    NormalizedEntry {
+        timestamp: None,
+        entry_type: NormalizedEntryType::SystemMessage,
+        content: format!("Skipped malformed codex event ({reason}): {snippet}"),
+        metadata: None,
+    }
+}
+
 pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
+    normalize_logs_with_debug(msg_store, worktree_path, normalize_debug_enabled());
+}
+
+fn normalize_logs_with_debug(msg_store: Arc<MsgStore>, worktree_path: &Path, debug: bool) {
     let entry_index = EntryIndexProvider::start_from(&msg_store);
     normalize_codex_stderr_logs(msg_store.clone(), entry_index.clone());
 
@@ -506,6 +711,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
             }
 
             if let Ok(approval) = serde_json::from_str::<Approval>(&line) {
+                state.record_approval(&approval);
                 if let Some(entry) = approval.to_normalized_entry_opt() {
                     add_normalized_entry(&msg_store, &entry_index, entry);
                 }
@@ -561,18 +767,32 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
 
             let notification: JSONRPCNotification = match serde_json::from_str(&line) {
                 Ok(value) => value,
-                Err(_) => continue,
+                Err(err) => {
+                    if debug && line.contains("\"codex/event") {
+                        let entry = malformed_codex_event_entry(&line, &err.to_string());
+                        add_normalized_entry(&msg_store, &entry_index, entry);
+                    }
+                    continue;
+                }
             };
 
             if !notification.method.starts_with("codex/event") {
                 continue;
             }
 
-            let Some(params) = notification
+            let params = match notification
                 .params
-                .and_then(|p| serde_json::from_value::<CodexNotificationParams>(p).ok())
-            else {
-                continue;
+                .map(serde_json::from_value::<CodexNotificationParams>)
+            {
+                Some(Ok(params)) => params,
+                Some(Err(err)) => {
+                    if debug {
+                        let entry = malformed_codex_event_entry(&line, &err.to_string());
+                        add_normalized_entry(&msg_store, &entry_index, entry);
+                    }
+                    continue;
+                }
+                None => continue,
             };
 
             if let Some(conversation_id) = params
@@ -644,11 +864,19 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                         command.join(" ")
                     };
 
+                    let retry_of = match state.commands.get(&call_id) {
+                        Some(command_state) => command_state.retry_of.clone(),
+                        None => state.take_denied_command(&call_id, &command_text),
+                    };
                     let command_state = state.commands.entry(call_id.clone()).or_default();
 
                     if command_state.command.is_empty() {
                         command_state.command = command_text;
                     }
+                    command_state.call_id = call_id.clone();
+                    command_state.retry_of = retry_of;
+                    command_state.output_format = state.command_output_format;
+                    command_state.output_ansi = state.command_output_ansi;
                     command_state.awaiting_approval = true;
                     if let Some(index) = command_state.index {
                         replace_normalized_entry(
@@ -676,7 +904,12 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                     state.thinking = None;
 
                     let normalized = normalize_file_changes(&worktree_path_str, &changes);
+                    let retry_of = match state.patches.get(&call_id) {
+                        Some(patch_state) => patch_state.retry_of.clone(),
+                        None => state.take_denied_patch(&call_id, &normalized),
+                    };
                     let patch_state = state.patches.entry(call_id.clone()).or_default();
+                    patch_state.retry_of = retry_of;
 
                     for entry in patch_state.entries.drain(..) {
                         if let Some(index) = entry.index {
@@ -692,6 +925,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                             status: ToolStatus::Created,
                             awaiting_approval: true,
                             call_id: call_id.clone(),
+                            retry_of: patch_state.retry_of.clone(),
                         };
                         let index = add_normalized_entry(
                             &msg_store,
@@ -718,16 +952,24 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                     if command_text.is_empty() {
                         continue;
                     }
+                    let retry_of = match state.commands.get(&call_id) {
+                        Some(command_state) => command_state.retry_of.clone(),
+                        None => state.take_denied_command(&call_id, &command_text),
+                    };
                     let mut command_state = CommandState {
                         index: None,
                         command: command_text,
-                        stdout: String::new(),
-                        stderr: String::new(),
+                        stdout: CommandOutput::default(),
+                        stderr: CommandOutput::default(),
                         formatted_output: None,
                         status: ToolStatus::Created,
                         exit_code: None,
+                        signal: None,
                         awaiting_approval: false,
                         call_id: call_id.clone(),
+                        retry_of,
+                        output_format: state.command_output_format,
+                        output_ansi: state.command_output_ansi,
                     };
                     let index = add_normalized_entry(
                         &msg_store,
@@ -742,14 +984,19 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                     stream,
                     chunk,
                 }) => {
+                    let max_bytes = state.command_output_max_bytes;
                     if let Some(command_state) = state.commands.get_mut(&call_id) {
                         let chunk = String::from_utf8_lossy(&chunk);
                         if chunk.is_empty() {
                             continue;
                         }
                         match stream {
-                            ExecOutputStream::Stdout => command_state.stdout.push_str(&chunk),
-                            ExecOutputStream::Stderr => command_state.stderr.push_str(&chunk),
+                            ExecOutputStream::Stdout => {
+                                command_state.stdout.push(&chunk, max_bytes)
+                            }
+                            ExecOutputStream::Stderr => {
+                                command_state.stderr.push(&chunk, max_bytes)
+                            }
                         }
                         let Some(index) = command_state.index else {
                             tracing::error!(
@@ -778,6 +1025,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                     ..
                 }) => match state.commands.remove(&call_id) {
                     Some(mut command_state) => {
+                        command_state.signal = termination_signal(exit_code, &formatted_output);
                         command_state.formatted_output = Some(formatted_output);
                         command_state.exit_code = Some(exit_code);
                         command_state.awaiting_approval = false;
@@ -1065,6 +1313,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                     state.thinking = None;
                     let normalized = normalize_file_changes(&worktree_path_str, &changes);
                     if let Some(patch_state) = state.patches.get_mut(&call_id) {
+                        let retry_of = patch_state.retry_of.clone();
                         let mut iter = normalized.into_iter();
                         for entry in &mut patch_state.entries {
                             if let Some((path, file_changes)) = iter.next() {
@@ -1096,6 +1345,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                                 status: ToolStatus::Created,
                                 awaiting_approval: false,
                                 call_id: call_id.clone(),
+                                retry_of: retry_of.clone(),
                             };
                             let index = add_normalized_entry(
                                 &msg_store,
@@ -1106,7 +1356,10 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                             patch_state.entries.push(entry);
                         }
                     } else {
-                        let mut patch_state = PatchState::default();
+                        let mut patch_state = PatchState {
+                            retry_of: state.take_denied_patch(&call_id, &normalized),
+                            ..Default::default()
+                        };
                         for (path, file_changes) in normalized {
                             let mut patch_entry = PatchEntry {
                                 index: None,
@@ -1115,6 +1368,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                                 status: ToolStatus::Created,
                                 awaiting_approval: false,
                                 call_id: call_id.clone(),
+                                retry_of: patch_state.retry_of.clone(),
                             };
                             let index = add_normalized_entry(
                                 &msg_store,
@@ -1366,26 +1620,158 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
     });
 }
 
+const COMMAND_OUTPUT_MAX_BYTES_ENV: &str = "VK_CODEX_COMMAND_OUTPUT_MAX_BYTES";
+const DEFAULT_COMMAND_OUTPUT_MAX_BYTES: usize = 256 * 1024;
+
+fn command_output_max_bytes() -> usize {
+    parse_command_output_max_bytes(std::env::var(COMMAND_OUTPUT_MAX_BYTES_ENV).ok().as_deref())
+}
+
+/// `0` disables the cap.
+fn parse_command_output_max_bytes(raw: Option<&str>) -> usize {
+    let Some(raw) = raw.map(str::trim) else {
+        return DEFAULT_COMMAND_OUTPUT_MAX_BYTES;
+    };
+    match raw.parse::<usize>() {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            tracing::warn!(
+                "Invalid {COMMAND_OUTPUT_MAX_BYTES_ENV}={raw:?}; using default \
+                 {DEFAULT_COMMAND_OUTPUT_MAX_BYTES}"
+            );
+            DEFAULT_COMMAND_OUTPUT_MAX_BYTES
+        }
+    }
+}
+
+const COMMAND_OUTPUT_FORMAT_ENV: &str = "VK_CODEX_COMMAND_OUTPUT_FORMAT";
+
+/// Which output a finished command shows in the transcript.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum CommandOutputFormat {
+    /// Codex's `formatted_output` from the end event.
+    #[default]
+    Formatted,
+    /// The streamed stdout/stderr, as captured from output deltas.
+    Raw,
+}
+
+fn command_output_format() -> CommandOutputFormat {
+    parse_command_output_format(std::env::var(COMMAND_OUTPUT_FORMAT_ENV).ok().as_deref())
+}
+
+fn parse_command_output_format(raw: Option<&str>) -> CommandOutputFormat {
+    let Some(raw) = raw.map(str::trim) else {
+        return CommandOutputFormat::default();
+    };
+    match raw.to_ascii_lowercase().as_str() {
+        "formatted" => CommandOutputFormat::Formatted,
+        "raw" => CommandOutputFormat::Raw,
+        _ => {
+            tracing::warn!(
+                "Invalid {COMMAND_OUTPUT_FORMAT_ENV}={raw:?}; expected \"formatted\" or \"raw\", \
+                 using formatted"
+            );
+            CommandOutputFormat::default()
+        }
+    }
+}
+
+const COMMAND_OUTPUT_ANSI_ENV: &str = "VK_CODEX_COMMAND_OUTPUT_ANSI";
+
+/// How ANSI escape codes in command output reach the transcript.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum CommandOutputAnsi {
+    /// Escape codes are stripped, leaving plain text.
+    #[default]
+    Plain,
+    /// SGR codes become HTML spans with `ansi-*` classes; the text is HTML-escaped.
+    Html,
+    /// Escape codes are kept for the frontend's ANSI renderer.
+    Raw,
+}
+
+impl CommandOutputAnsi {
+    fn render(self, output: &str) -> String {
+        match self {
+            CommandOutputAnsi::Plain => strip_ansi_escapes::strip_str(output),
+            CommandOutputAnsi::Html => ansi_to_html(output),
+            CommandOutputAnsi::Raw => output.to_string(),
+        }
+    }
+}
+
+fn command_output_ansi() -> CommandOutputAnsi {
+    parse_command_output_ansi(std::env::var(COMMAND_OUTPUT_ANSI_ENV).ok().as_deref())
+}
+
+fn parse_command_output_ansi(raw: Option<&str>) -> CommandOutputAnsi {
+    let Some(raw) = raw.map(str::trim) else {
+        return CommandOutputAnsi::default();
+    };
+    match raw.to_ascii_lowercase().as_str() {
+        "plain" => CommandOutputAnsi::Plain,
+        "html" => CommandOutputAnsi::Html,
+        "raw" => CommandOutputAnsi::Raw,
+        _ => {
+            tracing::warn!(
+                "Invalid {COMMAND_OUTPUT_ANSI_ENV}={raw:?}; expected \"plain\", \"html\" or \
+                 \"raw\", using plain"
+            );
+            CommandOutputAnsi::default()
+        }
+    }
+}
+
+const STDERR_TIME_GAP_ENV: &str = "VK_CODEX_STDERR_TIME_GAP_MS";
+const DEFAULT_STDERR_TIME_GAP: Duration = Duration::from_secs(2);
+
+fn stderr_time_gap() -> Duration {
+    parse_stderr_time_gap(std::env::var(STDERR_TIME_GAP_ENV).ok().as_deref())
+}
+
+fn parse_stderr_time_gap(raw: Option<&str>) -> Duration {
+    let Some(raw) = raw.map(str::trim) else {
+        return DEFAULT_STDERR_TIME_GAP;
+    };
+    match raw.parse::<u64>() {
+        Ok(millis) if millis > 0 => Duration::from_millis(millis),
+        _ => {
+            tracing::warn!(
+                "Invalid {STDERR_TIME_GAP_ENV}={raw:?}; using default {}ms",
+                DEFAULT_STDERR_TIME_GAP.as_millis()
+            );
+            DEFAULT_STDERR_TIME_GAP
+        }
+    }
+}
+
+fn codex_stderr_processor(
+    entry_index_provider: EntryIndexProvider,
+    time_gap: Duration,
+) -> PlainTextLogProcessor {
+    PlainTextLogProcessor::builder()
+        .normalized_entry_producer(Box::new(|content: String| NormalizedEntry {
+            timestamp: None,
+            entry_type: NormalizedEntryType::SystemMessage,
+            content: strip_ansi_escapes::strip_str(&content),
+            metadata: None,
+        }))
+        .transform_lines(Box::new(|lines| {
+            lines.retain(|line| {
+                // codex-core 0.84 logs this at ERROR even when it's benign.
+                !line.contains("codex_core::codex: needs_follow_up:")
+            });
+        }))
+        .time_gap(time_gap)
+        .index_provider(entry_index_provider)
+        .build()
+}
+
 fn normalize_codex_stderr_logs(msg_store: Arc<MsgStore>, entry_index_provider: EntryIndexProvider) {
     tokio::spawn(async move {
         let mut stderr = msg_store.clone().stderr_chunked_stream();
-
-        let mut processor = PlainTextLogProcessor::builder()
-            .normalized_entry_producer(Box::new(|content: String| NormalizedEntry {
-                timestamp: None,
-                entry_type: NormalizedEntryType::SystemMessage,
-                content: strip_ansi_escapes::strip_str(&content),
-                metadata: None,
-            }))
-            .transform_lines(Box::new(|lines| {
-                lines.retain(|line| {
-                    // codex-core 0.84 logs this at ERROR even when it's benign.
-                    !line.contains("codex_core::codex: needs_follow_up:")
-                });
-            }))
-            .time_gap(std::time::Duration::from_secs(2))
-            .index_provider(entry_index_provider)
-            .build();
+        let mut processor = codex_stderr_processor(entry_index_provider, stderr_time_gap());
 
         while let Some(Ok(chunk)) = stderr.next().await {
             for patch in processor.process(chunk) {
@@ -1410,6 +1796,33 @@ fn handle_jsonrpc_response(
 
     if let Some(thread_id) = thread_id {
         msg_store.push_session_id(thread_id);
+    } else if let Some(rollout_path) = response
+        .result
+        .get("rolloutPath")
+        .or_else(|| response.result.pointer("/thread/path"))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        match SessionHandler::extract_session_id_from_rollout_path(PathBuf::from(rollout_path)) {
+            Ok(session_id) => msg_store.push_session_id(session_id),
+            Err(err) => {
+                tracing::warn!("Failed to extract codex session id: {err}");
+                add_normalized_entry(
+                    msg_store,
+                    entry_index,
+                    NormalizedEntry {
+                        timestamp: None,
+                        entry_type: NormalizedEntryType::SystemMessage,
+                        content: format!(
+                            "Session tracking unavailable: {err}. Follow-ups and session-scoped \
+                             features may not work for this run."
+                        ),
+                        metadata: None,
+                    },
+                );
+            }
+        }
     }
 
     let model = response
@@ -1476,6 +1889,32 @@ fn build_command_output(stdout: Option<&str>, stderr: Option<&str>) -> Option<St
     }
 }
 
+/// Shells (and codex itself) report a command killed by signal `N` as exit code `128 + N`.
+const SIGNAL_EXIT_CODE_BASE: i32 = 128;
+const MAX_SIGNAL: i32 = 64;
+/// Signals inferred from the exit code alone: SIGINT, SIGKILL and SIGTERM. Other codes above 128
+/// are as likely to be a program's own exit status.
+const EXIT_CODE_SIGNALS: [i32; 3] = [2, 9, 15];
+
+static SIGNAL_IN_OUTPUT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:killed|terminated) by signal:?\s*(\d+)").expect("valid regex")
+});
+
+/// Returns the signal that terminated a command, judging by its formatted output first and
+/// falling back to the `128 + N` exit code convention for the common termination signals.
+fn termination_signal(exit_code: i32, formatted_output: &str) -> Option<i32> {
+    if let Some(signal) = SIGNAL_IN_OUTPUT
+        .captures(formatted_output)
+        .and_then(|caps| caps[1].parse::<i32>().ok())
+        .filter(|signal| (1..=MAX_SIGNAL).contains(signal))
+    {
+        return Some(signal);
+    }
+
+    let signal = exit_code - SIGNAL_EXIT_CODE_BASE;
+    EXIT_CODE_SIGNALS.contains(&signal).then_some(signal)
+}
+
 static SESSION_ID: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r#"^([0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12})"#)
         .expect("valid regex")
@@ -1530,6 +1969,20 @@ pub enum Approval {
         tool_name: String,
         approval_status: ApprovalStatus,
     },
+    /// An MCP server asked the user for input mid-run. Rendered as a tool entry so the pending
+    /// approval that carries the user's answer can attach to it.
+    ElicitationRequest {
+        call_id: String,
+        server_name: String,
+        message: String,
+    },
+    /// An MCP server's input request was declined without asking the user because its form
+    /// cannot be answered through an approval. Rendered as an error so the refusal is visible.
+    ElicitationDeclined {
+        call_id: String,
+        server_name: String,
+        reason: String,
+    },
 }
 
 impl Approval {
@@ -1545,27 +1998,87 @@ impl Approval {
         }
     }
 
+    pub fn elicitation_request(call_id: String, server_name: String, message: String) -> Self {
+        Self::ElicitationRequest {
+            call_id,
+            server_name,
+            message,
+        }
+    }
+
+    pub fn elicitation_declined(call_id: String, server_name: String, reason: String) -> Self {
+        Self::ElicitationDeclined {
+            call_id,
+            server_name,
+            reason,
+        }
+    }
+
     pub fn raw(&self) -> String {
         serde_json::to_string(self).unwrap_or_default()
     }
 
     pub fn display_tool_name(&self) -> String {
-        let Self::ApprovalResponse { tool_name, .. } = self;
-        match tool_name.as_str() {
-            "codex.exec_command" => "Exec Command".to_string(),
-            "codex.apply_patch" => "Edit".to_string(),
-            other => other.to_string(),
+        match self {
+            Self::ApprovalResponse { tool_name, .. } => match tool_name.as_str() {
+                "codex.exec_command" => "Exec Command".to_string(),
+                "codex.apply_patch" => "Edit".to_string(),
+                "codex.elicitation" => "Input Request".to_string(),
+                other => other.to_string(),
+            },
+            Self::ElicitationRequest { server_name, .. }
+            | Self::ElicitationDeclined { server_name, .. } => {
+                format!("{server_name} input request")
+            }
         }
     }
 }
 
 impl ToNormalizedEntryOpt for Approval {
     fn to_normalized_entry_opt(&self) -> Option<NormalizedEntry> {
-        let Self::ApprovalResponse {
-            call_id: _,
-            tool_name: _,
-            approval_status,
-        } = self;
+        let (call_id, approval_status) = match self {
+            Self::ApprovalResponse {
+                call_id,
+                approval_status,
+                ..
+            } => (call_id, approval_status),
+            Self::ElicitationRequest {
+                call_id, message, ..
+            } => {
+                return Some(NormalizedEntry {
+                    timestamp: None,
+                    entry_type: NormalizedEntryType::ToolUse {
+                        tool_name: self.display_tool_name(),
+                        action_type: ActionType::Other {
+                            description: message.clone(),
+                        },
+                        status: ToolStatus::Created,
+                    },
+                    content: message.clone(),
+                    metadata: serde_json::to_value(ToolCallMetadata {
+                        tool_call_id: call_id.clone(),
+                        ..Default::default()
+                    })
+                    .ok(),
+                });
+            }
+            Self::ElicitationDeclined {
+                call_id, reason, ..
+            } => {
+                return Some(NormalizedEntry {
+                    timestamp: None,
+                    entry_type: NormalizedEntryType::ErrorMessage {
+                        error_type: NormalizedEntryError::Other,
+                    },
+                    content: reason.clone(),
+                    metadata: serde_json::to_value(ToolCallMetadata {
+                        tool_call_id: call_id.clone(),
+                        ..Default::default()
+                    })
+                    .ok(),
+                });
+            }
+        };
         let tool_name = self.display_tool_name();
 
         match approval_status {
@@ -1581,7 +2094,11 @@ impl ToNormalizedEntryOpt for Approval {
                     .unwrap_or_else(|| "User denied this tool use request".to_string())
                     .trim()
                     .to_string(),
-                metadata: None,
+                metadata: serde_json::to_value(ToolCallMetadata {
+                    tool_call_id: call_id.clone(),
+                    ..Default::default()
+                })
+                .ok(),
             }),
             ApprovalStatus::TimedOut => Some(NormalizedEntry {
                 timestamp: None,
@@ -1661,6 +2178,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_stderr_time_gap_defaults_and_overrides() {
+        assert_eq!(parse_stderr_time_gap(None), DEFAULT_STDERR_TIME_GAP);
+        assert_eq!(parse_stderr_time_gap(Some("")), DEFAULT_STDERR_TIME_GAP);
+        assert_eq!(parse_stderr_time_gap(Some("0")), DEFAULT_STDERR_TIME_GAP);
+        assert_eq!(parse_stderr_time_gap(Some("soon")), DEFAULT_STDERR_TIME_GAP);
+        assert_eq!(
+            parse_stderr_time_gap(Some(" 5000 ")),
+            Duration::from_millis(5000)
+        );
+    }
+
+    #[test]
+    fn codex_stderr_processor_uses_configured_time_gap() {
+        let msg_store = MsgStore::new();
+        let processor = codex_stderr_processor(
+            EntryIndexProvider::start_from(&msg_store),
+            parse_stderr_time_gap(Some("750")),
+        );
+        assert_eq!(processor.time_gap(), Some(Duration::from_millis(750)));
+    }
+
     #[test]
     fn build_command_output_returns_none_for_empty_sections() {
         assert_eq!(build_command_output(None, None), None);
@@ -1673,6 +2212,208 @@ mod tests {
         assert_eq!(output, "stdout:\nok\n\nstderr:\nwarn");
     }
 
+    #[test]
+    fn termination_signal_detects_signal_exits() {
+        assert_eq!(termination_signal(137, ""), Some(9));
+        assert_eq!(termination_signal(143, "Terminated"), Some(15));
+        assert_eq!(
+            termination_signal(-1, "process killed by signal 9"),
+            Some(9)
+        );
+        assert_eq!(termination_signal(0, "ok"), None);
+        assert_eq!(termination_signal(2, "exit status 2"), None);
+        assert_eq!(termination_signal(128, ""), None);
+        assert_eq!(termination_signal(130, ""), Some(2));
+        // An exit status that merely lands above 128 is not a signal without other evidence.
+        assert_eq!(termination_signal(129, "tool failed"), None);
+        assert_eq!(termination_signal(141, ""), None);
+        assert_eq!(termination_signal(141, "killed by signal 13"), Some(13));
+        assert_eq!(termination_signal(-1, ""), None);
+    }
+
+    #[test]
+    fn command_output_truncates_oversized_streams_to_head_and_tail() {
+        let mut stdout = CommandOutput::default();
+        stdout.push("HEAD", 16);
+        for _ in 0..100 {
+            stdout.push("0123456789", 16);
+        }
+        stdout.push("TAIL", 16);
+
+        let rendered = stdout.render();
+        assert_eq!(rendered, "HEAD0123\n[... 992 bytes omitted ...]\n6789TAIL");
+
+        let mut small = CommandOutput::default();
+        small.push("short", 16);
+        assert_eq!(small.render(), "short");
+
+        let state = CommandState {
+            command: "cat big.log".to_string(),
+            stdout,
+            ..Default::default()
+        };
+        let NormalizedEntryType::ToolUse {
+            action_type: ActionType::CommandRun { result, .. },
+            ..
+        } = state.to_normalized_entry().entry_type
+        else {
+            panic!("expected command run entry");
+        };
+        let output = result
+            .and_then(|result| result.output)
+            .expect("expected output");
+        assert!(output.starts_with("stdout:\nHEAD0123\n"));
+        assert!(output.contains("[... 992 bytes omitted ...]"));
+        assert!(output.ends_with("6789TAIL"));
+
+        let state = CommandState {
+            formatted_output: Some("formatted".to_string()),
+            stdout: CommandOutput {
+                head: "raw".to_string(),
+                tail: "raw".to_string(),
+                omitted_bytes: 10,
+            },
+            ..Default::default()
+        };
+        let NormalizedEntryType::ToolUse {
+            action_type: ActionType::CommandRun { result, .. },
+            ..
+        } = state.to_normalized_entry().entry_type
+        else {
+            panic!("expected command run entry");
+        };
+        assert_eq!(
+            result.and_then(|result| result.output).as_deref(),
+            Some("formatted")
+        );
+    }
+
+    #[test]
+    fn command_output_format_raw_ignores_formatted_output() {
+        let command_output = |output_format| {
+            let state = CommandState {
+                command: "cargo build".to_string(),
+                stdout: CommandOutput {
+                    head: "Compiling app\n".to_string(),
+                    ..Default::default()
+                },
+                stderr: CommandOutput {
+                    head: "warning: unused\n".to_string(),
+                    ..Default::default()
+                },
+                formatted_output: Some("Compiled app with 1 warning".to_string()),
+                exit_code: Some(0),
+                output_format,
+                ..Default::default()
+            };
+            let NormalizedEntryType::ToolUse {
+                action_type: ActionType::CommandRun { result, .. },
+                ..
+            } = state.to_normalized_entry().entry_type
+            else {
+                panic!("expected command run entry");
+            };
+            result.and_then(|result| result.output)
+        };
+
+        assert_eq!(
+            command_output(CommandOutputFormat::Formatted).as_deref(),
+            Some("Compiled app with 1 warning")
+        );
+        assert_eq!(
+            command_output(CommandOutputFormat::Raw).as_deref(),
+            Some("stdout:\nCompiling app\n\nstderr:\nwarning: unused")
+        );
+
+        assert_eq!(
+            parse_command_output_format(None),
+            CommandOutputFormat::Formatted
+        );
+        assert_eq!(
+            parse_command_output_format(Some(" RAW ")),
+            CommandOutputFormat::Raw
+        );
+        assert_eq!(
+            parse_command_output_format(Some("pretty")),
+            CommandOutputFormat::Formatted
+        );
+    }
+
+    #[test]
+    fn command_output_ansi_html_renders_colors_as_spans() {
+        let command_output = |output_ansi| {
+            let state = CommandState {
+                command: "cargo test".to_string(),
+                formatted_output: Some(
+                    "\x1b[32mtest ok\x1b[0m\n\x1b[1;31merror\x1b[0m: a < b".to_string(),
+                ),
+                output_ansi,
+                ..Default::default()
+            };
+            let NormalizedEntryType::ToolUse {
+                action_type: ActionType::CommandRun { result, .. },
+                ..
+            } = state.to_normalized_entry().entry_type
+            else {
+                panic!("expected command run entry");
+            };
+            result.and_then(|result| result.output)
+        };
+
+        assert_eq!(
+            command_output(CommandOutputAnsi::Plain).as_deref(),
+            Some("test ok\nerror: a < b")
+        );
+        assert_eq!(
+            command_output(CommandOutputAnsi::Html).as_deref(),
+            Some(
+                "<span class=\"ansi-green-fg\">test ok</span>\n\
+                 <span class=\"ansi-bold ansi-red-fg\">error</span>: a &lt; b"
+            )
+        );
+        assert_eq!(
+            command_output(CommandOutputAnsi::Raw).as_deref(),
+            Some("\x1b[32mtest ok\x1b[0m\n\x1b[1;31merror\x1b[0m: a < b")
+        );
+
+        assert_eq!(parse_command_output_ansi(None), CommandOutputAnsi::Plain);
+        assert_eq!(
+            parse_command_output_ansi(Some("HTML")),
+            CommandOutputAnsi::Html
+        );
+        assert_eq!(
+            parse_command_output_ansi(Some("raw")),
+            CommandOutputAnsi::Raw
+        );
+        assert_eq!(
+            parse_command_output_ansi(Some("color")),
+            CommandOutputAnsi::Plain
+        );
+    }
+
+    #[test]
+    fn command_output_keeps_char_boundaries_and_can_be_disabled() {
+        let mut output = CommandOutput::default();
+        output.push("ééééé", 5);
+        assert!(output.render().contains("bytes omitted"));
+        assert!(output.render().starts_with('é'));
+
+        let mut unlimited = CommandOutput::default();
+        unlimited.push(&"x".repeat(1000), 0);
+        assert_eq!(unlimited.render().len(), 1000);
+
+        assert_eq!(
+            parse_command_output_max_bytes(None),
+            DEFAULT_COMMAND_OUTPUT_MAX_BYTES
+        );
+        assert_eq!(parse_command_output_max_bytes(Some(" 0 ")), 0);
+        assert_eq!(parse_command_output_max_bytes(Some("4096")), 4096);
+        assert_eq!(
+            parse_command_output_max_bytes(Some("lots")),
+            DEFAULT_COMMAND_OUTPUT_MAX_BYTES
+        );
+    }
+
     #[test]
     fn approval_display_tool_name_maps_known_names() {
         let approval = Approval::approval_response(
@@ -1697,6 +2438,88 @@ mod tests {
         assert_eq!(approval.display_tool_name(), "custom");
     }
 
+    #[tokio::test]
+    async fn normalize_logs_elicitation_request_creates_pending_tool_entry() {
+        let msg_store = Arc::new(MsgStore::new());
+        normalize_logs(msg_store.clone(), std::path::Path::new("/repo"));
+
+        push_json_line(
+            &msg_store,
+            Approval::elicitation_request(
+                "elicitation-7".to_string(),
+                "docs".to_string(),
+                "Sign in to continue".to_string(),
+            )
+            .raw(),
+        );
+
+        let entry = wait_for_entry(&msg_store, |entry| {
+            matches!(entry.entry_type, NormalizedEntryType::ToolUse { .. })
+        })
+        .await;
+        assert_eq!(entry.content, "Sign in to continue");
+        match &entry.entry_type {
+            NormalizedEntryType::ToolUse {
+                tool_name, status, ..
+            } => {
+                assert_eq!(tool_name, "docs input request");
+                assert!(matches!(status, ToolStatus::Created));
+            }
+            other => panic!("unexpected entry type: {other:?}"),
+        }
+        let metadata: ToolCallMetadata =
+            serde_json::from_value(entry.metadata.expect("metadata")).expect("tool call metadata");
+        assert_eq!(metadata.tool_call_id, "elicitation-7");
+
+        push_json_line(
+            &msg_store,
+            Approval::approval_response(
+                "elicitation-7".to_string(),
+                "codex.elicitation".to_string(),
+                ApprovalStatus::Denied {
+                    reason: Some("not now".to_string()),
+                },
+            )
+            .raw(),
+        );
+        let feedback = wait_for_entry(&msg_store, |entry| {
+            matches!(entry.entry_type, NormalizedEntryType::UserFeedback { .. })
+        })
+        .await;
+        match feedback.entry_type {
+            NormalizedEntryType::UserFeedback { denied_tool } => {
+                assert_eq!(denied_tool, "Input Request");
+            }
+            _ => unreachable!(),
+        }
+        msg_store.push_finished();
+    }
+
+    #[test]
+    fn declined_elicitation_emits_error_entry() {
+        let approval = Approval::elicitation_declined(
+            "elicitation-9".to_string(),
+            "docs".to_string(),
+            "Declined docs input request: it needs form fields (token).".to_string(),
+        );
+        let entry = approval
+            .to_normalized_entry_opt()
+            .expect("declined elicitation should render");
+        assert!(matches!(
+            entry.entry_type,
+            NormalizedEntryType::ErrorMessage {
+                error_type: NormalizedEntryError::Other
+            }
+        ));
+        assert_eq!(
+            entry.content,
+            "Declined docs input request: it needs form fields (token)."
+        );
+        let metadata: ToolCallMetadata =
+            serde_json::from_value(entry.metadata.expect("metadata")).expect("tool call metadata");
+        assert_eq!(metadata.tool_call_id, "elicitation-9");
+    }
+
     #[test]
     fn approval_denied_emits_user_feedback() {
         let approval = Approval::approval_response(
@@ -1796,15 +2619,59 @@ mod tests {
             FileChange::Edit {
                 unified_diff,
                 has_line_numbers,
+                language,
             } => {
                 assert!(unified_diff.contains("--- a/old.txt"));
                 assert!(unified_diff.contains("+++ b/old.txt"));
                 assert!(*has_line_numbers);
+                assert_eq!(language, &None);
             }
             _ => panic!("expected edit change"),
         }
     }
 
+    #[test]
+    fn normalize_file_changes_emits_only_rename_for_pure_move() {
+        for unified_diff in ["", "--- a/old.rs\n+++ b/new.rs\n"] {
+            let mut changes = HashMap::new();
+            changes.insert(
+                PathBuf::from("/repo/old.rs"),
+                CodexProtoFileChange::Update {
+                    unified_diff: unified_diff.to_string(),
+                    move_path: Some(PathBuf::from("/repo/new.rs")),
+                },
+            );
+
+            let results = normalize_file_changes("/repo", &changes);
+            let (path, edits) = &results[0];
+            assert_eq!(path, "old.rs");
+            match &edits[..] {
+                [FileChange::Rename { new_path }] => assert_eq!(new_path, "new.rs"),
+                _ => panic!("expected only a rename change"),
+            }
+        }
+    }
+
+    #[test]
+    fn normalize_file_changes_infers_edit_language_from_extension() {
+        let mut changes = HashMap::new();
+        changes.insert(
+            PathBuf::from("/repo/src/main.rs"),
+            CodexProtoFileChange::Update {
+                unified_diff: "@@ -1,1 +1,1 @@\n-fn old() {}\n+fn new() {}\n".to_string(),
+                move_path: None,
+            },
+        );
+
+        let results = normalize_file_changes("/repo", &changes);
+        let (path, edits) = &results[0];
+        assert_eq!(path, "src/main.rs");
+        match &edits[..] {
+            [FileChange::Edit { language, .. }] => assert_eq!(language.as_deref(), Some("rust")),
+            _ => panic!("expected a single edit change"),
+        }
+    }
+
     #[test]
     fn log_state_appends_assistant_messages() {
         let mut state = LogState::new(EntryIndexProvider::default());
@@ -1904,6 +2771,121 @@ mod tests {
         msg_store.push_finished();
     }
 
+    #[tokio::test]
+    async fn normalize_logs_links_retry_to_denied_command() {
+        let msg_store = Arc::new(MsgStore::new());
+        normalize_logs(msg_store.clone(), std::path::Path::new("/repo"));
+
+        let cargo_test = vec!["cargo".to_string(), "test".to_string()];
+        push_codex_event(
+            &msg_store,
+            EventMsg::ExecApprovalRequest(ExecApprovalRequestEvent {
+                call_id: "cmd-denied".to_string(),
+                approval_id: None,
+                turn_id: "turn-1".to_string(),
+                command: cargo_test.clone(),
+                cwd: PathBuf::from("/repo"),
+                reason: None,
+                network_approval_context: None,
+                proposed_execpolicy_amendment: None,
+                proposed_network_policy_amendments: None,
+                additional_permissions: None,
+                skill_metadata: None,
+                available_decisions: None,
+                parsed_cmd: Vec::new(),
+            }),
+        );
+        push_json_line(
+            &msg_store,
+            Approval::approval_response(
+                "cmd-denied".to_string(),
+                EXEC_COMMAND_APPROVAL_TOOL.to_string(),
+                ApprovalStatus::Denied {
+                    reason: Some("use the test runner".to_string()),
+                },
+            )
+            .raw(),
+        );
+        let ls = vec!["ls".to_string()];
+        for (call_id, command) in [
+            ("cmd-other", ls),
+            ("cmd-retry", cargo_test.clone()),
+            ("cmd-next", cargo_test),
+        ] {
+            push_codex_event(
+                &msg_store,
+                EventMsg::ExecCommandBegin(ExecCommandBeginEvent {
+                    call_id: call_id.to_string(),
+                    process_id: None,
+                    turn_id: "turn-1".to_string(),
+                    command: command.clone(),
+                    cwd: PathBuf::from("/repo"),
+                    parsed_cmd: Vec::new(),
+                    source: ExecCommandSource::default(),
+                    interaction_input: None,
+                }),
+            );
+            push_codex_event(
+                &msg_store,
+                EventMsg::ExecCommandEnd(ExecCommandEndEvent {
+                    call_id: call_id.to_string(),
+                    process_id: None,
+                    turn_id: "turn-1".to_string(),
+                    command,
+                    cwd: PathBuf::from("/repo"),
+                    parsed_cmd: Vec::new(),
+                    source: ExecCommandSource::default(),
+                    interaction_input: None,
+                    stdout: "ok".to_string(),
+                    stderr: String::new(),
+                    aggregated_output: String::new(),
+                    exit_code: 0,
+                    duration: Duration::from_secs(1),
+                    formatted_output: "ok".to_string(),
+                    status: codex_protocol::protocol::ExecCommandStatus::Completed,
+                }),
+            );
+        }
+
+        let metadata_of = |entry: &NormalizedEntry| {
+            serde_json::from_value::<ToolCallMetadata>(entry.metadata.clone().unwrap()).unwrap()
+        };
+        let completed = |call_id: &'static str| {
+            move |entry: &NormalizedEntry| {
+                matches!(
+                    entry.entry_type,
+                    NormalizedEntryType::ToolUse {
+                        status: ToolStatus::Success,
+                        ..
+                    }
+                ) && entry.metadata.as_ref().and_then(|m| m.get("tool_call_id"))
+                    == Some(&json!(call_id))
+            }
+        };
+
+        let denial = wait_for_entry(&msg_store, |entry| {
+            matches!(entry.entry_type, NormalizedEntryType::UserFeedback { .. })
+        })
+        .await;
+        assert_eq!(metadata_of(&denial).tool_call_id, "cmd-denied");
+
+        // An unrelated command after the denial is not a retry and leaves the denial pending.
+        let other = wait_for_entry(&msg_store, completed("cmd-other")).await;
+        assert_eq!(metadata_of(&other).retry_of_tool_call_id, None);
+
+        let retry = wait_for_entry(&msg_store, completed("cmd-retry")).await;
+        assert_eq!(
+            metadata_of(&retry).retry_of_tool_call_id.as_deref(),
+            Some("cmd-denied")
+        );
+
+        // The denial is consumed by its first retry.
+        let next = wait_for_entry(&msg_store, completed("cmd-next")).await;
+        assert_eq!(metadata_of(&next).retry_of_tool_call_id, None);
+
+        msg_store.push_finished();
+    }
+
     #[tokio::test]
     async fn normalize_logs_exec_command_failure_marks_failed() {
         let msg_store = Arc::new(MsgStore::new());
@@ -1973,6 +2955,179 @@ mod tests {
         msg_store.push_finished();
     }
 
+    #[tokio::test]
+    async fn normalize_logs_exec_command_killed_by_signal_reports_signal() {
+        let msg_store = Arc::new(MsgStore::new());
+        normalize_logs(msg_store.clone(), std::path::Path::new("/repo"));
+
+        push_codex_event(
+            &msg_store,
+            EventMsg::ExecCommandBegin(ExecCommandBeginEvent {
+                call_id: "cmd-oom".to_string(),
+                process_id: None,
+                turn_id: "turn-1".to_string(),
+                command: vec!["make".to_string()],
+                cwd: PathBuf::from("/repo"),
+                parsed_cmd: Vec::new(),
+                source: ExecCommandSource::default(),
+                interaction_input: None,
+            }),
+        );
+
+        push_codex_event(
+            &msg_store,
+            EventMsg::ExecCommandEnd(ExecCommandEndEvent {
+                call_id: "cmd-oom".to_string(),
+                process_id: None,
+                turn_id: "turn-1".to_string(),
+                command: vec!["make".to_string()],
+                cwd: PathBuf::from("/repo"),
+                parsed_cmd: Vec::new(),
+                source: ExecCommandSource::default(),
+                interaction_input: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                aggregated_output: String::new(),
+                exit_code: 137,
+                duration: Duration::from_secs(1),
+                formatted_output: "Killed".to_string(),
+                status: codex_protocol::protocol::ExecCommandStatus::Failed,
+            }),
+        );
+
+        let entry = wait_for_entry(&msg_store, |entry| match &entry.entry_type {
+            NormalizedEntryType::ToolUse {
+                action_type: ActionType::CommandRun { command, .. },
+                status,
+                ..
+            } => command == "make" && matches!(status, ToolStatus::Failed),
+            _ => false,
+        })
+        .await;
+
+        match entry.entry_type {
+            NormalizedEntryType::ToolUse {
+                action_type: ActionType::CommandRun { result, .. },
+                ..
+            } => {
+                let result = result.expect("command result");
+                match result.exit_status {
+                    Some(CommandExitStatus::Signal { signal }) => assert_eq!(signal, 9),
+                    other => panic!("unexpected exit status: {other:?}"),
+                }
+            }
+            _ => panic!("expected command tool entry"),
+        }
+
+        msg_store.push_finished();
+    }
+
+    #[tokio::test]
+    async fn normalize_logs_reports_malformed_codex_events_only_in_debug_mode() {
+        let malformed = json!({
+            "method": "codex/event/exec_command_end",
+            "params": { "msg": { "type": "exec_command_end", "call_id": 42 } },
+        })
+        .to_string();
+        let truncated = r#"{"jsonrpc":"2.0","method":"codex/event/agent_message","params":{"msg":"#;
+
+        let quiet_store = Arc::new(MsgStore::new());
+        normalize_logs_with_debug(quiet_store.clone(), std::path::Path::new("/repo"), false);
+        push_json_line(&quiet_store, malformed.clone());
+        push_json_line(&quiet_store, truncated.to_string());
+        push_codex_event(
+            &quiet_store,
+            EventMsg::AgentMessage(AgentMessageEvent {
+                message: "done".to_string(),
+                phase: None,
+            }),
+        );
+        wait_for_entry(&quiet_store, |entry| entry.content == "done").await;
+        assert!(
+            normalized_entries(&quiet_store)
+                .iter()
+                .all(|entry| !entry.content.contains("Skipped malformed codex event"))
+        );
+        quiet_store.push_finished();
+
+        let debug_store = Arc::new(MsgStore::new());
+        normalize_logs_with_debug(debug_store.clone(), std::path::Path::new("/repo"), true);
+        push_json_line(&debug_store, malformed);
+        push_json_line(&debug_store, truncated.to_string());
+        push_json_line(&debug_store, r#"{"unrelated":true}"#.to_string());
+        push_codex_event(
+            &debug_store,
+            EventMsg::AgentMessage(AgentMessageEvent {
+                message: "done".to_string(),
+                phase: None,
+            }),
+        );
+        wait_for_entry(&debug_store, |entry| entry.content == "done").await;
+
+        let debug_entries: Vec<_> = normalized_entries(&debug_store)
+            .into_iter()
+            .filter(|entry| entry.content.starts_with("Skipped malformed codex event"))
+            .collect();
+        assert_eq!(debug_entries.len(), 2);
+        assert!(
+            debug_entries
+                .iter()
+                .all(|entry| matches!(entry.entry_type, NormalizedEntryType::SystemMessage))
+        );
+        assert!(debug_entries[0].content.contains("exec_command_end"));
+        assert!(
+            debug_entries[1]
+                .content
+                .contains("codex/event/agent_message")
+        );
+        debug_store.push_finished();
+    }
+
+    #[tokio::test]
+    async fn normalize_logs_warns_when_rollout_path_has_no_session_id() {
+        let msg_store = Arc::new(MsgStore::new());
+        normalize_logs(msg_store.clone(), std::path::Path::new("/repo"));
+
+        let response = JSONRPCResponse {
+            id: RequestId::String("1".to_string()),
+            result: json!({ "rolloutPath": "/tmp/sessions/rollout-not-a-session.jsonl" }),
+        };
+        push_json_line(
+            &msg_store,
+            serde_json::to_string(&response).expect("response"),
+        );
+
+        let entry = wait_for_entry(&msg_store, |entry| {
+            matches!(entry.entry_type, NormalizedEntryType::SystemMessage)
+                && entry.content.starts_with("Session tracking unavailable")
+        })
+        .await;
+        assert!(entry.content.contains("rollout-not-a-session.jsonl"));
+        assert!(
+            !msg_store
+                .get_history()
+                .iter()
+                .any(|msg| matches!(msg, LogMsg::SessionId(_)))
+        );
+        msg_store.push_finished();
+    }
+
+    #[test]
+    fn malformed_codex_event_entry_truncates_long_lines() {
+        let line = format!(
+            r#"{{"method":"codex/event","params":"{}"}}"#,
+            "x".repeat(500)
+        );
+        let entry = malformed_codex_event_entry(&line, "bad params");
+        assert!(
+            entry
+                .content
+                .starts_with("Skipped malformed codex event (bad params): ")
+        );
+        assert!(entry.content.ends_with('…'));
+        assert!(entry.content.len() < line.len());
+    }
+
     #[tokio::test]
     async fn normalize_logs_exec_command_end_without_begin_emits_normalization_error() {
         let msg_store = Arc::new(MsgStore::new());
@@ -2081,6 +3236,7 @@ mod tests {
                     FileChange::Edit {
                         unified_diff,
                         has_line_numbers,
+                        ..
                     } => {
                         assert!(unified_diff.contains("--- a/file.txt"));
                         assert!(unified_diff.contains("+++ b/file.txt"));
@@ -750,6 +750,7 @@ impl CursorToolCall {
                     changes.push(FileChange::Edit {
                         unified_diff: normalize_unified_diff(&path, &apply_patch.patch_content),
                         has_line_numbers: false,
+                        language: None,
                     });
                 }
 
@@ -761,6 +762,7 @@ impl CursorToolCall {
                             &str_replace.new_text,
                         ),
                         has_line_numbers: false,
+                        language: None,
                     });
                 }
 
@@ -775,6 +777,7 @@ impl CursorToolCall {
                                 &edit.new_text,
                             ),
                             has_line_numbers: false,
+                            language: None,
                         })
                         .collect();
                     changes.extend(edits);
@@ -789,6 +792,7 @@ impl CursorToolCall {
                     changes.push(FileChange::Edit {
                         unified_diff: normalize_unified_diff(&path, diff_string),
                         has_line_numbers: false,
+                        language: None,
                     });
                 }
 
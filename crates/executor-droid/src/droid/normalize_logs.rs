@@ -234,6 +234,7 @@ pub fn normalize_logs(
                                 let changes = vec![FileChange::Edit {
                                     unified_diff: diff,
                                     has_line_numbers: false,
+                                    language: None,
                                 }];
 
                                 let tool_state = FileEditState {
@@ -268,6 +269,7 @@ pub fn normalize_logs(
                                                     &edit.new_string.clone().unwrap_or_default(),
                                                 ),
                                                 has_line_numbers: false,
+                                                language: None,
                                             })
                                         } else {
                                             None
@@ -760,6 +762,7 @@ fn parse_apply_patch_result(value: &Value, worktree_path: &str) -> Option<Action
         vec![FileChange::Edit {
             unified_diff: normalize_unified_diff(&relative_path, &diff_text),
             has_line_numbers: true,
+            language: None,
         }]
     } else if let Some(content_text) = content {
         vec![FileChange::Write {
@@ -66,7 +66,10 @@ impl ExecutorApprovalService for NoopExecutorApprovalService {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct ToolCallMetadata {
     pub tool_call_id: String,
+    /// Call id of the denied tool call this call retries, so the UI can thread the correction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_of_tool_call_id: Option<String>,
 }
@@ -286,6 +286,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                 content: get_tool_content(tool_data),
                 metadata: serde_json::to_value(ToolCallMetadata {
                     tool_call_id: tool_data.id.0.to_string(),
+                    ..Default::default()
                 })
                 .ok(),
             };
@@ -490,6 +491,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                         changes.push(FileChange::Edit {
                             unified_diff: unified,
                             has_line_numbers: false,
+                            language: None,
                         });
                     }
                 }
@@ -505,6 +507,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                             &diff,
                         ),
                         has_line_numbers: true,
+                        language: None,
                     });
                 } else if let Some(old) = edit_input.old_string
                     && let Some(new) = edit_input.new_string
@@ -516,6 +519,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                             &new,
                         ),
                         has_line_numbers: false,
+                        language: None,
                     });
                 }
             }
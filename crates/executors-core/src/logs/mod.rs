@@ -43,8 +43,16 @@ impl ToolResult {
 #[serde(tag = "type", rename_all = "snake_case")]
 #[ts(export)]
 pub enum CommandExitStatus {
-    ExitCode { code: i32 },
-    Success { success: bool },
+    ExitCode {
+        code: i32,
+    },
+    Success {
+        success: bool,
+    },
+    /// The command was terminated by a signal rather than exiting on its own.
+    Signal {
+        signal: i32,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -231,5 +239,9 @@ pub enum FileChange {
         unified_diff: String,
         /// Whether line number in the hunks are reliable.
         has_line_numbers: bool,
+        /// Syntax-highlighting language (highlight.js id) inferred from the file extension.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        language: Option<String>,
     },
 }
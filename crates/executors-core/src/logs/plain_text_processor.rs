@@ -190,6 +190,11 @@ pub struct PlainTextLogProcessor {
 }
 
 impl PlainTextLogProcessor {
+    /// Time gap after which newly arriving content starts a new entry, if configured.
+    pub fn time_gap(&self) -> Option<Duration> {
+        self.time_gap
+    }
+
     /// Process incoming text and return JSON patches for any complete entries
     pub fn process(&mut self, text_chunk: String) -> Vec<Patch> {
         if text_chunk.is_empty() {
@@ -0,0 +1,205 @@
+//! Conversion of ANSI-colored terminal output into minimal HTML.
+//!
+//! Only SGR sequences (`ESC [ ... m`) are interpreted; they become `<span>` elements with
+//! `ansi-*` classes so the UI decides the actual colors. Every other escape sequence is
+//! dropped and the text itself is HTML-escaped.
+
+const COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+struct Style {
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    /// Palette index 0-15; 8-15 are the bright variants.
+    fg: Option<u8>,
+    bg: Option<u8>,
+}
+
+impl Style {
+    fn classes(&self) -> Vec<String> {
+        let mut classes = Vec::new();
+        for (enabled, class) in [
+            (self.bold, "ansi-bold"),
+            (self.dim, "ansi-dim"),
+            (self.italic, "ansi-italic"),
+            (self.underline, "ansi-underline"),
+        ] {
+            if enabled {
+                classes.push(class.to_string());
+            }
+        }
+        if let Some(fg) = self.fg {
+            classes.push(format!("ansi-{}-fg", color_name(fg)));
+        }
+        if let Some(bg) = self.bg {
+            classes.push(format!("ansi-{}-bg", color_name(bg)));
+        }
+        classes
+    }
+
+    fn apply_sgr(&mut self, params: &str) {
+        let mut codes = params
+            .split(';')
+            .map(|code| code.parse::<u16>().unwrap_or(0));
+        while let Some(code) = codes.next() {
+            match code {
+                0 => *self = Style::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                30..=37 => self.fg = Some((code - 30) as u8),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some((code - 40) as u8),
+                49 => self.bg = None,
+                90..=97 => self.fg = Some((code - 90 + 8) as u8),
+                100..=107 => self.bg = Some((code - 100 + 8) as u8),
+                38 | 48 => {
+                    // 256-color and truecolor: only the 16 palette colors map to classes.
+                    let color = match codes.next() {
+                        Some(5) => codes.next().filter(|index| *index < 16).map(|i| i as u8),
+                        Some(2) => {
+                            codes.by_ref().take(3).for_each(drop);
+                            None
+                        }
+                        _ => None,
+                    };
+                    if code == 38 {
+                        self.fg = color;
+                    } else {
+                        self.bg = color;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn color_name(index: u8) -> String {
+    let name = COLOR_NAMES[usize::from(index % 8)];
+    if index >= 8 {
+        format!("bright-{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+fn push_escaped(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+fn flush_run(out: &mut String, run: &mut String, style: &Style) {
+    if run.is_empty() {
+        return;
+    }
+    let classes = style.classes();
+    if classes.is_empty() {
+        push_escaped(out, run);
+    } else {
+        out.push_str("<span class=\"");
+        out.push_str(&classes.join(" "));
+        out.push_str("\">");
+        push_escaped(out, run);
+        out.push_str("</span>");
+    }
+    run.clear();
+}
+
+/// Converts ANSI-colored text into HTML-escaped text wrapped in `ansi-*` class spans, e.g.
+/// `"\x1b[1;31merror\x1b[0m"` becomes `<span class="ansi-bold ansi-red-fg">error</span>`.
+pub fn ansi_to_html(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut run = String::new();
+    let mut style = Style::default();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            run.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('[') => {
+                let mut params = String::new();
+                let mut final_byte = None;
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        final_byte = Some(c);
+                        break;
+                    }
+                    params.push(c);
+                }
+                if final_byte == Some('m') {
+                    let mut next = style;
+                    next.apply_sgr(&params);
+                    if next != style {
+                        flush_run(&mut out, &mut run, &style);
+                        style = next;
+                    }
+                }
+            }
+            Some(']') => {
+                // OSC (e.g. hyperlinks, window titles), terminated by BEL or ST (`ESC \`).
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    flush_run(&mut out, &mut run, &style);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ansi_to_html;
+
+    #[test]
+    fn colored_output_becomes_class_spans() {
+        assert_eq!(
+            ansi_to_html("\x1b[1;31merror\x1b[0m: build <failed>\n\x1b[92mok\x1b[39m done"),
+            "<span class=\"ansi-bold ansi-red-fg\">error</span>: build &lt;failed&gt;\n\
+             <span class=\"ansi-bright-green-fg\">ok</span> done"
+        );
+    }
+
+    #[test]
+    fn non_color_sequences_are_dropped() {
+        assert_eq!(
+            ansi_to_html("\x1b]8;;https://example.com\x07link\x1b]8;;\x07\x1b[2K\x1b[38;2;1;2;3mx"),
+            "linkx"
+        );
+        assert_eq!(
+            ansi_to_html("\x1b[38;5;4mblue\x1b[m"),
+            "<span class=\"ansi-blue-fg\">blue</span>"
+        );
+        assert_eq!(ansi_to_html("plain"), "plain");
+    }
+}
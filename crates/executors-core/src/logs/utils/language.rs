@@ -0,0 +1,44 @@
+//! Syntax-highlighting language hints for file paths.
+
+use std::path::Path;
+
+/// Returns the highlight.js language id for `path`'s extension, or `None` if it is not mapped.
+/// Mirrors the frontend's `extToLanguage` table.
+pub fn language_for_path(path: &str) -> Option<&'static str> {
+    let extension = Path::new(path).extension()?.to_str()?.to_ascii_lowercase();
+    let language = match extension.as_str() {
+        "js" | "mjs" | "cjs" => "javascript",
+        "ts" => "typescript",
+        "jsx" => "jsx",
+        "tsx" => "tsx",
+        "html" | "htm" | "xml" => "xml",
+        "css" => "css",
+        "scss" => "scss",
+        "less" => "less",
+        "json" => "json",
+        "md" => "markdown",
+        "yml" | "yaml" => "yaml",
+        "sh" | "bash" | "zsh" => "bash",
+        "ps1" => "powershell",
+        "php" => "php",
+        "c" | "h" => "c",
+        "cpp" | "cc" | "cxx" | "hpp" => "cpp",
+        "cs" => "csharp",
+        "java" => "java",
+        "kt" => "kotlin",
+        "scala" => "scala",
+        "go" => "go",
+        "rs" => "rust",
+        "swift" => "swift",
+        "dart" => "dart",
+        "py" => "python",
+        "rb" => "ruby",
+        "pl" => "perl",
+        "lua" => "lua",
+        "r" => "r",
+        "sql" => "sql",
+        "tex" => "latex",
+        _ => return None,
+    };
+    Some(language)
+}
@@ -1,6 +1,8 @@
 //! Utility modules for executor framework
 
+pub mod ansi;
 pub mod entry_index;
+pub mod language;
 pub mod patch;
 
 pub use entry_index::EntryIndexProvider;
@@ -17,6 +17,13 @@ pub struct CodingAgentFollowUpRequest {
     pub working_dir: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub image_paths: Option<HashMap<String, PathBuf>>,
+    /// Optional model override for this run only; the executor profile is left untouched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Optional reasoning effort override (`low`, `medium`, `high`, `xhigh`).
+    /// Only applied by executors that support it (currently Codex).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reasoning_effort: Option<String>,
 }
 
 impl CodingAgentFollowUpRequest {
@@ -25,6 +25,28 @@ fn resolve_coding_agent(
         .map_err(|err| ExecutorError::UnknownExecutorType(err.to_string()))
 }
 
+/// Applies per-request model and reasoning effort overrides on executors that support them.
+/// Unknown reasoning efforts are ignored; callers validate them before building the request.
+#[cfg_attr(not(feature = "codex"), allow(unused_variables))]
+fn apply_run_overrides(agent: &mut CodingAgent, model: Option<&str>, effort: Option<&str>) {
+    if let Some(model) = model {
+        let base_agent = agent.base_agent();
+        match agent.model_mut() {
+            Some(slot) => *slot = Some(model.to_string()),
+            None => tracing::warn!(
+                "Ignoring model override '{model}': {base_agent} has no model setting"
+            ),
+        }
+    }
+    #[cfg(feature = "codex")]
+    if let (CodingAgent::Codex(codex), Some(effort)) = (agent, effort) {
+        match effort.parse() {
+            Ok(effort) => codex.model_reasoning_effort = Some(effort),
+            Err(_) => tracing::warn!("Ignoring unknown reasoning effort '{effort}'"),
+        }
+    }
+}
+
 #[async_trait]
 pub trait Executable {
     async fn spawn(
@@ -72,6 +94,11 @@ impl Executable for CodingAgentInitialRequest {
 
         let mut agent = resolve_coding_agent(&self.executor_profile_id)?;
         agent.use_approvals(approvals);
+        apply_run_overrides(
+            &mut agent,
+            self.model.as_deref(),
+            self.reasoning_effort.as_deref(),
+        );
 
         match &agent {
             #[cfg(feature = "codex")]
@@ -105,6 +132,11 @@ impl Executable for CodingAgentFollowUpRequest {
 
         let mut agent = resolve_coding_agent(&self.executor_profile_id)?;
         agent.use_approvals(approvals);
+        apply_run_overrides(
+            &mut agent,
+            self.model.as_deref(),
+            self.reasoning_effort.as_deref(),
+        );
 
         match &agent {
             #[cfg(feature = "codex")]
@@ -219,7 +251,7 @@ fn parse_direct_command(script: &str) -> Result<(String, Vec<String>), ExecutorE
 
 #[cfg(test)]
 mod tests {
-    use super::parse_direct_command;
+    use super::*;
 
     #[test]
     fn parse_direct_command_accepts_simple_command() {
@@ -233,4 +265,36 @@ mod tests {
         let err = parse_direct_command("npm run dev && rm -rf /").unwrap_err();
         assert!(err.to_string().contains("without shell operators"));
     }
+
+    #[cfg(feature = "codex")]
+    #[test]
+    fn reasoning_effort_override_reaches_codex() {
+        use crate::executors::codex::{Codex, ReasoningEffort};
+
+        let codex: Codex = serde_json::from_value(serde_json::json!({})).unwrap();
+        let mut agent = CodingAgent::Codex(codex);
+        apply_run_overrides(&mut agent, None, Some("high"));
+
+        let CodingAgent::Codex(codex) = agent else {
+            panic!("expected codex agent");
+        };
+        assert_eq!(codex.model_reasoning_effort, Some(ReasoningEffort::High));
+    }
+
+    #[cfg(feature = "codex")]
+    #[test]
+    fn model_override_replaces_profile_model_for_the_run() {
+        use crate::executors::codex::{Codex, ReasoningEffort};
+
+        let codex: Codex =
+            serde_json::from_value(serde_json::json!({ "model": "gpt-5.1-codex" })).unwrap();
+        let mut agent = CodingAgent::Codex(codex);
+        apply_run_overrides(&mut agent, Some("gpt-5.1-codex-max"), Some("xhigh"));
+
+        let CodingAgent::Codex(codex) = agent else {
+            panic!("expected codex agent");
+        };
+        assert_eq!(codex.model.as_deref(), Some("gpt-5.1-codex-max"));
+        assert_eq!(codex.model_reasoning_effort, Some(ReasoningEffort::Xhigh));
+    }
 }
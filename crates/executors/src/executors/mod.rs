@@ -148,6 +148,32 @@ impl CodingAgent {
         self.auto_retry_config().validate()
     }
 
+    /// The executor's model setting, or `None` for executors that don't expose one.
+    pub fn model_mut(&mut self) -> Option<&mut Option<String>> {
+        match self {
+            #[cfg(feature = "claude")]
+            Self::ClaudeCode(cfg) => Some(&mut cfg.model),
+            #[cfg(feature = "amp")]
+            Self::Amp(_) => None,
+            #[cfg(feature = "gemini")]
+            Self::Gemini(cfg) => Some(&mut cfg.model),
+            #[cfg(feature = "codex")]
+            Self::Codex(cfg) => Some(&mut cfg.model),
+            #[cfg(feature = "fake-agent")]
+            Self::FakeAgent(_) => None,
+            #[cfg(feature = "opencode")]
+            Self::Opencode(cfg) => Some(&mut cfg.model),
+            #[cfg(feature = "cursor")]
+            Self::CursorAgent(cfg) => Some(&mut cfg.model),
+            #[cfg(feature = "qwen")]
+            Self::QwenCode(_) => None,
+            #[cfg(feature = "copilot")]
+            Self::Copilot(cfg) => Some(&mut cfg.model),
+            #[cfg(feature = "droid")]
+            Self::Droid(cfg) => Some(&mut cfg.model),
+        }
+    }
+
     pub fn cmd_env_mut(&mut self) -> Option<&mut HashMap<String, String>> {
         match self {
             #[cfg(feature = "claude")]
@@ -373,12 +373,14 @@ impl MsgStore {
         (entries, has_more)
     }
 
-    pub fn raw_history_after(&self, limit: usize, after: usize) -> Vec<LogEntrySnapshot> {
+    /// Up to `limit` raw entries with an index greater than `after`, or from the first retained
+    /// entry when `after` is `None`.
+    pub fn raw_history_after(&self, limit: usize, after: Option<usize>) -> Vec<LogEntrySnapshot> {
         let inner = self.inner.read().unwrap();
         let mut entries: Vec<LogEntrySnapshot> = Vec::new();
 
         for entry in inner.raw_entries.iter() {
-            if entry.entry_index <= after {
+            if after.is_some_and(|after| entry.entry_index <= after) {
                 continue;
             }
             entries.push(LogEntrySnapshot {
@@ -435,13 +437,20 @@ impl MsgStore {
         (entries, has_more)
     }
 
-    pub fn normalized_history_after(&self, limit: usize, after: usize) -> Vec<LogEntrySnapshot> {
+    /// Up to `limit` normalized entries with an index greater than `after`, or from the first
+    /// retained entry when `after` is `None`.
+    pub fn normalized_history_after(
+        &self,
+        limit: usize,
+        after: Option<usize>,
+    ) -> Vec<LogEntrySnapshot> {
         use std::ops::Bound::{Excluded, Unbounded};
 
         let inner = self.inner.read().unwrap();
         let mut entries: Vec<LogEntrySnapshot> = Vec::new();
 
-        for (index, entry) in inner.normalized_entries.range((Excluded(after), Unbounded)) {
+        let start = after.map_or(Unbounded, Excluded);
+        for (index, entry) in inner.normalized_entries.range((start, Unbounded)) {
             entries.push(LogEntrySnapshot {
                 entry_index: *index,
                 entry_json: Arc::clone(&entry.entry_json),
@@ -1400,14 +1409,18 @@ mod tests {
         store.push_stdout("two");
         store.push_stdout("three");
 
-        let entries = store.raw_history_after(10, 0);
+        let entries = store.raw_history_after(10, Some(0));
         assert_eq!(entries.len(), 2);
         assert_eq!(entries[0].entry_index, 1);
         assert_eq!(entries[1].entry_index, 2);
 
-        let entries = store.raw_history_after(10, 1);
+        let entries = store.raw_history_after(10, Some(1));
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].entry_index, 2);
+
+        let entries = store.raw_history_after(2, None);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].entry_index, 0);
     }
 
     fn add_normalized_entry(store: &MsgStore, index: usize, content: &str) {
@@ -1436,14 +1449,18 @@ mod tests {
         add_normalized_entry(&store, 1, "one");
         add_normalized_entry(&store, 2, "two");
 
-        let entries = store.normalized_history_after(10, 0);
+        let entries = store.normalized_history_after(10, Some(0));
         assert_eq!(entries.len(), 2);
         assert_eq!(entries[0].entry_index, 1);
         assert_eq!(entries[1].entry_index, 2);
 
-        let entries = store.normalized_history_after(1, 0);
+        let entries = store.normalized_history_after(1, Some(0));
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].entry_index, 1);
+
+        let entries = store.normalized_history_after(10, None);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].entry_index, 0);
     }
 
     #[tokio::test]
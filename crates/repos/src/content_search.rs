@@ -0,0 +1,293 @@
+use std::{
+    io::Read as _,
+    path::{Component, Path},
+};
+
+use ignore::{WalkBuilder, overrides::OverrideBuilder};
+use regex::{Regex, RegexBuilder};
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+
+/// Bytes sniffed at the start of a file to decide whether it is binary.
+const BINARY_SNIFF_BYTES: usize = 8 * 1024;
+/// Upper bound on the compiled size of a user-supplied pattern.
+const MAX_PATTERN_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug, Error)]
+pub enum ContentSearchError {
+    #[error("Invalid search pattern: {0}")]
+    InvalidPattern(#[from] regex::Error),
+    #[error("Invalid path glob: {0}")]
+    InvalidGlob(#[from] ignore::Error),
+    #[error("Search root does not exist: {0}")]
+    RootNotFound(String),
+}
+
+/// Caps applied to a single content search.
+#[derive(Debug, Clone, Copy)]
+pub struct ContentSearchLimits {
+    pub max_matches: usize,
+    pub max_bytes_scanned: u64,
+    /// Larger files are skipped rather than partially scanned, which marks the outcome truncated.
+    pub max_file_bytes: u64,
+    /// Matching lines longer than this are cut to this many characters.
+    pub max_line_chars: usize,
+}
+
+impl Default for ContentSearchLimits {
+    fn default() -> Self {
+        Self {
+            max_matches: 200,
+            max_bytes_scanned: 32 * 1024 * 1024,
+            max_file_bytes: 1024 * 1024,
+            max_line_chars: 300,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentMatch {
+    /// Path relative to the search root, `/`-separated.
+    pub path: String,
+    /// 1-based line number.
+    pub line_number: usize,
+    pub line: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ContentSearchOutcome {
+    pub matches: Vec<ContentMatch>,
+    pub files_scanned: usize,
+    pub bytes_scanned: u64,
+    /// Set when a cap was hit, a file was skipped for its size, or the search was cancelled
+    /// before the walk finished.
+    pub truncated: bool,
+}
+
+/// Search regular files under `root` for lines matching `pattern`.
+///
+/// Honors `.gitignore`/`.ignore` rules, never follows symlinks (so the scan stays inside `root`),
+/// skips `.git` directories and binary files, and stops early once `limits` are reached or
+/// `cancel` fires. `path_glob` narrows the scan to paths (relative to `root`) matching the glob.
+pub fn search_content(
+    root: &Path,
+    pattern: &str,
+    path_glob: Option<&str>,
+    limits: ContentSearchLimits,
+    cancel: &CancellationToken,
+) -> Result<ContentSearchOutcome, ContentSearchError> {
+    if !root.is_dir() {
+        return Err(ContentSearchError::RootNotFound(root.display().to_string()));
+    }
+    let regex = RegexBuilder::new(pattern)
+        .size_limit(MAX_PATTERN_SIZE)
+        .build()?;
+
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .follow_links(false)
+        .hidden(false)
+        .git_ignore(true)
+        .git_global(true)
+        .git_exclude(true)
+        .filter_entry(|entry| entry.file_name() != ".git");
+    if let Some(glob) = path_glob.map(str::trim).filter(|glob| !glob.is_empty()) {
+        let mut overrides = OverrideBuilder::new(root);
+        overrides.add(glob)?;
+        builder.overrides(overrides.build()?);
+    }
+
+    let mut outcome = ContentSearchOutcome::default();
+    for entry in builder.build() {
+        if cancel.is_cancelled() {
+            outcome.truncated = true;
+            break;
+        }
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry
+            .file_type()
+            .is_some_and(|file_type| file_type.is_file())
+        {
+            continue;
+        }
+        let Some(relative) = relative_path(root, entry.path()) else {
+            continue;
+        };
+        let size = entry.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        if size > limits.max_file_bytes {
+            outcome.truncated = true;
+            continue;
+        }
+        if outcome.bytes_scanned + size > limits.max_bytes_scanned {
+            outcome.truncated = true;
+            break;
+        }
+
+        let Some(contents) = read_text_file(entry.path(), limits.max_file_bytes) else {
+            continue;
+        };
+        outcome.files_scanned += 1;
+        outcome.bytes_scanned += contents.len() as u64;
+
+        if collect_matches(&regex, &relative, &contents, limits, &mut outcome.matches) {
+            outcome.truncated = true;
+            break;
+        }
+    }
+
+    Ok(outcome)
+}
+
+/// `/`-separated path of `path` under `root`; `None` if it escapes `root`.
+fn relative_path(root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    let mut parts = Vec::new();
+    for component in relative.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy()),
+            _ => return None,
+        }
+    }
+    Some(parts.join("/"))
+}
+
+/// Read a file as lossy UTF-8, or `None` when it is unreadable or looks binary.
+fn read_text_file(path: &Path, max_bytes: u64) -> Option<String> {
+    let file = std::fs::File::open(path).ok()?;
+    let mut bytes = Vec::new();
+    file.take(max_bytes).read_to_end(&mut bytes).ok()?;
+    let sniff = &bytes[..bytes.len().min(BINARY_SNIFF_BYTES)];
+    if sniff.contains(&0) {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Append matching lines; returns true once `max_matches` is reached.
+fn collect_matches(
+    regex: &Regex,
+    path: &str,
+    contents: &str,
+    limits: ContentSearchLimits,
+    matches: &mut Vec<ContentMatch>,
+) -> bool {
+    for (index, line) in contents.lines().enumerate() {
+        if !regex.is_match(line) {
+            continue;
+        }
+        if matches.len() >= limits.max_matches {
+            return true;
+        }
+        matches.push(ContentMatch {
+            path: path.to_string(),
+            line_number: index + 1,
+            line: line.chars().take(limits.max_line_chars).collect(),
+        });
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+    use tokio_util::sync::CancellationToken;
+
+    use super::{ContentMatch, ContentSearchLimits, search_content};
+
+    #[test]
+    fn search_content_returns_matching_lines_and_honors_ignore_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        fs::create_dir_all(repo.join("src")).unwrap();
+        fs::create_dir_all(repo.join("target")).unwrap();
+        fs::write(repo.join(".gitignore"), "target/\n").unwrap();
+        fs::write(
+            repo.join("src/lib.rs"),
+            "fn main() {}\nfn parse_config() {}\n// TODO: parse_args\n",
+        )
+        .unwrap();
+        fs::write(repo.join("README.md"), "Call parse_config first.\n").unwrap();
+        fs::write(repo.join("target/out.rs"), "fn parse_config() {}\n").unwrap();
+        fs::write(repo.join("src/blob.bin"), b"parse_config\0\x01").unwrap();
+
+        let cancel = CancellationToken::new();
+        let outcome = search_content(
+            temp_dir.path(),
+            r"parse_\w+",
+            None,
+            ContentSearchLimits::default(),
+            &cancel,
+        )
+        .unwrap();
+        let mut matches = outcome.matches;
+        matches.sort_by(|a, b| (&a.path, a.line_number).cmp(&(&b.path, b.line_number)));
+        assert_eq!(
+            matches,
+            vec![
+                ContentMatch {
+                    path: "repo/README.md".to_string(),
+                    line_number: 1,
+                    line: "Call parse_config first.".to_string(),
+                },
+                ContentMatch {
+                    path: "repo/src/lib.rs".to_string(),
+                    line_number: 2,
+                    line: "fn parse_config() {}".to_string(),
+                },
+                ContentMatch {
+                    path: "repo/src/lib.rs".to_string(),
+                    line_number: 3,
+                    line: "// TODO: parse_args".to_string(),
+                },
+            ]
+        );
+        assert!(!outcome.truncated);
+
+        let outcome = search_content(
+            temp_dir.path(),
+            "parse_config",
+            Some("*.rs"),
+            ContentSearchLimits {
+                max_matches: 1,
+                ..ContentSearchLimits::default()
+            },
+            &cancel,
+        )
+        .unwrap();
+        assert_eq!(outcome.matches.len(), 1);
+        assert_eq!(outcome.matches[0].path, "repo/src/lib.rs");
+
+        // A skipped oversized file means some content was not searched.
+        let outcome = search_content(
+            temp_dir.path(),
+            "parse_config",
+            Some("repo/README.md"),
+            ContentSearchLimits {
+                max_file_bytes: 4,
+                ..ContentSearchLimits::default()
+            },
+            &cancel,
+        )
+        .unwrap();
+        assert!(outcome.matches.is_empty());
+        assert_eq!(outcome.files_scanned, 0);
+        assert!(outcome.truncated);
+
+        cancel.cancel();
+        let outcome = search_content(
+            temp_dir.path(),
+            "parse_config",
+            None,
+            ContentSearchLimits::default(),
+            &cancel,
+        )
+        .unwrap();
+        assert!(outcome.matches.is_empty());
+        assert!(outcome.truncated);
+    }
+}
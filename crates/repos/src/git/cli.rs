@@ -247,6 +247,28 @@ impl GitCli {
         Ok(())
     }
 
+    /// Run `git -C <worktree> sparse-checkout set --no-cone -- <patterns...>`
+    ///
+    /// Patterns use gitignore syntax. Git turns on `extensions.worktreeConfig` and writes
+    /// `core.sparseCheckout` to the worktree's own `config.worktree`, so the source checkout and
+    /// other worktrees of the same repository keep their full checkout.
+    pub fn sparse_checkout_set(
+        &self,
+        worktree_path: &Path,
+        patterns: &[String],
+    ) -> Result<(), GitCliError> {
+        self.ensure_available()?;
+        let mut args: Vec<OsString> = vec![
+            "sparse-checkout".into(),
+            "set".into(),
+            "--no-cone".into(),
+            "--".into(),
+        ];
+        args.extend(patterns.iter().map(OsString::from));
+        self.git(worktree_path, args)?;
+        Ok(())
+    }
+
     /// Prune stale worktree metadata
     pub fn worktree_prune(&self, repo_path: &Path) -> Result<(), GitCliError> {
         self.git(repo_path, ["worktree", "prune"])?;
@@ -448,6 +470,35 @@ impl GitCli {
         self.diff_numstat_entries_with_prepared_index(worktree_path, base_commit, opts, &prepared)
     }
 
+    /// Full binary-safe patch of a worktree (including untracked files) vs a base commit.
+    ///
+    /// Paths in the patch are emitted as `a/<path_prefix><path>` / `b/<path_prefix><path>`, so a
+    /// prefix such as `repo/` keeps multi-repo patches unambiguous.
+    pub fn diff_worktree_patch(
+        &self,
+        worktree_path: &Path,
+        base_commit: &Commit,
+        path_prefix: &str,
+    ) -> Result<Vec<u8>, GitCliError> {
+        let prepared = self.prepare_temp_index_for_worktree_diff(worktree_path)?;
+        let args: Vec<OsString> = vec![
+            "-c".into(),
+            "core.quotepath=false".into(),
+            "diff".into(),
+            "--cached".into(),
+            "--binary".into(),
+            "--no-color".into(),
+            "--no-ext-diff".into(),
+            "--no-textconv".into(),
+            "-M".into(),
+            format!("--src-prefix=a/{path_prefix}").into(),
+            format!("--dst-prefix=b/{path_prefix}").into(),
+            OsString::from(base_commit.to_string()),
+        ];
+        let args = Self::apply_default_excludes(args);
+        self.git_impl(worktree_path, args, Some(prepared.envs()), None)
+    }
+
     /// Return `git diff --name-status -z` parsed entries between two revisions.
     pub fn diff_name_status_between(
         &self,
@@ -917,6 +968,136 @@ impl GitCli {
         Ok(out.trim().to_string())
     }
 
+    /// Run `git rev-list <range>` and return the commit shas, newest first.
+    pub fn rev_list(&self, repo_path: &Path, range: &str) -> Result<Vec<String>, GitCliError> {
+        let out = self.git(repo_path, ["rev-list", range])?;
+        Ok(out
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Apply `patch` to the working tree with `git apply -p<strip>`; `check_only` validates
+    /// without touching any file. `git apply` is all-or-nothing, so a failed apply leaves the
+    /// tree untouched.
+    pub fn apply_patch(
+        &self,
+        worktree_path: &Path,
+        patch: &[u8],
+        strip: usize,
+        check_only: bool,
+    ) -> Result<(), GitCliError> {
+        let mut args = vec!["apply".to_string(), format!("-p{strip}")];
+        if check_only {
+            args.push("--check".to_string());
+        }
+        args.push("-".to_string());
+        self.git_with_stdin(worktree_path, args, None, patch)?;
+        Ok(())
+    }
+
+    /// Paths `git apply -p<strip>` would touch for `patch`, as git itself parses them (`git apply
+    /// --numstat --summary -z`). Nothing is applied and the preimage is not checked.
+    pub fn patch_targets(
+        &self,
+        worktree_path: &Path,
+        patch: &[u8],
+        strip: usize,
+    ) -> Result<PatchTargets, GitCliError> {
+        let out = self.git_with_stdin(
+            worktree_path,
+            [
+                "apply".to_string(),
+                format!("-p{strip}"),
+                "--numstat".to_string(),
+                "--summary".to_string(),
+                "-z".to_string(),
+                "-".to_string(),
+            ],
+            None,
+            patch,
+        )?;
+        Ok(Self::parse_patch_targets(&out))
+    }
+
+    fn parse_patch_targets(out: &str) -> PatchTargets {
+        // NUL-terminated "<added>\t<deleted>\t<path>" records, followed by the summary lines.
+        let mut records: Vec<&str> = out.split('\0').collect();
+        let summary = records.pop().unwrap_or_default();
+        let paths = records
+            .into_iter()
+            .filter_map(|record| record.splitn(3, '\t').nth(2))
+            .map(str::to_string)
+            .collect();
+        let creates_symlink = summary.lines().any(|line| {
+            let line = line.trim_start();
+            line.starts_with("create mode 120000 ")
+                || (line.starts_with("mode change ") && line.contains(" => 120000 "))
+        });
+        PatchTargets {
+            paths,
+            creates_symlink,
+        }
+    }
+
+    /// Run `git blame --line-porcelain -- <path>` against the working tree copy of `rel_path`.
+    ///
+    /// Lines that are not committed yet are attributed to the all-zero commit.
+    pub fn blame(
+        &self,
+        worktree_path: &Path,
+        rel_path: &str,
+    ) -> Result<Vec<BlameEntry>, GitCliError> {
+        self.ensure_available()?;
+        let out = self.git(worktree_path, ["blame", "--line-porcelain", "--", rel_path])?;
+        Ok(Self::parse_blame_porcelain(&out))
+    }
+
+    fn parse_blame_porcelain(out: &str) -> Vec<BlameEntry> {
+        let mut entries = Vec::new();
+        let mut current: Option<BlameEntry> = None;
+        for line in out.lines() {
+            if let Some(content) = line.strip_prefix('\t') {
+                if let Some(mut entry) = current.take() {
+                    entry.line = content.to_string();
+                    entries.push(entry);
+                }
+                continue;
+            }
+            let Some(entry) = current.as_mut() else {
+                // Header: "<sha> <orig line> <final line> [<group size>]"
+                let mut parts = line.split_whitespace();
+                let (Some(commit), Some(_), Some(final_line)) =
+                    (parts.next(), parts.next(), parts.next())
+                else {
+                    continue;
+                };
+                current = Some(BlameEntry {
+                    commit: commit.to_string(),
+                    line_number: final_line.parse().unwrap_or(entries.len() + 1),
+                    ..BlameEntry::default()
+                });
+                continue;
+            };
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            match key {
+                "author" => entry.author_name = value.to_string(),
+                "author-mail" => {
+                    entry.author_email = value
+                        .trim_start_matches('<')
+                        .trim_end_matches('>')
+                        .to_string()
+                }
+                "author-time" => entry.author_time = value.parse().unwrap_or_default(),
+                "summary" => entry.summary = value.to_string(),
+                _ => {}
+            }
+        }
+        entries
+    }
+
     pub fn for_each_ref(
         &self,
         repo_path: &Path,
@@ -1145,6 +1326,60 @@ impl GitCli {
         }
     }
 
+    /// Whether `path` is a file (a blob, which includes symlinks) in the tree of `rev`.
+    /// Directories and missing paths are both `false`.
+    pub fn file_exists_at_rev(
+        &self,
+        repo_path: &Path,
+        rev: &str,
+        path: &str,
+    ) -> Result<bool, GitCliError> {
+        let git = self.git_executable()?;
+        let out = Command::new(git)
+            .arg("-C")
+            .arg(repo_path)
+            .args(["cat-file", "-t"])
+            .arg(format!("{rev}:{path}"))
+            .output()
+            .map_err(|e| GitCliError::CommandFailed(e.to_string()))?;
+        Ok(out.status.success() && String::from_utf8_lossy(&out.stdout).trim() == "blob")
+    }
+
+    /// Dry-run merge of `branch` into `base` via `git merge-tree --write-tree`.
+    ///
+    /// Touches neither the index nor any worktree; returns the conflicted paths (empty when the
+    /// merge would be clean).
+    pub fn merge_tree_conflicts(
+        &self,
+        repo_path: &Path,
+        base: &str,
+        branch: &str,
+    ) -> Result<Vec<String>, GitCliError> {
+        let git = self.git_executable()?;
+        let out = Command::new(git)
+            .arg("-C")
+            .arg(repo_path)
+            .args(["merge-tree", "--write-tree", "--name-only", "--no-messages"])
+            .arg(base)
+            .arg(branch)
+            .output()
+            .map_err(|e| GitCliError::CommandFailed(e.to_string()))?;
+        match out.status.code() {
+            Some(0) => Ok(Vec::new()),
+            // Exit code 1 means conflicts: the first line is the tree oid, then one path per line.
+            Some(1) => Ok(String::from_utf8_lossy(&out.stdout)
+                .lines()
+                .skip(1)
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect()),
+            _ => Err(GitCliError::CommandFailed(
+                String::from_utf8_lossy(&out.stderr).trim().to_string(),
+            )),
+        }
+    }
+
     /// Checkout base branch, squash-merge from_branch, and commit with message. Returns new HEAD sha.
     pub fn merge_squash_commit(
         &self,
@@ -1197,6 +1432,31 @@ impl GitCli {
         Ok(sha)
     }
 
+    /// Squash-merge `from_branch` into the checked-out branch without committing.
+    ///
+    /// Returns the conflicted paths (empty on a clean merge). On conflicts the merge state is left
+    /// in place so the caller can inspect it before resetting.
+    pub fn merge_squash_no_commit(
+        &self,
+        worktree_path: &Path,
+        from_branch: &str,
+    ) -> Result<Vec<String>, GitCliError> {
+        match self.git(
+            worktree_path,
+            ["merge", "--squash", "--no-commit", from_branch],
+        ) {
+            Ok(_) => Ok(Vec::new()),
+            Err(err) => {
+                let conflicts = self.get_conflicted_files(worktree_path).unwrap_or_default();
+                if conflicts.is_empty() {
+                    let _ = self.reset_merge(worktree_path);
+                    return Err(err);
+                }
+                Ok(conflicts)
+            }
+        }
+    }
+
     /// Update a ref to a specific sha in the repo.
     pub fn update_ref(
         &self,
@@ -1505,6 +1765,36 @@ pub struct StatusEntry {
     pub is_untracked: bool,
 }
 
+/// One line of `git blame --line-porcelain` output
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlameEntry {
+    pub commit: String,
+    /// 1-based line number in the blamed file
+    pub line_number: usize,
+    pub author_name: String,
+    pub author_email: String,
+    /// Unix timestamp (seconds)
+    pub author_time: i64,
+    pub summary: String,
+    pub line: String,
+}
+
+impl BlameEntry {
+    /// True for lines that only exist in the working tree (or index), not in any commit.
+    pub fn is_uncommitted(&self) -> bool {
+        self.commit.bytes().all(|b| b == b'0')
+    }
+}
+
+/// Files a patch touches, as reported by `git apply --numstat --summary`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PatchTargets {
+    /// Touched paths after `-p` stripping; the new name for renames and copies
+    pub paths: Vec<String>,
+    /// True if the patch creates a symlink or turns a file into one
+    pub creates_symlink: bool,
+}
+
 /// Summary + entries for a working tree status
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WorktreeStatus {
@@ -1546,6 +1836,27 @@ mod tests {
         assert_eq!(summary.deletions, 1);
     }
 
+    #[test]
+    fn parse_patch_targets_reads_paths_and_symlink_modes() {
+        let out = concat!(
+            "1\t0\trepo/notes.txt\0",
+            "-\t-\trepo/logo.png\0",
+            "1\t0\trepo/link\0",
+            " create mode 120000 repo/link\n",
+        );
+        let targets = GitCli::parse_patch_targets(out);
+        assert_eq!(
+            targets.paths,
+            vec!["repo/notes.txt", "repo/logo.png", "repo/link"]
+        );
+        assert!(targets.creates_symlink);
+
+        let out = "0\t0\trepo/m\0 mode change 100644 => 120000 repo/m\n";
+        assert!(GitCli::parse_patch_targets(out).creates_symlink);
+        let out = "1\t0\trepo/a\0 create mode 100644 repo/a\n";
+        assert!(!GitCli::parse_patch_targets(out).creates_symlink);
+    }
+
     #[test]
     fn classify_cli_error_redacts_and_maps_auth_failed() {
         let git = GitCli::new();
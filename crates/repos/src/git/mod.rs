@@ -15,8 +15,8 @@ use utils_core::diff::{Diff, DiffChangeKind, DiffSummary, compute_line_change_co
 
 mod cli;
 
+pub use cli::{BlameEntry, GitCli, GitCliError, PatchTargets};
 use cli::{ChangeType, NumstatEntry, StatusDiffEntry, StatusDiffOptions};
-pub use cli::{GitCli, GitCliError};
 
 use super::file_ranker::FileStat;
 use crate::GitHubRepoInfo;
@@ -164,6 +164,8 @@ pub struct GitBranch {
     pub name: String,
     pub is_current: bool,
     pub is_remote: bool,
+    /// The repository's default branch (remote `HEAD`, else `main`/`master`, else current).
+    pub is_default: bool,
     #[ts(type = "Date")]
     pub last_commit_date: DateTime<Utc>,
 }
@@ -245,6 +247,76 @@ impl GitMergeOptions {
     }
 }
 
+/// Porcelain-style file status of a worktree, grouped like `git status`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+pub struct WorktreeFileStatus {
+    pub staged: Vec<WorktreeStatusEntry>,
+    pub unstaged: Vec<WorktreeStatusEntry>,
+    /// Untracked paths; untracked directories are collapsed to `dir/`.
+    pub untracked: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+pub struct WorktreeStatusEntry {
+    /// Porcelain status letter (`M`, `A`, `D`, `R`, `C`, `T` or `U`).
+    pub status: String,
+    pub path: String,
+    /// Previous path for renames and copies.
+    pub orig_path: Option<String>,
+}
+
+/// Result of [`GitService::revert_file`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevertFileOutcome {
+    /// The file was restored to its committed content.
+    Restored,
+    /// The file was not in `HEAD`, so it was unstaged and deleted.
+    Removed,
+    /// The path is neither committed nor present in the worktree.
+    NotFound,
+}
+
+/// Result of [`GitService::apply_branch_to_local_branch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApplyBranchOutcome {
+    /// The changes were committed onto the target branch.
+    Applied { commit_sha: String },
+    /// The target branch already contains every change on the source branch.
+    UpToDate,
+    /// The merge was aborted; the target branch is unchanged.
+    Conflicts { conflicted_files: Vec<String> },
+}
+
+/// Result of [`GitService::rebase_onto_base`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RebaseOntoBaseOutcome {
+    /// The task branch now sits on top of the base branch.
+    Rebased { head_sha: String },
+    /// The task branch already contained the base branch.
+    UpToDate,
+    /// The rebase was aborted; the task branch and worktree are unchanged.
+    Conflicts { conflicted_files: Vec<String> },
+}
+
+/// How a task branch relates to its base branch after the base has moved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BaseBranchConflictStatus {
+    /// Commits on the base branch that the task branch does not contain.
+    pub commits_behind: usize,
+    /// Paths that would conflict when merging the task branch into the base branch.
+    pub conflicted_files: Vec<String>,
+}
+
+impl BaseBranchConflictStatus {
+    pub fn base_diverged(&self) -> bool {
+        self.commits_behind > 0
+    }
+
+    pub fn has_conflicts(&self) -> bool {
+        !self.conflicted_files.is_empty()
+    }
+}
+
 /// Target for diff generation
 pub enum DiffTarget<'p> {
     /// Work-in-progress branch checked out in this worktree
@@ -357,6 +429,11 @@ impl GitService {
         Self {}
     }
 
+    /// Whether git recognizes `path` as a repository (a broken `.git` entry does not count).
+    pub fn is_git_repository(&self, path: &Path) -> bool {
+        GitCli::new().git(path, ["rev-parse", "--git-dir"]).is_ok()
+    }
+
     pub fn is_branch_name_valid(&self, name: &str) -> bool {
         GitCli::new().check_ref_format_branch(name).unwrap_or(false)
     }
@@ -1076,6 +1153,104 @@ impl GitService {
         Ok(sha)
     }
 
+    /// Squash the changes on `source_branch` into the local `target_branch` as a single commit.
+    ///
+    /// Unlike [`Self::merge_changes`], the target may be any local branch and the source branch
+    /// ref is left untouched. Conflicts abort the merge and are reported rather than left behind.
+    pub fn apply_branch_to_local_branch(
+        &self,
+        repo_path: &Path,
+        source_branch: &str,
+        target_branch: &str,
+        commit_message: &str,
+        options: GitMergeOptions,
+    ) -> Result<ApplyBranchOutcome, GitServiceError> {
+        if !Self::ref_exists(repo_path, &format!("refs/heads/{target_branch}"))? {
+            return Err(GitServiceError::BranchNotFound(target_branch.to_string()));
+        }
+
+        let git = GitCli::new();
+        let existing_checkout = self.find_checkout_path_for_branch(repo_path, target_branch)?;
+
+        let mut tmp_worktree: Option<tempfile::TempDir> = None;
+        let apply_worktree_path = match existing_checkout {
+            Some(path) => path,
+            None => {
+                let tmp = tempfile::TempDir::new().map_err(|e| {
+                    GitServiceError::InvalidRepository(format!("temp dir create failed: {e}"))
+                })?;
+                git.worktree_add(repo_path, tmp.path(), target_branch)
+                    .map_err(|e| {
+                        GitServiceError::InvalidRepository(format!("git worktree add failed: {e}"))
+                    })?;
+                let path = tmp.path().to_path_buf();
+                tmp_worktree = Some(tmp);
+                path
+            }
+        };
+
+        let result = Self::apply_branch_in_worktree(
+            &git,
+            &apply_worktree_path,
+            source_branch,
+            target_branch,
+            commit_message,
+            options,
+        );
+
+        if tmp_worktree.is_some() {
+            let _ = git.worktree_remove(repo_path, &apply_worktree_path, true);
+            let _ = git.worktree_prune(repo_path);
+        }
+
+        result
+    }
+
+    fn apply_branch_in_worktree(
+        git: &GitCli,
+        worktree_path: &Path,
+        source_branch: &str,
+        target_branch: &str,
+        commit_message: &str,
+        options: GitMergeOptions,
+    ) -> Result<ApplyBranchOutcome, GitServiceError> {
+        let has_staged = git.has_staged_changes(worktree_path).map_err(|e| {
+            GitServiceError::InvalidRepository(format!("git diff --cached failed: {e}"))
+        })?;
+        if has_staged {
+            return Err(GitServiceError::WorktreeDirty(
+                target_branch.to_string(),
+                "staged changes present".to_string(),
+            ));
+        }
+
+        let conflicted_files = git.merge_squash_no_commit(worktree_path, source_branch)?;
+        if !conflicted_files.is_empty() {
+            git.reset_merge(worktree_path)?;
+            return Ok(ApplyBranchOutcome::Conflicts { conflicted_files });
+        }
+
+        let has_changes = git.has_staged_changes(worktree_path).map_err(|e| {
+            GitServiceError::InvalidRepository(format!("git diff --cached failed: {e}"))
+        })?;
+        if !has_changes {
+            let _ = git.reset_merge(worktree_path);
+            return Ok(ApplyBranchOutcome::UpToDate);
+        }
+
+        if let Err(err) = git.commit_with_options(
+            worktree_path,
+            commit_message,
+            GitCommitOptions::new(options.no_verify),
+        ) {
+            let _ = git.reset_merge(worktree_path);
+            return Err(err.into());
+        }
+
+        let commit_sha = git.rev_parse(worktree_path, "HEAD")?;
+        Ok(ApplyBranchOutcome::Applied { commit_sha })
+    }
+
     pub fn get_branch_status(
         &self,
         repo_path: &Path,
@@ -1087,6 +1262,30 @@ impl GitService {
             .map_err(GitServiceError::from)
     }
 
+    /// Check whether `branch_name` still merges cleanly into `base_branch_name`.
+    ///
+    /// Uses a `merge-tree` dry run, so no worktree or index is touched. The dry run is skipped when
+    /// the base branch has not advanced, since the merge is then trivially clean.
+    pub fn get_base_conflict_status(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+        base_branch_name: &str,
+    ) -> Result<BaseBranchConflictStatus, GitServiceError> {
+        let (_, commits_behind) =
+            self.get_branch_status(repo_path, branch_name, base_branch_name)?;
+        if commits_behind == 0 {
+            return Ok(BaseBranchConflictStatus::default());
+        }
+
+        let conflicted_files =
+            GitCli::new().merge_tree_conflicts(repo_path, base_branch_name, branch_name)?;
+        Ok(BaseBranchConflictStatus {
+            commits_behind,
+            conflicted_files,
+        })
+    }
+
     pub fn get_base_commit(
         &self,
         repo_path: &Path,
@@ -1354,6 +1553,181 @@ impl GitService {
         Ok((st.uncommitted_tracked, st.untracked))
     }
 
+    /// Discard every change the branch made to a single file since it forked from
+    /// `base_branch`, committed or not, restoring the file's content at the merge-base.
+    ///
+    /// A path that does not exist at the merge-base (a file added on the branch) is removed
+    /// instead. `rel_path` is relative to `worktree_path`; callers are responsible for
+    /// validating it.
+    pub fn revert_file(
+        &self,
+        worktree_path: &Path,
+        base_branch: &str,
+        rel_path: &str,
+    ) -> Result<RevertFileOutcome, GitServiceError> {
+        let git = GitCli::new();
+        let merge_base = git.merge_base(worktree_path, base_branch, "HEAD")?;
+        // A literal pathspec keeps `*`, `?` and `[` in file names from matching other files.
+        let pathspec = format!(":(literal){rel_path}");
+        if git.file_exists_at_rev(worktree_path, &merge_base, rel_path)? {
+            git.git(worktree_path, ["checkout", &merge_base, "--", &pathspec])?;
+            return Ok(RevertFileOutcome::Restored);
+        }
+
+        git.git(
+            worktree_path,
+            [
+                "rm",
+                "--cached",
+                "--quiet",
+                "--ignore-unmatch",
+                "--",
+                &pathspec,
+            ],
+        )?;
+        let full_path = worktree_path.join(rel_path);
+        if full_path.symlink_metadata().is_err() {
+            return Ok(RevertFileOutcome::NotFound);
+        }
+        std::fs::remove_file(&full_path)?;
+        Ok(RevertFileOutcome::Removed)
+    }
+
+    /// Blame each line of the working-tree copy of `rel_path`, including uncommitted edits.
+    ///
+    /// Untracked files have no history, so every line is reported as uncommitted. `rel_path` is
+    /// relative to `worktree_path`; callers are responsible for validating it.
+    pub fn blame_file(
+        &self,
+        worktree_path: &Path,
+        rel_path: &str,
+    ) -> Result<Vec<BlameEntry>, GitServiceError> {
+        let git = GitCli::new();
+        if git.file_exists_at_rev(worktree_path, "HEAD", rel_path)? {
+            return Ok(git.blame(worktree_path, rel_path)?);
+        }
+
+        let contents = std::fs::read(worktree_path.join(rel_path))?;
+        Ok(String::from_utf8_lossy(&contents)
+            .lines()
+            .enumerate()
+            .map(|(index, line)| BlameEntry {
+                commit: "0".repeat(40),
+                line_number: index + 1,
+                author_name: "Not Committed Yet".to_string(),
+                line: line.to_string(),
+                ..BlameEntry::default()
+            })
+            .collect())
+    }
+
+    /// Complete patch of the worktree (committed, uncommitted and untracked changes) vs
+    /// `base_commit`, with every path prefixed by `path_prefix`.
+    pub fn worktree_patch(
+        &self,
+        worktree_path: &Path,
+        base_commit: &Commit,
+        path_prefix: &str,
+    ) -> Result<Vec<u8>, GitServiceError> {
+        Ok(GitCli::new().diff_worktree_patch(worktree_path, base_commit, path_prefix)?)
+    }
+
+    /// Dry-run `patch` against the worktree; returns git's error messages, empty when it applies
+    /// cleanly.
+    pub fn check_patch(
+        &self,
+        worktree_path: &Path,
+        patch: &[u8],
+        strip: usize,
+    ) -> Result<Vec<String>, GitServiceError> {
+        match GitCli::new().apply_patch(worktree_path, patch, strip, true) {
+            Ok(()) => Ok(Vec::new()),
+            Err(GitCliError::CommandFailed(output)) => {
+                let errors: Vec<String> = output
+                    .lines()
+                    .filter_map(|line| line.strip_prefix("error: "))
+                    .map(str::to_string)
+                    .collect();
+                Ok(if errors.is_empty() {
+                    vec![output]
+                } else {
+                    errors
+                })
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Paths `git apply -p<strip>` would touch for `patch`, without applying it.
+    pub fn patch_targets(
+        &self,
+        worktree_path: &Path,
+        patch: &[u8],
+        strip: usize,
+    ) -> Result<PatchTargets, GitServiceError> {
+        Ok(GitCli::new().patch_targets(worktree_path, patch, strip)?)
+    }
+
+    /// Apply `patch` to the worktree's files (not the index) with `git apply -p<strip>`.
+    pub fn apply_patch(
+        &self,
+        worktree_path: &Path,
+        patch: &[u8],
+        strip: usize,
+    ) -> Result<(), GitServiceError> {
+        Ok(GitCli::new().apply_patch(worktree_path, patch, strip, false)?)
+    }
+
+    /// Commits reachable from `head` but not from `base` (`git rev-list base..head`).
+    pub fn commits_between(
+        &self,
+        repo_path: &Path,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<String>, GitServiceError> {
+        Ok(GitCli::new().rev_list(repo_path, &format!("{base}..{head}"))?)
+    }
+
+    /// Staged, unstaged and untracked files in the worktree.
+    ///
+    /// A file that is both staged and modified again appears in both `staged` and `unstaged`.
+    pub fn get_worktree_file_status(
+        &self,
+        worktree_path: &Path,
+    ) -> Result<WorktreeFileStatus, GitServiceError> {
+        let st = GitCli::new()
+            .get_worktree_status(worktree_path)
+            .map_err(|e| GitServiceError::InvalidRepository(format!("git status failed: {e}")))?;
+
+        let mut status = WorktreeFileStatus::default();
+        for entry in st.entries {
+            let path = String::from_utf8_lossy(&entry.path).into_owned();
+            if entry.is_untracked {
+                status.untracked.push(path);
+                continue;
+            }
+            let orig_path = entry
+                .orig_path
+                .as_deref()
+                .map(|orig| String::from_utf8_lossy(orig).into_owned());
+            if entry.staged != ' ' {
+                status.staged.push(WorktreeStatusEntry {
+                    status: entry.staged.to_string(),
+                    path: path.clone(),
+                    orig_path: orig_path.clone(),
+                });
+            }
+            if entry.unstaged != ' ' {
+                status.unstaged.push(WorktreeStatusEntry {
+                    status: entry.unstaged.to_string(),
+                    path,
+                    orig_path,
+                });
+            }
+        }
+        Ok(status)
+    }
+
     /// Evaluate whether any action is needed to reset to `target_commit_oid` and
     /// optionally perform the actions.
     pub fn reconcile_worktree_to_commit(
@@ -1455,6 +1829,18 @@ impl GitService {
         Ok(())
     }
 
+    /// Limit the files materialized in `worktree_path` to those matching `patterns`
+    pub fn set_sparse_checkout(
+        &self,
+        worktree_path: &Path,
+        patterns: &[String],
+    ) -> Result<(), GitServiceError> {
+        let git = GitCli::new();
+        git.sparse_checkout_set(worktree_path, patterns)
+            .map_err(|e| GitServiceError::InvalidRepository(e.to_string()))?;
+        Ok(())
+    }
+
     pub fn prune_worktrees(&self, repo_path: &Path) -> Result<(), GitServiceError> {
         let git = GitCli::new();
         git.worktree_prune(repo_path)
@@ -1478,6 +1864,7 @@ impl GitService {
         )?;
 
         let mut branches = Vec::new();
+        let mut local_names = Vec::new();
         for line in out.lines() {
             let line = line.trim();
             if line.is_empty() {
@@ -1500,14 +1887,38 @@ impl GitService {
             let seconds = ts.parse::<i64>().unwrap_or(0);
             let last_commit_date = DateTime::from_timestamp(seconds, 0).unwrap_or_else(Utc::now);
 
+            if !is_remote {
+                local_names.push(short.to_string());
+            }
             branches.push(GitBranch {
                 name: short.to_string(),
                 is_current: !is_remote && short == current_branch,
                 is_remote,
+                is_default: false,
                 last_commit_date,
             });
         }
 
+        let remote = self.default_remote_name(repo_path);
+        let default_branch = Self::remote_head_branch(repo_path, &remote)
+            .or_else(|| {
+                ["main", "master"]
+                    .into_iter()
+                    .find(|name| local_names.iter().any(|local| local == name))
+                    .map(str::to_string)
+            })
+            .or_else(|| (!current_branch.is_empty()).then(|| current_branch.clone()));
+        if let Some(default_branch) = default_branch {
+            let remote_default = format!("{remote}/{default_branch}");
+            for branch in &mut branches {
+                branch.is_default = if branch.is_remote {
+                    branch.name == remote_default
+                } else {
+                    branch.name == default_branch
+                };
+            }
+        }
+
         branches.sort_by(|a, b| {
             if a.is_current && !b.is_current {
                 std::cmp::Ordering::Less
@@ -1521,6 +1932,21 @@ impl GitService {
         Ok(branches)
     }
 
+    /// Branch that `refs/remotes/<remote>/HEAD` points at, without the remote prefix.
+    fn remote_head_branch(repo_path: &Path, remote: &str) -> Option<String> {
+        let head_ref = format!("refs/remotes/{remote}/HEAD");
+        let head = GitCli::new()
+            .git(
+                repo_path,
+                ["symbolic-ref", "--quiet", "--short", head_ref.as_str()],
+            )
+            .ok()?;
+        head.trim()
+            .strip_prefix(&format!("{remote}/"))
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+    }
+
     /// Rebase a worktree branch onto a new base
     pub fn rebase_branch(
         &self,
@@ -1605,6 +2031,38 @@ impl GitService {
         Ok(git.rev_parse(worktree_path, "HEAD").unwrap_or_default())
     }
 
+    /// Rebase `task_branch` onto the current tip of `base_branch`.
+    ///
+    /// Unlike [`Self::rebase_branch`], conflicts never leave a rebase in progress: the conflicted
+    /// paths are collected and the rebase is aborted so the worktree stays usable.
+    pub fn rebase_onto_base(
+        &self,
+        repo_path: &Path,
+        worktree_path: &Path,
+        base_branch: &str,
+        task_branch: &str,
+    ) -> Result<RebaseOntoBaseOutcome, GitServiceError> {
+        let git = GitCli::new();
+        let head_before = git.rev_parse(worktree_path, "HEAD")?;
+
+        match self.rebase_branch(
+            repo_path,
+            worktree_path,
+            base_branch,
+            base_branch,
+            task_branch,
+        ) {
+            Ok(head_sha) if head_sha == head_before => Ok(RebaseOntoBaseOutcome::UpToDate),
+            Ok(head_sha) => Ok(RebaseOntoBaseOutcome::Rebased { head_sha }),
+            Err(GitServiceError::MergeConflicts(_)) => {
+                let conflicted_files = git.get_conflicted_files(worktree_path).unwrap_or_default();
+                git.abort_rebase(worktree_path)?;
+                Ok(RebaseOntoBaseOutcome::Conflicts { conflicted_files })
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     pub fn find_branch_type(
         &self,
         repo_path: &Path,
@@ -1676,6 +2134,16 @@ impl GitService {
         Ok(())
     }
 
+    /// Force-deletes local branch `branch_name`; it must not be checked out in any worktree.
+    pub fn delete_local_branch(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+    ) -> Result<(), GitServiceError> {
+        GitCli::new().git(repo_path, ["branch", "-D", branch_name])?;
+        Ok(())
+    }
+
     /// Return true if a rebase is currently in progress in this worktree.
     pub fn is_rebase_in_progress(&self, worktree_path: &Path) -> Result<bool, GitServiceError> {
         let git = GitCli::new();
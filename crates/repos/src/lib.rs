@@ -1,9 +1,11 @@
+pub mod content_search;
 pub mod file_ranker;
 pub mod file_search_cache;
 pub mod filesystem;
 pub mod filesystem_watcher;
 pub mod git;
 mod github_repo;
+pub mod patch_files;
 pub mod project;
 pub mod repo;
 pub mod workspace_manager;
@@ -0,0 +1,274 @@
+//! Split a unified diff into per-file sections so callers can route each one to its repository.
+//!
+//! Only section headers are read, so `git apply` can still find more in a section than is listed
+//! here; validate the paths it reports (`GitCli::patch_targets`) before applying.
+use thiserror::Error;
+
+const SYMLINK_MODE: &str = "120000";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PatchParseError {
+    #[error("Patch does not contain any file changes")]
+    Empty,
+    #[error("Could not determine the file path of a patch section")]
+    MissingPath,
+    #[error("Quoted patch paths are not supported: {0}")]
+    QuotedPath(String),
+    #[error("Patch path has no a/ or b/ style prefix: {0}")]
+    UnprefixedPath(String),
+}
+
+/// One file's section of a patch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchFile {
+    /// Paths touched by the section with the leading `a/`/`b/` component removed (as `git apply
+    /// -p1` would), e.g. `repo/src/lib.rs`. Renames and copies list both sides.
+    pub paths: Vec<String>,
+    /// Set when the section creates a symlink or turns a file into one.
+    pub creates_symlink: bool,
+    /// Raw text of the section, ready to be concatenated back into a patch.
+    pub text: String,
+}
+
+/// Split `patch` into file sections.
+///
+/// Git-style patches (`diff --git` headers, including binary and rename-only sections) are split
+/// on their headers; plain unified diffs are split on `---`/`+++` pairs, counting hunk lines so a
+/// removed line that happens to start with `-- ` is not mistaken for a new file.
+pub fn split_patch_files(patch: &str) -> Result<Vec<PatchFile>, PatchParseError> {
+    let lines: Vec<&str> = patch.split_inclusive('\n').collect();
+    let sections = if lines.iter().any(|line| line.starts_with("diff --git ")) {
+        split_git_sections(&lines)
+    } else {
+        split_unified_sections(&lines)
+    };
+    if sections.is_empty() {
+        return Err(PatchParseError::Empty);
+    }
+    sections.into_iter().map(parse_section).collect()
+}
+
+fn split_git_sections<'a>(lines: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let mut sections: Vec<Vec<&str>> = Vec::new();
+    for &line in lines {
+        if line.starts_with("diff --git ") {
+            sections.push(vec![line]);
+        } else if let Some(section) = sections.last_mut() {
+            section.push(line);
+        }
+    }
+    sections
+}
+
+fn split_unified_sections<'a>(lines: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let mut sections: Vec<Vec<&str>> = Vec::new();
+    let (mut old_left, mut new_left) = (0usize, 0usize);
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        if old_left == 0 && new_left == 0 {
+            if line.starts_with("--- ")
+                && lines
+                    .get(index + 1)
+                    .is_some_and(|next| next.starts_with("+++ "))
+            {
+                sections.push(vec![line, lines[index + 1]]);
+                index += 2;
+                continue;
+            }
+            if let Some((old, new)) = parse_hunk_header(line) {
+                old_left = old;
+                new_left = new;
+            }
+        } else {
+            match line.as_bytes().first() {
+                Some(b'-') => old_left = old_left.saturating_sub(1),
+                Some(b'+') => new_left = new_left.saturating_sub(1),
+                Some(b'\\') => {}
+                _ => {
+                    old_left = old_left.saturating_sub(1);
+                    new_left = new_left.saturating_sub(1);
+                }
+            }
+        }
+        if let Some(section) = sections.last_mut() {
+            section.push(line);
+        }
+        index += 1;
+    }
+    sections
+}
+
+/// Old and new line counts of a `@@ -a,b +c,d @@` hunk header.
+fn parse_hunk_header(line: &str) -> Option<(usize, usize)> {
+    let (ranges, _) = line.strip_prefix("@@ -")?.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    Some((hunk_range_len(old)?, hunk_range_len(new)?))
+}
+
+fn hunk_range_len(range: &str) -> Option<usize> {
+    match range.split_once(',') {
+        Some((_, len)) => len.parse().ok(),
+        None => range.parse::<usize>().ok().map(|_| 1),
+    }
+}
+
+fn parse_section(lines: Vec<&str>) -> Result<PatchFile, PatchParseError> {
+    let mut paths: Vec<String> = Vec::new();
+    let mut creates_symlink = false;
+    for line in &lines {
+        let line = line.trim_end_matches(['\n', '\r']);
+        if line.starts_with("@@") || line.starts_with("GIT binary patch") {
+            break;
+        }
+        let found = if let Some(header) = line.strip_prefix("diff --git ") {
+            git_header_path(header)?
+        } else if let Some(path) = line
+            .strip_prefix("--- ")
+            .or_else(|| line.strip_prefix("+++ "))
+        {
+            // Plain diffs may append a tab-separated timestamp.
+            let path = path.split('\t').next().unwrap_or_default();
+            if path == "/dev/null" {
+                None
+            } else {
+                Some(strip_prefix_component(unquoted(path)?)?)
+            }
+        } else if let Some(path) = ["rename from ", "rename to ", "copy from ", "copy to "]
+            .iter()
+            .find_map(|prefix| line.strip_prefix(*prefix))
+        {
+            Some(unquoted(path)?.to_string())
+        } else {
+            if let Some(mode) = line
+                .strip_prefix("new file mode ")
+                .or_else(|| line.strip_prefix("new mode "))
+            {
+                creates_symlink |= mode.trim() == SYMLINK_MODE;
+            }
+            None
+        };
+        if let Some(path) = found
+            && !paths.contains(&path)
+        {
+            paths.push(path);
+        }
+    }
+
+    if paths.is_empty() {
+        return Err(PatchParseError::MissingPath);
+    }
+    Ok(PatchFile {
+        paths,
+        creates_symlink,
+        text: lines.concat(),
+    })
+}
+
+/// Path from a `diff --git a/<path> b/<path>` header when both sides agree; renames are read from
+/// their `rename from`/`rename to` lines instead.
+fn git_header_path(header: &str) -> Result<Option<String>, PatchParseError> {
+    let header = unquoted(header)?;
+    let mid = header.len() / 2;
+    let (Some(old), Some(new)) = (header.get(..mid), header.get(mid + 1..)) else {
+        return Ok(None);
+    };
+    if header.as_bytes().get(mid) != Some(&b' ') {
+        return Ok(None);
+    }
+    let old = strip_prefix_component(old)?;
+    let new = strip_prefix_component(new)?;
+    Ok((old == new).then_some(old))
+}
+
+fn unquoted(path: &str) -> Result<&str, PatchParseError> {
+    if path.starts_with('"') {
+        return Err(PatchParseError::QuotedPath(path.to_string()));
+    }
+    Ok(path)
+}
+
+fn strip_prefix_component(path: &str) -> Result<String, PatchParseError> {
+    path.split_once('/')
+        .map(|(_, rest)| rest.to_string())
+        .ok_or_else(|| PatchParseError::UnprefixedPath(path.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PatchParseError, split_patch_files};
+
+    #[test]
+    fn split_patch_files_reads_git_and_plain_sections() {
+        let git_patch = "\
+diff --git a/repo/notes.txt b/repo/notes.txt
+index 1111111..2222222 100644
+--- a/repo/notes.txt
++++ b/repo/notes.txt
+@@ -1 +1,2 @@
+ first
++second
+diff --git a/repo/old.rs b/repo/new.rs
+similarity index 100%
+rename from repo/old.rs
+rename to repo/new.rs
+diff --git a/repo/link b/repo/link
+new file mode 120000
+index 0000000..3333333
+--- /dev/null
++++ b/repo/link
+@@ -0,0 +1 @@
++../../outside
+\\ No newline at end of file
+";
+        let files = split_patch_files(git_patch).unwrap();
+        let paths: Vec<_> = files.iter().map(|file| file.paths.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                vec!["repo/notes.txt".to_string()],
+                vec!["repo/old.rs".to_string(), "repo/new.rs".to_string()],
+                vec!["repo/link".to_string()],
+            ]
+        );
+        assert_eq!(
+            files
+                .iter()
+                .map(|file| file.creates_symlink)
+                .collect::<Vec<_>>(),
+            vec![false, false, true]
+        );
+        assert_eq!(
+            files
+                .iter()
+                .map(|file| file.text.as_str())
+                .collect::<String>(),
+            git_patch
+        );
+
+        // The removed line "-- b" must not be read as the start of another file.
+        let plain_patch = "\
+--- a/repo/a.txt\t2024-01-01
++++ b/repo/a.txt\t2024-01-02
+@@ -1,2 +1 @@
+--- b
+ keep
+--- a/other/b.txt
++++ b/other/b.txt
+@@ -1 +1 @@
+-x
++y
+";
+        let files = split_patch_files(plain_patch).unwrap();
+        let paths: Vec<_> = files.iter().map(|file| file.paths.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                vec!["repo/a.txt".to_string()],
+                vec!["other/b.txt".to_string()]
+            ]
+        );
+
+        assert_eq!(split_patch_files("hello\n"), Err(PatchParseError::Empty));
+    }
+}
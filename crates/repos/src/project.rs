@@ -10,7 +10,7 @@ use db::{
             CreateProject, Project, ProjectError, ProjectFileSearchResponse, SearchMatchType,
             SearchResult, UpdateProject, WorkspaceLifecycleHookConfig,
         },
-        project_repo::{CreateProjectRepo, ProjectRepo},
+        project_repo::{CreateProjectRepo, ProjectRepo, disambiguate_repo_name},
         repo::Repo,
     },
 };
@@ -21,6 +21,7 @@ use uuid::Uuid;
 use super::{
     file_ranker::FileRanker,
     file_search_cache::{CacheError, FileSearchCache, RepoSearchResponse, SearchMode, SearchQuery},
+    git::GitService,
     repo::{RepoError, RepoService},
 };
 
@@ -42,6 +43,11 @@ pub enum ProjectServiceError {
     DuplicateGitRepoPath,
     #[error("Duplicate repository name in project")]
     DuplicateRepositoryName,
+    #[error(
+        "Repository name '{name}' is already used in this project; repo-prefixed paths would be \
+         ambiguous (rename the directory, e.g. to '{suggestion}')"
+    )]
+    RepositoryNameConflict { name: String, suggestion: String },
     #[error("Repository not found")]
     RepositoryNotFound,
     #[error("Git operation failed: {0}")]
@@ -54,6 +60,23 @@ pub enum ProjectServiceError {
     InvalidSchedulerSetting(String),
     #[error("Invalid workspace lifecycle hook: {0}")]
     InvalidWorkspaceLifecycleHook(String),
+    #[error("Invalid repository paths: {}", format_invalid_repository_paths(.0))]
+    InvalidRepositoryPaths(Vec<InvalidRepositoryPath>),
+}
+
+/// A repository path rejected when creating a project, with why it was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidRepositoryPath {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+fn format_invalid_repository_paths(paths: &[InvalidRepositoryPath]) -> String {
+    paths
+        .iter()
+        .map(|invalid| format!("{} ({})", invalid.path.display(), invalid.reason))
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 pub type Result<T> = std::result::Result<T, ProjectServiceError>;
@@ -240,13 +263,30 @@ impl ProjectService {
         payload: CreateProject,
     ) -> Result<Project> {
         // Validate all repository paths and check for duplicates within the payload
+        let git = GitService::new();
         let mut seen_names = HashSet::new();
         let mut seen_paths = HashSet::new();
+        let mut seen_repo_names = HashSet::new();
         let mut normalized_repos = Vec::new();
+        let mut invalid_paths = Vec::new();
 
         for repo in &payload.repositories {
             let path = repo_service.normalize_path(&repo.git_repo_path)?;
-            repo_service.validate_git_repo_path(&path)?;
+            let reason = match repo_service.validate_git_repo_path(&path) {
+                Ok(()) if git.is_git_repository(&path) => None,
+                Ok(()) => Some("git does not recognize it as a repository"),
+                Err(RepoError::PathNotFound(_)) => Some("does not exist"),
+                Err(RepoError::PathNotDirectory(_)) => Some("is not a directory"),
+                Err(RepoError::NotGitRepository(_)) => Some("is not a git repository"),
+                Err(err) => return Err(err.into()),
+            };
+            if let Some(reason) = reason {
+                invalid_paths.push(InvalidRepositoryPath {
+                    path,
+                    reason: reason.to_string(),
+                });
+                continue;
+            }
 
             let normalized_path = path.to_string_lossy().to_string();
 
@@ -258,12 +298,27 @@ impl ProjectService {
                 return Err(ProjectServiceError::DuplicateGitRepoPath);
             }
 
+            // Mirrors the name `Repo::find_or_create` derives for new repos.
+            if let Some(repo_name) = path.file_name().map(|name| name.to_string_lossy()) {
+                if seen_repo_names.contains(repo_name.as_ref()) {
+                    return Err(ProjectServiceError::RepositoryNameConflict {
+                        suggestion: disambiguate_repo_name(&repo_name, &seen_repo_names),
+                        name: repo_name.to_string(),
+                    });
+                }
+                seen_repo_names.insert(repo_name.to_string());
+            }
+
             normalized_repos.push(CreateProjectRepo {
                 display_name: repo.display_name.clone(),
                 git_repo_path: normalized_path,
             });
         }
 
+        if !invalid_paths.is_empty() {
+            return Err(ProjectServiceError::InvalidRepositoryPaths(invalid_paths));
+        }
+
         let id = Uuid::new_v4();
 
         let project = Project::create(pool, &payload, id)
@@ -346,6 +401,9 @@ impl ProjectService {
             db::models::project_repo::ProjectRepoError::AlreadyExists => {
                 ProjectServiceError::DuplicateGitRepoPath
             }
+            db::models::project_repo::ProjectRepoError::NameConflict { name, suggestion } => {
+                ProjectServiceError::RepositoryNameConflict { name, suggestion }
+            }
             db::models::project_repo::ProjectRepoError::Database(e) => {
                 ProjectServiceError::Database(e)
             }
@@ -669,7 +727,7 @@ mod tests {
     use tempfile::tempdir;
     use uuid::Uuid;
 
-    use super::{ProjectService, ProjectServiceError, RepoService};
+    use super::{InvalidRepositoryPath, ProjectService, ProjectServiceError, RepoService};
     use crate::git::GitService;
 
     async fn setup_db() -> DBService {
@@ -851,6 +909,113 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn create_project_rejects_missing_and_non_git_paths_by_name() {
+        let db = setup_db().await;
+        let project_service = ProjectService::new();
+        let repo_service = RepoService::new();
+
+        let tmp = tempdir().unwrap();
+        let valid = tmp.path().join("valid");
+        GitService::new()
+            .initialize_repo_with_main_branch(&valid)
+            .unwrap();
+        let plain_dir = tmp.path().join("plain");
+        std::fs::create_dir(&plain_dir).unwrap();
+        let missing = tmp.path().join("missing");
+
+        let repo = |name: &str, path: &std::path::Path| CreateProjectRepo {
+            display_name: name.to_string(),
+            git_repo_path: path.to_string_lossy().to_string(),
+        };
+        let result = project_service
+            .create_project(
+                &db.pool,
+                &repo_service,
+                CreateProject {
+                    name: "bad-paths".to_string(),
+                    repositories: vec![
+                        repo("valid", &valid),
+                        repo("missing", &missing),
+                        repo("plain", &plain_dir),
+                    ],
+                },
+            )
+            .await;
+
+        let err = result.expect_err("expected invalid repository paths");
+        let message = err.to_string();
+        assert!(message.contains(&missing.display().to_string()));
+        assert!(!message.contains(&valid.display().to_string()));
+        match err {
+            ProjectServiceError::InvalidRepositoryPaths(paths) => assert_eq!(
+                paths,
+                vec![
+                    InvalidRepositoryPath {
+                        path: missing,
+                        reason: "does not exist".to_string(),
+                    },
+                    InvalidRepositoryPath {
+                        path: plain_dir,
+                        reason: "is not a git repository".to_string(),
+                    },
+                ]
+            ),
+            other => panic!("unexpected error: {other}"),
+        }
+        assert_eq!(Project::count(&db.pool).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn add_repository_rejects_colliding_repo_name_with_suggestion() {
+        let db = setup_db().await;
+        let project_service = ProjectService::new();
+        let repo_service = RepoService::new();
+        let git = GitService::new();
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            &db.pool,
+            &CreateProject {
+                name: "name-collision".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let tmp = tempdir().unwrap();
+        let first = tmp.path().join("team-a").join("app");
+        let second = tmp.path().join("team-b").join("app");
+        git.initialize_repo_with_main_branch(&first).unwrap();
+        git.initialize_repo_with_main_branch(&second).unwrap();
+
+        let add = |path: &std::path::Path, display_name: &str| CreateProjectRepo {
+            display_name: display_name.to_string(),
+            git_repo_path: path.to_string_lossy().to_string(),
+        };
+        project_service
+            .add_repository(&db.pool, &repo_service, project_id, &add(&first, "App A"))
+            .await
+            .unwrap();
+        let result = project_service
+            .add_repository(&db.pool, &repo_service, project_id, &add(&second, "App B"))
+            .await;
+
+        match result {
+            Err(ProjectServiceError::RepositoryNameConflict { name, suggestion }) => {
+                assert_eq!(name, "app");
+                assert_eq!(suggestion, "app-2");
+            }
+            other => panic!("expected repository name conflict, got {other:?}"),
+        }
+        let repos = ProjectRepo::find_by_project_id(&db.pool, project_id)
+            .await
+            .unwrap();
+        assert_eq!(repos.len(), 1);
+    }
+
     #[tokio::test]
     async fn add_repository_concurrent_requests_clear_default_working_dir() {
         let db = setup_db().await;
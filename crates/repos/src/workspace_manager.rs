@@ -1,16 +1,29 @@
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use db::models::{repo::Repo, workspace::Workspace as DbWorkspace};
+use futures::stream::{self, StreamExt};
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use super::worktree_manager::{WorktreeCleanup, WorktreeError, WorktreeManager};
 
+/// Upper bound on per-repo worktrees created at once for a multi-repo workspace.
+const MAX_CONCURRENT_WORKTREE_SETUPS: usize = 4;
+
+/// Empty file written into every workspace directory this app creates, so orphan reclaim never
+/// deletes directories it does not own.
+pub const WORKSPACE_MARKER_FILE: &str = ".vibe-kanban-workspace";
+
 #[derive(Debug, Clone)]
 pub struct RepoWorkspaceInput {
     pub repo: Repo,
     pub target_branch: String,
+    /// Sparse-checkout patterns applied to the repo's worktree; empty = full checkout.
+    pub sparse_paths: Vec<String>,
 }
 
 impl RepoWorkspaceInput {
@@ -18,8 +31,14 @@ impl RepoWorkspaceInput {
         Self {
             repo,
             target_branch,
+            sparse_paths: Vec::new(),
         }
     }
+
+    pub fn with_sparse_paths(mut self, sparse_paths: Vec<String>) -> Self {
+        self.sparse_paths = sparse_paths;
+        self
+    }
 }
 
 #[derive(Debug, Error)]
@@ -50,6 +69,41 @@ pub struct WorktreeContainer {
     pub worktrees: Vec<RepoWorktree>,
 }
 
+/// Stage of a single repo's worktree while a workspace is being set up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoSetupStage {
+    Cloning,
+    Ready,
+    Failed,
+}
+
+/// Callback invoked as each repo's worktree moves through [`RepoSetupStage`]s.
+pub type RepoSetupProgress<'a> = &'a (dyn Fn(&Repo, RepoSetupStage) + Send + Sync);
+
+/// Outcome of an orphaned-workspace reclaim pass.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OrphanReclaimSummary {
+    pub removed: usize,
+    pub reclaimed_bytes: u64,
+}
+
+/// Total size of regular files under `path`; symlinks are not followed.
+pub fn directory_size_bytes(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => directory_size_bytes(&entry.path()),
+            Ok(file_type) if file_type.is_file() => {
+                entry.metadata().map(|metadata| metadata.len()).unwrap_or(0)
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
 pub struct WorkspaceManager;
 
 impl WorkspaceManager {
@@ -59,6 +113,16 @@ impl WorkspaceManager {
         workspace_dir: &Path,
         repos: &[RepoWorkspaceInput],
         branch_name: &str,
+    ) -> Result<WorktreeContainer, WorkspaceError> {
+        Self::create_workspace_with_progress(workspace_dir, repos, branch_name, &|_, _| {}).await
+    }
+
+    /// Like [`Self::create_workspace`], reporting each repo's setup stage to `progress`.
+    pub async fn create_workspace_with_progress(
+        workspace_dir: &Path,
+        repos: &[RepoWorkspaceInput],
+        branch_name: &str,
+        progress: RepoSetupProgress<'_>,
     ) -> Result<WorktreeContainer, WorkspaceError> {
         if repos.is_empty() {
             return Err(WorkspaceError::NoRepositories);
@@ -71,58 +135,46 @@ impl WorkspaceManager {
         );
 
         tokio::fs::create_dir_all(workspace_dir).await?;
-
-        let mut created_worktrees: Vec<RepoWorktree> = Vec::new();
-
-        for input in repos {
-            let worktree_path = workspace_dir.join(&input.repo.name);
-
-            debug!(
-                "Creating worktree for repo '{}' at {}",
-                input.repo.name,
-                worktree_path.display()
-            );
-
-            match WorktreeManager::create_worktree(
-                &input.repo.path,
-                branch_name,
-                &worktree_path,
-                &input.target_branch,
-                true,
-            )
-            .await
-            {
-                Ok(()) => {
-                    created_worktrees.push(RepoWorktree {
-                        repo_id: input.repo.id,
-                        repo_name: input.repo.name.clone(),
-                        source_repo_path: input.repo.path.clone(),
-                        worktree_path,
-                    });
-                }
+        tokio::fs::write(workspace_dir.join(WORKSPACE_MARKER_FILE), b"").await?;
+
+        let results =
+            Self::create_repo_worktrees(workspace_dir, repos, branch_name, progress).await;
+        let mut created_worktrees: Vec<RepoWorktree> = Vec::with_capacity(results.len());
+        let mut failures: Vec<String> = Vec::new();
+        for (input, result) in repos.iter().zip(results) {
+            match result {
+                Ok(worktree) => created_worktrees.push(worktree),
                 Err(e) => {
                     error!(
-                        "Failed to create worktree for repo '{}': {}. Rolling back...",
+                        "Failed to create worktree for repo '{}': {}",
                         input.repo.name, e
                     );
+                    failures.push(format!("repo '{}': {}", input.repo.name, e));
+                }
+            }
+        }
 
-                    // Rollback: cleanup all worktrees we've created so far
-                    Self::cleanup_created_worktrees(&created_worktrees).await;
-
-                    // Also remove the workspace directory if it's empty
-                    if let Err(cleanup_err) = tokio::fs::remove_dir(workspace_dir).await {
-                        debug!(
-                            "Could not remove workspace dir during rollback: {}",
-                            cleanup_err
-                        );
-                    }
+        if !failures.is_empty() {
+            error!(
+                "Rolling back {} created worktrees after {} failures",
+                created_worktrees.len(),
+                failures.len()
+            );
+            Self::cleanup_created_worktrees(&created_worktrees).await;
 
-                    return Err(WorkspaceError::PartialCreation(format!(
-                        "Failed to create worktree for repo '{}': {}",
-                        input.repo.name, e
-                    )));
-                }
+            // Also remove the workspace directory if it's empty
+            let _ = tokio::fs::remove_file(workspace_dir.join(WORKSPACE_MARKER_FILE)).await;
+            if let Err(cleanup_err) = tokio::fs::remove_dir(workspace_dir).await {
+                debug!(
+                    "Could not remove workspace dir during rollback: {}",
+                    cleanup_err
+                );
             }
+
+            return Err(WorkspaceError::PartialCreation(format!(
+                "Failed to create worktree for {}",
+                failures.join("; ")
+            )));
         }
 
         info!(
@@ -156,28 +208,76 @@ impl WorkspaceManager {
             tokio::fs::create_dir_all(workspace_dir).await?;
         }
 
-        for input in repos {
-            let worktree_path = workspace_dir.join(&input.repo.name);
-
-            debug!(
-                "Ensuring worktree exists for repo '{}' at {}",
-                input.repo.name,
-                worktree_path.display()
-            );
-
-            WorktreeManager::create_worktree(
-                &input.repo.path,
-                branch_name,
-                &worktree_path,
-                &input.target_branch,
-                true,
-            )
-            .await?;
+        let failures: Vec<String> =
+            Self::create_repo_worktrees(workspace_dir, repos, branch_name, &|_, _| {})
+                .await
+                .into_iter()
+                .zip(repos)
+                .filter_map(|(result, input)| {
+                    result
+                        .err()
+                        .map(|e| format!("repo '{}': {}", input.repo.name, e))
+                })
+                .collect();
+        if !failures.is_empty() {
+            return Err(WorkspaceError::PartialCreation(format!(
+                "Failed to ensure worktree for {}",
+                failures.join("; ")
+            )));
         }
 
         Ok(())
     }
 
+    /// Create (or reuse) each repo's worktree under `workspace_dir`, up to
+    /// `MAX_CONCURRENT_WORKTREE_SETUPS` at a time. Results are in `repos` order.
+    async fn create_repo_worktrees(
+        workspace_dir: &Path,
+        repos: &[RepoWorkspaceInput],
+        branch_name: &str,
+        progress: RepoSetupProgress<'_>,
+    ) -> Vec<Result<RepoWorktree, WorktreeError>> {
+        stream::iter(repos)
+            .map(|input| async move {
+                let worktree_path = workspace_dir.join(&input.repo.name);
+                debug!(
+                    "Creating worktree for repo '{}' at {}",
+                    input.repo.name,
+                    worktree_path.display()
+                );
+                progress(&input.repo, RepoSetupStage::Cloning);
+                let created = WorktreeManager::create_worktree(
+                    &input.repo.path,
+                    branch_name,
+                    &worktree_path,
+                    &input.target_branch,
+                    true,
+                )
+                .await;
+                let created = match created {
+                    Ok(()) if !input.sparse_paths.is_empty() => {
+                        WorktreeManager::set_sparse_checkout(&worktree_path, &input.sparse_paths)
+                            .await
+                    }
+                    other => other,
+                };
+                if let Err(e) = created {
+                    progress(&input.repo, RepoSetupStage::Failed);
+                    return Err(e);
+                }
+                progress(&input.repo, RepoSetupStage::Ready);
+                Ok(RepoWorktree {
+                    repo_id: input.repo.id,
+                    repo_name: input.repo.name.clone(),
+                    source_repo_path: input.repo.path.clone(),
+                    worktree_path,
+                })
+            })
+            .buffered(MAX_CONCURRENT_WORKTREE_SETUPS)
+            .collect()
+            .await
+    }
+
     /// Clean up all worktrees in a workspace
     pub async fn cleanup_workspace(
         workspace_dir: &Path,
@@ -290,23 +390,44 @@ impl WorkspaceManager {
     }
 
     pub async fn cleanup_orphan_workspaces(db: &db::DbPool) {
+        Self::reclaim_orphan_workspaces(db, &Self::get_workspace_base_dir(), Duration::ZERO).await;
+    }
+
+    /// Removes workspace directories under `workspace_base_dir` that no workspace row
+    /// references, returning what was reclaimed. Only directories this app created are
+    /// considered (see [`Self::is_app_workspace_dir`]). Directories modified within `min_age` are
+    /// skipped: an attempt that is still starting has created its directory but not yet recorded
+    /// it as the container ref.
+    pub async fn reclaim_orphan_workspaces(
+        db: &db::DbPool,
+        workspace_base_dir: &Path,
+        min_age: Duration,
+    ) -> OrphanReclaimSummary {
         if std::env::var("DISABLE_WORKTREE_ORPHAN_CLEANUP").is_ok() {
             debug!(
                 "Orphan workspace cleanup is disabled via DISABLE_WORKTREE_ORPHAN_CLEANUP environment variable"
             );
-            return;
+            return OrphanReclaimSummary::default();
         }
 
-        let workspace_base_dir = Self::get_workspace_base_dir();
+        Self::reclaim_orphan_workspaces_in(db, workspace_base_dir, min_age).await
+    }
+
+    pub async fn reclaim_orphan_workspaces_in(
+        db: &db::DbPool,
+        workspace_base_dir: &Path,
+        min_age: Duration,
+    ) -> OrphanReclaimSummary {
+        let mut summary = OrphanReclaimSummary::default();
         if !workspace_base_dir.exists() {
             debug!(
                 "Workspace base directory {} does not exist, skipping orphan cleanup",
                 workspace_base_dir.display()
             );
-            return;
+            return summary;
         }
 
-        let entries = match std::fs::read_dir(&workspace_base_dir) {
+        let entries = match std::fs::read_dir(workspace_base_dir) {
             Ok(entries) => entries,
             Err(e) => {
                 error!(
@@ -314,7 +435,7 @@ impl WorkspaceManager {
                     workspace_base_dir.display(),
                     e
                 );
-                return;
+                return summary;
             }
         };
 
@@ -331,10 +452,24 @@ impl WorkspaceManager {
             if !path.is_dir() {
                 continue;
             }
+            if !Self::is_app_workspace_dir(&path) {
+                debug!(
+                    "Skipping {} during orphan cleanup: not a workspace directory",
+                    path.display()
+                );
+                continue;
+            }
+            if !min_age.is_zero() && Self::modified_within(&path, min_age) {
+                continue;
+            }
 
             let workspace_path_str = path.to_string_lossy().to_string();
             if let Ok(false) = DbWorkspace::container_ref_exists(db, &workspace_path_str).await {
                 info!("Found orphaned workspace: {}", workspace_path_str);
+                let size_path = path.clone();
+                let bytes = tokio::task::spawn_blocking(move || directory_size_bytes(&size_path))
+                    .await
+                    .unwrap_or(0);
                 if let Err(e) = Self::cleanup_workspace_without_repos(&path).await {
                     error!(
                         "Failed to remove orphaned workspace {}: {}",
@@ -342,12 +477,56 @@ impl WorkspaceManager {
                     );
                 } else {
                     info!(
-                        "Successfully removed orphaned workspace: {}",
-                        workspace_path_str
+                        reclaimed_bytes = bytes,
+                        "Successfully removed orphaned workspace: {}", workspace_path_str
                     );
+                    summary.removed += 1;
+                    summary.reclaimed_bytes += bytes;
                 }
             }
         }
+
+        if summary.removed > 0 {
+            info!(
+                removed = summary.removed,
+                reclaimed_bytes = summary.reclaimed_bytes,
+                "Reclaimed orphaned workspaces"
+            );
+        }
+        summary
+    }
+
+    /// Whether `path` is a workspace directory created by this app: it carries
+    /// [`WORKSPACE_MARKER_FILE`], or (for workspaces created before the marker existed) its name
+    /// has the `<4 hex chars of the workspace id>-<task title slug>` shape.
+    fn is_app_workspace_dir(path: &Path) -> bool {
+        if path.join(WORKSPACE_MARKER_FILE).is_file() {
+            return true;
+        }
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            return false;
+        };
+        let Some((prefix, slug)) = name.split_once('-') else {
+            return false;
+        };
+        prefix.len() == 4
+            && prefix
+                .bytes()
+                .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+            && slug.len() <= 16
+            && !slug.starts_with('-')
+            && !slug.ends_with('-')
+            && slug
+                .bytes()
+                .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-')
+    }
+
+    fn modified_within(path: &Path, window: Duration) -> bool {
+        std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_none_or(|age| age < window)
     }
 
     async fn cleanup_workspace_without_repos(workspace_dir: &Path) -> Result<(), WorkspaceError> {
@@ -545,6 +545,24 @@ impl WorktreeManager {
         .map_err(|e| WorktreeError::TaskJoin(format!("{e}")))?
     }
 
+    /// Restrict a worktree's checkout to the given sparse-checkout patterns
+    pub async fn set_sparse_checkout(
+        worktree_path: &Path,
+        patterns: &[String],
+    ) -> Result<(), WorktreeError> {
+        let worktree_path = worktree_path.to_path_buf();
+        let patterns = patterns.to_vec();
+
+        tokio::task::spawn_blocking(move || {
+            let git_service = GitService::new();
+            git_service
+                .set_sparse_checkout(&worktree_path, &patterns)
+                .map_err(WorktreeError::GitService)
+        })
+        .await
+        .map_err(|e| WorktreeError::TaskJoin(format!("{e}")))?
+    }
+
     /// Get the base directory for vibe-kanban worktrees
     pub fn get_worktree_base_dir() -> std::path::PathBuf {
         utils_core::path::get_vibe_kanban_temp_dir().join("worktrees")
@@ -6,14 +6,17 @@ use std::{
 
 use repos::{
     GitHubRepoInfo, GitHubRepoInfoError,
-    git::{DiffContentPolicy, DiffTarget, GitCli, GitService},
+    git::{
+        ApplyBranchOutcome, DiffContentPolicy, DiffTarget, GitCli, GitMergeOptions, GitService,
+        RebaseOntoBaseOutcome,
+    },
 };
 use tempfile::TempDir;
 use utils_core::diff::DiffChangeKind;
 
 mod git_test_utils;
 use git_test_utils::{
-    git_branch_force, git_checkout, git_config_user, git_detach_head, git_rev_parse,
+    git_branch_force, git_checkout, git_config_user, git_detach_head, git_ok, git_rev_parse,
     git_show_author,
 };
 
@@ -315,6 +318,23 @@ fn get_all_branches_lists_current_and_others() {
     assert!(main_entry.is_current);
 }
 
+#[test]
+fn get_all_branches_marks_default_branch_independently_of_current() {
+    let td = TempDir::new().unwrap();
+    let repo_path = init_repo_main(&td);
+    create_branch(&repo_path, "feature");
+    checkout_branch(&repo_path, "feature");
+
+    let s = GitService::new();
+    let branches = s.get_all_branches(&repo_path).unwrap();
+    let main_entry = branches.iter().find(|b| b.name == "main").unwrap();
+    let feature_entry = branches.iter().find(|b| b.name == "feature").unwrap();
+    assert!(main_entry.is_default);
+    assert!(!main_entry.is_current);
+    assert!(feature_entry.is_current);
+    assert!(!feature_entry.is_default);
+}
+
 #[test]
 fn get_branch_diffs_between_branches() {
     let td = TempDir::new().unwrap();
@@ -582,3 +602,163 @@ fn squash_merge_libgit2_sets_author_without_user() {
         assert_eq!(email.as_deref(), Some("noreply@localhost"));
     }
 }
+
+/// Repo on `main` with an `attempt` worktree and a `release` branch, both forked from main.
+fn setup_apply_repo(td: &TempDir) -> (PathBuf, PathBuf) {
+    let repo_path = init_repo_main(td);
+    write_file(&repo_path, "shared.txt", "base\n");
+    let s = GitService::new();
+    s.commit(&repo_path, "base").unwrap();
+    create_branch(&repo_path, "release");
+    create_branch(&repo_path, "attempt");
+
+    let worktree_path = td.path().join("wt_attempt");
+    s.add_worktree(&repo_path, &worktree_path, "attempt")
+        .unwrap();
+    configure_user(&worktree_path, "Test User", "test@example.com");
+    (repo_path, worktree_path)
+}
+
+#[test]
+fn apply_branch_to_local_branch_commits_clean_changes() {
+    let td = TempDir::new().unwrap();
+    let (repo_path, worktree_path) = setup_apply_repo(&td);
+    let s = GitService::new();
+    write_file(&worktree_path, "feature.txt", "feature\n");
+    s.commit(&worktree_path, "add feature").unwrap();
+    let attempt_head = git_rev_parse(&repo_path, "attempt");
+
+    let outcome = s
+        .apply_branch_to_local_branch(
+            &repo_path,
+            "attempt",
+            "release",
+            "apply attempt",
+            GitMergeOptions::default(),
+        )
+        .unwrap();
+
+    let ApplyBranchOutcome::Applied { commit_sha } = outcome else {
+        panic!("expected applied outcome, got {outcome:?}");
+    };
+    assert_eq!(git_rev_parse(&repo_path, "release"), commit_sha);
+    assert_eq!(
+        git_ok(&repo_path, ["show", "release:feature.txt"]),
+        "feature"
+    );
+    // The attempt branch and the main checkout are left untouched.
+    assert_eq!(git_rev_parse(&repo_path, "attempt"), attempt_head);
+    assert_eq!(s.get_head_info(&repo_path).unwrap().branch, "main");
+
+    // Re-applying is a no-op.
+    let outcome = s
+        .apply_branch_to_local_branch(
+            &repo_path,
+            "attempt",
+            "release",
+            "apply attempt",
+            GitMergeOptions::default(),
+        )
+        .unwrap();
+    assert_eq!(outcome, ApplyBranchOutcome::UpToDate);
+    assert_eq!(git_rev_parse(&repo_path, "release"), commit_sha);
+}
+
+#[test]
+fn apply_branch_to_local_branch_reports_conflicts_without_changing_target() {
+    let td = TempDir::new().unwrap();
+    let (repo_path, worktree_path) = setup_apply_repo(&td);
+    let s = GitService::new();
+    write_file(&worktree_path, "shared.txt", "attempt\n");
+    s.commit(&worktree_path, "attempt edit").unwrap();
+
+    checkout_branch(&repo_path, "release");
+    write_file(&repo_path, "shared.txt", "release\n");
+    s.commit(&repo_path, "release edit").unwrap();
+    let release_head = git_rev_parse(&repo_path, "release");
+
+    let outcome = s
+        .apply_branch_to_local_branch(
+            &repo_path,
+            "attempt",
+            "release",
+            "apply attempt",
+            GitMergeOptions::default(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        outcome,
+        ApplyBranchOutcome::Conflicts {
+            conflicted_files: vec!["shared.txt".to_string()],
+        }
+    );
+    assert_eq!(git_rev_parse(&repo_path, "release"), release_head);
+    assert!(s.is_worktree_clean(&repo_path).unwrap());
+    assert_eq!(
+        fs::read_to_string(repo_path.join("shared.txt")).unwrap(),
+        "release\n"
+    );
+}
+
+#[test]
+fn rebase_onto_base_moves_attempt_onto_advanced_base() {
+    let td = TempDir::new().unwrap();
+    let (repo_path, worktree_path) = setup_apply_repo(&td);
+    let s = GitService::new();
+    write_file(&worktree_path, "feature.txt", "feature\n");
+    s.commit(&worktree_path, "add feature").unwrap();
+    write_file(&repo_path, "other.txt", "other\n");
+    s.commit(&repo_path, "advance main").unwrap();
+
+    let outcome = s
+        .rebase_onto_base(&repo_path, &worktree_path, "main", "attempt")
+        .unwrap();
+
+    let RebaseOntoBaseOutcome::Rebased { head_sha } = outcome else {
+        panic!("expected rebased outcome, got {outcome:?}");
+    };
+    assert_eq!(git_rev_parse(&repo_path, "attempt"), head_sha);
+    assert_eq!(
+        git_ok(&repo_path, ["merge-base", "main", "attempt"]),
+        git_rev_parse(&repo_path, "main")
+    );
+    assert!(worktree_path.join("other.txt").exists());
+    assert!(worktree_path.join("feature.txt").exists());
+
+    let outcome = s
+        .rebase_onto_base(&repo_path, &worktree_path, "main", "attempt")
+        .unwrap();
+    assert_eq!(outcome, RebaseOntoBaseOutcome::UpToDate);
+}
+
+#[test]
+fn rebase_onto_base_aborts_cleanly_on_conflict() {
+    let td = TempDir::new().unwrap();
+    let (repo_path, worktree_path) = setup_apply_repo(&td);
+    let s = GitService::new();
+    write_file(&worktree_path, "shared.txt", "attempt\n");
+    s.commit(&worktree_path, "attempt edit").unwrap();
+    write_file(&repo_path, "shared.txt", "main\n");
+    s.commit(&repo_path, "main edit").unwrap();
+    let attempt_head = git_rev_parse(&repo_path, "attempt");
+
+    let outcome = s
+        .rebase_onto_base(&repo_path, &worktree_path, "main", "attempt")
+        .unwrap();
+
+    assert_eq!(
+        outcome,
+        RebaseOntoBaseOutcome::Conflicts {
+            conflicted_files: vec!["shared.txt".to_string()],
+        }
+    );
+    assert!(!s.is_rebase_in_progress(&worktree_path).unwrap());
+    assert!(s.is_worktree_clean(&worktree_path).unwrap());
+    assert_eq!(git_rev_parse(&repo_path, "attempt"), attempt_head);
+    assert_eq!(s.get_head_info(&worktree_path).unwrap().branch, "attempt");
+    assert_eq!(
+        fs::read_to_string(worktree_path.join("shared.txt")).unwrap(),
+        "attempt\n"
+    );
+}
@@ -1,24 +1,48 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
 use chrono::Utc;
 use db::models::repo::Repo;
 use repos::{
     git::GitService,
-    workspace_manager::{RepoWorkspaceInput, WorkspaceManager},
+    workspace_manager::{RepoWorkspaceInput, WORKSPACE_MARKER_FILE, WorkspaceManager},
     worktree_manager::WorktreeManager,
 };
+use sea_orm::Database;
+use sea_orm_migration::MigratorTrait;
 use tempfile::TempDir;
 use uuid::Uuid;
 
 mod git_test_utils;
-use git_test_utils::{git_rev_parse, git_try};
+use git_test_utils::{git_commit_all, git_config_user, git_rev_parse, git_try};
+
+/// Initializes a repo with a `main` branch at `root/name`.
+fn init_repo(root: &Path, name: &str) -> PathBuf {
+    let repo_path = root.join(name);
+    GitService::new()
+        .initialize_repo_with_main_branch(&repo_path)
+        .expect("init repo");
+    repo_path
+}
+
+fn repo_model(path: &Path, name: &str) -> Repo {
+    Repo {
+        id: Uuid::new_v4(),
+        path: path.to_path_buf(),
+        name: name.to_string(),
+        display_name: name.to_string(),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
 
 #[tokio::test]
 async fn create_worktree_succeeds_when_git_worktrees_dir_is_missing() {
     let td = TempDir::new().expect("tempdir");
 
-    let repo_path = td.path().join("repo");
-    GitService::new()
-        .initialize_repo_with_main_branch(&repo_path)
-        .expect("init repo");
+    let repo_path = init_repo(td.path(), "repo");
 
     // No worktrees exist yet, so `.git/worktrees` should be absent.
     assert!(
@@ -54,10 +78,7 @@ async fn create_worktree_succeeds_when_git_worktrees_dir_is_missing() {
 async fn ensure_workspace_creates_missing_attempt_branch_from_target_branch() {
     let td = TempDir::new().expect("tempdir");
 
-    let repo_path = td.path().join("repo");
-    GitService::new()
-        .initialize_repo_with_main_branch(&repo_path)
-        .expect("init repo");
+    let repo_path = init_repo(td.path(), "repo");
 
     let main_oid = git_rev_parse(&repo_path, "refs/heads/main");
 
@@ -76,15 +97,10 @@ async fn ensure_workspace_creates_missing_attempt_branch_from_target_branch() {
         "attempt branch should not exist before ensure"
     );
 
-    let repo_model = Repo {
-        id: Uuid::new_v4(),
-        path: repo_path.clone(),
-        name: "repo".to_string(),
-        display_name: "repo".to_string(),
-        created_at: Utc::now(),
-        updated_at: Utc::now(),
-    };
-    let inputs = vec![RepoWorkspaceInput::new(repo_model, "main".to_string())];
+    let inputs = vec![RepoWorkspaceInput::new(
+        repo_model(&repo_path, "repo"),
+        "main".to_string(),
+    )];
 
     let workspace_dir = td.path().join("workspace");
     WorkspaceManager::ensure_workspace_exists(&workspace_dir, &inputs, attempt_branch)
@@ -112,10 +128,7 @@ async fn ensure_workspace_creates_missing_attempt_branch_from_target_branch() {
 async fn ensure_worktree_is_idempotent_when_already_set_up() {
     let td = TempDir::new().expect("tempdir");
 
-    let repo_path = td.path().join("repo");
-    GitService::new()
-        .initialize_repo_with_main_branch(&repo_path)
-        .expect("init repo");
+    let repo_path = init_repo(td.path(), "repo");
 
     let branch = "vk/test-idempotent";
     let worktree_path = td.path().join("workspace").join("repo");
@@ -133,3 +146,157 @@ async fn ensure_worktree_is_idempotent_when_already_set_up() {
         "expected worktree to remain valid"
     );
 }
+
+#[tokio::test]
+async fn reclaim_orphan_workspaces_removes_unreferenced_workspace_dirs() {
+    let td = TempDir::new().expect("tempdir");
+    let pool = Database::connect("sqlite::memory:").await.unwrap();
+    db_migration::Migrator::up(&pool, None).await.unwrap();
+
+    let repo_path = init_repo(td.path(), "repo");
+
+    let base_dir = td.path().join("worktrees");
+    let orphan_dir = base_dir.join("dead-orphaned-attempt");
+    let orphan_worktree = orphan_dir.join("repo");
+    std::fs::create_dir_all(&orphan_dir).expect("create orphan dir");
+    WorktreeManager::create_worktree(
+        &repo_path,
+        "vk/orphaned-attempt",
+        &orphan_worktree,
+        "main",
+        true,
+    )
+    .await
+    .expect("create worktree");
+    std::fs::write(orphan_worktree.join("scratch.txt"), "leftover").unwrap();
+    // A marked directory is reclaimed whatever its name.
+    let marked_dir = base_dir.join("renamed by hand");
+    std::fs::create_dir_all(&marked_dir).unwrap();
+    std::fs::write(marked_dir.join(WORKSPACE_MARKER_FILE), "").unwrap();
+    // Directories the app did not create must never be deleted.
+    let foreign_dirs = [base_dir.join("my-project"), base_dir.join("deadbeef-notes")];
+    for dir in &foreign_dirs {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join("keep.txt"), "mine").unwrap();
+    }
+
+    // A fresh directory looks like an attempt that is still starting and must survive.
+    let summary =
+        WorkspaceManager::reclaim_orphan_workspaces_in(&pool, &base_dir, Duration::from_secs(3600))
+            .await;
+    assert_eq!(summary.removed, 0);
+    assert!(orphan_dir.exists());
+
+    let summary =
+        WorkspaceManager::reclaim_orphan_workspaces_in(&pool, &base_dir, Duration::ZERO).await;
+    assert_eq!(summary.removed, 2);
+    assert!(summary.reclaimed_bytes > 0);
+    assert!(
+        !orphan_dir.exists(),
+        "orphaned workspace dir should be removed"
+    );
+    assert!(!marked_dir.exists());
+    for dir in &foreign_dirs {
+        assert!(
+            dir.join("keep.txt").exists(),
+            "{} is not a workspace and must survive",
+            dir.display()
+        );
+    }
+}
+
+#[tokio::test]
+async fn create_workspace_sets_up_every_repo_and_names_the_failing_one() {
+    let td = TempDir::new().expect("tempdir");
+    let frontend_path = init_repo(td.path(), "frontend");
+    let backend_path = init_repo(td.path(), "backend");
+
+    let inputs = vec![
+        RepoWorkspaceInput::new(repo_model(&frontend_path, "frontend"), "main".to_string()),
+        RepoWorkspaceInput::new(repo_model(&backend_path, "backend"), "main".to_string()),
+    ];
+    let workspace_dir = td.path().join("workspace");
+    let container = WorkspaceManager::create_workspace(&workspace_dir, &inputs, "vk/multi")
+        .await
+        .expect("create workspace");
+    let names: Vec<&str> = container
+        .worktrees
+        .iter()
+        .map(|worktree| worktree.repo_name.as_str())
+        .collect();
+    assert_eq!(names, vec!["frontend", "backend"]);
+    for worktree in &container.worktrees {
+        assert!(worktree.worktree_path.join(".git").is_file());
+        assert_eq!(
+            git_try(
+                &worktree.worktree_path,
+                ["rev-parse", "--abbrev-ref", "HEAD"]
+            )
+            .unwrap(),
+            "vk/multi"
+        );
+    }
+
+    let broken = vec![
+        RepoWorkspaceInput::new(repo_model(&frontend_path, "frontend"), "main".to_string()),
+        RepoWorkspaceInput::new(
+            repo_model(&backend_path, "backend"),
+            "no-such-branch".to_string(),
+        ),
+    ];
+    let failed_dir = td.path().join("workspace-broken");
+    let err = WorkspaceManager::create_workspace(&failed_dir, &broken, "vk/broken")
+        .await
+        .expect_err("missing target branch should fail");
+    let message = err.to_string();
+    assert!(message.contains("repo 'backend'"), "{message}");
+    assert!(!message.contains("repo 'frontend'"), "{message}");
+    assert!(
+        !failed_dir.join("frontend").exists(),
+        "successful worktrees should be rolled back"
+    );
+}
+
+#[tokio::test]
+async fn create_workspace_materializes_only_sparse_checkout_paths() {
+    let td = TempDir::new().expect("tempdir");
+    let repo_path = init_repo(td.path(), "monorepo");
+    git_config_user(&repo_path, "Test User", "test@example.com");
+    for file in ["packages/web/index.ts", "packages/api/main.rs", "README.md"] {
+        let path = repo_path.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).expect("create parent dir");
+        std::fs::write(&path, file).expect("write file");
+    }
+    git_commit_all(&repo_path, "Add packages");
+
+    let inputs = vec![
+        RepoWorkspaceInput::new(repo_model(&repo_path, "monorepo"), "main".to_string())
+            .with_sparse_paths(vec!["/packages/web/".to_string()]),
+    ];
+    let workspace_dir = td.path().join("workspace");
+    let container = WorkspaceManager::create_workspace(&workspace_dir, &inputs, "vk/sparse")
+        .await
+        .expect("create workspace");
+
+    let worktree_path = &container.worktrees[0].worktree_path;
+    assert!(worktree_path.join("packages/web/index.ts").is_file());
+    assert!(!worktree_path.join("packages/api").exists());
+    assert!(!worktree_path.join("README.md").exists());
+    assert!(
+        repo_path.join("packages/api/main.rs").is_file(),
+        "source checkout should stay complete"
+    );
+    // Sparse-checkout is enabled in the worktree's own config, never in the shared one.
+    assert_eq!(
+        git_try(
+            worktree_path,
+            ["config", "--worktree", "--get", "core.sparseCheckout"]
+        )
+        .unwrap(),
+        "true"
+    );
+    assert!(
+        git_try(&repo_path, ["config", "--get", "core.sparseCheckout"]).is_err(),
+        "source repo must not have sparse-checkout enabled"
+    );
+}
@@ -428,7 +428,7 @@ async fn dispatch_task(
 ) -> Result<DispatchOutcome> {
     let executor_profile_id = {
         let config = deployment.config().read().await;
-        config.executor_profile.clone()
+        config.executor_profile_for_project(project_id)
     };
 
     let current_retry_count = task
@@ -505,7 +505,9 @@ async fn dispatch_task(
             executor_profile_id,
             repos,
             prompt_override: Some(prompt),
+            reasoning_effort: None,
             agent_working_dir: project.default_agent_working_dir.clone(),
+            labels: Vec::new(),
         },
     )
     .await
@@ -644,6 +646,7 @@ async fn resolve_workspace_repos(
             create: CreateWorkspaceRepo {
                 repo_id: repo.id,
                 target_branch,
+                sparse_paths: Vec::new(),
             },
             display_name: repo.display_name,
         });
@@ -57,6 +57,8 @@ fn generate_types_content() -> String {
         db::models::task::TaskLineageSummary::decl(),
         db::models::task::CreateTask::decl(),
         db::models::task::UpdateTask::decl(),
+        db::models::task_schedule::TaskSchedule::decl(),
+        db::models::task_schedule::TaskScheduleRepo::decl(),
         db::models::milestone::Milestone::decl(),
         db::models::milestone_plan_application::MilestonePlanApplicationSummary::decl(),
         db::models::milestone::CreateMilestone::decl(),
@@ -104,6 +106,7 @@ fn generate_types_content() -> String {
         db::models::session::Session::decl(),
         db::models::execution_process::ExecutionProcessPublic::decl(),
         db::models::execution_process::ExecutionProcessStatus::decl(),
+        db::models::execution_process::ExecutionProcessFailureKind::decl(),
         db::models::execution_process::ExecutionProcessRunReason::decl(),
         db::models::execution_process_repo_state::ExecutionProcessRepoState::decl(),
         db::models::merge::Merge::decl(),
@@ -122,8 +125,19 @@ fn generate_types_content() -> String {
         server::routes::repo::InitRepoRequest::decl(),
         server::routes::tags::TagSearchParams::decl(),
         server::routes::config::UserSystemInfo::decl(),
+        execution::container::ExecutionCapacity::decl(),
+        execution::container::RepoStartupStatus::decl(),
+        execution::container::RepoStartupProgress::decl(),
+        execution::container::WorkspaceStartupProgress::decl(),
         server::routes::config::Environment::decl(),
+        server::routes::config::ExecutorAvailability::decl(),
         server::routes::config::ConfigStatusResponse::decl(),
+        server::routes::config::ConfigExportQuery::decl(),
+        server::routes::config::ConfigExportResponse::decl(),
+        server::routes::config::ConfigImportResponse::decl(),
+        server::routes::release_notes::ReleaseNotesEntry::decl(),
+        server::routes::release_notes::ReleaseNotesResponse::decl(),
+        server::routes::sounds::CustomSound::decl(),
         server::routes::config::CheckEditorAvailabilityQuery::decl(),
         server::routes::config::CheckEditorAvailabilityResponse::decl(),
         server::routes::config::CheckAgentAvailabilityQuery::decl(),
@@ -136,12 +150,35 @@ fn generate_types_content() -> String {
         server::routes::task_attempts::ChangeTargetBranchRequest::decl(),
         server::routes::task_attempts::ChangeTargetBranchResponse::decl(),
         server::routes::task_attempts::MergeTaskAttemptRequest::decl(),
+        server::routes::task_attempts::ApplyTaskAttemptRequest::decl(),
+        server::routes::task_attempts::ApplyTaskAttemptResponse::decl(),
+        server::routes::task_attempts::ApplyTaskAttemptError::decl(),
         server::routes::task_attempts::PushTaskAttemptRequest::decl(),
         server::routes::task_attempts::RenameBranchRequest::decl(),
         server::routes::task_attempts::RenameBranchResponse::decl(),
         server::routes::task_attempts::AttemptState::decl(),
         server::routes::task_attempts::TaskAttemptStatusResponse::decl(),
+        server::routes::task_attempts::AttemptRepoGitStatus::decl(),
+        server::routes::task_attempts::AttemptGitStatusResponse::decl(),
+        server::routes::task_attempts::AttemptComparisonFile::decl(),
+        server::routes::task_attempts::CompareAttemptsResponse::decl(),
+        server::routes::task_attempts::AttemptContentMatch::decl(),
+        server::routes::task_attempts::AttemptContentSearchResponse::decl(),
+        server::routes::task_attempts::RevertAttemptFileRequest::decl(),
+        server::routes::task_attempts::RevertAttemptFileResponse::decl(),
+        server::routes::task_attempts::AttemptBlameLine::decl(),
+        server::routes::task_attempts::AttemptBlameResponse::decl(),
+        server::routes::task_attempts::ApplyAttemptPatchRequest::decl(),
+        server::routes::task_attempts::AttemptPatchConflict::decl(),
+        server::routes::task_attempts::ApplyAttemptPatchResponse::decl(),
+        server::routes::task_attempts::ApprovalDecision::decl(),
+        server::routes::task_attempts::RespondAllApprovalsRequest::decl(),
+        server::routes::task_attempts::ApprovalBatchResult::decl(),
+        server::routes::task_attempts::RespondAllApprovalsResponse::decl(),
         server::routes::tasks::CreateAndStartTaskRequest::decl(),
+        server::routes::tasks::MoveTaskRequest::decl(),
+        server::routes::tasks::UpdateTaskError::decl(),
+        server::routes::tasks::UpsertTaskScheduleRequest::decl(),
         server::routes::archived_kanbans::ArchiveProjectKanbanRequest::decl(),
         server::routes::archived_kanbans::ArchiveProjectKanbanResponse::decl(),
         server::routes::archived_kanbans::GetArchivedKanbanResponse::decl(),
@@ -153,12 +190,19 @@ fn generate_types_content() -> String {
         server::routes::execution_processes::IndexedLogEntry::decl(),
         server::routes::execution_processes::LogHistoryPage::decl(),
         server::routes::execution_processes::LogStreamEvent::decl(),
+        server::routes::execution_processes::RenormalizeLogsResponse::decl(),
         server::routes::task_attempts::CreateTaskAttemptBody::decl(),
+        server::routes::task_attempts::UpdateTaskAttemptLabelsRequest::decl(),
         server::routes::task_attempts::TaskAttemptPromptPreset::decl(),
         server::routes::task_attempts::WorkspaceRepoInput::decl(),
+        server::routes::task_attempts::MissingTargetBranch::decl(),
+        server::routes::task_attempts::CreateTaskAttemptError::decl(),
         server::routes::task_attempts::RunAgentSetupRequest::decl(),
         server::routes::task_attempts::RunAgentSetupResponse::decl(),
         server::routes::task_attempts::RebaseTaskAttemptRequest::decl(),
+        server::routes::task_attempts::RebaseOntoBaseRequest::decl(),
+        server::routes::task_attempts::RebaseOntoBaseResponse::decl(),
+        server::routes::task_attempts::RebaseOntoBaseError::decl(),
         server::routes::task_attempts::AbortConflictsRequest::decl(),
         server::routes::task_attempts::GitOperationError::decl(),
         server::routes::task_attempts::PushError::decl(),
@@ -171,6 +215,8 @@ fn generate_types_content() -> String {
         config::ProjectConfig::decl(),
         config::Config::decl(),
         config::NotificationConfig::decl(),
+        config::NotificationEventSounds::decl(),
+        config::NotificationSound::decl(),
         config::ThemeMode::decl(),
         config::EditorConfig::decl(),
         config::EditorType::decl(),
@@ -181,11 +227,17 @@ fn generate_types_content() -> String {
         config::ShowcaseState::decl(),
         config::DiffPreviewGuardPreset::decl(),
         config::AccessControlMode::decl(),
+        config::ExecutionCapacityPolicy::decl(),
+        config::AccessScope::decl(),
+        config::ScopedAccessToken::decl(),
         config::AccessControlConfig::decl(),
+        config::ProxyConfig::decl(),
         repos::git::GitBranch::decl(),
         execution::queued_message::QueuedMessage::decl(),
         execution::queued_message::QueueStatus::decl(),
         repos::git::ConflictOp::decl(),
+        repos::git::WorktreeFileStatus::decl(),
+        repos::git::WorktreeStatusEntry::decl(),
         executors_protocol::actions::ExecutorAction::decl(),
         executors::mcp_config::McpConfig::decl(),
         executors_protocol::actions::ExecutorActionType::decl(),
@@ -1,6 +1,8 @@
+use std::sync::Arc;
+
 use app_runtime::Deployment;
 use rmcp::{ServiceExt, transport::stdio};
-use server::{DeploymentImpl, mcp::task_server::TaskServer};
+use server::{DeploymentImpl, mcp::task_server::TaskServer, turn_summary::LlmTurnSummarizer};
 use tracing_subscriber::{EnvFilter, prelude::*};
 
 fn main() -> anyhow::Result<()> {
@@ -21,6 +23,11 @@ fn main() -> anyhow::Result<()> {
             tracing::debug!("[MCP] Starting MCP task server version {version}...");
 
             let deployment = DeploymentImpl::new().await?;
+            deployment
+                .container()
+                .set_turn_summarizer(Arc::new(LlmTurnSummarizer::new(
+                    deployment.config().clone(),
+                )));
 
             let service = TaskServer::new(deployment)
                 .serve(stdio())
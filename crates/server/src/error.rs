@@ -11,7 +11,7 @@ use db::{
     models::{
         execution_process::ExecutionProcessError, project::ProjectError,
         project_repo::ProjectRepoError, repo::RepoError, scratch::ScratchError,
-        session::SessionError, workspace::WorkspaceError,
+        session::SessionError, tag::TagError, workspace::WorkspaceError,
     },
 };
 use execution::{container::ContainerError, image::ImageError};
@@ -38,6 +38,8 @@ pub enum ApiError {
     #[error(transparent)]
     ScratchError(#[from] ScratchError),
     #[error(transparent)]
+    Tag(#[from] TagError),
+    #[error(transparent)]
     ExecutionProcess(#[from] ExecutionProcessError),
     #[error(transparent)]
     GitService(#[from] GitServiceError),
@@ -109,8 +111,15 @@ impl IntoResponse for ApiError {
             ApiError::ScratchError(err) => match err {
                 ScratchError::TypeMismatch { .. } => (StatusCode::BAD_REQUEST, "ScratchError"),
                 ScratchError::Serde(_) => (StatusCode::BAD_REQUEST, "ScratchError"),
+                ScratchError::TooLarge { .. } => (StatusCode::PAYLOAD_TOO_LARGE, "ScratchTooLarge"),
                 _ => (StatusCode::INTERNAL_SERVER_ERROR, "ScratchError"),
             },
+            ApiError::Tag(err) => match err {
+                TagError::InvalidName(_) => (StatusCode::BAD_REQUEST, "TagError"),
+                TagError::DuplicateName(_) => (StatusCode::CONFLICT, "TagError"),
+                TagError::Database(DbErr::RecordNotFound(_)) => (StatusCode::NOT_FOUND, "TagError"),
+                TagError::Database(_) => (StatusCode::INTERNAL_SERVER_ERROR, "TagError"),
+            },
             ApiError::ExecutionProcess(err) => match err {
                 ExecutionProcessError::ExecutionProcessNotFound => {
                     (StatusCode::NOT_FOUND, "ExecutionProcessError")
@@ -134,6 +143,9 @@ impl IntoResponse for ApiError {
                 _ => (StatusCode::INTERNAL_SERVER_ERROR, "GitServiceError"),
             },
             ApiError::Deployment(_) => (StatusCode::INTERNAL_SERVER_ERROR, "DeploymentError"),
+            ApiError::Container(ContainerError::CapacityExceeded { .. }) => {
+                (StatusCode::TOO_MANY_REQUESTS, "CapacityExceeded")
+            }
             ApiError::Container(_) => (StatusCode::INTERNAL_SERVER_ERROR, "ContainerError"),
             ApiError::Executor(_) => (StatusCode::INTERNAL_SERVER_ERROR, "ExecutorError"),
             ApiError::Database(db_err) => match db_err {
@@ -148,6 +160,12 @@ impl IntoResponse for ApiError {
             ApiError::Image(img_err) => match img_err {
                 ImageError::InvalidFormat => (StatusCode::BAD_REQUEST, "InvalidImageFormat"),
                 ImageError::TooLarge(_, _) => (StatusCode::PAYLOAD_TOO_LARGE, "ImageTooLarge"),
+                ImageError::ContentMismatch(_) => {
+                    (StatusCode::UNSUPPORTED_MEDIA_TYPE, "ImageContentMismatch")
+                }
+                ImageError::DimensionsTooLarge { .. } => {
+                    (StatusCode::PAYLOAD_TOO_LARGE, "ImageDimensionsTooLarge")
+                }
                 ImageError::NotFound => (StatusCode::NOT_FOUND, "ImageNotFound"),
                 _ => (StatusCode::INTERNAL_SERVER_ERROR, "ImageError"),
             },
@@ -175,11 +193,18 @@ impl IntoResponse for ApiError {
                     *size as f64 / 1_048_576.0,
                     *max as f64 / 1_048_576.0
                 ),
+                ImageError::ContentMismatch(_) => "The file contents do not match its image type. Please upload a valid PNG, JPG, GIF, WebP, or BMP image.".to_string(),
+                ImageError::DimensionsTooLarge { width, height, max } => format!(
+                    "This image is too large ({width}x{height} pixels). Maximum width and height is {max} pixels."
+                ),
                 ImageError::NotFound => "Image not found.".to_string(),
                 _ => {
                     "Failed to process image. Please try again.".to_string()
                 }
             },
+            ApiError::ScratchError(ScratchError::TooLarge { size, max }) => format!(
+                "Scratch content is too large ({size} bytes). Maximum size is {max} bytes."
+            ),
             ApiError::GitService(git_err) => match git_err {
                 repos::git::GitServiceError::MergeConflicts(msg) => msg.clone(),
                 repos::git::GitServiceError::RebaseInProgress => {
@@ -187,6 +212,10 @@ impl IntoResponse for ApiError {
                 }
                 _ => format!("{}: {}", error_type, self),
             },
+            ApiError::Tag(err @ (TagError::InvalidName(_) | TagError::DuplicateName(_))) => {
+                err.to_string()
+            }
+            ApiError::Container(err @ ContainerError::CapacityExceeded { .. }) => err.to_string(),
             ApiError::Multipart(_) => "Failed to upload file. Please ensure the file is valid and try again.".to_string(),
             ApiError::Unauthorized => "Unauthorized. Please sign in again.".to_string(),
             ApiError::NotFound(msg) => msg.clone(),
@@ -241,6 +270,12 @@ impl From<ProjectServiceError> for ApiError {
             ProjectServiceError::InvalidDevScriptWorkingDir(msg) => ApiError::BadRequest(msg),
             ProjectServiceError::InvalidSchedulerSetting(msg) => ApiError::BadRequest(msg),
             ProjectServiceError::InvalidWorkspaceLifecycleHook(msg) => ApiError::BadRequest(msg),
+            err @ ProjectServiceError::InvalidRepositoryPaths(_) => {
+                ApiError::BadRequest(err.to_string())
+            }
+            err @ ProjectServiceError::RepositoryNameConflict { .. } => {
+                ApiError::Conflict(err.to_string())
+            }
         }
     }
 }
@@ -283,6 +318,7 @@ impl From<ProjectRepoError> for ApiError {
             ProjectRepoError::AlreadyExists => {
                 ApiError::Conflict("Repository already exists in project".to_string())
             }
+            err @ ProjectRepoError::NameConflict { .. } => ApiError::Conflict(err.to_string()),
         }
     }
 }
@@ -366,5 +402,23 @@ mod tests {
                 .status(),
             StatusCode::NOT_FOUND
         );
+        assert_eq!(
+            ApiError::from(TagError::InvalidName("a b".to_string()))
+                .into_response()
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            ApiError::from(TagError::DuplicateName("dup".to_string()))
+                .into_response()
+                .status(),
+            StatusCode::CONFLICT
+        );
+        assert_eq!(
+            ApiError::from(ScratchError::TooLarge { size: 10, max: 5 })
+                .into_response()
+                .status(),
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
     }
 }
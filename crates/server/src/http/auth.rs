@@ -4,10 +4,11 @@ use app_runtime::Deployment;
 use axum::{
     Json,
     extract::{ConnectInfo, Request, State},
-    http::header,
+    http::{Method, StatusCode, header},
     middleware::Next,
     response::{IntoResponse, Response},
 };
+use config::AccessScope;
 use url::form_urlencoded;
 use utils_core::response::ApiResponse;
 
@@ -53,6 +54,11 @@ fn is_sse_events_endpoint(req: &Request) -> bool {
     req.uri().path().starts_with("/events")
 }
 
+fn is_signed_webhook_endpoint(req: &Request) -> bool {
+    // Inbound webhooks authenticate with an HMAC body signature instead of the API token.
+    req.uri().path().starts_with("/webhooks/")
+}
+
 fn peer_is_loopback(req: &Request) -> Option<bool> {
     req.extensions()
         .get::<ConnectInfo<SocketAddr>>()
@@ -89,6 +95,54 @@ fn extract_request_token(req: &Request) -> Option<String> {
     None
 }
 
+/// Route prefixes (relative to `/api`) that need `admin` for every method, reads included:
+/// operator debugging views, config and executor profiles (which can include secrets), and host
+/// filesystem browsing.
+const ADMIN_PATH_PREFIXES: &[&str] = &[
+    "/admin",
+    "/config",
+    "/profiles",
+    "/filesystem/directory",
+    "/filesystem/git-repos",
+];
+
+/// Scopes a scoped token must hold (all of them) to call `method path`. Paths are relative to
+/// `/api`; routes not listed here fall back to `admin`.
+fn required_scopes(method: &Method, path: &str) -> &'static [AccessScope] {
+    let path = path.trim_end_matches('/');
+    if ADMIN_PATH_PREFIXES.iter().any(|prefix| {
+        path.strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }) {
+        return &[AccessScope::Admin];
+    }
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return &[AccessScope::Read];
+    }
+
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    match segments.as_slice() {
+        ["task-attempts", "latest-summaries"] | ["task-attempts", _, "patch"] => {
+            &[AccessScope::Read]
+        }
+        ["tasks", "create-and-start"] => &[AccessScope::TasksWrite, AccessScope::AttemptsStart],
+        ["task-attempts"] => &[AccessScope::AttemptsStart],
+        ["tasks" | "tags" | "images", ..] => &[AccessScope::TasksWrite],
+        [
+            "task-attempts" | "sessions" | "execution-processes" | "approvals",
+            ..,
+        ] => &[AccessScope::AttemptsWrite],
+        _ => &[AccessScope::Admin],
+    }
+}
+
+fn request_peer(req: &Request) -> String {
+    req.extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|connect_info| connect_info.0.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
 pub async fn require_api_auth(
     State(deployment): State<DeploymentImpl>,
     req: Request,
@@ -99,29 +153,32 @@ pub async fn require_api_auth(
         config.access_control.clone()
     };
 
-    if matches!(access_control.mode, config::AccessControlMode::Disabled) {
+    if matches!(access_control.mode, config::AccessControlMode::Disabled)
+        || is_signed_webhook_endpoint(&req)
+    {
         return next.run(req).await;
     }
 
-    let Some(expected_token) = access_control
+    let superuser_token = access_control
         .token
         .as_deref()
         .map(str::trim)
-        .filter(|t| !t.is_empty())
-    else {
+        .filter(|t| !t.is_empty());
+    let scoped_tokens: Vec<_> = access_control
+        .tokens
+        .iter()
+        .filter(|t| !t.token.trim().is_empty())
+        .collect();
+    if superuser_token.is_none() && scoped_tokens.is_empty() {
         tracing::error!(
-            "accessControl.mode=TOKEN but accessControl.token is missing/empty; rejecting all /api/** requests (fail-closed)"
+            "accessControl.mode=TOKEN but neither accessControl.token nor accessControl.tokens is set; rejecting all /api/** requests (fail-closed)"
         );
 
         let response = ApiResponse::<()>::error(
-            "Access control misconfigured: accessControl.mode=TOKEN requires a non-empty accessControl.token.",
+            "Access control misconfigured: accessControl.mode=TOKEN requires a non-empty accessControl.token or at least one accessControl.tokens entry.",
         );
-        return (
-            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-            Json(response),
-        )
-            .into_response();
-    };
+        return (StatusCode::INTERNAL_SERVER_ERROR, Json(response)).into_response();
+    }
 
     let is_loopback = peer_is_loopback(&req).unwrap_or(false);
     if access_control.allow_localhost_bypass && is_loopback {
@@ -129,12 +186,14 @@ pub async fn require_api_auth(
     }
 
     let presented = extract_request_token(&req);
-    if presented.as_deref() != Some(expected_token) {
-        let peer = req
-            .extensions()
-            .get::<ConnectInfo<SocketAddr>>()
-            .map(|connect_info| connect_info.0.to_string())
-            .unwrap_or_else(|| "unknown".to_string());
+    if presented.is_some() && presented.as_deref() == superuser_token {
+        return next.run(req).await;
+    }
+
+    let Some(scoped) = presented
+        .as_deref()
+        .and_then(|presented| scoped_tokens.iter().find(|t| t.token.trim() == presented))
+    else {
         let reason = if presented.is_none() {
             "missing_token"
         } else {
@@ -144,7 +203,7 @@ pub async fn require_api_auth(
         tracing::warn!(
             path = %req.uri().path(),
             method = %req.method(),
-            peer = %peer,
+            peer = %request_peer(&req),
             reason,
             "Unauthorized API request"
         );
@@ -152,7 +211,28 @@ pub async fn require_api_auth(
         // Ensure all unauthorized requests return the standard ApiResponse
         // error envelope with a 401 status.
         let response = ApiResponse::<()>::error("Unauthorized");
-        return (axum::http::StatusCode::UNAUTHORIZED, Json(response)).into_response();
+        return (StatusCode::UNAUTHORIZED, Json(response)).into_response();
+    };
+
+    if let Some(missing) = required_scopes(req.method(), req.uri().path())
+        .iter()
+        .find(|scope| !scoped.grants(**scope))
+    {
+        tracing::warn!(
+            path = %req.uri().path(),
+            method = %req.method(),
+            peer = %request_peer(&req),
+            token = %scoped.name,
+            scope = missing.as_str(),
+            "Forbidden API request: token lacks required scope"
+        );
+
+        let response = ApiResponse::<()>::error(&format!(
+            "Forbidden: token '{}' lacks the '{}' scope",
+            scoped.name,
+            missing.as_str()
+        ));
+        return (StatusCode::FORBIDDEN, Json(response)).into_response();
     }
 
     next.run(req).await
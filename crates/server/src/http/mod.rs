@@ -1,14 +1,30 @@
 use axum::{
     Router,
     http::StatusCode,
-    middleware::from_fn_with_state,
+    middleware::{from_fn, from_fn_with_state},
     routing::{any, get},
 };
+use tower_http::compression::{
+    CompressionLayer,
+    predicate::{NotForContentType, Predicate, SizeAbove},
+};
 
 use crate::{DeploymentImpl, routes};
 
 mod auth;
 mod frontend;
+pub(crate) mod request_id;
+
+/// gzip/br for API responses. SSE streams are left uncompressed so each event reaches the client
+/// as soon as it is sent instead of waiting in the encoder's buffer.
+fn compression_layer() -> CompressionLayer<impl Predicate> {
+    CompressionLayer::new().compress_when(
+        SizeAbove::new(256)
+            .and(NotForContentType::GRPC)
+            .and(NotForContentType::IMAGES)
+            .and(NotForContentType::const_new("text/event-stream")),
+    )
+}
 
 pub fn router(deployment: DeploymentImpl) -> Router {
     let api_routes = Router::new()
@@ -24,22 +40,28 @@ pub fn router(deployment: DeploymentImpl) -> Router {
         .merge(routes::filesystem::router())
         .merge(routes::repo::router())
         .merge(routes::events::router(&deployment))
+        .merge(routes::admin::router())
         .merge(routes::approvals::router())
         .merge(routes::scratch::router(&deployment))
         .merge(routes::sessions::router(&deployment))
         .merge(routes::translation::router())
+        .merge(routes::release_notes::router())
+        .merge(routes::webhooks::router())
+        .merge(routes::sounds::router())
         .nest("/images", routes::images::routes())
         .route("/{*path}", any(|| async { StatusCode::NOT_FOUND }))
         .layer(from_fn_with_state(
             deployment.clone(),
             auth::require_api_auth,
-        ));
+        ))
+        .layer(compression_layer());
 
     Router::new()
         .route("/health", get(routes::health::health_check))
         .nest("/api", api_routes)
         .route("/", get(frontend::serve_frontend_root))
         .route("/{*path}", get(frontend::serve_frontend))
+        .layer(from_fn(request_id::propagate_request_id))
         .with_state(deployment)
 }
 
@@ -56,7 +78,7 @@ mod tests {
         extract::ConnectInfo,
         http::{Request, StatusCode, header},
     };
-    use config::AccessControlMode;
+    use config::{AccessControlMode, AccessScope, ProjectConfig, ScopedAccessToken};
     use test_support::{TempRoot, TestDb, TestEnv, TestEnvGuard};
     use tower::ServiceExt;
     use uuid::Uuid;
@@ -81,6 +103,20 @@ mod tests {
         config.access_control.allow_localhost_bypass = allow_localhost_bypass;
     }
 
+    async fn add_scoped_token(
+        deployment: &DeploymentImpl,
+        name: &str,
+        token: &str,
+        scopes: &[AccessScope],
+    ) {
+        let mut config = deployment.config().write().await;
+        config.access_control.tokens.push(ScopedAccessToken {
+            name: name.to_string(),
+            token: token.to_string(),
+            scopes: scopes.to_vec(),
+        });
+    }
+
     async fn set_misconfigured_token_boundary(deployment: &DeploymentImpl) {
         let mut config = deployment.config().write().await;
         config.access_control.mode = AccessControlMode::Token;
@@ -207,6 +243,138 @@ mod tests {
         assert!(token_value.unwrap().is_null());
     }
 
+    #[tokio::test]
+    async fn tasks_write_token_can_create_task_but_not_start_attempt() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        set_token_boundary(&deployment, "sekrit", false).await;
+        add_scoped_token(
+            &deployment,
+            "planner",
+            "planner-token",
+            &[AccessScope::TasksWrite],
+        )
+        .await;
+
+        let project_id = Uuid::new_v4();
+        deployment.config().write().await.projects.push(
+            serde_json::from_value::<ProjectConfig>(
+                serde_json::json!({ "id": project_id, "name": "Scoped" }),
+            )
+            .unwrap(),
+        );
+
+        let app = super::router(deployment);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/tasks")
+                    .header(header::AUTHORIZATION, "Bearer planner-token")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "project_id": project_id, "title": "Scoped task" })
+                            .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/task-attempts")
+                    .header(header::AUTHORIZATION, "Bearer planner-token")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from("{}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json.get("success").and_then(|v| v.as_bool()), Some(false));
+        assert!(
+            json.get("message")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .contains("attempts:start")
+        );
+
+        // The single legacy token keeps full access.
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/task-attempts")
+                    .header(header::AUTHORIZATION, "Bearer sekrit")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from("{}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_ne!(response.status(), StatusCode::FORBIDDEN);
+        assert_ne!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn scoped_tokens_alone_satisfy_token_mode() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        set_misconfigured_token_boundary(&deployment).await;
+        add_scoped_token(&deployment, "viewer", "viewer-token", &[AccessScope::Read]).await;
+
+        let app = super::router(deployment);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/info")
+                    .header(header::AUTHORIZATION, "Bearer viewer-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn read_token_is_forbidden_from_admin_reads() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        set_token_boundary(&deployment, "sekrit", false).await;
+        add_scoped_token(&deployment, "viewer", "viewer-token", &[AccessScope::Read]).await;
+
+        let app = super::router(deployment);
+
+        for uri in [
+            "/api/filesystem/directory?path=/",
+            "/api/filesystem/git-repos",
+            "/api/profiles",
+            "/api/config/export",
+        ] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .uri(uri)
+                        .header(header::AUTHORIZATION, "Bearer viewer-token")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN, "{uri}");
+        }
+    }
+
     #[tokio::test]
     async fn api_info_allows_localhost_bypass_when_enabled() {
         let (_env_guard, deployment) = setup_deployment().await;
@@ -267,6 +435,46 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn project_list_returns_not_modified_for_matching_etag() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        let app = super::router(deployment);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/projects")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let etag = response
+            .headers()
+            .get(header::ETAG)
+            .cloned()
+            .expect("etag header");
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/projects")
+                    .header(header::IF_NONE_MATCH, etag.clone())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers().get(header::ETAG), Some(&etag));
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+    }
+
     #[tokio::test]
     async fn removed_pr_endpoints_return_not_found() {
         let (_env_guard, deployment) = setup_deployment().await;
@@ -490,6 +698,25 @@ mod tests {
         assert!(content_type.contains("text/event-stream"));
     }
 
+    #[tokio::test]
+    async fn events_are_not_compressed() {
+        let (_env_guard, deployment) = setup_deployment().await;
+
+        let response = super::router(deployment)
+            .oneshot(
+                Request::builder()
+                    .uri("/api/events")
+                    .header(header::ACCEPT_ENCODING, "gzip, br")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+    }
+
     #[tokio::test]
     async fn events_fail_closed_when_token_mode_is_misconfigured() {
         let (_env_guard, deployment) = setup_deployment().await;
@@ -820,4 +1047,51 @@ exit 1
         assert!(message.contains("Expected protocol fingerprint"));
         assert!(message.contains("Base command:"));
     }
+
+    #[tokio::test]
+    async fn request_id_round_trips_into_error_envelope() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        let app = super::router(deployment);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/images/{}/file", Uuid::new_v4()))
+                    .header("x-request-id", "trace-me-123")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response
+                .headers()
+                .get("x-request-id")
+                .and_then(|v| v.to_str().ok()),
+            Some("trace-me-123")
+        );
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], false);
+        assert_eq!(json["request_id"], "trace-me-123");
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let generated = response
+            .headers()
+            .get("x-request-id")
+            .and_then(|v| v.to_str().ok())
+            .expect("a request id is generated when the client sends none");
+        assert!(Uuid::parse_str(generated).is_ok());
+    }
 }
@@ -0,0 +1,90 @@
+use axum::{
+    body::{Body, HttpBody, to_bytes},
+    extract::Request,
+    http::{HeaderName, HeaderValue, header},
+    middleware::Next,
+    response::Response,
+};
+use serde_json::Value;
+use tracing::Instrument;
+use uuid::Uuid;
+
+pub(crate) static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied request id that is propagated; longer ones are replaced.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Error bodies larger than this are passed through without a `request_id` field.
+const MAX_ERROR_BODY_BYTES: usize = 64 * 1024;
+
+fn incoming_request_id(req: &Request) -> Option<String> {
+    let value = req.headers().get(&REQUEST_ID_HEADER)?.to_str().ok()?.trim();
+    let valid = !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LEN
+        && value.bytes().all(|b| b.is_ascii_graphic());
+    valid.then(|| value.to_string())
+}
+
+/// Tags every request with an `x-request-id` (the caller's, or a fresh UUID), records it on the
+/// request's tracing span, echoes it in the response header, and adds it as `request_id` to JSON
+/// error envelopes so a client-side failure can be matched to server logs.
+pub async fn propagate_request_id(mut req: Request, next: Next) -> Response {
+    let request_id = incoming_request_id(&req).unwrap_or_else(|| Uuid::new_v4().to_string());
+    let header_value =
+        HeaderValue::from_str(&request_id).expect("request ids are visible ASCII by construction");
+    req.headers_mut()
+        .insert(REQUEST_ID_HEADER.clone(), header_value.clone());
+
+    let span = tracing::info_span!(
+        "http_request",
+        request_id = %request_id,
+        method = %req.method(),
+        path = %req.uri().path(),
+    );
+    let mut response = next.run(req).instrument(span).await;
+
+    if response.status().is_client_error() || response.status().is_server_error() {
+        response = attach_request_id_to_error_body(response, &request_id).await;
+    }
+    response
+        .headers_mut()
+        .insert(REQUEST_ID_HEADER.clone(), header_value);
+    response
+}
+
+async fn attach_request_id_to_error_body(response: Response, request_id: &str) -> Response {
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    let small = response
+        .body()
+        .size_hint()
+        .upper()
+        .is_some_and(|len| len <= MAX_ERROR_BODY_BYTES as u64);
+    if !is_json || !small {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_ERROR_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            tracing::warn!(error = %err, "Failed to buffer error body for request id");
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+    let Ok(Value::Object(mut envelope)) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    envelope.insert(
+        "request_id".to_string(),
+        Value::String(request_id.to_string()),
+    );
+    let Ok(body) = serde_json::to_vec(&envelope) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(body))
+}
@@ -7,8 +7,13 @@ pub mod mcp;
 pub mod middleware;
 pub mod milestone_dispatch;
 pub mod milestone_planning;
+pub mod proxy;
 pub mod routes;
 pub mod task_runtime;
+pub mod task_scheduler;
+#[cfg(test)]
+pub(crate) mod test_fixtures;
+pub mod turn_summary;
 
 // #[cfg(feature = "cloud")]
 // type DeploymentImpl = vibe_kanban_cloud::deployment::CloudDeployment;
@@ -1,11 +1,14 @@
-use std::future::{Future, IntoFuture};
+use std::{
+    future::{Future, IntoFuture},
+    sync::Arc,
+};
 
 use anyhow::{self, Error as AnyhowError};
 use app_runtime::{Deployment, DeploymentError};
 use chrono::Utc;
 use db::DbErr;
 use execution::container::ContainerService;
-use server::{DeploymentImpl, http};
+use server::{DeploymentImpl, http, turn_summary::LlmTurnSummarizer};
 use strip_ansi_escapes::strip;
 use thiserror::Error;
 use tokio::sync::watch;
@@ -13,13 +16,22 @@ use tracing_subscriber::{EnvFilter, prelude::*};
 use utils_assets::asset_dir;
 use utils_core::{browser::open_browser, port_file::write_port_file};
 
-const GRACEFUL_SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
-const CLEANUP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15);
+const DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+const DEFAULT_CLEANUP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15);
+const SHUTDOWN_TIMEOUT_ENV: &str = "VK_SHUTDOWN_TIMEOUT_SECS";
+const CLEANUP_TIMEOUT_ENV: &str = "VK_CLEANUP_TIMEOUT_SECS";
 const IDEMPOTENCY_PRUNE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
 const DEFAULT_IDEMPOTENCY_IN_PROGRESS_TTL_SECS: i64 = 60 * 60;
 const DEFAULT_IDEMPOTENCY_COMPLETED_TTL_SECS: i64 = 60 * 60 * 24 * 7;
 const IDEMPOTENCY_IN_PROGRESS_TTL_ENV: &str = "VK_IDEMPOTENCY_IN_PROGRESS_TTL_SECS";
 const IDEMPOTENCY_COMPLETED_TTL_ENV: &str = "VK_IDEMPOTENCY_COMPLETED_TTL_SECS";
+const SCRATCH_PRUNE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+const DEFAULT_SCRATCH_TTL_SECS: i64 = 60 * 60 * 24 * 30;
+const SCRATCH_TTL_ENV: &str = "VK_SCRATCH_TTL_SECS";
+const EVENT_OUTBOX_PRUNE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+const DEFAULT_EVENT_OUTBOX_TTL_SECS: i64 = 60 * 60 * 24 * 7;
+const EVENT_OUTBOX_TTL_ENV: &str = "VK_EVENT_OUTBOX_TTL_SECS";
+const DELETED_TASK_PRUNE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
 const OPEN_BROWSER_STARTUP_ENV: &str = "VK_OPEN_BROWSER_STARTUP";
 
 #[derive(Debug, Error)]
@@ -134,6 +146,11 @@ async fn main() -> Result<(), VibeKanbanError> {
 
     let deployment = DeploymentImpl::new().await?;
     deployment.log_cache_budgets();
+    deployment
+        .container()
+        .set_turn_summarizer(Arc::new(LlmTurnSummarizer::new(
+            deployment.config().clone(),
+        )));
     deployment
         .container()
         .cleanup_orphan_executions()
@@ -166,7 +183,9 @@ async fn main() -> Result<(), VibeKanbanError> {
             tracing::warn!("Failed to cleanup legacy JSONL logs: {}", err);
         }
     });
+    spawn_background(server::routes::config::run_executor_self_check());
     let _auto_orchestrator_handle = server::auto_orchestrator::spawn(deployment.clone());
+    let _task_scheduler_handle = server::task_scheduler::spawn(deployment.clone());
     // Pre-warm file search cache for most active projects
     let deployment_for_cache = deployment.clone();
     tokio::spawn(async move {
@@ -240,6 +259,115 @@ async fn main() -> Result<(), VibeKanbanError> {
         }
     });
 
+    let scratch_pool = deployment.db().pool.clone();
+    let scratch_shutdown = deployment.shutdown_token();
+    spawn_background(async move {
+        let Some(ttl_secs) = read_ttl_secs(SCRATCH_TTL_ENV, DEFAULT_SCRATCH_TTL_SECS) else {
+            tracing::info!("Scratch retention job disabled");
+            return;
+        };
+        tracing::info!(ttl_secs, "Starting scratch retention job");
+
+        loop {
+            let prune_result = tokio::select! {
+                _ = scratch_shutdown.cancelled() => {
+                    tracing::info!("Stopping scratch retention job");
+                    break;
+                }
+                result = prune_scratches_once(&scratch_pool, ttl_secs) => result,
+            };
+
+            if let Err(err) = prune_result {
+                tracing::warn!(error = %err, "Failed to prune scratches");
+            }
+
+            tokio::select! {
+                _ = scratch_shutdown.cancelled() => {
+                    tracing::info!("Stopping scratch retention job");
+                    break;
+                }
+                _ = tokio::time::sleep(SCRATCH_PRUNE_INTERVAL) => {}
+            }
+        }
+    });
+
+    let outbox_pool = deployment.db().pool.clone();
+    let outbox_shutdown = deployment.shutdown_token();
+    spawn_background(async move {
+        let Some(ttl_secs) = read_ttl_secs(EVENT_OUTBOX_TTL_ENV, DEFAULT_EVENT_OUTBOX_TTL_SECS)
+        else {
+            tracing::info!("Event outbox retention job disabled");
+            return;
+        };
+        tracing::info!(ttl_secs, "Starting event outbox retention job");
+
+        loop {
+            let prune_result = tokio::select! {
+                _ = outbox_shutdown.cancelled() => {
+                    tracing::info!("Stopping event outbox retention job");
+                    break;
+                }
+                result = prune_event_outbox_once(&outbox_pool, ttl_secs) => result,
+            };
+
+            if let Err(err) = prune_result {
+                tracing::warn!(error = %err, "Failed to prune event outbox");
+            }
+
+            tokio::select! {
+                _ = outbox_shutdown.cancelled() => {
+                    tracing::info!("Stopping event outbox retention job");
+                    break;
+                }
+                _ = tokio::time::sleep(EVENT_OUTBOX_PRUNE_INTERVAL) => {}
+            }
+        }
+    });
+
+    let deleted_task_deployment = deployment.clone();
+    let deleted_task_shutdown = deployment.shutdown_token();
+    spawn_background(async move {
+        let Some(window) = server::routes::task_deletion::recovery_window() else {
+            tracing::info!("Deleted task retention job disabled");
+            return;
+        };
+        tracing::info!(
+            window_secs = window.num_seconds(),
+            "Starting deleted task retention job"
+        );
+
+        loop {
+            let prune_result = tokio::select! {
+                _ = deleted_task_shutdown.cancelled() => {
+                    tracing::info!("Stopping deleted task retention job");
+                    break;
+                }
+                result = server::routes::task_deletion::prune_deleted_tasks_once(
+                    &deleted_task_deployment,
+                    window,
+                ) => result,
+            };
+
+            match prune_result {
+                Ok(removed) if removed > 0 => {
+                    tracing::info!(removed, "Pruned deleted tasks");
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    tracing::warn!(error = %err, "Failed to prune deleted tasks");
+                }
+            }
+
+            tokio::select! {
+                _ = deleted_task_shutdown.cancelled() => {
+                    tracing::info!("Stopping deleted task retention job");
+                    break;
+                }
+                _ = tokio::time::sleep(DELETED_TASK_PRUNE_INTERVAL) => {}
+            }
+        }
+    });
+
     let app_router = http::router(deployment.clone());
 
     let port = std::env::var("BACKEND_PORT")
@@ -282,6 +410,9 @@ async fn main() -> Result<(), VibeKanbanError> {
         });
     }
 
+    let graceful_shutdown_timeout =
+        read_timeout(SHUTDOWN_TIMEOUT_ENV, DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT);
+    let cleanup_timeout = read_timeout(CLEANUP_TIMEOUT_ENV, DEFAULT_CLEANUP_TIMEOUT);
     let (shutdown_rx, force_exit_rx) = spawn_shutdown_watchers();
     let deployment_for_shutdown = deployment.clone();
     let shutdown_bridge_rx = shutdown_rx.clone();
@@ -304,10 +435,10 @@ async fn main() -> Result<(), VibeKanbanError> {
             tracing::warn!("Force shutdown requested (second signal), exiting immediately");
             std::process::exit(130);
         }
-        _ = shutdown_deadline(shutdown_rx.clone(), GRACEFUL_SHUTDOWN_TIMEOUT) => {
+        _ = shutdown_deadline(shutdown_rx.clone(), graceful_shutdown_timeout) => {
             tracing::warn!(
                 "Graceful shutdown timed out after {:?}, exiting immediately",
-                GRACEFUL_SHUTDOWN_TIMEOUT
+                graceful_shutdown_timeout
             );
             std::process::exit(130);
         }
@@ -321,8 +452,8 @@ async fn main() -> Result<(), VibeKanbanError> {
             tracing::warn!("Force shutdown requested during cleanup, exiting immediately");
             std::process::exit(130);
         }
-        _ = tokio::time::sleep(CLEANUP_TIMEOUT) => {
-            tracing::warn!("Cleanup timed out after {:?}, exiting immediately", CLEANUP_TIMEOUT);
+        _ = tokio::time::sleep(cleanup_timeout) => {
+            tracing::warn!("Cleanup timed out after {:?}, exiting immediately", cleanup_timeout);
             std::process::exit(130);
         }
     }
@@ -379,45 +510,128 @@ fn spawn_shutdown_watchers() -> (watch::Receiver<bool>, watch::Receiver<bool>) {
                     } => {},
                 }
 
-                if !shutdown_sent {
-                    shutdown_sent = true;
-                    tracing::info!(
-                        "Shutdown signal received, starting graceful shutdown (press Ctrl+C again to force)"
-                    );
-                    let _ = shutdown_tx.send(true);
-                } else {
-                    tracing::warn!("Second shutdown signal received, forcing exit");
-                    let _ = force_exit_tx.send(true);
+                if relay_shutdown_signal(&mut shutdown_sent, &shutdown_tx, &force_exit_tx) {
                     break;
                 }
             }
         }
 
-        #[cfg(not(unix))]
+        // Service managers stop console processes with CTRL_CLOSE/CTRL_SHUTDOWN rather than Ctrl+C,
+        // so treat those the same way SIGTERM is treated on unix.
+        #[cfg(windows)]
         {
-            if let Err(e) = tokio::signal::ctrl_c().await {
-                tracing::error!("Failed to install Ctrl+C handler: {e}");
-                return;
-            }
+            use tokio::signal::windows;
 
-            tracing::info!(
-                "Shutdown signal received, starting graceful shutdown (press Ctrl+C again to force)"
-            );
-            let _ = shutdown_tx.send(true);
+            let mut ctrl_c = match windows::ctrl_c() {
+                Ok(sig) => sig,
+                Err(e) => {
+                    tracing::error!("Failed to install Ctrl+C handler: {e}");
+                    return;
+                }
+            };
+
+            let mut ctrl_close = match windows::ctrl_close() {
+                Ok(sig) => Some(sig),
+                Err(e) => {
+                    tracing::error!("Failed to install CTRL_CLOSE handler: {e}");
+                    None
+                }
+            };
+
+            let mut ctrl_shutdown = match windows::ctrl_shutdown() {
+                Ok(sig) => Some(sig),
+                Err(e) => {
+                    tracing::error!("Failed to install CTRL_SHUTDOWN handler: {e}");
+                    None
+                }
+            };
+
+            loop {
+                let event = tokio::select! {
+                    _ = ctrl_c.recv() => ConsoleEvent::CtrlC,
+                    _ = async {
+                        if let Some(ctrl_close) = ctrl_close.as_mut() {
+                            ctrl_close.recv().await;
+                        } else {
+                            std::future::pending::<()>().await;
+                        }
+                    } => ConsoleEvent::Close,
+                    _ = async {
+                        if let Some(ctrl_shutdown) = ctrl_shutdown.as_mut() {
+                            ctrl_shutdown.recv().await;
+                        } else {
+                            std::future::pending::<()>().await;
+                        }
+                    } => ConsoleEvent::Shutdown,
+                };
 
-            if let Err(e) = tokio::signal::ctrl_c().await {
-                tracing::error!("Failed to install Ctrl+C handler: {e}");
-                return;
+                if handle_console_event(event, &mut shutdown_sent, &shutdown_tx, &force_exit_tx) {
+                    break;
+                }
             }
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            loop {
+                if let Err(e) = tokio::signal::ctrl_c().await {
+                    tracing::error!("Failed to install Ctrl+C handler: {e}");
+                    return;
+                }
 
-            tracing::warn!("Second shutdown signal received, forcing exit");
-            let _ = force_exit_tx.send(true);
+                if relay_shutdown_signal(&mut shutdown_sent, &shutdown_tx, &force_exit_tx) {
+                    break;
+                }
+            }
         }
     });
 
     (shutdown_rx, force_exit_rx)
 }
 
+/// Windows console control events the shutdown watcher listens for.
+#[cfg(windows)]
+#[derive(Debug, Clone, Copy)]
+enum ConsoleEvent {
+    CtrlC,
+    Close,
+    Shutdown,
+}
+
+/// Callback for a received console control event; returns true once no more events need
+/// watching. Every event counts as a shutdown signal, see [`relay_shutdown_signal`].
+#[cfg(windows)]
+fn handle_console_event(
+    event: ConsoleEvent,
+    shutdown_sent: &mut bool,
+    shutdown_tx: &watch::Sender<bool>,
+    force_exit_tx: &watch::Sender<bool>,
+) -> bool {
+    tracing::debug!(?event, "Console control event received");
+    relay_shutdown_signal(shutdown_sent, shutdown_tx, force_exit_tx)
+}
+
+/// Handles one received shutdown signal: the first requests a graceful shutdown, the second forces
+/// exit. Returns true once the force-exit flag has been set and no more signals need watching.
+fn relay_shutdown_signal(
+    shutdown_sent: &mut bool,
+    shutdown_tx: &watch::Sender<bool>,
+    force_exit_tx: &watch::Sender<bool>,
+) -> bool {
+    if !*shutdown_sent {
+        *shutdown_sent = true;
+        tracing::info!(
+            "Shutdown signal received, starting graceful shutdown (press Ctrl+C again to force)"
+        );
+        let _ = shutdown_tx.send(true);
+        false
+    } else {
+        tracing::warn!("Second shutdown signal received, forcing exit");
+        let _ = force_exit_tx.send(true);
+        true
+    }
+}
+
 fn read_ttl_secs(name: &str, default: i64) -> Option<i64> {
     let raw = match std::env::var(name) {
         Ok(value) => value,
@@ -444,6 +658,37 @@ fn read_ttl_secs(name: &str, default: i64) -> Option<i64> {
     }
 }
 
+fn read_timeout(name: &str, default: std::time::Duration) -> std::time::Duration {
+    match std::env::var(name) {
+        Ok(raw) => parse_timeout_secs(name, &raw, default),
+        Err(std::env::VarError::NotPresent) => default,
+        Err(err) => {
+            tracing::warn!(error = %err, "Failed to read {name}; using default");
+            default
+        }
+    }
+}
+
+fn parse_timeout_secs(name: &str, raw: &str, default: std::time::Duration) -> std::time::Duration {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        tracing::warn!("{name} is set but empty; using default");
+        return default;
+    }
+
+    match trimmed.parse::<u64>() {
+        Ok(0) => {
+            tracing::warn!("{name} must be greater than zero; using default");
+            default
+        }
+        Ok(value) => std::time::Duration::from_secs(value),
+        Err(err) => {
+            tracing::warn!(value = trimmed, error = %err, "Invalid {name}; using default");
+            default
+        }
+    }
+}
+
 async fn prune_idempotency_keys_once(
     db: &db::DbPool,
     in_progress_ttl_secs: Option<i64>,
@@ -474,6 +719,24 @@ async fn prune_idempotency_keys_once(
     Ok(())
 }
 
+async fn prune_scratches_once(db: &db::DbPool, ttl_secs: i64) -> Result<(), db::DbErr> {
+    let cutoff = Utc::now() - chrono::Duration::seconds(ttl_secs);
+    let removed = db::models::scratch::Scratch::prune_updated_before(db, cutoff).await?;
+    if removed > 0 {
+        tracing::info!(removed, "Pruned expired scratches");
+    }
+    Ok(())
+}
+
+async fn prune_event_outbox_once(db: &db::DbPool, ttl_secs: i64) -> Result<(), db::DbErr> {
+    let cutoff = Utc::now() - chrono::Duration::seconds(ttl_secs);
+    let removed = db::models::event_outbox::EventOutbox::prune_events_before(db, cutoff).await?;
+    if removed > 0 {
+        tracing::info!(removed, "Pruned published outbox events");
+    }
+    Ok(())
+}
+
 async fn wait_for_watch_true(mut rx: watch::Receiver<bool>) {
     loop {
         if *rx.borrow() {
@@ -493,9 +756,11 @@ async fn shutdown_deadline(rx: watch::Receiver<bool>, timeout: std::time::Durati
 
 #[cfg(test)]
 mod tests {
-    use tokio::sync::oneshot;
+    use std::time::Duration;
+
+    use tokio::sync::{oneshot, watch};
 
-    use super::spawn_background;
+    use super::{parse_timeout_secs, relay_shutdown_signal, spawn_background};
 
     #[tokio::test]
     async fn spawn_background_returns_immediately() {
@@ -509,4 +774,79 @@ mod tests {
         let _ = tx.send(());
         let _ = handle.await;
     }
+
+    #[test]
+    fn parse_timeout_secs_falls_back_to_default_on_bad_input() {
+        let default = Duration::from_secs(10);
+
+        assert_eq!(
+            parse_timeout_secs("VK_TEST_TIMEOUT", "30", default),
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            parse_timeout_secs("VK_TEST_TIMEOUT", " 45 ", default),
+            Duration::from_secs(45)
+        );
+        for raw in ["", "  ", "0", "-5", "soon", "1.5"] {
+            assert_eq!(
+                parse_timeout_secs("VK_TEST_TIMEOUT", raw, default),
+                default,
+                "raw={raw:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn relay_shutdown_signal_requests_graceful_then_forced_exit() {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let (force_exit_tx, force_exit_rx) = watch::channel(false);
+        let mut shutdown_sent = false;
+
+        assert!(!relay_shutdown_signal(
+            &mut shutdown_sent,
+            &shutdown_tx,
+            &force_exit_tx
+        ));
+        assert!(*shutdown_rx.borrow());
+        assert!(!*force_exit_rx.borrow());
+
+        assert!(relay_shutdown_signal(
+            &mut shutdown_sent,
+            &shutdown_tx,
+            &force_exit_tx
+        ));
+        assert!(*force_exit_rx.borrow());
+    }
+
+    #[cfg(windows)]
+    #[tokio::test]
+    async fn windows_console_close_and_shutdown_events_stop_the_server() {
+        use super::{ConsoleEvent, handle_console_event};
+
+        assert!(tokio::signal::windows::ctrl_close().is_ok());
+        assert!(tokio::signal::windows::ctrl_shutdown().is_ok());
+
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        let (force_exit_tx, mut force_exit_rx) = watch::channel(false);
+        let mut shutdown_sent = false;
+
+        assert!(!handle_console_event(
+            ConsoleEvent::Close,
+            &mut shutdown_sent,
+            &shutdown_tx,
+            &force_exit_tx
+        ));
+        shutdown_rx.changed().await.unwrap();
+        assert!(*shutdown_rx.borrow());
+        assert!(!*force_exit_rx.borrow());
+
+        assert!(handle_console_event(
+            ConsoleEvent::Shutdown,
+            &mut shutdown_sent,
+            &shutdown_tx,
+            &force_exit_tx
+        ));
+        force_exit_rx.changed().await.unwrap();
+        assert!(*force_exit_rx.borrow());
+    }
 }
@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::HashMap,
     str::FromStr,
     sync::{
         Arc,
@@ -68,7 +68,9 @@ use crate::{DeploymentImpl, error::ApiError, mcp::params::Parameters};
 mod errors;
 mod params;
 mod runtime;
+mod tags;
 mod tools;
+mod transitions;
 
 use errors::*;
 pub use params::*;
@@ -91,6 +93,20 @@ const MCP_TASK_POLL_INTERVAL_MS_ENV: &str = "VK_MCP_TASK_POLL_INTERVAL_MS";
 const DEFAULT_MCP_TASK_MAX_CONCURRENCY: usize = 4;
 const MCP_TASK_MAX_CONCURRENCY_ENV: &str = "VK_MCP_TASK_MAX_CONCURRENCY";
 
+const DEFAULT_TAG_EXPANSION_MAX_DEPTH: usize = 5;
+
+const LIST_TASKS_DESCRIPTION_MAX_CHARS: usize = 2_000;
+const LIST_TASKS_DESCRIPTION_BUDGET_CHARS: usize = 32_000;
+const TAG_EXPANSION_MAX_DEPTH_ENV: &str = "VK_MCP_TAG_EXPANSION_MAX_DEPTH";
+
+const DEFAULT_MCP_SERVER_NAME: &str = "vibe-kanban";
+const DEFAULT_MCP_SERVER_TITLE: &str = "Vibe Kanban MCP Server";
+const DEFAULT_MCP_SERVER_WEBSITE_URL: &str = "https://github.com/BloopAI/vibe-kanban";
+const MCP_SERVER_NAME_ENV: &str = "VK_MCP_SERVER_NAME";
+const MCP_SERVER_TITLE_ENV: &str = "VK_MCP_SERVER_TITLE";
+const MCP_SERVER_ICON_URL_ENV: &str = "VK_MCP_SERVER_ICON_URL";
+const MCP_SERVER_WEBSITE_URL_ENV: &str = "VK_MCP_SERVER_WEBSITE_URL";
+
 fn tool_output_schema<T: schemars::JsonSchema + 'static>() -> Arc<Map<String, Value>> {
     rmcp::handler::server::tool::schema_for_output::<T>().unwrap_or_else(|e| {
         panic!(
@@ -708,39 +724,26 @@ impl TaskServer {
     }
 
     async fn expand_tags(&self, text: &str) -> String {
-        let tag_pattern = match Regex::new(r"@([^\s@]+)") {
-            Ok(re) => re,
-            Err(_) => return text.to_string(),
-        };
-
-        let tag_names: Vec<String> = tag_pattern
-            .captures_iter(text)
-            .filter_map(|cap| cap.get(1).map(|m| m.as_str().to_string()))
-            .collect::<HashSet<_>>()
-            .into_iter()
-            .collect();
-        if tag_names.is_empty() {
+        if !text.contains('@') {
             return text.to_string();
         }
 
-        let tags: Vec<Tag> = match Tag::find_all(&self.deployment.db().pool).await {
-            Ok(tags) => tags,
-            Err(_) => return text.to_string(),
-        };
-        let tag_map: HashMap<&str, &str> = tags
-            .iter()
-            .map(|t| (t.tag_name.as_str(), t.content.as_str()))
-            .collect();
-
-        tag_pattern
-            .replace_all(text, |caps: &regex::Captures| {
-                let tag_name = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-                match tag_map.get(tag_name) {
-                    Some(content) => (*content).to_string(),
-                    None => caps.get(0).map(|m| m.as_str()).unwrap_or("").to_string(),
-                }
-            })
-            .into_owned()
+        match self.expand_tags_detailed(text).await {
+            Ok(expansion) => expansion.text,
+            Err(_) => text.to_string(),
+        }
+    }
+
+    async fn expand_tags_detailed(&self, text: &str) -> Result<tags::TagExpansion, DbErr> {
+        let tags: Vec<Tag> = Tag::find_all(&self.deployment.db().pool).await?;
+        let tag_map: HashMap<String, String> =
+            tags.into_iter().map(|t| (t.tag_name, t.content)).collect();
+
+        Ok(tags::expand_tag_references(
+            text,
+            &tag_map,
+            tag_expansion_max_depth(),
+        ))
     }
 
     async fn resolve_session_id(
@@ -1516,15 +1519,24 @@ impl ServerHandler for TaskServer {
                 .enable_tasks_with(TasksCapability::server_default())
                 .build(),
             server_info: Implementation {
-                name: "vibe-kanban".to_string(),
-                title: Some("Vibe Kanban MCP Server".to_string()),
+                name: branding_override(MCP_SERVER_NAME_ENV)
+                    .unwrap_or_else(|| DEFAULT_MCP_SERVER_NAME.to_string()),
+                title: Some(
+                    branding_override(MCP_SERVER_TITLE_ENV)
+                        .unwrap_or_else(|| DEFAULT_MCP_SERVER_TITLE.to_string()),
+                ),
                 version: env!("CARGO_PKG_VERSION").to_string(),
                 description: Some(
                     "Local-first Kanban MCP control plane for multi-agent orchestration."
                         .to_string(),
                 ),
-                icons: None,
-                website_url: Some("https://github.com/BloopAI/vibe-kanban".to_string()),
+                icons: branding_override(MCP_SERVER_ICON_URL_ENV)
+                    .and_then(branding_icon)
+                    .map(|icon| vec![icon]),
+                website_url: Some(
+                    branding_override(MCP_SERVER_WEBSITE_URL_ENV)
+                        .unwrap_or_else(|| DEFAULT_MCP_SERVER_WEBSITE_URL.to_string()),
+                ),
             },
             instructions: Some(instruction),
         }
@@ -13,6 +13,10 @@ pub(super) const MCP_CODE_ATTEMPT_CLAIM_REQUIRED: &str = "attempt_claim_required
 pub(super) const MCP_CODE_ATTEMPT_CLAIM_CONFLICT: &str = "attempt_claim_conflict";
 pub(super) const MCP_CODE_INVALID_CONTROL_TOKEN: &str = "invalid_control_token";
 pub(super) const MCP_CODE_PROFILE_POLICY_REJECTED: &str = "profile_policy_rejected";
+pub(super) const MCP_CODE_STALE_WRITE: &str = "stale_write";
+pub(super) const MCP_CODE_INVALID_TRANSITION: &str = "invalid_transition";
+pub(super) const MCP_CODE_DATABASE_UNREACHABLE: &str = "database_unreachable";
+pub(super) const MCP_CODE_ATTEMPTS_NOT_COMPARABLE: &str = "attempts_not_comparable";
 
 #[derive(Debug)]
 pub(super) enum ToolOrRpcError {
@@ -44,6 +44,14 @@ pub struct UpdateTaskRequest {
     pub description: Option<String>,
     #[schemars(description = "New status: 'todo', 'inprogress', 'inreview', 'done', 'cancelled'")]
     pub status: Option<String>,
+    #[schemars(
+        description = "Optional last-seen task updated_at (RFC3339, as returned by get_task). When provided, the update is rejected with code=stale_write if the task changed since."
+    )]
+    pub expected_updated_at: Option<String>,
+    #[schemars(
+        description = "Optional idempotency key for safe retries. When provided, repeated calls with the same key and same payload return the same result."
+    )]
+    pub request_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
@@ -52,11 +60,38 @@ pub struct UpdateTaskResponse {
     pub task_id: String,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TransitionTaskRequest {
+    #[schemars(description = "The ID of the task to transition (UUID string)")]
+    pub task_id: Uuid,
+    #[schemars(
+        description = "Target status: 'todo', 'inprogress', 'inreview', 'done', 'cancelled'"
+    )]
+    pub to: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct TransitionTaskResponse {
+    #[schemars(description = "The transitioned task id (UUID string)")]
+    pub task_id: String,
+    #[schemars(description = "Status before the transition")]
+    pub from: String,
+    #[schemars(description = "Status after the transition")]
+    pub to: String,
+    #[schemars(description = "False when the task was already in the target status")]
+    pub changed: bool,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DeleteTaskRequest {
     #[schemars(description = "The ID of the task to delete (UUID string)")]
     pub task_id: Uuid,
+    #[schemars(
+        description = "Optional idempotency key for safe retries. When provided, a retried delete with the same key returns the original result instead of a no-op."
+    )]
+    pub request_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
@@ -71,6 +106,12 @@ pub struct ProjectSummary {
     pub id: String,
     #[schemars(description = "The name of the project")]
     pub name: String,
+    #[schemars(
+        description = "Executor start_attempt uses when executor is omitted (project default, else the global default)"
+    )]
+    pub default_executor: String,
+    #[schemars(description = "Variant paired with default_executor, or null")]
+    pub default_variant: Option<String>,
     #[schemars(description = "When the project was created")]
     pub created_at: String,
     #[schemars(description = "When the project was last updated")]
@@ -110,6 +151,39 @@ pub struct ListReposResponse {
     pub project_id: String,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListRepoBranchesRequest {
+    #[schemars(description = "The ID of the repository to list branches for (UUID string)")]
+    pub repo_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct McpBranchSummary {
+    #[schemars(
+        description = "Branch name (remote branches are prefixed with the remote, e.g. origin/main). Use as start_attempt.repos[].target_branch."
+    )]
+    pub name: String,
+    #[schemars(description = "Whether this is the branch currently checked out in the repo")]
+    pub is_current: bool,
+    #[schemars(description = "Whether this is a remote-tracking branch")]
+    pub is_remote: bool,
+    #[schemars(description = "Whether this is the repository's default branch")]
+    pub is_default: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ListRepoBranchesResponse {
+    #[schemars(description = "Branches, current branch first then most recently committed")]
+    pub branches: Vec<McpBranchSummary>,
+    #[schemars(description = "Number of branches returned")]
+    pub count: usize,
+    #[schemars(description = "Local default branch name, if one could be determined")]
+    pub default_branch: Option<String>,
+    #[schemars(description = "The repository identifier used for the query (UUID string)")]
+    pub repo_id: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct McpExecutorSummary {
     #[schemars(description = "Stable executor identifier (use as start_attempt.executor)")]
@@ -143,6 +217,10 @@ pub struct ListTasksRequest {
     pub status: Option<String>,
     #[schemars(description = "Maximum number of tasks to return (default: 50)")]
     pub limit: Option<i32>,
+    #[schemars(
+        description = "Include each task's description (truncated to 2000 characters; omitted once the response-wide budget is spent). Default: false"
+    )]
+    pub include_description: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
@@ -151,6 +229,10 @@ pub struct TaskSummary {
     pub id: String,
     #[schemars(description = "The title of the task")]
     pub title: String,
+    #[schemars(
+        description = "Task description, present only when include_description was requested (may be truncated)"
+    )]
+    pub description: Option<String>,
     #[schemars(description = "Current status of the task")]
     pub status: String,
     #[schemars(description = "Task kind: default or milestone")]
@@ -212,6 +294,7 @@ impl TaskSummary {
         Self {
             id: task.id.to_string(),
             title: task.title,
+            description: None,
             status: task.status.to_string(),
             task_kind: task.task_kind.to_string(),
             milestone_id: task.milestone_id.map(|id| id.to_string()),
@@ -239,6 +322,41 @@ pub struct ListTasksResponse {
     pub count: usize,
     #[schemars(description = "The project identifier used for the query (UUID string)")]
     pub project_id: String,
+    #[schemars(
+        description = "Whether any description was shortened or omitted to keep the response small"
+    )]
+    pub descriptions_truncated: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetBoardRequest {
+    #[schemars(description = "The ID of the project to summarize (UUID string)")]
+    pub project_id: Uuid,
+    #[schemars(description = "Maximum number of in-progress tasks to include (default: 10)")]
+    pub in_progress_limit: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct BoardStatusBucket {
+    #[schemars(description = "Task status: todo, inprogress, inreview, done or cancelled")]
+    pub status: String,
+    #[schemars(description = "Number of tasks with this status")]
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct GetBoardResponse {
+    #[schemars(description = "The project identifier used for the query (UUID string)")]
+    pub project_id: String,
+    #[schemars(description = "Total number of tasks on the board")]
+    pub total: usize,
+    #[schemars(description = "Task counts for every status, in board column order")]
+    pub buckets: Vec<BoardStatusBucket>,
+    #[schemars(
+        description = "Most recently created in-progress tasks with their latest attempt summary"
+    )]
+    pub in_progress: Vec<TaskSummary>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -326,6 +444,25 @@ pub struct GetTaskRequest {
     pub task_id: Uuid,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PreviewTagExpansionRequest {
+    #[schemars(description = "Text containing @tag references to expand.")]
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct PreviewTagExpansionResponse {
+    #[schemars(description = "The text after @tag expansion (same logic as create_task).")]
+    pub expanded_text: String,
+    #[schemars(description = "Tag names that were found and substituted, including nested ones.")]
+    pub resolved_tags: Vec<String>,
+    #[schemars(
+        description = "Referenced tag names that do not exist; left untouched in the text."
+    )]
+    pub unresolved_tags: Vec<String>,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum McpTaskControlTransferReasonCode {
@@ -519,6 +656,10 @@ pub struct GetReviewHandoffResponse {
 pub struct ListTaskAttemptsRequest {
     #[schemars(description = "The ID of the task to list attempts for (UUID string)")]
     pub task_id: Uuid,
+    #[schemars(
+        description = "Optional label filter; only attempts carrying this label are returned"
+    )]
+    pub label: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
@@ -527,6 +668,9 @@ pub struct AttemptSummary {
     pub attempt_id: String,
     #[schemars(description = "Workspace branch name")]
     pub workspace_branch: String,
+    #[schemars(description = "Labels attached to the attempt (e.g. experiment, production)")]
+    #[serde(default)]
+    pub labels: Vec<String>,
     #[schemars(description = "When the attempt was created (RFC3339)")]
     pub created_at: String,
     #[schemars(description = "When the attempt was last updated (RFC3339)")]
@@ -551,6 +695,35 @@ pub struct ListTaskAttemptsResponse {
     pub latest_session_id: Option<String>,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListSessionsRequest {
+    #[schemars(description = "Attempt id to list sessions for (UUID string)")]
+    pub attempt_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct SessionSummary {
+    #[schemars(description = "Session id (UUID string)")]
+    pub session_id: String,
+    #[schemars(description = "Executor that ran the session, if recorded")]
+    pub executor: Option<String>,
+    #[schemars(description = "When the session was created (RFC3339)")]
+    pub created_at: String,
+    #[schemars(description = "When the session was last updated (RFC3339)")]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ListSessionsResponse {
+    #[schemars(description = "Attempt id (UUID string)")]
+    pub attempt_id: String,
+    #[schemars(description = "Sessions (newest first)")]
+    pub sessions: Vec<SessionSummary>,
+    #[schemars(description = "Number of sessions returned")]
+    pub count: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct WorkspaceRepoInput {
@@ -558,6 +731,11 @@ pub struct WorkspaceRepoInput {
     pub repo_id: Uuid,
     #[schemars(description = "Target branch name for this repo")]
     pub target_branch: String,
+    #[serde(default)]
+    #[schemars(
+        description = "Optional ref to create target_branch from when it does not exist yet (e.g. main)"
+    )]
+    pub create_from: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -566,7 +744,7 @@ pub struct StartAttemptRequest {
     #[schemars(description = "The task id to start an attempt for (UUID string)")]
     pub task_id: Uuid,
     #[schemars(
-        description = "Optional executor override name (e.g., CLAUDE_CODE). When omitted, the server default executor profile is used."
+        description = "Optional executor override name (e.g., CLAUDE_CODE). When omitted, the project default_executor_profile is used, falling back to the server default executor profile."
     )]
     pub executor: Option<String>,
     #[schemars(description = "Optional executor variant")]
@@ -581,6 +759,14 @@ pub struct StartAttemptRequest {
         description = "Optional prompt override. When provided, this prompt is used as the initial agent prompt instead of the task title/description."
     )]
     pub prompt: Option<String>,
+    #[schemars(
+        description = "Optional labels for the attempt (e.g. experiment, production). Used to filter list_task_attempts."
+    )]
+    pub labels: Option<Vec<String>>,
+    #[schemars(
+        description = "Optional reasoning effort override: 'low', 'medium', 'high', 'xhigh'. Applied by executors that support it (currently CODEX)."
+    )]
+    pub reasoning_effort: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
@@ -682,6 +868,11 @@ pub struct SendFollowUpResponse {
     pub session_id: String,
     #[schemars(description = "Execution process id started for this follow-up (UUID string)")]
     pub execution_process_id: String,
+    #[schemars(
+        description = "Number of follow-up messages still queued for this session. They run in FIFO order as the agent becomes idle."
+    )]
+    #[serde(default)]
+    pub queue_depth: usize,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -714,6 +905,43 @@ pub enum McpAttemptState {
     Failed,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAttemptStatusRequest {
+    #[schemars(description = "Attempt/workspace id (UUID string)")]
+    pub attempt_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct GetAttemptStatusResponse {
+    pub attempt_id: String,
+    pub task_id: String,
+    pub workspace_branch: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub state: McpAttemptState,
+    pub latest_session_id: Option<String>,
+    pub latest_execution_process_id: Option<String>,
+    #[schemars(description = "Last process output or state change (RFC3339)")]
+    pub last_activity_at: Option<String>,
+    pub failure_summary: Option<String>,
+    #[schemars(
+        description = "True when the running attempt is blocked on an unanswered approval request (state stays running)"
+    )]
+    #[serde(default)]
+    pub waiting_on_approval: bool,
+    #[schemars(description = "Tool name of the pending approval when waiting_on_approval is true")]
+    pub pending_approval_tool: Option<String>,
+    #[schemars(description = "True when a target branch has commits the attempt branch lacks")]
+    #[serde(default)]
+    pub base_diverged: bool,
+    #[schemars(
+        description = "True when merging the attempt into a target branch would conflict (merge-tree dry run)"
+    )]
+    #[serde(default)]
+    pub has_conflicts: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct McpIndexedLogEntry {
     #[schemars(description = "Monotonic log entry index")]
@@ -760,6 +988,9 @@ pub struct McpSessionMessageTurn {
     pub entry_index: i64,
     pub turn_id: String,
     pub prompt: Option<String>,
+    #[schemars(
+        description = "Summary stored when the turn finishes: the last assistant message, or a generated one-liner when turn_summary_llm_enabled is set (null while running)"
+    )]
     pub summary: Option<String>,
     pub created_at: String,
     pub updated_at: String,
@@ -837,6 +1068,15 @@ pub struct McpAttemptChangesSummary {
     pub total_bytes: usize,
 }
 
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct McpAttemptRepoChangesSummary {
+    #[schemars(description = "Repo directory name; matches the prefix used in `files`")]
+    pub repo_name: String,
+    pub file_count: usize,
+    pub added: usize,
+    pub deleted: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct GetAttemptChangesResponse {
     pub attempt_id: String,
@@ -847,6 +1087,9 @@ pub struct GetAttemptChangesResponse {
     pub retryable: Option<bool>,
     pub hint: Option<String>,
     pub files: Option<Vec<String>>,
+    #[schemars(description = "Per-repo breakdown of summary (multi-repo attempts)")]
+    #[serde(default)]
+    pub repos: Vec<McpAttemptRepoChangesSummary>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -856,6 +1099,45 @@ pub struct GetAttemptChangesRequest {
     pub force: Option<bool>,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CompareAttemptsRequest {
+    #[schemars(description = "Baseline attempt id (UUID string)")]
+    pub attempt_a: Uuid,
+    #[schemars(description = "Attempt to compare against attempt_a; must belong to the same task")]
+    pub attempt_b: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct McpAttemptComparisonFile {
+    #[schemars(description = "Repo directory name")]
+    pub repo_name: String,
+    #[schemars(description = "Path within the repo")]
+    pub path: String,
+    #[schemars(description = "Previous path for renames and copies")]
+    pub old_path: Option<String>,
+    #[schemars(
+        description = "Change from attempt_a to attempt_b: added|deleted|modified|renamed|copied|permission_change"
+    )]
+    pub change: String,
+    pub additions: Option<usize>,
+    pub deletions: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct CompareAttemptsResponse {
+    pub attempt_a: String,
+    pub attempt_b: String,
+    #[schemars(
+        description = "Files whose committed content differs between the two attempt branches"
+    )]
+    pub files: Vec<McpAttemptComparisonFile>,
+    #[schemars(description = "Number of differing files")]
+    pub count: usize,
+    #[schemars(description = "Repos attached to only one of the attempts (not compared)")]
+    pub skipped_repos: Vec<String>,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum McpAttemptArtifactBlockedReason {
@@ -866,6 +1148,13 @@ pub enum McpAttemptArtifactBlockedReason {
     ThresholdExceeded,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum McpAttemptFileEncoding {
+    Utf8,
+    Base64,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct GetAttemptFileRequest {
@@ -873,6 +1162,10 @@ pub struct GetAttemptFileRequest {
     pub path: String,
     pub start: Option<u64>,
     pub max_bytes: Option<usize>,
+    #[schemars(
+        description = "Content encoding (default utf8). Use base64 to fetch binary files such as images; max_bytes applies to the raw bytes"
+    )]
+    pub encoding: Option<McpAttemptFileEncoding>,
 }
 
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
@@ -889,6 +1182,108 @@ pub struct GetAttemptFileResponse {
     pub total_bytes: Option<u64>,
     pub path: String,
     pub content: Option<String>,
+    #[schemars(description = "True when the file is binary; content is null in that case")]
+    #[serde(default)]
+    pub binary: bool,
+    #[schemars(description = "Detected mime type for binary files")]
+    pub mime_type: Option<String>,
+    #[schemars(description = "Encoding of content; null when no content is returned")]
+    pub encoding: Option<McpAttemptFileEncoding>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FileSearchRequest {
+    #[schemars(description = "The attempt/workspace id (UUID string)")]
+    pub attempt_id: Uuid,
+    #[schemars(description = "Case-insensitive substring of the file or directory path")]
+    pub query: String,
+    #[schemars(description = "Maximum results to return (1-10, default 10)")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct McpFileSearchResult {
+    #[schemars(
+        description = "Repo-prefixed path (<repo>/<path in repo>), usable with get_attempt_file"
+    )]
+    pub path: String,
+    pub is_file: bool,
+    #[schemars(description = "Where the query matched: file_name|directory_name|full_path")]
+    pub match_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct FileSearchResponse {
+    pub attempt_id: String,
+    pub query: String,
+    #[schemars(description = "Matches ranked best first; gitignored paths are excluded")]
+    pub results: Vec<McpFileSearchResult>,
+    #[schemars(description = "Number of results returned")]
+    pub count: usize,
+    #[schemars(description = "Repos whose file index was truncated; results may be incomplete")]
+    pub truncated_repos: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SearchAttemptContentRequest {
+    #[schemars(description = "The attempt/workspace id (UUID string)")]
+    pub attempt_id: Uuid,
+    #[schemars(description = "Regular expression matched against each line")]
+    pub pattern: String,
+    #[schemars(
+        description = "Optional glob (gitignore syntax) restricting the scanned paths; paths start with the repo name, e.g. *.rs or <repo>/src/**"
+    )]
+    pub path_glob: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct McpAttemptContentMatch {
+    #[schemars(description = "Repo-prefixed path (<repo>/<path in repo>)")]
+    pub path: String,
+    #[schemars(description = "1-based line number")]
+    pub line_number: usize,
+    pub line: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct SearchAttemptContentResponse {
+    pub attempt_id: String,
+    pub matches: Vec<McpAttemptContentMatch>,
+    #[schemars(description = "Number of matches returned")]
+    pub count: usize,
+    pub files_scanned: usize,
+    #[schemars(
+        description = "True when the match, byte or time budget stopped the scan early or a file was too large to scan; narrow pattern or path_glob"
+    )]
+    pub truncated: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevertAttemptFileRequest {
+    #[schemars(description = "The attempt/workspace id (UUID string). This is required!")]
+    pub attempt_id: Uuid,
+    #[schemars(
+        description = "Attempt control token (lease bearer token). Obtain via start_attempt or claim_attempt_control."
+    )]
+    pub control_token: Option<Uuid>,
+    #[schemars(
+        description = "Repo-prefixed file path (<repo>/<path in repo>), as returned by get_attempt_changes"
+    )]
+    pub path: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct RevertAttemptFileResponse {
+    #[schemars(description = "Attempt/workspace id (UUID string)")]
+    pub attempt_id: String,
+    pub path: String,
+    #[schemars(
+        description = "True when the file was uncommitted and has been deleted instead of restored"
+    )]
+    pub removed: bool,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -933,6 +1328,20 @@ pub struct ListApprovalsResponse {
     pub next_cursor: Option<i64>,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListPendingApprovalsRequest {
+    #[schemars(description = "Attempt/workspace id (UUID string)")]
+    pub attempt_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ListPendingApprovalsResponse {
+    pub attempt_id: String,
+    #[schemars(description = "Unanswered approvals, oldest first")]
+    pub approvals: Vec<McpApprovalSummary>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct GetApprovalRequest {
@@ -948,8 +1357,15 @@ pub struct GetApprovalResponse {
 #[serde(deny_unknown_fields)]
 pub struct RespondApprovalRequest {
     pub approval_id: String,
-    pub execution_process_id: Uuid,
-    #[schemars(description = "approved|denied|timed_out")]
+    #[schemars(
+        description = "Optional attempt id; when set, the approval must belong to this attempt"
+    )]
+    pub attempt_id: Option<Uuid>,
+    #[schemars(
+        description = "Execution process the approval is bound to; defaults to the approval's own"
+    )]
+    pub execution_process_id: Option<Uuid>,
+    #[schemars(description = "approved|denied|timed_out (approve/deny are accepted too)")]
     pub status: String,
     pub denial_reason: Option<String>,
     pub responded_by_client_id: Option<String>,
@@ -962,6 +1378,37 @@ pub struct RespondApprovalResponse {
     pub status: String,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RespondAllApprovalsRequest {
+    #[schemars(description = "Attempt/workspace id (UUID string)")]
+    pub attempt_id: Uuid,
+    #[schemars(description = "approved|denied (approve/deny are accepted too)")]
+    pub decision: String,
+    pub denial_reason: Option<String>,
+    pub responded_by_client_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct McpApprovalBatchResult {
+    pub approval_id: String,
+    pub tool_name: String,
+    #[schemars(
+        description = "Final status (pending|approved|denied|timed_out); null when responding failed"
+    )]
+    pub status: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct RespondAllApprovalsResponse {
+    pub attempt_id: String,
+    #[schemars(
+        description = "One entry per approval pending when the call started; approvals created meanwhile are not touched"
+    )]
+    pub results: Vec<McpApprovalBatchResult>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TailProjectActivityRequest {
@@ -1020,3 +1467,11 @@ pub struct CliDependencyPreflightResponse {
     pub all_ok: bool,
     pub checks: Vec<CliDependencyCheck>,
 }
+
+#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct PingResponse {
+    pub reachable: bool,
+    pub latency_ms: u64,
+    #[schemars(description = "Version of the MCP task server")]
+    pub version: String,
+}
@@ -141,3 +141,62 @@ pub(super) fn mcp_task_max_concurrency() -> usize {
         }
     }
 }
+
+pub(super) fn tag_expansion_max_depth() -> usize {
+    let raw = match std::env::var(TAG_EXPANSION_MAX_DEPTH_ENV) {
+        Ok(value) => value,
+        Err(std::env::VarError::NotPresent) => return DEFAULT_TAG_EXPANSION_MAX_DEPTH,
+        Err(err) => {
+            tracing::warn!(
+                error = %err,
+                "Failed to read {TAG_EXPANSION_MAX_DEPTH_ENV}; using default"
+            );
+            return DEFAULT_TAG_EXPANSION_MAX_DEPTH;
+        }
+    };
+
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        tracing::warn!("{TAG_EXPANSION_MAX_DEPTH_ENV} is set but empty; using default");
+        return DEFAULT_TAG_EXPANSION_MAX_DEPTH;
+    }
+
+    match trimmed.parse::<usize>() {
+        Ok(0) => 1,
+        Ok(value) => value,
+        Err(err) => {
+            tracing::warn!(
+                value = trimmed,
+                error = %err,
+                "Invalid {TAG_EXPANSION_MAX_DEPTH_ENV}; using default"
+            );
+            DEFAULT_TAG_EXPANSION_MAX_DEPTH
+        }
+    }
+}
+
+/// Optional server identity override for white-labeled deployments. Unset or blank values fall
+/// back to the built-in defaults.
+pub(super) fn branding_override(key: &str) -> Option<String> {
+    match std::env::var(key) {
+        Ok(value) => {
+            let trimmed = value.trim();
+            (!trimmed.is_empty()).then(|| trimmed.to_string())
+        }
+        Err(std::env::VarError::NotPresent) => None,
+        Err(err) => {
+            tracing::warn!(error = %err, "Failed to read {key}; using default");
+            None
+        }
+    }
+}
+
+pub(super) fn branding_icon(src: String) -> Option<rmcp::model::Icon> {
+    match serde_json::from_value(json!({ "src": src })) {
+        Ok(icon) => Some(icon),
+        Err(err) => {
+            tracing::warn!(error = %err, "Invalid {MCP_SERVER_ICON_URL_ENV}; ignoring");
+            None
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::BTreeSet;
+
+use super::*;
+
+/// Result of expanding `@tag` references in a piece of text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct TagExpansion {
+    pub text: String,
+    /// Tag names that were found and substituted (at any depth).
+    pub resolved: Vec<String>,
+    /// Tag names that were referenced but do not exist; left untouched in `text`.
+    pub unresolved: Vec<String>,
+}
+
+fn tag_pattern() -> Option<Regex> {
+    Regex::new(r"@([^\s@]+)").ok()
+}
+
+/// Expands `@tag` references using `tags`, recursing into substituted content up to `max_depth`
+/// levels. A tag that is already being expanded higher up the chain is left as-is, so cycles such
+/// as `@a` -> `@b` -> `@a` terminate.
+pub(super) fn expand_tag_references(
+    text: &str,
+    tags: &HashMap<String, String>,
+    max_depth: usize,
+) -> TagExpansion {
+    let Some(pattern) = tag_pattern() else {
+        return TagExpansion {
+            text: text.to_string(),
+            ..Default::default()
+        };
+    };
+
+    let mut resolved = BTreeSet::new();
+    let mut unresolved = BTreeSet::new();
+    let mut stack = Vec::new();
+    let text = expand_level(
+        &pattern,
+        text,
+        tags,
+        max_depth.max(1),
+        &mut stack,
+        &mut resolved,
+        &mut unresolved,
+    );
+
+    TagExpansion {
+        text,
+        resolved: resolved.into_iter().collect(),
+        unresolved: unresolved.into_iter().collect(),
+    }
+}
+
+fn expand_level(
+    pattern: &Regex,
+    text: &str,
+    tags: &HashMap<String, String>,
+    remaining_depth: usize,
+    stack: &mut Vec<String>,
+    resolved: &mut BTreeSet<String>,
+    unresolved: &mut BTreeSet<String>,
+) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for caps in pattern.captures_iter(text) {
+        let (Some(whole), Some(name)) = (caps.get(0), caps.get(1)) else {
+            continue;
+        };
+        out.push_str(&text[last..whole.start()]);
+        last = whole.end();
+
+        let name = name.as_str();
+        match tags.get(name) {
+            Some(_) if stack.iter().any(|active| active == name) => {
+                out.push_str(whole.as_str());
+            }
+            Some(content) => {
+                resolved.insert(name.to_string());
+                if remaining_depth > 1 {
+                    stack.push(name.to_string());
+                    out.push_str(&expand_level(
+                        pattern,
+                        content,
+                        tags,
+                        remaining_depth - 1,
+                        stack,
+                        resolved,
+                        unresolved,
+                    ));
+                    stack.pop();
+                } else {
+                    out.push_str(content);
+                }
+            }
+            None => {
+                unresolved.insert(name.to_string());
+                out.push_str(whole.as_str());
+            }
+        }
+    }
+
+    out.push_str(&text[last..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, content)| (name.to_string(), content.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn nested_tags_are_expanded() {
+        let tags = tags(&[("a", "see @b"), ("b", "content")]);
+
+        let expansion = expand_tag_references("start @a end", &tags, 5);
+        assert_eq!(expansion.text, "start see content end");
+        assert_eq!(expansion.resolved, vec!["a".to_string(), "b".to_string()]);
+        assert!(expansion.unresolved.is_empty());
+    }
+
+    #[test]
+    fn max_depth_limits_nested_expansion() {
+        let tags = tags(&[("a", "see @b"), ("b", "content")]);
+
+        let expansion = expand_tag_references("@a", &tags, 1);
+        assert_eq!(expansion.text, "see @b");
+        assert_eq!(expansion.resolved, vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn cyclic_tags_terminate() {
+        let tags = tags(&[("a", "A(@b)"), ("b", "B(@a)")]);
+
+        let expansion = expand_tag_references("@a", &tags, 50);
+        assert_eq!(expansion.text, "A(B(@a))");
+
+        let self_ref = tags(&[("loop", "again @loop")]);
+        let expansion = expand_tag_references("@loop", &self_ref, 50);
+        assert_eq!(expansion.text, "again @loop");
+    }
+
+    #[test]
+    fn unknown_tags_are_left_untouched() {
+        let tags = tags(&[("known", "value")]);
+
+        let expansion = expand_tag_references("@known and @missing", &tags, 5);
+        assert_eq!(expansion.text, "value and @missing");
+        assert_eq!(expansion.resolved, vec!["known".to_string()]);
+        assert_eq!(expansion.unresolved, vec!["missing".to_string()]);
+    }
+}
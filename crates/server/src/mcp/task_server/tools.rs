@@ -1,5 +1,6 @@
 use db::models::{
     repo::Repo, task::TaskUpdateParams, task_orchestration_state::TaskOrchestrationState,
+    workspace::normalize_labels,
 };
 use rmcp::{tool, tool_router};
 
@@ -80,6 +81,36 @@ Avoid: Using this as a health check for long-running processes."#,
         Self::success(&CliDependencyPreflightResponse { all_ok, checks })
     }
 
+    #[tool(
+        description = r#"Use when: Cheap reachability preflight before a longer tool sequence.
+Required: (none)
+Optional: (none)
+Next: list_projects
+Avoid: Polling this in a loop; use tail_attempt_feed to watch attempts."#,
+        output_schema = tool_output_schema::<PingResponse>(),
+        annotations(read_only_hint = true)
+    )]
+    async fn ping(&self) -> Result<CallToolResult, ErrorData> {
+        let started = std::time::Instant::now();
+        let result = self.deployment.db().pool.ping().await;
+        let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        match result {
+            Ok(()) => Self::success(&PingResponse {
+                reachable: true,
+                latency_ms,
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            }),
+            Err(err) => Self::err_with(
+                "Database is unreachable",
+                Some(json!({ "error": err.to_string(), "latency_ms": latency_ms })),
+                Some("Database is unavailable; retry ping before calling other tools.".to_string()),
+                Some(MCP_CODE_DATABASE_UNREACHABLE),
+                Some(true),
+            ),
+        }
+    }
+
     #[tool(
         description = r#"Use when: Discover project_id values.
 Required: (none)
@@ -98,9 +129,12 @@ Avoid: Guessing UUIDs."#,
             .iter()
             .filter_map(|project| {
                 let id = project.id?;
+                let default_profile = config.executor_profile_for_project(id);
                 Some(ProjectSummary {
                     id: id.to_string(),
                     name: project.name.clone(),
+                    default_executor: default_profile.executor.to_string(),
+                    default_variant: default_profile.variant,
                     created_at: now.clone(),
                     updated_at: now.clone(),
                 })
@@ -116,7 +150,7 @@ Avoid: Guessing UUIDs."#,
         description = r#"Use when: Get repo_id + names for a project.
 Required: project_id
 Optional: (none)
-Next: start_attempt
+Next: list_repo_branches, start_attempt
 Avoid: Passing a task_id/attempt_id instead of project_id."#,
         output_schema = tool_output_schema::<ListReposResponse>(),
         annotations(read_only_hint = true)
@@ -180,6 +214,72 @@ Avoid: Passing a task_id/attempt_id instead of project_id."#,
         })
     }
 
+    #[tool(
+        description = r#"Use when: Pick a valid target_branch for start_attempt.
+Required: repo_id
+Optional: (none)
+Next: start_attempt
+Avoid: Guessing branch names; passing a project_id instead of repo_id."#,
+        output_schema = tool_output_schema::<ListRepoBranchesResponse>(),
+        annotations(read_only_hint = true)
+    )]
+    async fn list_repo_branches(
+        &self,
+        Parameters(ListRepoBranchesRequest { repo_id }): Parameters<ListRepoBranchesRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let repo = Repo::find_by_id(&self.deployment.db().pool, repo_id)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to load repo",
+                    Some(json!({ "error": e.to_string(), "repo_id": repo_id })),
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorData::invalid_params(
+                    "Repo not found",
+                    Some(json!({
+                        "code": "not_found",
+                        "retryable": false,
+                        "hint": "Call list_repos to get a valid repo_id.",
+                        "repo_id": repo_id,
+                    })),
+                )
+            })?;
+
+        let branches = self
+            .deployment
+            .git()
+            .get_all_branches(&repo.path)
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to list repo branches",
+                    Some(json!({ "error": e.to_string(), "repo_id": repo_id })),
+                )
+            })?;
+
+        let default_branch = branches
+            .iter()
+            .find(|branch| branch.is_default && !branch.is_remote)
+            .map(|branch| branch.name.clone());
+        let branches = branches
+            .into_iter()
+            .map(|branch| McpBranchSummary {
+                name: branch.name,
+                is_current: branch.is_current,
+                is_remote: branch.is_remote,
+                is_default: branch.is_default,
+            })
+            .collect::<Vec<_>>();
+
+        Self::success(&ListRepoBranchesResponse {
+            count: branches.len(),
+            branches,
+            default_branch,
+            repo_id: repo_id.to_string(),
+        })
+    }
+
     #[tool(
         description = r#"Use when: Discover valid executor ids + variants for start_attempt.
 Required: (none)
@@ -225,9 +325,9 @@ Avoid: Guessing executor names; passing DEFAULT as a variant (omit variant inste
     #[tool(
         description = r#"Use when: List tasks in a project (includes latest attempt/session summary fields).
 Required: project_id
-Optional: status, limit
+Optional: status, limit, include_description
 Next: get_task, start_attempt, list_task_attempts
-Avoid: Using this as an attempt/session listing (use list_task_attempts)."#,
+Avoid: Using this as an attempt/session listing (use list_task_attempts); calling get_task per task just to read descriptions (set include_description)."#,
         output_schema = tool_output_schema::<ListTasksResponse>(),
         annotations(read_only_hint = true)
     )]
@@ -237,6 +337,7 @@ Avoid: Using this as an attempt/session listing (use list_task_attempts)."#,
             project_id,
             status,
             limit,
+            include_description,
         }): Parameters<ListTasksRequest>,
     ) -> Result<Json<ListTasksResponse>, ErrorData> {
         let status_filter = if let Some(ref status_str) = status {
@@ -305,16 +406,114 @@ Avoid: Using this as an attempt/session listing (use list_task_attempts)."#,
             )
         })?;
 
+        let include_description = include_description.unwrap_or(false);
+        let mut description_budget = LIST_TASKS_DESCRIPTION_BUDGET_CHARS;
+        let mut descriptions_truncated = false;
         let mut task_summaries = Vec::with_capacity(limited.len());
         for task in limited {
             let attempt_summary = summaries.get(&task.id).cloned().unwrap_or_default();
-            task_summaries.push(TaskSummary::from_task_with_status(task, attempt_summary));
+            let description = if include_description {
+                task.description.clone()
+            } else {
+                None
+            };
+            let mut summary = TaskSummary::from_task_with_status(task, attempt_summary);
+            if let Some(description) = description {
+                let len = description.chars().count();
+                let take = len
+                    .min(LIST_TASKS_DESCRIPTION_MAX_CHARS)
+                    .min(description_budget);
+                if take < len {
+                    descriptions_truncated = true;
+                }
+                if take > 0 || len == 0 {
+                    description_budget -= take;
+                    summary.description = Some(description.chars().take(take).collect());
+                }
+            }
+            task_summaries.push(summary);
         }
 
         Ok(Json(ListTasksResponse {
             count: task_summaries.len(),
             tasks: task_summaries,
             project_id: project_id.to_string(),
+            descriptions_truncated,
+        }))
+    }
+
+    #[tool(
+        description = r#"Use when: Get a compact snapshot of a project's board (task counts per status plus in-progress tasks).
+Required: project_id
+Optional: in_progress_limit
+Next: list_tasks, get_attempt_status, tail_attempt_feed
+Avoid: Using this to page through every task (use list_tasks with a status filter)."#,
+        output_schema = tool_output_schema::<GetBoardResponse>(),
+        annotations(read_only_hint = true)
+    )]
+    async fn get_board(
+        &self,
+        Parameters(GetBoardRequest {
+            project_id,
+            in_progress_limit,
+        }): Parameters<GetBoardRequest>,
+    ) -> Result<Json<GetBoardResponse>, ErrorData> {
+        let all_tasks: Vec<TaskWithAttemptStatus> =
+            Task::find_by_project_id_with_attempt_status(&self.deployment.db().pool, project_id)
+                .await
+                .map_err(|e| {
+                    ErrorData::internal_error(
+                        "Failed to list tasks",
+                        Some(json!({ "error": e.to_string(), "project_id": project_id })),
+                    )
+                })?;
+
+        let buckets = [
+            TaskStatus::Todo,
+            TaskStatus::InProgress,
+            TaskStatus::InReview,
+            TaskStatus::Done,
+            TaskStatus::Cancelled,
+        ]
+        .into_iter()
+        .map(|status| BoardStatusBucket {
+            count: all_tasks
+                .iter()
+                .filter(|task| task.status == status)
+                .count(),
+            status: status.to_string(),
+        })
+        .collect::<Vec<_>>();
+        let total = all_tasks.len();
+
+        let in_progress_limit = in_progress_limit.unwrap_or(10).max(0) as usize;
+        let in_progress: Vec<TaskWithAttemptStatus> = all_tasks
+            .into_iter()
+            .filter(|task| task.status == TaskStatus::InProgress)
+            .take(in_progress_limit)
+            .collect();
+
+        let task_ids: Vec<Uuid> = in_progress.iter().map(|task| task.id).collect();
+        let summaries = self.task_attempt_summaries(task_ids).await.map_err(|e| {
+            ErrorData::internal_error(
+                "Failed to compute attempt summaries",
+                Some(json!({ "error": e.to_string() })),
+            )
+        })?;
+
+        let in_progress = in_progress
+            .into_iter()
+            .map(|task| {
+                let attempt_summary = summaries.get(&task.id).cloned().unwrap_or_default();
+                TaskSummary::from_task_with_status(task, attempt_summary)
+            })
+            .collect();
+
+        Ok(Json(GetBoardResponse {
+            project_id: project_id.to_string(),
+            total,
+            buckets,
+            in_progress,
         }))
     }
 
@@ -632,6 +831,32 @@ Avoid: Expecting attempt/session info here (use list_tasks/list_task_attempts)."
         })
     }
 
+    #[tool(
+        description = r#"Use when: Preview what @tag references in a prompt/description expand to.
+Required: text
+Optional: (none)
+Next: create_task, start_attempt
+Avoid: Calling this just to create a task; create_task/start_attempt expand tags already."#,
+        output_schema = tool_output_schema::<PreviewTagExpansionResponse>(),
+        annotations(read_only_hint = true)
+    )]
+    async fn preview_tag_expansion(
+        &self,
+        Parameters(PreviewTagExpansionRequest { text }): Parameters<PreviewTagExpansionRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let expansion = self.expand_tags_detailed(&text).await.map_err(|e| {
+            ErrorData::internal_error(
+                "Failed to load tags",
+                Some(json!({ "error": e.to_string() })),
+            )
+        })?;
+        Self::success(&PreviewTagExpansionResponse {
+            expanded_text: expansion.text,
+            resolved_tags: expansion.resolved,
+            unresolved_tags: expansion.unresolved,
+        })
+    }
+
     #[tool(
         description = r#"Use when: Fetch a concise review handoff payload for an auto-managed task.
 Required: at least one of {task_id, attempt_id}
@@ -1146,7 +1371,7 @@ Avoid: Empty title; guessing project_id (use list_projects)."#,
     #[tool(
         description = r#"Use when: Update a task's title/description/status.
 Required: task_id
-Optional: title, description, status
+Optional: title, description, status, expected_updated_at, request_id
 Next: get_task, start_attempt
 Avoid: Calling this just to set status=inprogress (start_attempt already does that)."#,
         output_schema = tool_output_schema::<UpdateTaskResponse>(),
@@ -1163,9 +1388,19 @@ Avoid: Calling this just to set status=inprogress (start_attempt already does th
             title,
             description,
             status,
+            expected_updated_at,
+            request_id,
         }): Parameters<UpdateTaskRequest>,
     ) -> Result<CallToolResult, ErrorData> {
         let pool = &self.deployment.db().pool;
+        let request_hash = Self::request_hash(&json!({
+            "task_id": task_id,
+            "title": title,
+            "description": description,
+            "status": status,
+            "expected_updated_at": expected_updated_at,
+        }))?;
+        let key = Self::stable_tool_idempotency_key(request_id);
         let existing = Task::find_by_id(pool, task_id)
             .await
             .map_err(|e| {
@@ -1192,6 +1427,31 @@ Avoid: Calling this just to set status=inprogress (start_attempt already does th
             );
         }
 
+        let expected_updated_at = match expected_updated_at
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            Some(raw) => match chrono::DateTime::parse_from_rfc3339(raw) {
+                Ok(value) => Some(value.with_timezone(&chrono::Utc)),
+                Err(_) => {
+                    return Self::err_with(
+                        "Invalid expected_updated_at",
+                        Some(json!({
+                            "tool": "update_task",
+                            "path": "expected_updated_at",
+                            "value": raw,
+                        })),
+                        Some(
+                            "Pass the updated_at value returned by get_task (RFC3339).".to_string(),
+                        ),
+                        Some("invalid_argument"),
+                        Some(false),
+                    );
+                }
+            },
+            None => None,
+        };
         let status = status.and_then(|s| {
             let trimmed = s.trim();
             if trimmed.is_empty() {
@@ -1239,37 +1499,193 @@ Avoid: Calling this just to set status=inprogress (start_attempt already does th
         });
 
         let description = description.map(|d| d.trim().to_string());
-        let parent_workspace_id = existing.parent_workspace_id;
+        let task_update = TaskUpdateParams {
+            project_id: existing.project_id,
+            title: title.unwrap_or(existing.title),
+            description: description.or(existing.description),
+            status: status.unwrap_or(existing.status),
+            parent_workspace_id: existing.parent_workspace_id,
+            continuation_turns_override: None,
+            expected_updated_at,
+        };
 
-        Task::update(
-            pool,
-            existing.id,
-            TaskUpdateParams {
-                project_id: existing.project_id,
-                title: title.unwrap_or(existing.title),
-                description: description.or(existing.description),
-                status: status.unwrap_or(existing.status),
-                parent_workspace_id,
-                continuation_turns_override: None,
-            },
+        // Set when the conditional update matched no row because the task changed since
+        // `expected_updated_at`. A replayed request never reaches the update, so it cannot trip
+        // this on the updated_at it produced.
+        let stale = AtomicBool::new(false);
+        let response = match self
+            .idempotent("update_task", key, request_hash, || async {
+                Task::update(pool, existing.id, task_update)
+                    .await
+                    .map_err(|e| {
+                        if matches!(e, DbErr::RecordNotUpdated) {
+                            stale.store(true, Ordering::Relaxed);
+                        }
+                        ErrorData::internal_error(
+                            "Failed to update task",
+                            Some(json!({ "error": e.to_string() })),
+                        )
+                    })?;
+                Ok(UpdateTaskResponse {
+                    task_id: task_id.to_string(),
+                })
+            })
+            .await
+        {
+            Ok(response) => response,
+            Err(ToolOrRpcError::Rpc(_)) if stale.load(Ordering::Relaxed) => {
+                let current_updated_at = Task::find_by_id(pool, task_id)
+                    .await
+                    .ok()
+                    .flatten()
+                    .map(|task| task.updated_at.to_rfc3339());
+                return Self::err_with(
+                    "Task was modified since it was last read.",
+                    Some(json!({
+                        "tool": "update_task",
+                        "task_id": task_id,
+                        "current_updated_at": current_updated_at,
+                    })),
+                    Some(
+                        "Call get_task to re-read the task, then retry with its updated_at."
+                            .to_string(),
+                    ),
+                    Some(MCP_CODE_STALE_WRITE),
+                    Some(true),
+                );
+            }
+            Err(ToolOrRpcError::Tool(tool_error)) => return Ok(tool_error),
+            Err(ToolOrRpcError::Rpc(err)) => return Err(err),
+        };
+
+        Self::success(&response)
+    }
+
+    #[tool(
+        description = r#"Use when: Move a task to another status, enforcing the allowed-transition matrix.
+Required: task_id, to
+Next: get_task, start_attempt
+Avoid: Using update_task for status changes that should be validated (e.g. cancelled -> inprogress)."#,
+        output_schema = tool_output_schema::<TransitionTaskResponse>(),
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = true
         )
-        .await
-        .map_err(|e| {
-            ErrorData::internal_error(
-                "Failed to update task",
-                Some(json!({ "error": e.to_string() })),
+    )]
+    async fn transition_task(
+        &self,
+        Parameters(TransitionTaskRequest { task_id, to }): Parameters<TransitionTaskRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let pool = &self.deployment.db().pool;
+        let to_raw = to.trim();
+        let to = TaskStatus::from_str(to_raw).map_err(|_| {
+            let mut details = serde_json::Map::new();
+            details.insert("tool".to_string(), json!("transition_task"));
+            details.insert("path".to_string(), json!("to"));
+            details.insert("value".to_string(), json!(to_raw));
+            details.insert(
+                "valid_values".to_string(),
+                json!(["todo", "inprogress", "inreview", "done", "cancelled"]),
+            );
+            details.insert("next_tools".to_string(), json!([]));
+            details.insert(
+                "example_args".to_string(),
+                json!({ "task_id": task_id, "to": "inreview" }),
+            );
+
+            ErrorData::invalid_params(
+                "Invalid task status",
+                Some(crate::mcp::params::invalid_params_payload(
+                    "invalid_argument",
+                    "Valid values: todo, inprogress, inreview, done, cancelled.".to_string(),
+                    details,
+                )),
             )
         })?;
 
-        Self::success(&UpdateTaskResponse {
+        let existing = Task::find_by_id(pool, task_id)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to load task",
+                    Some(json!({ "error": e.to_string(), "task_id": task_id })),
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorData::invalid_params("Task not found", Some(json!({ "task_id": task_id })))
+            })?;
+
+        if let Some(archive_id) = existing.archived_kanban_id {
+            return Self::err_with(
+                "Task is archived. Restore it before changing its status.",
+                Some(json!({
+                    "tool": "transition_task",
+                    "task_id": task_id,
+                    "archived_kanban_id": archive_id,
+                })),
+                Some("Restore the archived kanban batch first, then retry.".to_string()),
+                Some(MCP_CODE_BLOCKED_GUARDRAILS),
+                Some(false),
+            );
+        }
+
+        let from = existing.status;
+        if from == to {
+            return Self::success(&TransitionTaskResponse {
+                task_id: task_id.to_string(),
+                from: from.to_string(),
+                to: to.to_string(),
+                changed: false,
+            });
+        }
+
+        let allowed = {
+            let config = self.deployment.config().read().await;
+            transitions::allowed_task_transitions(&config.task_status_transitions, &from)
+        };
+        if !allowed.contains(&to) {
+            let allowed = allowed.iter().map(ToString::to_string).collect::<Vec<_>>();
+            return Self::err_with(
+                format!("Transition {from} -> {to} is not allowed."),
+                Some(json!({
+                    "tool": "transition_task",
+                    "task_id": task_id,
+                    "from": from.to_string(),
+                    "to": to.to_string(),
+                    "allowed": allowed,
+                })),
+                Some(if allowed.is_empty() {
+                    format!("No transitions are allowed from {from}.")
+                } else {
+                    format!("Allowed targets from {from}: {}.", allowed.join(", "))
+                }),
+                Some(MCP_CODE_INVALID_TRANSITION),
+                Some(false),
+            );
+        }
+
+        Task::update_status(pool, task_id, to.clone())
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to update task status",
+                    Some(json!({ "error": e.to_string(), "task_id": task_id })),
+                )
+            })?;
+
+        Self::success(&TransitionTaskResponse {
             task_id: task_id.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            changed: true,
         })
     }
 
     #[tool(
         description = r#"Use when: Permanently delete a task/ticket.
 Required: task_id
-Optional: (none)
+Optional: request_id
 Next: list_tasks
 Avoid: Deleting the wrong task (confirm with get_task first)."#,
         output_schema = tool_output_schema::<DeleteTaskResponse>(),
@@ -1281,7 +1697,10 @@ Avoid: Deleting the wrong task (confirm with get_task first)."#,
     )]
     async fn delete_task(
         &self,
-        Parameters(DeleteTaskRequest { task_id }): Parameters<DeleteTaskRequest>,
+        Parameters(DeleteTaskRequest {
+            task_id,
+            request_id,
+        }): Parameters<DeleteTaskRequest>,
     ) -> Result<CallToolResult, ErrorData> {
         let pool = &self.deployment.db().pool;
         if let Some(task) = Task::find_by_id(pool, task_id).await.map_err(|e| {
@@ -1306,24 +1725,43 @@ Avoid: Deleting the wrong task (confirm with get_task first)."#,
             );
         }
 
-        let rows = Task::delete(pool, task_id).await.map_err(|e| {
-            ErrorData::internal_error(
-                "Failed to delete task",
-                Some(json!({ "error": e.to_string() })),
-            )
-        })?;
-        let deleted_task_id = if rows > 0 {
-            Some(task_id.to_string())
-        } else {
-            None
+        let request_hash = Self::request_hash(&json!({ "task_id": task_id }))?;
+        let key = Self::stable_tool_idempotency_key(request_id);
+
+        let response = match self
+            .idempotent("delete_task", key, request_hash, || async {
+                let rows = if crate::routes::task_deletion::recovery_window().is_some() {
+                    tasks::task_deletion::soft_delete_task_record(pool, task_id).await
+                } else {
+                    Task::delete(pool, task_id).await
+                }
+                .map_err(|e| {
+                    ErrorData::internal_error(
+                        "Failed to delete task",
+                        Some(json!({ "error": e.to_string() })),
+                    )
+                })?;
+                let deleted_task_id = if rows > 0 {
+                    Some(task_id.to_string())
+                } else {
+                    None
+                };
+                Ok(DeleteTaskResponse { deleted_task_id })
+            })
+            .await
+        {
+            Ok(response) => response,
+            Err(ToolOrRpcError::Tool(tool_error)) => return Ok(tool_error),
+            Err(ToolOrRpcError::Rpc(err)) => return Err(err),
         };
-        Self::success(&DeleteTaskResponse { deleted_task_id })
+
+        Self::success(&response)
     }
 
     #[tool(
         description = r#"Use when: List attempts for a task (workspace history).
 Required: task_id
-Optional: (none)
+Optional: label
 Next: tail_attempt_feed, send_follow_up, stop_attempt
 Avoid: Assuming a task always has an attempt."#,
         output_schema = tool_output_schema::<ListTaskAttemptsResponse>(),
@@ -1331,10 +1769,10 @@ Avoid: Assuming a task always has an attempt."#,
     )]
     async fn list_task_attempts(
         &self,
-        Parameters(ListTaskAttemptsRequest { task_id }): Parameters<ListTaskAttemptsRequest>,
+        Parameters(ListTaskAttemptsRequest { task_id, label }): Parameters<ListTaskAttemptsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
         let pool = &self.deployment.db().pool;
-        let workspaces = Workspace::fetch_all(pool, Some(task_id))
+        let mut workspaces = Workspace::fetch_all(pool, Some(task_id))
             .await
             .map_err(|e| {
                 ErrorData::internal_error(
@@ -1342,6 +1780,9 @@ Avoid: Assuming a task always has an attempt."#,
                     Some(json!({ "error": e.to_string(), "task_id": task_id })),
                 )
             })?;
+        if let Some(label) = label.as_deref().map(str::trim).filter(|l| !l.is_empty()) {
+            workspaces.retain(|ws| ws.has_label(label));
+        }
         let workspace_ids: Vec<Uuid> = workspaces.iter().map(|w| w.id).collect();
         let sessions_by_workspace = Session::find_latest_by_workspace_ids(pool, &workspace_ids)
             .await
@@ -1358,6 +1799,7 @@ Avoid: Assuming a task always has an attempt."#,
             attempts.push(AttemptSummary {
                 attempt_id: ws.id.to_string(),
                 workspace_branch: ws.branch.clone(),
+                labels: ws.labels.clone(),
                 created_at: ws.created_at.to_rfc3339(),
                 updated_at: ws.updated_at.to_rfc3339(),
                 latest_session_id: session.map(|s| s.id.to_string()),
@@ -1382,15 +1824,68 @@ Avoid: Assuming a task always has an attempt."#,
     }
 
     #[tool(
-        description = r#"Use when: Create a new attempt/workspace for a task and start the executor.
-Required: task_id, repos
-Optional: executor, variant, request_id, prompt
-Next: tail_attempt_feed, send_follow_up, claim_attempt_control
-Avoid: Empty repos; guessing executor (use list_executors)."#,
-        output_schema = tool_output_schema::<StartAttemptResponse>(),
-        annotations(
-            read_only_hint = false,
-            destructive_hint = false,
+        description = r#"Use when: Enumerate every session of an attempt (one per executor run chain).
+Required: attempt_id
+Optional: (none)
+Next: tail_session_messages, send_follow_up
+Avoid: Assuming list_task_attempts' latest_session_id is the only session."#,
+        output_schema = tool_output_schema::<ListSessionsResponse>(),
+        annotations(read_only_hint = true)
+    )]
+    async fn list_sessions(
+        &self,
+        Parameters(ListSessionsRequest { attempt_id }): Parameters<ListSessionsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let pool = &self.deployment.db().pool;
+        let _ = Workspace::find_by_id(pool, attempt_id)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to load workspace",
+                    Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorData::invalid_params(
+                    "Attempt not found",
+                    Some(json!({ "attempt_id": attempt_id })),
+                )
+            })?;
+
+        let sessions = Session::find_by_workspace_id(pool, attempt_id)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to list sessions",
+                    Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                )
+            })?
+            .into_iter()
+            .map(|session| SessionSummary {
+                session_id: session.id.to_string(),
+                executor: session.executor,
+                created_at: session.created_at.to_rfc3339(),
+                updated_at: session.updated_at.to_rfc3339(),
+            })
+            .collect::<Vec<_>>();
+
+        Self::success(&ListSessionsResponse {
+            attempt_id: attempt_id.to_string(),
+            count: sessions.len(),
+            sessions,
+        })
+    }
+
+    #[tool(
+        description = r#"Use when: Create a new attempt/workspace for a task and start the executor.
+Required: task_id, repos
+Optional: executor, variant, request_id, prompt, labels, reasoning_effort
+Next: tail_attempt_feed, send_follow_up, claim_attempt_control
+Avoid: Empty repos; guessing executor (use list_executors)."#,
+        output_schema = tool_output_schema::<StartAttemptResponse>(),
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
             idempotent_hint = true
         ),
         execution(task_support = "optional")
@@ -1404,6 +1899,8 @@ Avoid: Empty repos; guessing executor (use list_executors)."#,
             repos,
             request_id,
             prompt,
+            labels,
+            reasoning_effort,
         }): Parameters<StartAttemptRequest>,
     ) -> Result<CallToolResult, ErrorData> {
         let pool = &self.deployment.db().pool;
@@ -1468,10 +1965,37 @@ Avoid: Empty repos; guessing executor (use list_executors)."#,
             }
         });
 
+        let reasoning_effort = match reasoning_effort
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            Some(value) => {
+                match executors::executors::codex::ReasoningEffort::from_str(
+                    &value.to_ascii_lowercase(),
+                ) {
+                    Ok(effort) => Some(effort.as_ref().to_string()),
+                    Err(_) => {
+                        return Self::err_with(
+                            format!("Unknown reasoning effort '{value}'."),
+                            Some(json!({
+                                "value": value,
+                                "valid_values": ["low", "medium", "high", "xhigh"],
+                            })),
+                            Some("Use one of: low, medium, high, xhigh.".to_string()),
+                            Some("invalid_argument"),
+                            None,
+                        );
+                    }
+                }
+            }
+            None => None,
+        };
+
         let override_requested = executor.is_some() || variant.is_some();
         let default_executor_profile_id = {
             let config = self.deployment.config().read().await;
-            config.executor_profile.clone()
+            config.executor_profile_for_project(task.project_id)
         };
 
         let base_executor = if let Some(executor_trimmed) = executor.as_deref() {
@@ -1667,6 +2191,32 @@ Avoid: Empty repos; guessing executor (use list_executors)."#,
                     None,
                 );
             }
+            if let Some(create_from) = repo
+                .create_from
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                && let Err(err) = crate::routes::task_attempts::create_target_branch_if_missing(
+                    &self.deployment,
+                    repo.repo_id,
+                    target_branch,
+                    create_from,
+                )
+                .await
+            {
+                return Self::err_with(
+                    "Failed to create target branch.",
+                    Some(json!({
+                        "field": format!("repos[{index}].create_from"),
+                        "error": err.to_string(),
+                    })),
+                    Some(
+                        "Pass an existing ref as create_from (see list_repo_branches).".to_string(),
+                    ),
+                    Some("invalid_argument"),
+                    None,
+                );
+            }
             repo_specs_for_hash.push(RepoSpecForHash {
                 repo_id: repo.repo_id,
                 target_branch: target_branch.to_string(),
@@ -1674,9 +2224,37 @@ Avoid: Empty repos; guessing executor (use list_executors)."#,
             workspace_repos.push(CreateWorkspaceRepo {
                 repo_id: repo.repo_id,
                 target_branch: target_branch.to_string(),
+                sparse_paths: Vec::new(),
             });
         }
 
+        let missing_branches = crate::routes::task_attempts::find_missing_target_branches(
+            &self.deployment,
+            &workspace_repos,
+        )
+        .await
+        .map_err(|e| {
+            ErrorData::internal_error(
+                "Failed to validate target branches",
+                Some(json!({ "error": e.to_string(), "task_id": task_id })),
+            )
+        })?;
+        if !missing_branches.is_empty() {
+            return Self::err_with(
+                "Target branch not found.",
+                Some(json!({
+                    "tool": "start_attempt",
+                    "task_id": task_id,
+                    "repos": missing_branches,
+                })),
+                Some("Call list_repo_branches to pick an existing target_branch.".to_string()),
+                Some("invalid_argument"),
+                Some(false),
+            );
+        }
+
+        let labels = normalize_labels(&labels.unwrap_or_default());
+
         #[derive(Serialize)]
         struct StartAttemptIdempotencyPayload<'a> {
             task_id: Uuid,
@@ -1684,6 +2262,9 @@ Avoid: Empty repos; guessing executor (use list_executors)."#,
             variant: &'a Option<String>,
             repos: &'a [RepoSpecForHash],
             prompt: &'a Option<String>,
+            labels: &'a [String],
+            #[serde(skip_serializing_if = "Option::is_none")]
+            reasoning_effort: &'a Option<String>,
         }
 
         let payload_hash = Self::request_hash(&StartAttemptIdempotencyPayload {
@@ -1692,6 +2273,8 @@ Avoid: Empty repos; guessing executor (use list_executors)."#,
             variant: &executor_profile_id.variant,
             repos: &repo_specs_for_hash,
             prompt: &prompt,
+            labels: &labels,
+            reasoning_effort: &reasoning_effort,
         })?;
         let key = Self::stable_tool_idempotency_key(request_id);
 
@@ -1743,7 +2326,7 @@ Avoid: Empty repos; guessing executor (use list_executors)."#,
                     .git_branch_from_workspace(&attempt_id, &task.title)
                     .await;
 
-                let workspace = Workspace::create(
+                let mut workspace = Workspace::create(
                     pool,
                     &CreateWorkspace {
                         branch: git_branch_name.clone(),
@@ -1759,6 +2342,16 @@ Avoid: Empty repos; guessing executor (use list_executors)."#,
                         Some(json!({ "error": e.to_string(), "task_id": task_id })),
                     )
                 })?;
+                if !labels.is_empty() {
+                    workspace = Workspace::set_labels(pool, workspace.id, &labels)
+                        .await
+                        .map_err(|e| {
+                            ErrorData::internal_error(
+                                "Failed to label workspace",
+                                Some(json!({ "error": e.to_string(), "attempt_id": workspace.id })),
+                            )
+                        })?;
+                }
 
                 WorkspaceRepo::create_many(pool, workspace.id, &workspace_repos)
                     .await
@@ -1776,7 +2369,12 @@ Avoid: Empty repos; guessing executor (use list_executors)."#,
                 let exec = self
                     .deployment
                     .container()
-                    .start_workspace(&workspace, executor_profile_id.clone(), prompt.clone())
+                    .start_workspace(
+                        &workspace,
+                        executor_profile_id.clone(),
+                        prompt.clone(),
+                        reasoning_effort.clone(),
+                    )
                     .await
                     .map_err(|e| {
                         ErrorData::internal_error(
@@ -2545,6 +3143,8 @@ Avoid: Providing both attempt_id and session_id; missing prompt."#,
                         executor_profile_id: executor_profile_id.clone(),
                         working_dir: working_dir.clone(),
                         image_paths: None,
+                        model: None,
+                        reasoning_effort: None,
                     })
                 } else {
                     ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
@@ -2552,6 +3152,8 @@ Avoid: Providing both attempt_id and session_id; missing prompt."#,
                         executor_profile_id: executor_profile_id.clone(),
                         working_dir,
                         image_paths: None,
+                        model: None,
+                        reasoning_effort: None,
                     })
                 };
 
@@ -2609,6 +3211,10 @@ Avoid: Providing both attempt_id and session_id; missing prompt."#,
                 Ok(SendFollowUpResponse {
                     session_id: session.id.to_string(),
                     execution_process_id: exec.id.to_string(),
+                    queue_depth: self
+                        .deployment
+                        .queued_message_service()
+                        .queue_depth(session.id),
                 })
             })
             .await
@@ -3106,6 +3712,75 @@ Avoid: Mixing cursor with after_log_index; using wait_ms without after_log_index
         })
     }
 
+    #[tool(
+        description = r#"Use when: Check an attempt's state and whether it still merges cleanly into its base.
+Required: attempt_id
+Next: tail_attempt_feed, get_attempt_changes; respond_approval when waiting_on_approval
+Avoid: Polling for logs (use tail_attempt_feed)."#,
+        output_schema = tool_output_schema::<GetAttemptStatusResponse>(),
+        annotations(read_only_hint = true)
+    )]
+    async fn get_attempt_status(
+        &self,
+        Parameters(GetAttemptStatusRequest { attempt_id }): Parameters<GetAttemptStatusRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace = Workspace::find_by_id(&self.deployment.db().pool, attempt_id)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to load workspace",
+                    Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorData::invalid_params(
+                    "Attempt not found",
+                    Some(json!({ "attempt_id": attempt_id })),
+                )
+            })?;
+
+        let ResponseJson(response) = crate::routes::task_attempts::get_task_attempt_status(
+            axum::Extension(workspace),
+            axum::extract::State(self.deployment.clone()),
+        )
+        .await
+        .map_err(|e| {
+            ErrorData::internal_error(
+                "Failed to load attempt status",
+                Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+            )
+        })?;
+        let status = response.into_data().ok_or_else(|| {
+            ErrorData::internal_error(
+                "Attempt status unavailable",
+                Some(json!({ "attempt_id": attempt_id })),
+            )
+        })?;
+
+        Self::success(&GetAttemptStatusResponse {
+            attempt_id: status.attempt_id.to_string(),
+            task_id: status.task_id.to_string(),
+            workspace_branch: status.workspace_branch,
+            labels: status.labels,
+            state: match status.state {
+                crate::routes::task_attempts::AttemptState::Idle => McpAttemptState::Idle,
+                crate::routes::task_attempts::AttemptState::Running => McpAttemptState::Running,
+                crate::routes::task_attempts::AttemptState::Completed => McpAttemptState::Completed,
+                crate::routes::task_attempts::AttemptState::Failed => McpAttemptState::Failed,
+            },
+            latest_session_id: status.latest_session_id.map(|id| id.to_string()),
+            latest_execution_process_id: status
+                .latest_execution_process_id
+                .map(|id| id.to_string()),
+            last_activity_at: status.last_activity_at.map(|at| at.to_rfc3339()),
+            failure_summary: status.failure_summary,
+            waiting_on_approval: status.waiting_on_approval,
+            pending_approval_tool: status.pending_approval_tool,
+            base_diverged: status.base_diverged,
+            has_conflicts: status.has_conflicts,
+        })
+    }
+
     #[tool(
         description = r#"Use when: Tail session transcript context (prompt + summary per turn).
 Required: exactly one of {attempt_id, session_id}
@@ -3239,6 +3914,16 @@ Avoid: Assuming files will be returned when blocked=true; using force unless you
             deleted: changes.summary.deleted,
             total_bytes: changes.summary.total_bytes,
         };
+        let repos: Vec<McpAttemptRepoChangesSummary> = changes
+            .repos
+            .iter()
+            .map(|repo| McpAttemptRepoChangesSummary {
+                repo_name: repo.repo_name.clone(),
+                file_count: repo.file_count,
+                added: repo.added,
+                deleted: repo.deleted,
+            })
+            .collect();
 
         if changes.blocked {
             let hint = match blocked_reason {
@@ -3261,6 +3946,7 @@ Avoid: Assuming files will be returned when blocked=true; using force unless you
                     "attempt_id": attempt_id,
                     "blocked_reason": blocked_reason,
                     "summary": summary,
+                    "repos": repos,
                 })),
                 Some(hint),
                 Some(MCP_CODE_BLOCKED_GUARDRAILS),
@@ -3283,15 +3969,126 @@ Avoid: Assuming files will be returned when blocked=true; using force unless you
             retryable: None,
             hint: None,
             files,
+            repos,
+        })
+    }
+
+    #[tool(
+        description = r#"Use when: Compare two attempts of the same task (e.g. two agents' approaches) file by file.
+Required: attempt_a, attempt_b
+Optional: (none)
+Next: get_attempt_patch, get_attempt_file
+Avoid: Attempts of different tasks; expecting uncommitted worktree edits (only committed branch content is compared)."#,
+        output_schema = tool_output_schema::<CompareAttemptsResponse>(),
+        annotations(read_only_hint = true),
+        execution(task_support = "optional")
+    )]
+    async fn compare_attempts(
+        &self,
+        Parameters(CompareAttemptsRequest {
+            attempt_a,
+            attempt_b,
+        }): Parameters<CompareAttemptsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let pool = &self.deployment.db().pool;
+        let mut workspaces = Vec::with_capacity(2);
+        for attempt_id in [attempt_a, attempt_b] {
+            let workspace = Workspace::find_by_id(pool, attempt_id)
+                .await
+                .map_err(|e| {
+                    ErrorData::internal_error(
+                        "Failed to load workspace",
+                        Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                    )
+                })?
+                .ok_or_else(|| {
+                    ErrorData::invalid_params(
+                        "Attempt not found",
+                        Some(json!({ "attempt_id": attempt_id })),
+                    )
+                })?;
+            workspaces.push(workspace);
+        }
+        let workspace_b = workspaces.pop().expect("two workspaces loaded");
+        let workspace_a = workspaces.pop().expect("two workspaces loaded");
+
+        if workspace_a.task_id != workspace_b.task_id {
+            return Self::err_with(
+                "Attempts belong to different tasks.",
+                Some(json!({
+                    "attempt_a": attempt_a,
+                    "attempt_b": attempt_b,
+                    "task_a": workspace_a.task_id,
+                    "task_b": workspace_b.task_id,
+                })),
+                Some("Pick two attempts of the same task (see list_task_attempts).".to_string()),
+                Some(MCP_CODE_ATTEMPTS_NOT_COMPARABLE),
+                Some(false),
+            );
+        }
+
+        let query = crate::routes::task_attempts::CompareAttemptsQuery {
+            other_attempt_id: attempt_b,
+        };
+        let ResponseJson(response) = crate::routes::task_attempts::compare_task_attempts(
+            axum::Extension(workspace_a),
+            axum::extract::State(self.deployment.clone()),
+            axum::extract::Query(query),
+        )
+        .await
+        .map_err(|e| {
+            ErrorData::internal_error(
+                "Failed to compare attempts",
+                Some(json!({
+                    "error": e.to_string(),
+                    "attempt_a": attempt_a,
+                    "attempt_b": attempt_b,
+                })),
+            )
+        })?;
+        let comparison = response.into_data().ok_or_else(|| {
+            ErrorData::internal_error(
+                "Attempt comparison response missing data",
+                Some(json!({ "attempt_a": attempt_a, "attempt_b": attempt_b })),
+            )
+        })?;
+
+        let files = comparison
+            .files
+            .into_iter()
+            .map(|file| McpAttemptComparisonFile {
+                repo_name: file.repo_name,
+                path: file.path,
+                old_path: file.old_path,
+                change: match file.change {
+                    utils_core::diff::DiffChangeKind::Added => "added",
+                    utils_core::diff::DiffChangeKind::Deleted => "deleted",
+                    utils_core::diff::DiffChangeKind::Modified => "modified",
+                    utils_core::diff::DiffChangeKind::Renamed => "renamed",
+                    utils_core::diff::DiffChangeKind::Copied => "copied",
+                    utils_core::diff::DiffChangeKind::PermissionChange => "permission_change",
+                }
+                .to_string(),
+                additions: file.additions,
+                deletions: file.deletions,
+            })
+            .collect::<Vec<_>>();
+
+        Self::success(&CompareAttemptsResponse {
+            attempt_a: attempt_a.to_string(),
+            attempt_b: attempt_b.to_string(),
+            count: files.len(),
+            files,
+            skipped_repos: comparison.skipped_repos,
         })
     }
 
     #[tool(
         description = r#"Use when: Fetch a file inside an attempt workspace.
 Required: attempt_id, path
-Optional: start, max_bytes
+Optional: start, max_bytes, encoding
 Next: get_attempt_patch
-Avoid: Absolute paths or .. traversal."#,
+Avoid: Absolute paths or .. traversal; utf8 reads of binary files (use encoding=base64)."#,
         output_schema = tool_output_schema::<GetAttemptFileResponse>(),
         annotations(read_only_hint = true),
         execution(task_support = "optional")
@@ -3303,6 +4100,7 @@ Avoid: Absolute paths or .. traversal."#,
             path,
             start,
             max_bytes,
+            encoding,
         }): Parameters<GetAttemptFileRequest>,
     ) -> Result<CallToolResult, ErrorData> {
         let workspace = Workspace::find_by_id(&self.deployment.db().pool, attempt_id)
@@ -3324,6 +4122,14 @@ Avoid: Absolute paths or .. traversal."#,
             path: Some(path.clone()),
             start,
             max_bytes,
+            encoding: encoding.map(|encoding| match encoding {
+                McpAttemptFileEncoding::Utf8 => {
+                    crate::routes::task_attempts::AttemptFileEncoding::Utf8
+                }
+                McpAttemptFileEncoding::Base64 => {
+                    crate::routes::task_attempts::AttemptFileEncoding::Base64
+                }
+            }),
         };
         let ResponseJson(response) = crate::routes::task_attempts::get_task_attempt_file(
             axum::Extension(workspace),
@@ -3352,6 +4158,9 @@ Avoid: Absolute paths or .. traversal."#,
                     bytes: 0,
                     total_bytes: None,
                     content: None,
+                    binary: false,
+                    mime_type: None,
+                    encoding: None,
                 });
 
         let blocked_reason = file.blocked_reason.map(|reason| match reason {
@@ -3422,31 +4231,50 @@ Avoid: Absolute paths or .. traversal."#,
             total_bytes: file.total_bytes,
             path: file.path,
             content: file.content,
+            binary: file.binary,
+            mime_type: file.mime_type,
+            encoding: file.encoding.map(|encoding| match encoding {
+                crate::routes::task_attempts::AttemptFileEncoding::Utf8 => {
+                    McpAttemptFileEncoding::Utf8
+                }
+                crate::routes::task_attempts::AttemptFileEncoding::Base64 => {
+                    McpAttemptFileEncoding::Base64
+                }
+            }),
         })
     }
 
     #[tool(
-        description = r#"Use when: Fetch a unified diff patch for selected paths in an attempt.
-Required: attempt_id, paths
-Optional: force, max_bytes
-Next: send_follow_up
-Avoid: Too many paths; huge max_bytes."#,
-        output_schema = tool_output_schema::<GetAttemptPatchResponse>(),
-        annotations(read_only_hint = true),
-        execution(task_support = "optional")
+        description = r#"Use when: Locate files in an attempt workspace by (partial) name before reading them.
+Required: attempt_id, query
+Optional: limit
+Next: get_attempt_file
+Avoid: Searching file contents (only paths are matched); expecting gitignored files in results."#,
+        output_schema = tool_output_schema::<FileSearchResponse>(),
+        annotations(read_only_hint = true)
     )]
-    async fn get_attempt_patch(
+    async fn file_search(
         &self,
-        Parameters(GetAttemptPatchRequest {
+        Parameters(FileSearchRequest {
             attempt_id,
-            paths,
-            force,
-            max_bytes,
-        }): Parameters<GetAttemptPatchRequest>,
+            query,
+            limit,
+        }): Parameters<FileSearchRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let force = force.unwrap_or(false);
+        // Project file search ranks across repos and keeps the top 10.
+        const MAX_LIMIT: usize = 10;
 
-        let workspace = Workspace::find_by_id(&self.deployment.db().pool, attempt_id)
+        let query = query.trim().to_string();
+        if query.is_empty() {
+            return Err(ErrorData::invalid_params(
+                "query must not be empty",
+                Some(json!({ "attempt_id": attempt_id })),
+            ));
+        }
+        let limit = limit.unwrap_or(MAX_LIMIT).clamp(1, MAX_LIMIT);
+
+        let pool = &self.deployment.db().pool;
+        let workspace = Workspace::find_by_id(pool, attempt_id)
             .await
             .map_err(|e| {
                 ErrorData::internal_error(
@@ -3461,35 +4289,301 @@ Avoid: Too many paths; huge max_bytes."#,
                 )
             })?;
 
-        let req = crate::routes::task_attempts::AttemptPatchRequest {
-            paths: paths.clone(),
-            force,
-            max_bytes,
+        // Read-only: never recreate a missing worktree here.
+        let Some(workspace_root) = workspace
+            .container_ref
+            .as_ref()
+            .map(std::path::PathBuf::from)
+            .filter(|path| path.exists())
+        else {
+            return Self::err_with(
+                "Attempt workspace does not exist",
+                Some(json!({ "attempt_id": attempt_id })),
+                Some("The attempt's worktree is missing; send_follow_up recreates it.".to_string()),
+                Some("not_found"),
+                Some(false),
+            );
         };
-        let ResponseJson(response) = crate::routes::task_attempts::get_task_attempt_patch(
-            axum::Extension(workspace),
-            axum::extract::State(self.deployment.clone()),
-            axum::Json(req),
-        )
-        .await
-        .map_err(|e| {
-            ErrorData::internal_error(
-                "Failed to compute attempt patch",
-                Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
-            )
-        })?;
 
-        let patch =
-            response
-                .into_data()
-                .unwrap_or(crate::routes::task_attempts::AttemptPatchResponse {
-                    blocked: true,
-                    blocked_reason: Some(
-                        crate::routes::task_attempts::AttemptArtifactBlockedReason::SummaryFailed,
-                    ),
-                    truncated: false,
-                    bytes: 0,
-                    paths,
+        // Search each repo's worktree so results reflect the attempt, not the source checkout.
+        let repos = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to load attempt repos",
+                    Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                )
+            })?
+            .into_iter()
+            .map(|repo| Repo {
+                path: workspace_root.join(&repo.name),
+                ..repo
+            })
+            .collect::<Vec<_>>();
+
+        let search = self
+            .deployment
+            .project()
+            .search_files(
+                self.deployment.file_search_cache().as_ref(),
+                &repos,
+                &repos::file_search_cache::SearchQuery {
+                    q: query.clone(),
+                    mode: repos::file_search_cache::SearchMode::TaskForm,
+                },
+            )
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to search attempt files",
+                    Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                )
+            })?;
+
+        let results = search
+            .results
+            .into_iter()
+            .take(limit)
+            .map(|result| McpFileSearchResult {
+                path: result.path,
+                is_file: result.is_file,
+                match_type: match result.match_type {
+                    db::models::project::SearchMatchType::FileName => "file_name",
+                    db::models::project::SearchMatchType::DirectoryName => "directory_name",
+                    db::models::project::SearchMatchType::FullPath => "full_path",
+                }
+                .to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        Self::success(&FileSearchResponse {
+            attempt_id: attempt_id.to_string(),
+            query,
+            count: results.len(),
+            results,
+            truncated_repos: search.truncated_repos,
+        })
+    }
+
+    #[tool(
+        description = r#"Use when: Find which files and lines in an attempt workspace contain some text (grep).
+Required: attempt_id, pattern
+Optional: path_glob
+Next: get_attempt_file
+Avoid: Very broad patterns over the whole workspace (results are capped; check truncated)."#,
+        output_schema = tool_output_schema::<SearchAttemptContentResponse>(),
+        annotations(read_only_hint = true),
+        execution(task_support = "optional")
+    )]
+    async fn search_attempt_content(
+        &self,
+        Parameters(SearchAttemptContentRequest {
+            attempt_id,
+            pattern,
+            path_glob,
+        }): Parameters<SearchAttemptContentRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace = Workspace::find_by_id(&self.deployment.db().pool, attempt_id)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to load workspace",
+                    Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorData::invalid_params(
+                    "Attempt not found",
+                    Some(json!({ "attempt_id": attempt_id })),
+                )
+            })?;
+
+        let query = crate::routes::task_attempts::AttemptContentSearchQuery { pattern, path_glob };
+        let ResponseJson(response) = crate::routes::task_attempts::search_task_attempt_content(
+            axum::Extension(workspace),
+            axum::extract::State(self.deployment.clone()),
+            axum::extract::Query(query),
+        )
+        .await
+        .map_err(|e| match e {
+            ApiError::BadRequest(message) => {
+                ErrorData::invalid_params(message, Some(json!({ "attempt_id": attempt_id })))
+            }
+            e => ErrorData::internal_error(
+                "Failed to search attempt content",
+                Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+            ),
+        })?;
+        let search = response.into_data().ok_or_else(|| {
+            ErrorData::internal_error(
+                "Attempt content search response missing data",
+                Some(json!({ "attempt_id": attempt_id })),
+            )
+        })?;
+
+        let matches = search
+            .matches
+            .into_iter()
+            .map(|found| McpAttemptContentMatch {
+                path: found.path,
+                line_number: found.line_number,
+                line: found.line,
+            })
+            .collect::<Vec<_>>();
+
+        Self::success(&SearchAttemptContentResponse {
+            attempt_id: attempt_id.to_string(),
+            count: matches.len(),
+            matches,
+            files_scanned: search.files_scanned,
+            truncated: search.truncated,
+        })
+    }
+
+    #[tool(
+        description = r#"Use when: Discard an attempt's changes to a single file (committed or not), restoring its content from the attempt's fork point on the target branch.
+Required: attempt_id, path
+Also required (mutating): control_token
+Next: get_attempt_changes
+Avoid: Absolute paths or .. traversal; paths without the repo prefix; reverting while the agent is still running (rejected)."#,
+        output_schema = tool_output_schema::<RevertAttemptFileResponse>(),
+        annotations(
+            read_only_hint = false,
+            destructive_hint = true,
+            idempotent_hint = true
+        )
+    )]
+    async fn revert_attempt_file(
+        &self,
+        Parameters(RevertAttemptFileRequest {
+            attempt_id,
+            control_token,
+            path,
+        }): Parameters<RevertAttemptFileRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace = Workspace::find_by_id(&self.deployment.db().pool, attempt_id)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to load workspace",
+                    Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorData::invalid_params(
+                    "Attempt not found",
+                    Some(json!({ "attempt_id": attempt_id })),
+                )
+            })?;
+
+        if let Err(err) = self
+            .require_attempt_control_token(attempt_id, control_token, "revert_attempt_file")
+            .await
+        {
+            return Ok(err);
+        }
+
+        let request = crate::routes::task_attempts::RevertAttemptFileRequest { path: path.clone() };
+        let ResponseJson(response) = match crate::routes::task_attempts::revert_task_attempt_file(
+            axum::Extension(workspace),
+            axum::extract::State(self.deployment.clone()),
+            axum::Json(request),
+        )
+        .await
+        {
+            Ok(ok) => ok,
+            Err(err) => {
+                return Self::tool_error_from_api_error(
+                    "revert_attempt_file",
+                    err,
+                    json!({
+                        "tool": "revert_attempt_file",
+                        "attempt_id": attempt_id,
+                        "path": path,
+                    }),
+                );
+            }
+        };
+
+        let data = response.into_data().ok_or_else(|| {
+            ErrorData::internal_error(
+                "Revert response missing data",
+                Some(json!({ "attempt_id": attempt_id, "path": path })),
+            )
+        })?;
+
+        Self::success(&RevertAttemptFileResponse {
+            attempt_id: attempt_id.to_string(),
+            path: data.path,
+            removed: data.removed,
+        })
+    }
+
+    #[tool(
+        description = r#"Use when: Fetch a unified diff patch for selected paths in an attempt.
+Required: attempt_id, paths
+Optional: force, max_bytes
+Next: send_follow_up
+Avoid: Too many paths; huge max_bytes."#,
+        output_schema = tool_output_schema::<GetAttemptPatchResponse>(),
+        annotations(read_only_hint = true),
+        execution(task_support = "optional")
+    )]
+    async fn get_attempt_patch(
+        &self,
+        Parameters(GetAttemptPatchRequest {
+            attempt_id,
+            paths,
+            force,
+            max_bytes,
+        }): Parameters<GetAttemptPatchRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let force = force.unwrap_or(false);
+
+        let workspace = Workspace::find_by_id(&self.deployment.db().pool, attempt_id)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to load workspace",
+                    Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorData::invalid_params(
+                    "Attempt not found",
+                    Some(json!({ "attempt_id": attempt_id })),
+                )
+            })?;
+
+        let req = crate::routes::task_attempts::AttemptPatchRequest {
+            paths: paths.clone(),
+            force,
+            max_bytes,
+        };
+        let ResponseJson(response) = crate::routes::task_attempts::get_task_attempt_patch(
+            axum::Extension(workspace),
+            axum::extract::State(self.deployment.clone()),
+            axum::Json(req),
+        )
+        .await
+        .map_err(|e| {
+            ErrorData::internal_error(
+                "Failed to compute attempt patch",
+                Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+            )
+        })?;
+
+        let patch =
+            response
+                .into_data()
+                .unwrap_or(crate::routes::task_attempts::AttemptPatchResponse {
+                    blocked: true,
+                    blocked_reason: Some(
+                        crate::routes::task_attempts::AttemptArtifactBlockedReason::SummaryFailed,
+                    ),
+                    truncated: false,
+                    bytes: 0,
+                    paths,
                     patch: None,
                 });
 
@@ -3636,6 +4730,49 @@ Avoid: Guessing attempt_id."#,
         })
     }
 
+    #[tool(
+        description = r#"Use when: See which approvals block an attempt right now.
+Required: attempt_id
+Optional: (none)
+Next: respond_approval
+Avoid: Paging approval history (use list_approvals)."#,
+        output_schema = tool_output_schema::<ListPendingApprovalsResponse>(),
+        annotations(read_only_hint = true)
+    )]
+    async fn list_pending_approvals(
+        &self,
+        Parameters(ListPendingApprovalsRequest { attempt_id }): Parameters<
+            ListPendingApprovalsRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let (mut approvals, _) = self
+            .deployment
+            .approvals()
+            .list_approvals_by_attempt(
+                &self.deployment.db().pool,
+                attempt_id,
+                Some("pending"),
+                200,
+                None,
+            )
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    "Failed to list pending approvals",
+                    Some(json!({ "error": e.to_string(), "attempt_id": attempt_id })),
+                )
+            })?;
+        approvals.reverse();
+
+        Self::success(&ListPendingApprovalsResponse {
+            attempt_id: attempt_id.to_string(),
+            approvals: approvals
+                .into_iter()
+                .map(Self::approval_to_summary)
+                .collect(),
+        })
+    }
+
     #[tool(
         description = r#"Use when: Fetch approval details to render a prompt.
 Required: approval_id
@@ -3667,10 +4804,10 @@ Avoid: Assuming approval exists."#,
 
     #[tool(
         description = r#"Use when: Respond to a pending approval (approve/deny).
-Required: approval_id, execution_process_id, status
-Optional: denial_reason, responded_by_client_id, request_id
+Required: approval_id, status
+Optional: attempt_id, execution_process_id, denial_reason, responded_by_client_id, request_id
 Next: tail_attempt_feed
-Avoid: Responding with mismatched execution_process_id."#,
+Avoid: Responding with mismatched attempt_id or execution_process_id."#,
         output_schema = tool_output_schema::<RespondApprovalResponse>(),
         annotations(
             read_only_hint = false,
@@ -3682,6 +4819,7 @@ Avoid: Responding with mismatched execution_process_id."#,
         &self,
         Parameters(RespondApprovalRequest {
             approval_id,
+            attempt_id,
             execution_process_id,
             status,
             denial_reason,
@@ -3689,7 +4827,12 @@ Avoid: Responding with mismatched execution_process_id."#,
             request_id,
         }): Parameters<RespondApprovalRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let status_trim = status.trim().to_string();
+        let status_trim = match status.trim() {
+            "approve" => "approved",
+            "deny" => "denied",
+            other => other,
+        }
+        .to_string();
         if !matches!(status_trim.as_str(), "approved" | "denied" | "timed_out") {
             return Self::err_with(
                 "Invalid status",
@@ -3700,6 +4843,46 @@ Avoid: Responding with mismatched execution_process_id."#,
             );
         }
 
+        let approval = match self
+            .deployment
+            .approvals()
+            .get_approval(&self.deployment.db().pool, &approval_id)
+            .await
+        {
+            Ok(approval) => approval,
+            Err(tasks::approvals::ApprovalError::NotFound) => {
+                return Self::err_with(
+                    "Approval not found",
+                    Some(json!({ "approval_id": approval_id })),
+                    Some("Use list_pending_approvals to find approval ids.".to_string()),
+                    Some("not_found"),
+                    Some(false),
+                );
+            }
+            Err(e) => {
+                return Err(ErrorData::internal_error(
+                    "Failed to load approval",
+                    Some(json!({ "error": e.to_string(), "approval_id": approval_id })),
+                ));
+            }
+        };
+        if let Some(attempt_id) = attempt_id
+            && approval.attempt_id != attempt_id
+        {
+            return Self::err_with(
+                "Approval does not belong to attempt",
+                Some(json!({
+                    "approval_id": approval_id,
+                    "attempt_id": attempt_id,
+                    "approval_attempt_id": approval.attempt_id,
+                })),
+                Some("Use list_pending_approvals(attempt_id) to find its approvals.".to_string()),
+                Some("invalid_argument"),
+                Some(false),
+            );
+        }
+        let execution_process_id = execution_process_id.unwrap_or(approval.execution_process_id);
+
         let responded_by_client_id = responded_by_client_id
             .and_then(|value| {
                 let trimmed = value.trim();
@@ -3789,6 +4972,91 @@ Avoid: Responding with mismatched execution_process_id."#,
         Self::success(&response)
     }
 
+    #[tool(
+        description = r#"Use when: Approve or deny every approval currently pending for an attempt.
+Required: attempt_id, decision
+Optional: denial_reason, responded_by_client_id
+Next: tail_attempt_feed
+Avoid: Blanket-approving without reviewing list_pending_approvals first."#,
+        output_schema = tool_output_schema::<RespondAllApprovalsResponse>(),
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = true
+        )
+    )]
+    async fn respond_all_approvals(
+        &self,
+        Parameters(RespondAllApprovalsRequest {
+            attempt_id,
+            decision,
+            denial_reason,
+            responded_by_client_id,
+        }): Parameters<RespondAllApprovalsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let decision = match decision.trim() {
+            "approved" | "approve" => crate::routes::task_attempts::ApprovalDecision::Approved,
+            "denied" | "deny" => crate::routes::task_attempts::ApprovalDecision::Denied,
+            _ => {
+                return Self::err_with(
+                    "Invalid decision",
+                    Some(json!({ "value": decision })),
+                    Some("Valid values: approved, denied.".to_string()),
+                    Some("invalid_argument"),
+                    Some(false),
+                );
+            }
+        };
+        let responded_by_client_id = responded_by_client_id
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .or_else(|| self.default_peer_client_id())
+            .or_else(|| Some("mcp:unknown".to_string()));
+
+        let response = match crate::routes::task_attempts::respond_to_pending_approvals(
+            &self.deployment,
+            attempt_id,
+            crate::routes::task_attempts::RespondAllApprovalsRequest {
+                decision,
+                denial_reason,
+            },
+            responded_by_client_id,
+        )
+        .await
+        {
+            Ok(response) => response,
+            Err(err) => {
+                return Self::tool_error_from_api_error(
+                    "respond_all_approvals",
+                    err,
+                    json!({ "attempt_id": attempt_id }),
+                );
+            }
+        };
+
+        Self::success(&RespondAllApprovalsResponse {
+            attempt_id: attempt_id.to_string(),
+            results: response
+                .results
+                .into_iter()
+                .map(|result| McpApprovalBatchResult {
+                    approval_id: result.approval_id,
+                    tool_name: result.tool_name,
+                    status: result.status.map(|status| {
+                        match status {
+                            utils_core::approvals::ApprovalStatus::Pending => "pending",
+                            utils_core::approvals::ApprovalStatus::Approved => "approved",
+                            utils_core::approvals::ApprovalStatus::Denied { .. } => "denied",
+                            utils_core::approvals::ApprovalStatus::TimedOut => "timed_out",
+                        }
+                        .to_string()
+                    }),
+                    error: result.error,
+                })
+                .collect(),
+        })
+    }
+
     #[tool(
         description = r#"Use when: Tail project activity events (incremental via after_event_id, or older paging via cursor).
 Required: project_id
@@ -4041,9 +5309,12 @@ mod tests {
         ServiceExt,
         handler::{client::ClientHandler, server::tool::IntoCallToolResult},
     };
-    use test_support::TestEnvGuard;
+    use test_support::{EnvVarGuard, TempRoot, TestEnvGuard};
 
     use super::*;
+    use crate::test_fixtures::{
+        create_attempt_with_worktree, create_pending_approval, create_running_attempt,
+    };
 
     #[derive(Clone)]
     struct TestElicitationClient {
@@ -4130,25 +5401,37 @@ mod tests {
             "archive_project_kanban",
             "claim_attempt_control",
             "cli_dependency_preflight",
+            "compare_attempts",
             "create_task",
             "delete_task",
+            "file_search",
             "get_approval",
             "get_attempt_changes",
             "get_attempt_control",
             "get_attempt_file",
             "get_attempt_patch",
+            "get_attempt_status",
+            "get_board",
             "get_review_handoff",
             "get_task",
             "list_archived_kanbans",
             "list_approvals",
             "list_executors",
+            "list_pending_approvals",
             "list_projects",
+            "list_repo_branches",
             "list_repos",
+            "list_sessions",
             "list_task_attempts",
             "list_tasks",
+            "ping",
+            "preview_tag_expansion",
             "release_attempt_control",
+            "respond_all_approvals",
             "respond_approval",
             "restore_archived_kanban",
+            "revert_attempt_file",
+            "search_attempt_content",
             "send_follow_up",
             "start_attempt",
             "stop_attempt",
@@ -4156,6 +5439,7 @@ mod tests {
             "tail_project_activity",
             "tail_session_messages",
             "tail_task_activity",
+            "transition_task",
             "update_task",
         ];
 
@@ -4198,6 +5482,7 @@ mod tests {
             "list_tasks",
             "tail_attempt_feed",
             "get_attempt_changes",
+            "get_attempt_status",
         ] {
             let annotations = tool(name)
                 .annotations
@@ -4261,9 +5546,11 @@ mod tests {
         };
 
         for name in [
+            "compare_attempts",
             "get_attempt_changes",
             "get_attempt_file",
             "get_attempt_patch",
+            "search_attempt_content",
             "start_attempt",
         ] {
             let execution = tool(name)
@@ -4362,6 +5649,39 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_root);
     }
 
+    #[tokio::test]
+    async fn server_info_honors_branding_overrides() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+        let deployment = DeploymentImpl::new().await.expect("deployment");
+        let server = TaskServer::new(deployment.clone());
+
+        let info = server.get_info();
+        assert_eq!(info.server_info.name, "vibe-kanban");
+        assert!(info.server_info.icons.is_none());
+
+        let mut env = EnvVarGuard::new();
+        env.set_var("VK_MCP_SERVER_NAME", "acme-board");
+        env.set_var("VK_MCP_SERVER_TITLE", "Acme Board MCP");
+        env.set_var("VK_MCP_SERVER_ICON_URL", "https://example.com/icon.png");
+        env.set_var("VK_MCP_SERVER_WEBSITE_URL", "  ");
+
+        let info = server.get_info();
+        assert_eq!(info.server_info.name, "acme-board");
+        assert_eq!(info.server_info.title.as_deref(), Some("Acme Board MCP"));
+        let icons = info.server_info.icons.expect("icons");
+        assert_eq!(icons.len(), 1);
+        assert_eq!(icons[0].src, "https://example.com/icon.png");
+        assert_eq!(
+            info.server_info.website_url.as_deref(),
+            Some("https://github.com/BloopAI/vibe-kanban")
+        );
+
+        drop(env);
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
+
     #[tokio::test]
     async fn list_projects_and_list_tasks_return_structured_content() {
         let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
@@ -4410,6 +5730,7 @@ mod tests {
                 project_id,
                 status: None,
                 limit: Some(10),
+                include_description: None,
             }))
             .await
             .into_call_tool_result()
@@ -4497,6 +5818,7 @@ mod tests {
                 project_id,
                 status: None,
                 limit: Some(50),
+                include_description: None,
             }))
             .await
             .into_call_tool_result()
@@ -4526,6 +5848,164 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn list_tasks_includes_descriptions_only_when_requested() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &db::models::project::CreateProject {
+                name: "Descriptions".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let short_task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(
+                project_id,
+                "Short".to_string(),
+                Some("Fix the login flow".to_string()),
+            ),
+            short_task_id,
+        )
+        .await
+        .unwrap();
+        let long_task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(
+                project_id,
+                "Long".to_string(),
+                Some("x".repeat(LIST_TASKS_DESCRIPTION_MAX_CHARS + 100)),
+            ),
+            long_task_id,
+        )
+        .await
+        .unwrap();
+
+        let server = TaskServer::new(deployment.clone());
+        let list = |include_description| {
+            server.list_tasks(Parameters(ListTasksRequest {
+                project_id,
+                status: None,
+                limit: None,
+                include_description,
+            }))
+        };
+
+        let Json(response) = list(None).await.unwrap();
+        assert!(response.tasks.iter().all(|task| task.description.is_none()));
+        assert!(!response.descriptions_truncated);
+
+        let Json(response) = list(Some(true)).await.unwrap();
+        let description_of = |id: Uuid| {
+            response
+                .tasks
+                .iter()
+                .find(|task| task.id == id.to_string())
+                .and_then(|task| task.description.clone())
+                .expect("description")
+        };
+        assert_eq!(description_of(short_task_id), "Fix the login flow");
+        assert_eq!(
+            description_of(long_task_id).chars().count(),
+            LIST_TASKS_DESCRIPTION_MAX_CHARS
+        );
+        assert!(response.descriptions_truncated);
+
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
+
+    #[tokio::test]
+    async fn get_board_buckets_match_task_statuses() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = deployment.db().pool.clone();
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            &pool,
+            &db::models::project::CreateProject {
+                name: "Board project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let statuses = [
+            TaskStatus::Todo,
+            TaskStatus::Todo,
+            TaskStatus::InProgress,
+            TaskStatus::InProgress,
+            TaskStatus::InProgress,
+            TaskStatus::Done,
+        ];
+        for (index, status) in statuses.into_iter().enumerate() {
+            let task_id = Uuid::new_v4();
+            Task::create(
+                &pool,
+                &CreateTask::from_title_description(project_id, format!("Task {index}"), None),
+                task_id,
+            )
+            .await
+            .unwrap();
+            Task::update_status(&pool, task_id, status).await.unwrap();
+        }
+
+        let server = TaskServer::new(deployment.clone());
+        let result = server
+            .get_board(Parameters(GetBoardRequest {
+                project_id,
+                in_progress_limit: Some(2),
+            }))
+            .await
+            .into_call_tool_result()
+            .unwrap();
+
+        let payload = result.structured_content.expect("structured content");
+        let response: GetBoardResponse = serde_json::from_value(payload).expect("board");
+
+        assert_eq!(response.total, statuses.len());
+        let counts = response
+            .buckets
+            .iter()
+            .map(|bucket| (bucket.status.as_str(), bucket.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            counts,
+            vec![
+                ("todo", 2),
+                ("inprogress", 3),
+                ("inreview", 0),
+                ("done", 1),
+                ("cancelled", 0),
+            ]
+        );
+        assert_eq!(response.in_progress.len(), 2);
+        assert!(
+            response
+                .in_progress
+                .iter()
+                .all(|task| task.status == "inprogress")
+        );
+    }
+
     #[tokio::test]
     async fn get_review_handoff_not_applicable_for_human_managed_task() {
         let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
@@ -4796,6 +6276,65 @@ mod tests {
         assert!(next_action_codes.contains(&McpReviewHandoffNextActionCode::TakeOver));
     }
 
+    #[tokio::test]
+    async fn start_attempt_rejects_unknown_reasoning_effort() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &db::models::project::CreateProject {
+                name: "Reasoning effort".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+        let task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(project_id, "Think hard".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let server = TaskServer::new(deployment.clone());
+        let result = server
+            .start_attempt(Parameters(StartAttemptRequest {
+                task_id,
+                executor: Some("CODEX".to_string()),
+                variant: None,
+                repos: vec![WorkspaceRepoInput {
+                    repo_id: Uuid::new_v4(),
+                    target_branch: "main".to_string(),
+                    create_from: None,
+                }],
+                request_id: None,
+                prompt: None,
+                labels: None,
+                reasoning_effort: Some("extreme".to_string()),
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let payload = result.structured_content.expect("structured content");
+        assert_eq!(payload["code"].as_str(), Some("invalid_argument"));
+        assert_eq!(
+            payload["details"]["valid_values"],
+            json!(["low", "medium", "high", "xhigh"])
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
+
     #[tokio::test]
     async fn start_attempt_rejects_archived_tasks_with_structured_error() {
         let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
@@ -4867,6 +6406,8 @@ mod tests {
                 repos: Vec::new(),
                 request_id: None,
                 prompt: None,
+                labels: None,
+                reasoning_effort: None,
             }))
             .await
             .into_call_tool_result()
@@ -4977,9 +6518,12 @@ mod tests {
                 repos: vec![WorkspaceRepoInput {
                     repo_id: Uuid::new_v4(),
                     target_branch: "main".to_string(),
+                    create_from: None,
                 }],
                 request_id: None,
                 prompt: None,
+                labels: None,
+                reasoning_effort: None,
             }))
             .await
             .into_call_tool_result()
@@ -5176,6 +6720,7 @@ mod tests {
             &[db::models::workspace_repo::CreateWorkspaceRepo {
                 repo_id: repo.id,
                 target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
             }],
         )
         .await
@@ -5199,6 +6744,8 @@ mod tests {
                 executor_profile_id: ExecutorProfileId::new(BaseCodingAgent::ClaudeCode),
                 working_dir: None,
                 image_paths: None,
+                model: None,
+                reasoning_effort: None,
             }),
             None,
         );
@@ -5408,6 +6955,7 @@ mod tests {
                 project_id,
                 status: Some("not-a-status".to_string()),
                 limit: None,
+                include_description: None,
             }))
             .await
             .err()
@@ -5512,30 +7060,110 @@ mod tests {
         std::fs::create_dir_all(&temp_root).unwrap();
         let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
 
-        let deployment = DeploymentImpl::new().await.unwrap();
-        let server = TaskServer::new(deployment);
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let server = TaskServer::new(deployment);
+
+        let attempt_id = Uuid::new_v4();
+        let result = server
+            .tail_attempt_feed(Parameters(TailAttemptFeedRequest {
+                attempt_id,
+                limit: Some(10),
+                cursor: None,
+                after_log_index: Some(0),
+                wait_ms: Some(TAIL_ATTEMPT_FEED_MAX_WAIT_MS + 1),
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let structured = result
+            .structured_content
+            .clone()
+            .expect("structured_content should be present");
+        assert_eq!(
+            structured["code"].as_str(),
+            Some(MCP_CODE_WAIT_MS_TOO_LARGE)
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
+
+    #[tokio::test]
+    async fn list_sessions_returns_all_sessions_newest_first() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = deployment.db().pool.clone();
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            &pool,
+            &db::models::project::CreateProject {
+                name: "Test project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let task_id = Uuid::new_v4();
+        Task::create(
+            &pool,
+            &CreateTask::from_title_description(project_id, "Test task".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
 
         let attempt_id = Uuid::new_v4();
-        let result = server
-            .tail_attempt_feed(Parameters(TailAttemptFeedRequest {
+        Workspace::create(
+            &pool,
+            &CreateWorkspace {
+                branch: "test-branch".to_string(),
+                agent_working_dir: None,
+            },
+            attempt_id,
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let mut created = Vec::new();
+        for executor in ["CLAUDE_CODE", "CODEX"] {
+            let session = Session::create(
+                &pool,
+                &CreateSession {
+                    executor: Some(executor.to_string()),
+                },
+                Uuid::new_v4(),
                 attempt_id,
-                limit: Some(10),
-                cursor: None,
-                after_log_index: Some(0),
-                wait_ms: Some(TAIL_ATTEMPT_FEED_MAX_WAIT_MS + 1),
-            }))
+            )
             .await
             .unwrap();
+            created.push(session.id.to_string());
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
 
-        assert_eq!(result.is_error, Some(true));
-        let structured = result
-            .structured_content
-            .clone()
-            .expect("structured_content should be present");
-        assert_eq!(
-            structured["code"].as_str(),
-            Some(MCP_CODE_WAIT_MS_TOO_LARGE)
-        );
+        let server = TaskServer::new(deployment.clone());
+        let result = server
+            .list_sessions(Parameters(ListSessionsRequest { attempt_id }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let response: ListSessionsResponse =
+            serde_json::from_value(result.structured_content.expect("structured content")).unwrap();
+        assert_eq!(response.count, 2);
+        let ids = response
+            .sessions
+            .iter()
+            .map(|session| session.session_id.clone())
+            .collect::<Vec<_>>();
+        created.reverse();
+        assert_eq!(ids, created);
+        assert_eq!(response.sessions[0].executor.as_deref(), Some("CODEX"));
 
         let _ = std::fs::remove_dir_all(&temp_root);
     }
@@ -5597,6 +7225,7 @@ mod tests {
             &[CreateWorkspaceRepo {
                 repo_id: repo.id,
                 target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
             }],
         )
         .await
@@ -5759,6 +7388,7 @@ mod tests {
             &[CreateWorkspaceRepo {
                 repo_id: repo.id,
                 target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
             }],
         )
         .await
@@ -6324,6 +7954,79 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_root);
     }
 
+    /// Creates a project, task, attempt, session and running execution process; returns the
+    /// `(attempt_id, execution_process_id)` pair approvals can be attached to.
+    #[tokio::test]
+    async fn respond_approval_approves_and_denies_pending_requests_of_attempt() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+        let (attempt, execution_process_id) = create_running_attempt(pool, "approvals").await;
+        let attempt_id = attempt.id;
+        let first = create_pending_approval(&deployment, execution_process_id, "call-1").await;
+        let second = create_pending_approval(&deployment, execution_process_id, "call-2").await;
+
+        let server = &TaskServer::new(deployment.clone());
+        let list_pending = move || async move {
+            let result = server
+                .list_pending_approvals(Parameters(ListPendingApprovalsRequest { attempt_id }))
+                .await
+                .unwrap();
+            assert_eq!(result.is_error, Some(false));
+            serde_json::from_value::<ListPendingApprovalsResponse>(
+                result.structured_content.expect("structured content"),
+            )
+            .unwrap()
+            .approvals
+            .into_iter()
+            .map(|approval| approval.approval_id)
+            .collect::<Vec<_>>()
+        };
+        let respond = move |approval_id: &str, attempt_id: Uuid, status: &str| {
+            server.respond_approval(Parameters(RespondApprovalRequest {
+                approval_id: approval_id.to_string(),
+                attempt_id: Some(attempt_id),
+                execution_process_id: None,
+                status: status.to_string(),
+                denial_reason: (status == "deny").then(|| "not on CI".to_string()),
+                responded_by_client_id: None,
+                request_id: None,
+            }))
+        };
+        assert_eq!(list_pending().await, vec![first.clone(), second.clone()]);
+
+        let result = respond(&first, Uuid::new_v4(), "approve").await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let payload = result.structured_content.expect("structured content");
+        assert_eq!(payload["code"].as_str(), Some("invalid_argument"));
+        assert_eq!(list_pending().await.len(), 2);
+
+        let result = respond(&first, attempt_id, "approve").await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let response: RespondApprovalResponse =
+            serde_json::from_value(result.structured_content.expect("structured content")).unwrap();
+        assert_eq!(response.status, "approved");
+        assert_eq!(list_pending().await, vec![second.clone()]);
+
+        let result = respond(&second, attempt_id, "deny").await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let persisted = approval_model::get_by_id(pool, Uuid::parse_str(&second).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            persisted.status,
+            utils_core::approvals::ApprovalStatus::Denied { reason: Some(ref reason) }
+                if reason == "not on CI"
+        ));
+        assert!(list_pending().await.is_empty());
+
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
+
     #[tokio::test]
     async fn create_task_follow_up_defaults_source_and_keeps_project_manual() {
         let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
@@ -6392,6 +8095,7 @@ mod tests {
                 project_id,
                 status: None,
                 limit: Some(20),
+                include_description: None,
             }))
             .await
             .unwrap();
@@ -6476,6 +8180,98 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_root);
     }
 
+    #[tokio::test]
+    async fn transition_task_applies_allowed_and_rejects_invalid_transitions() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &db::models::project::CreateProject {
+                name: "Transitions".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(project_id, "Move me".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let server = TaskServer::new(deployment.clone());
+        let result = server
+            .transition_task(Parameters(TransitionTaskRequest {
+                task_id,
+                to: "inprogress".to_string(),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let response: TransitionTaskResponse =
+            serde_json::from_value(result.structured_content.expect("structured content")).unwrap();
+        assert_eq!(response.from, "todo");
+        assert_eq!(response.to, "inprogress");
+        assert!(response.changed);
+
+        Task::update_status(pool, task_id, TaskStatus::Cancelled)
+            .await
+            .unwrap();
+        let result = server
+            .transition_task(Parameters(TransitionTaskRequest {
+                task_id,
+                to: "inprogress".to_string(),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let payload = result.structured_content.expect("structured content");
+        assert_eq!(payload["code"].as_str(), Some("invalid_transition"));
+        assert_eq!(payload["details"]["allowed"], json!(["todo"]));
+
+        let task = Task::find_by_id(pool, task_id).await.unwrap().unwrap();
+        assert_eq!(task.status, TaskStatus::Cancelled);
+
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
+
+    #[tokio::test]
+    async fn ping_reports_database_reachability() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let server = TaskServer::new(deployment.clone());
+
+        let result = server.ping().await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let response: PingResponse =
+            serde_json::from_value(result.structured_content.expect("structured content")).unwrap();
+        assert!(response.reachable);
+        assert_eq!(response.version, env!("CARGO_PKG_VERSION"));
+
+        deployment.db().pool.clone().close().await.unwrap();
+        let result = server.ping().await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let payload = result.structured_content.expect("structured content");
+        assert_eq!(payload["code"].as_str(), Some("database_unreachable"));
+        assert_eq!(payload["retryable"].as_bool(), Some(true));
+
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
+
     #[tokio::test]
     async fn update_task_status_is_persisted() {
         let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
@@ -6517,6 +8313,8 @@ mod tests {
                 title: None,
                 description: None,
                 status: Some("done".to_string()),
+                expected_updated_at: None,
+                request_id: None,
             }))
             .await
             .unwrap();
@@ -6539,6 +8337,105 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_root);
     }
 
+    #[tokio::test]
+    async fn delete_task_retry_with_same_request_id_replays_original_result() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &db::models::project::CreateProject {
+                name: "Delete retries".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(project_id, "Doomed".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let server = TaskServer::new(deployment.clone());
+        let mut payloads = Vec::new();
+        for _ in 0..2 {
+            let result = server
+                .delete_task(Parameters(DeleteTaskRequest {
+                    task_id,
+                    request_id: Some("delete-once".to_string()),
+                }))
+                .await
+                .unwrap();
+            assert_eq!(result.is_error, Some(false));
+            let payload: serde_json::Value =
+                serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+            payloads.push(payload);
+        }
+
+        let expected_task_id = task_id.to_string();
+        assert_eq!(
+            payloads[0]["deleted_task_id"].as_str(),
+            Some(expected_task_id.as_str())
+        );
+        assert_eq!(payloads[0], payloads[1]);
+        assert!(Task::find_by_id(pool, task_id).await.unwrap().is_none());
+
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
+
+    #[tokio::test]
+    async fn preview_tag_expansion_reports_resolved_and_unresolved_tags() {
+        let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_root).unwrap();
+        let _guard = TestEnvGuard::new(&temp_root, "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        for (tag_name, content) in [("outer", "Do @inner."), ("inner", "the thing")] {
+            Tag::create(
+                pool,
+                &db::models::tag::CreateTag {
+                    tag_name: tag_name.to_string(),
+                    content: content.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        let server = TaskServer::new(deployment.clone());
+        let result = server
+            .preview_tag_expansion(Parameters(PreviewTagExpansionRequest {
+                text: "@outer then @missing".to_string(),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(
+            payload["expanded_text"].as_str(),
+            Some("Do the thing. then @missing")
+        );
+        assert_eq!(payload["resolved_tags"], json!(["inner", "outer"]));
+        assert_eq!(payload["unresolved_tags"], json!(["missing"]));
+
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
+
     #[tokio::test]
     async fn create_task_idempotency_conflict_is_structured_tool_error() {
         let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
@@ -6608,6 +8505,84 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_root);
     }
 
+    #[tokio::test]
+    async fn file_search_returns_ranked_repo_prefixed_paths_without_ignored_files() {
+        let temp_root = TempRoot::new("vk-mcp-test-");
+        let _guard = TestEnvGuard::new(temp_root.path(), "sqlite::memory:".to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let attempt = create_attempt_with_worktree(
+            &deployment,
+            &temp_root,
+            "repo",
+            &[
+                (".gitignore", "target/\n"),
+                ("src/lib.rs", ""),
+                ("src/search/index.rs", ""),
+                ("docs/search.md", ""),
+            ],
+        )
+        .await;
+        std::fs::create_dir_all(attempt.worktree.join("target")).unwrap();
+        std::fs::write(attempt.worktree.join("target/search.log"), "").unwrap();
+        let attempt_id = attempt.workspace.id;
+
+        let server = TaskServer::new(deployment.clone());
+        let result = server
+            .file_search(Parameters(FileSearchRequest {
+                attempt_id,
+                query: "search".to_string(),
+                limit: None,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let response: FileSearchResponse =
+            serde_json::from_value(result.structured_content.expect("structured content")).unwrap();
+        let found = response
+            .results
+            .iter()
+            .map(|result| (result.path.as_str(), result.match_type.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                ("repo/docs/search.md", "file_name"),
+                ("repo/src/search", "file_name"),
+                ("repo/src/search/index.rs", "directory_name"),
+            ]
+        );
+        assert_eq!(response.count, 3);
+
+        let result = server
+            .file_search(Parameters(FileSearchRequest {
+                attempt_id,
+                query: "search".to_string(),
+                limit: Some(1),
+            }))
+            .await
+            .unwrap();
+        let response: FileSearchResponse =
+            serde_json::from_value(result.structured_content.expect("structured content")).unwrap();
+        assert_eq!(response.count, 1);
+        assert_eq!(response.results[0].path, "repo/docs/search.md");
+
+        // A missing worktree is reported, not recreated.
+        attempt.cleanup().await;
+        let result = server
+            .file_search(Parameters(FileSearchRequest {
+                attempt_id,
+                query: "search".to_string(),
+                limit: None,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let payload = result.structured_content.expect("structured content");
+        assert_eq!(payload["code"].as_str(), Some("not_found"));
+        assert!(!attempt.workspace_dir.exists());
+    }
+
     #[tokio::test]
     async fn get_attempt_changes_guardrails_blocked_is_structured_tool_error() {
         let temp_root = std::env::temp_dir().join(format!("vk-mcp-test-{}", Uuid::new_v4()));
@@ -6751,6 +8726,7 @@ mod tests {
             &[CreateWorkspaceRepo {
                 repo_id: repo.id,
                 target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
             }],
         )
         .await
@@ -0,0 +1,98 @@
+use super::*;
+
+/// Status changes `transition_task` allows when `task_status_transitions` is not configured.
+const DEFAULT_TASK_STATUS_TRANSITIONS: &[(TaskStatus, &[TaskStatus])] = &[
+    (
+        TaskStatus::Todo,
+        &[TaskStatus::InProgress, TaskStatus::Cancelled],
+    ),
+    (
+        TaskStatus::InProgress,
+        &[
+            TaskStatus::Todo,
+            TaskStatus::InReview,
+            TaskStatus::Done,
+            TaskStatus::Cancelled,
+        ],
+    ),
+    (
+        TaskStatus::InReview,
+        &[
+            TaskStatus::InProgress,
+            TaskStatus::Done,
+            TaskStatus::Cancelled,
+        ],
+    ),
+    (TaskStatus::Done, &[TaskStatus::Todo]),
+    (TaskStatus::Cancelled, &[TaskStatus::Todo]),
+];
+
+/// Statuses a task in `from` may move to. A non-empty `configured` matrix replaces the default
+/// entirely; entries naming unknown statuses are ignored.
+pub(super) fn allowed_task_transitions(
+    configured: &HashMap<String, Vec<String>>,
+    from: &TaskStatus,
+) -> Vec<TaskStatus> {
+    if configured.is_empty() {
+        return DEFAULT_TASK_STATUS_TRANSITIONS
+            .iter()
+            .find(|(status, _)| status == from)
+            .map(|(_, targets)| targets.to_vec())
+            .unwrap_or_default();
+    }
+
+    let mut allowed = Vec::new();
+    for (source, targets) in configured {
+        match TaskStatus::from_str(source.trim()) {
+            Ok(status) if &status == from => {}
+            Ok(_) => continue,
+            Err(_) => {
+                tracing::warn!("Ignoring unknown status '{source}' in task_status_transitions");
+                continue;
+            }
+        }
+        for target in targets {
+            match TaskStatus::from_str(target.trim()) {
+                Ok(status) if !allowed.contains(&status) => allowed.push(status),
+                Ok(_) => {}
+                Err(_) => tracing::warn!(
+                    "Ignoring unknown status '{target}' in task_status_transitions.{source}"
+                ),
+            }
+        }
+    }
+    allowed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_matrix_blocks_reviving_cancelled_tasks() {
+        let configured = HashMap::new();
+
+        assert!(
+            allowed_task_transitions(&configured, &TaskStatus::Todo)
+                .contains(&TaskStatus::InProgress)
+        );
+        assert_eq!(
+            allowed_task_transitions(&configured, &TaskStatus::Cancelled),
+            vec![TaskStatus::Todo]
+        );
+    }
+
+    #[test]
+    fn configured_matrix_replaces_default() {
+        let configured = HashMap::from([(
+            "cancelled".to_string(),
+            vec!["inprogress".to_string(), "bogus".to_string()],
+        )]);
+
+        assert_eq!(
+            allowed_task_transitions(&configured, &TaskStatus::Cancelled),
+            vec![TaskStatus::InProgress]
+        );
+        assert!(allowed_task_transitions(&configured, &TaskStatus::Todo).is_empty());
+    }
+}
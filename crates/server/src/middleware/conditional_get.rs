@@ -0,0 +1,125 @@
+use axum::{
+    body::{Body, HttpBody, to_bytes},
+    extract::Request,
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+
+/// Bodies larger than this, or of unknown length, are passed through without an ETag.
+const MAX_ETAG_BODY_BYTES: usize = 4 * 1024 * 1024;
+
+fn etag_for(body: &[u8]) -> String {
+    format!("\"{:x}\"", Sha256::digest(body))
+}
+
+/// True when `If-None-Match` lists `etag` (weak or strong) or is `*`.
+fn if_none_match_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
+/// Adds a content-hash `ETag` to successful GET responses and answers `304 Not Modified` when
+/// the client already holds that representation. Meant for small JSON reads that clients poll.
+pub async fn etag_middleware(request: Request, next: Next) -> Response {
+    if !matches!(*request.method(), Method::GET | Method::HEAD) {
+        return next.run(request).await;
+    }
+
+    let request_headers = request.headers().clone();
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let fits = response
+        .body()
+        .size_hint()
+        .upper()
+        .is_some_and(|len| len <= MAX_ETAG_BODY_BYTES as u64);
+    if !fits {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_ETAG_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            tracing::warn!(error = %err, "Failed to buffer response for ETag");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let etag = etag_for(&bytes);
+    let Ok(etag_value) = HeaderValue::from_str(&etag) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    if if_none_match_matches(&request_headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag_value)]).into_response();
+    }
+
+    parts.headers.insert(header::ETAG, etag_value);
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{HeaderMap, HeaderValue, Request, StatusCode, header},
+        middleware::from_fn,
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    use super::{MAX_ETAG_BODY_BYTES, etag_for, etag_middleware, if_none_match_matches};
+
+    #[tokio::test]
+    async fn oversized_bodies_pass_through_without_etag() {
+        let app = Router::new()
+            .route("/small", get(|| async { "{}" }))
+            .route(
+                "/large",
+                get(|| async { "x".repeat(MAX_ETAG_BODY_BYTES + 1) }),
+            )
+            .layer(from_fn(etag_middleware));
+        let request = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let response = app.clone().oneshot(request("/small")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key(header::ETAG));
+
+        let response = app.oneshot(request("/large")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!response.headers().contains_key(header::ETAG));
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body.len(), MAX_ETAG_BODY_BYTES + 1);
+    }
+
+    #[test]
+    fn if_none_match_accepts_lists_weak_tags_and_wildcard() {
+        let etag = etag_for(b"{}");
+        let headers_with = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::IF_NONE_MATCH, HeaderValue::from_str(value).unwrap());
+            headers
+        };
+
+        assert!(if_none_match_matches(&headers_with(&etag), &etag));
+        assert!(if_none_match_matches(
+            &headers_with(&format!("\"other\", W/{etag}")),
+            &etag
+        ));
+        assert!(if_none_match_matches(&headers_with("*"), &etag));
+        assert!(!if_none_match_matches(&headers_with("\"other\""), &etag));
+        assert!(!if_none_match_matches(&HeaderMap::new(), &etag));
+    }
+}
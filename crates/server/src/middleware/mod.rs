@@ -1,3 +1,4 @@
+pub mod conditional_get;
 pub mod model_loaders;
 
 pub use model_loaders::*;
@@ -0,0 +1,79 @@
+use config::ProxyConfig;
+
+/// Builds a `reqwest::Client` that routes outbound requests through the configured proxy. Without
+/// any configured proxy, reqwest's default behaviour (system proxy env vars) applies.
+pub fn build_http_client(proxy: &ProxyConfig) -> Result<reqwest::Client, reqwest::Error> {
+    let no_proxy = proxy
+        .no_proxy
+        .as_deref()
+        .and_then(reqwest::NoProxy::from_string);
+
+    let mut builder = reqwest::Client::builder();
+    if let Some(url) = proxy.http_proxy.as_deref() {
+        builder = builder.proxy(reqwest::Proxy::http(url)?.no_proxy(no_proxy.clone()));
+    }
+    if let Some(url) = proxy.https_proxy.as_deref() {
+        builder = builder.proxy(reqwest::Proxy::https(url)?.no_proxy(no_proxy));
+    }
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn client_sends_requests_through_configured_http_proxy() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_addr = listener.local_addr().unwrap();
+        let proxy_task = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\ncontent-length: 7\r\nconnection: close\r\n\r\nproxied",
+                )
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+
+        let client = build_http_client(&ProxyConfig {
+            http_proxy: Some(format!("http://{proxy_addr}")),
+            https_proxy: None,
+            no_proxy: None,
+        })
+        .unwrap();
+        let body = client
+            .get("http://vk-proxy-test.invalid/ping")
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+
+        assert_eq!(body, "proxied");
+        let request = proxy_task.await.unwrap();
+        assert!(
+            request.starts_with("GET http://vk-proxy-test.invalid/ping HTTP/1.1"),
+            "unexpected proxied request: {request}"
+        );
+    }
+
+    #[test]
+    fn invalid_proxy_url_is_rejected() {
+        let result = build_http_client(&ProxyConfig {
+            http_proxy: Some("not a url".to_string()),
+            https_proxy: None,
+            no_proxy: None,
+        });
+        assert!(result.is_err());
+    }
+}
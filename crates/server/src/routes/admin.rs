@@ -0,0 +1,382 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::LazyLock,
+    time::{Duration, Instant},
+};
+
+use app_runtime::Deployment;
+use axum::{
+    Router,
+    extract::{Query, State},
+    response::Json as ResponseJson,
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use db::models::{
+    event_outbox::{EventOutbox, EventOutboxEntry},
+    execution_process_log_entries::ExecutionProcessLogEntry,
+    execution_process_logs::ExecutionProcessLogs,
+    project::Project,
+    task::Task,
+    workspace::Workspace,
+};
+use repos::workspace_manager::directory_size_bytes;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::Mutex;
+use utils_core::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+const DEFAULT_EVENTS_PAGE_LIMIT: u64 = 50;
+const REDACTED: &str = "<redacted>";
+/// Walking every worktree is expensive, so a computed report is reused for this long.
+const DISK_USAGE_CACHE_TTL: Duration = Duration::from_secs(300);
+
+static DISK_USAGE_CACHE: LazyLock<Mutex<Option<(Instant, DiskUsageReport)>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+#[derive(Debug, Deserialize)]
+pub struct AdminEventsQuery {
+    /// Outbox row id to continue after (exclusive); omit to start from the oldest row.
+    pub after: Option<i64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdminOutboxEvent {
+    pub id: i64,
+    pub uuid: Uuid,
+    pub event_type: String,
+    pub entity_type: String,
+    pub entity_uuid: Uuid,
+    pub payload: Value,
+    pub created_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdminEventsPage {
+    pub events: Vec<AdminOutboxEvent>,
+    /// Pass as `after` to fetch the next page.
+    pub next_cursor: Option<i64>,
+    pub has_more: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DiskUsageQuery {
+    /// Recompute instead of serving a cached report.
+    pub refresh: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AttemptDiskUsage {
+    pub attempt_id: Uuid,
+    pub task_id: Uuid,
+    pub container_ref: String,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectDiskUsage {
+    pub project_id: Uuid,
+    pub project_name: Option<String>,
+    /// Sum of the project's attempt worktrees.
+    pub bytes: u64,
+    pub attempts: Vec<AttemptDiskUsage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskUsageReport {
+    pub computed_at: DateTime<Utc>,
+    pub total_bytes: u64,
+    pub worktree_bytes: u64,
+    pub database_bytes: u64,
+    /// Execution logs stored in the database; already included in `database_bytes`.
+    pub log_bytes: u64,
+    pub projects: Vec<ProjectDiskUsage>,
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let lowered = key.to_ascii_lowercase();
+    [
+        "token",
+        "secret",
+        "password",
+        "api_key",
+        "apikey",
+        "authorization",
+        "private_key",
+    ]
+    .iter()
+    .any(|needle| lowered.contains(needle))
+}
+
+fn redact_sensitive_fields(value: &mut Value) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(redact_sensitive_fields),
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive_key(key) {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact_sensitive_fields(value);
+                }
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {}
+    }
+}
+
+impl From<EventOutboxEntry> for AdminOutboxEvent {
+    fn from(entry: EventOutboxEntry) -> Self {
+        let mut payload = entry.payload;
+        redact_sensitive_fields(&mut payload);
+        Self {
+            id: entry.id,
+            uuid: entry.uuid,
+            event_type: entry.event_type,
+            entity_type: entry.entity_type,
+            entity_uuid: entry.entity_uuid,
+            payload,
+            created_at: entry.created_at,
+            published_at: entry.published_at,
+            attempts: entry.attempts,
+            last_error: entry.last_error,
+        }
+    }
+}
+
+pub async fn list_outbox_events(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<AdminEventsQuery>,
+) -> Result<ResponseJson<ApiResponse<AdminEventsPage>>, ApiError> {
+    let limit = query.limit.unwrap_or(DEFAULT_EVENTS_PAGE_LIMIT);
+    let (entries, next_cursor, has_more) =
+        EventOutbox::page_after(&deployment.db().pool, query.after, limit).await?;
+
+    Ok(ResponseJson(ApiResponse::success(AdminEventsPage {
+        events: entries.into_iter().map(AdminOutboxEvent::from).collect(),
+        next_cursor,
+        has_more,
+    })))
+}
+
+async fn compute_disk_usage(deployment: &DeploymentImpl) -> Result<DiskUsageReport, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let project_names: HashMap<Uuid, String> = Project::find_all(pool)
+        .await?
+        .into_iter()
+        .map(|project| (project.id, project.name))
+        .collect();
+
+    let mut task_projects: HashMap<Uuid, Uuid> = HashMap::new();
+    let mut worktrees: Vec<(Uuid, AttemptDiskUsage)> = Vec::new();
+    for workspace in Workspace::fetch_all(pool, None).await? {
+        let Some(container_ref) = workspace.container_ref else {
+            continue;
+        };
+        let project_id = match task_projects.get(&workspace.task_id) {
+            Some(project_id) => *project_id,
+            None => {
+                let Some(task) =
+                    Task::find_by_id_including_deleted(pool, workspace.task_id).await?
+                else {
+                    continue;
+                };
+                task_projects.insert(workspace.task_id, task.project_id);
+                task.project_id
+            }
+        };
+        worktrees.push((
+            project_id,
+            AttemptDiskUsage {
+                attempt_id: workspace.id,
+                task_id: workspace.task_id,
+                container_ref,
+                bytes: 0,
+            },
+        ));
+    }
+
+    let worktrees = tokio::task::spawn_blocking(move || {
+        for (_, attempt) in worktrees.iter_mut() {
+            attempt.bytes = directory_size_bytes(&PathBuf::from(&attempt.container_ref));
+        }
+        worktrees
+    })
+    .await
+    .map_err(|err| ApiError::Internal(format!("Failed to measure worktrees: {err}")))?;
+
+    let mut projects: HashMap<Uuid, ProjectDiskUsage> = HashMap::new();
+    for (project_id, attempt) in worktrees {
+        let usage = projects
+            .entry(project_id)
+            .or_insert_with(|| ProjectDiskUsage {
+                project_id,
+                project_name: project_names.get(&project_id).cloned(),
+                bytes: 0,
+                attempts: Vec::new(),
+            });
+        usage.bytes += attempt.bytes;
+        usage.attempts.push(attempt);
+    }
+    let mut projects: Vec<ProjectDiskUsage> = projects.into_values().collect();
+    for project in &mut projects {
+        project.attempts.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+    }
+    projects.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+
+    let worktree_bytes = projects.iter().map(|project| project.bytes).sum::<u64>();
+    let database_bytes = deployment.db().size_bytes().await?;
+    let log_bytes = ExecutionProcessLogs::total_bytes(pool).await?
+        + ExecutionProcessLogEntry::total_json_bytes(pool).await?;
+
+    Ok(DiskUsageReport {
+        computed_at: Utc::now(),
+        total_bytes: worktree_bytes + database_bytes,
+        worktree_bytes,
+        database_bytes,
+        log_bytes: u64::try_from(log_bytes).unwrap_or(0),
+        projects,
+    })
+}
+
+pub async fn get_disk_usage(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<DiskUsageQuery>,
+) -> Result<ResponseJson<ApiResponse<DiskUsageReport>>, ApiError> {
+    let mut cache = DISK_USAGE_CACHE.lock().await;
+    if !query.refresh.unwrap_or(false)
+        && let Some((computed_at, report)) = cache.as_ref()
+        && computed_at.elapsed() < DISK_USAGE_CACHE_TTL
+    {
+        return Ok(ResponseJson(ApiResponse::success(report.clone())));
+    }
+
+    let report = compute_disk_usage(&deployment).await?;
+    *cache = Some((Instant::now(), report.clone()));
+    Ok(ResponseJson(ApiResponse::success(report)))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/admin/events", get(list_outbox_events))
+        .route("/admin/disk-usage", get(get_disk_usage))
+}
+
+#[cfg(test)]
+mod tests {
+    use app_runtime::Deployment;
+    use axum::extract::{Query, State};
+    use db::models::{event_outbox::EventOutbox, task::Task};
+    use test_support::{TempRoot, TestDb, TestEnv, TestEnvGuard};
+    use uuid::Uuid;
+
+    use super::{AdminEventsQuery, DiskUsageQuery, get_disk_usage, list_outbox_events};
+    use crate::{DeploymentImpl, test_fixtures::create_attempt_with_worktree};
+
+    #[tokio::test]
+    async fn list_outbox_events_pages_with_cursor_and_redacts_secrets() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        for value in 0..3 {
+            EventOutbox::enqueue(
+                pool,
+                "admin.test",
+                "test",
+                Uuid::new_v4(),
+                serde_json::json!({
+                    "value": value,
+                    "nested": { "api_token": "s3cr3t" },
+                }),
+            )
+            .await
+            .unwrap();
+        }
+
+        let mut seen = Vec::new();
+        let mut after = None;
+        loop {
+            let response = list_outbox_events(
+                State(deployment.clone()),
+                Query(AdminEventsQuery {
+                    after,
+                    limit: Some(2),
+                }),
+            )
+            .await
+            .unwrap();
+            let page = response.0.into_data().expect("page");
+            for event in &page.events {
+                if event.event_type == "admin.test" {
+                    assert_eq!(event.payload["nested"]["api_token"], "<redacted>");
+                    seen.push(event.payload["value"].as_i64().unwrap());
+                }
+            }
+            after = page.next_cursor;
+            if !page.has_more {
+                break;
+            }
+        }
+
+        assert_eq!(seen, vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn disk_usage_reports_attempt_worktree_bytes_per_project() {
+        let env = TestEnv::new("vk-test-");
+        let temp_root = env.temp_root();
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let attempt = create_attempt_with_worktree(&deployment, temp_root, "disk-repo", &[]).await;
+        let (project_id, task_id) = (attempt.project_id, attempt.task_id);
+
+        // A soft-deleted task keeps its worktree until it is purged, so it still counts.
+        Task::soft_delete(pool, task_id).await.unwrap();
+
+        let response = get_disk_usage(
+            State(deployment.clone()),
+            Query(DiskUsageQuery {
+                refresh: Some(true),
+            }),
+        )
+        .await
+        .unwrap();
+        let report = response.0.into_data().expect("report");
+
+        assert!(report.database_bytes > 0);
+        assert_eq!(
+            report.total_bytes,
+            report.worktree_bytes + report.database_bytes
+        );
+        let project = report
+            .projects
+            .iter()
+            .find(|project| project.project_id == project_id)
+            .expect("project usage");
+        assert_eq!(
+            project.project_name.as_deref(),
+            Some(attempt.project_name.as_str())
+        );
+        assert_eq!(project.attempts.len(), 1);
+        let usage = &project.attempts[0];
+        assert_eq!(usage.attempt_id, attempt.workspace.id);
+        assert_eq!(usage.task_id, task_id);
+        assert!(usage.bytes > 0, "worktree should contribute usage");
+        assert_eq!(project.bytes, usage.bytes);
+        assert!(report.worktree_bytes >= usage.bytes);
+
+        attempt.cleanup().await;
+    }
+}
@@ -4,24 +4,27 @@ use anyhow;
 use app_runtime::Deployment;
 use axum::{
     Extension, Router,
+    body::Body,
     extract::{
         Path, Query, State,
         ws::{CloseCode, CloseFrame, Message, Utf8Bytes, WebSocket, WebSocketUpgrade},
     },
     http::{HeaderMap, header},
     middleware::from_fn_with_state,
-    response::{IntoResponse, Json as ResponseJson},
+    response::{IntoResponse, Json as ResponseJson, Response},
     routing::{get, post},
 };
 use db::models::{
     execution_process::{ExecutionProcess, ExecutionProcessPublic, ExecutionProcessStatus},
+    execution_process_log_entries::ExecutionProcessLogEntry,
     execution_process_repo_state::ExecutionProcessRepoState,
 };
 use execution::container::ContainerService;
-use executors::logs::utils::patch::PatchType;
+use executors::logs::{NormalizedEntryType, utils::patch::PatchType};
+use executors_protocol::actions::ExecutorActionType;
 use futures_util::{SinkExt, StreamExt, TryStreamExt};
 use logs_axum::SequencedLogMsgAxumExt;
-use logs_store::LogEntryEvent;
+use logs_store::{LogEntryEvent, LogEntrySnapshot};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utils_core::{log_entries::LogEntryChannel, response::ApiResponse};
@@ -32,6 +35,22 @@ use crate::{DeploymentImpl, error::ApiError, middleware::load_execution_process_
 const DEFAULT_NORMALIZED_HISTORY_PAGE_SIZE: usize = 20;
 const DEFAULT_RAW_HISTORY_PAGE_SIZE: usize = 200;
 const MAX_HISTORY_PAGE_SIZE: usize = 1000;
+/// Upper bound on entries examined for one `types`-filtered page; the page then ends early and
+/// `next_cursor` resumes the scan.
+const MAX_FILTERED_SCAN_ENTRIES: usize = 10 * MAX_HISTORY_PAGE_SIZE;
+/// `type` tags of [`NormalizedEntryType`] accepted by the `types` filter.
+const NORMALIZED_ENTRY_TYPES: &[&str] = &[
+    "user_message",
+    "user_feedback",
+    "assistant_message",
+    "tool_use",
+    "system_message",
+    "error_message",
+    "thinking",
+    "loading",
+    "next_action",
+];
+const MAX_LOG_WAIT_MS: u64 = 30_000;
 const WS_PING_INTERVAL: Duration = Duration::from_secs(30);
 
 #[derive(Debug, Deserialize)]
@@ -47,6 +66,15 @@ pub struct ExecutionProcessQuery {
 pub struct LogHistoryQuery {
     pub limit: Option<usize>,
     pub cursor: Option<i64>,
+    /// Return only entries with `entry_index` greater than this, oldest first. Mutually
+    /// exclusive with `cursor`; pass the last returned `entry_index` to keep tailing.
+    pub after: Option<i64>,
+    /// With `after`, block up to this long (capped at 30s) for a new entry when none is
+    /// available yet.
+    pub wait_ms: Option<u64>,
+    /// Comma-separated normalized entry types (e.g. `tool_use,error_message`); only matching
+    /// entries are returned. Normalized logs only, and not combinable with `after`.
+    pub types: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, TS)]
@@ -63,6 +91,33 @@ pub struct LogHistoryPage {
     pub history_truncated: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, TS)]
+pub struct RenormalizeLogsResponse {
+    /// Number of normalized entries written.
+    pub entries: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogExportFormat {
+    #[default]
+    Md,
+    Json,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LogExportQuery {
+    #[serde(default)]
+    pub format: LogExportFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct RawLogExportLine<'a> {
+    entry_index: i64,
+    stream: &'static str,
+    content: &'a str,
+}
+
 #[derive(Debug, Serialize, Deserialize, TS)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum LogStreamEvent {
@@ -159,13 +214,255 @@ async fn build_log_history_page(
         .unwrap_or(default_limit)
         .clamp(1, MAX_HISTORY_PAGE_SIZE);
 
+    if query.cursor.is_some() && query.after.is_some() {
+        return Err(ApiError::BadRequest(
+            "cursor and after are mutually exclusive".to_string(),
+        ));
+    }
+    let entry_types = query
+        .types
+        .as_deref()
+        .map(parse_entry_type_filter)
+        .transpose()?;
+    if entry_types.is_some() {
+        if channel == LogEntryChannel::Raw {
+            return Err(ApiError::BadRequest(
+                "types is only supported for normalized logs".to_string(),
+            ));
+        }
+        if query.after.is_some() {
+            return Err(ApiError::BadRequest(
+                "types cannot be combined with after".to_string(),
+            ));
+        }
+    }
+    if let Some(after) = query.after {
+        let wait = Duration::from_millis(query.wait_ms.unwrap_or(0).min(MAX_LOG_WAIT_MS));
+        return build_log_page_after(deployment, execution_process, channel, limit, after, wait)
+            .await;
+    }
+    if query.wait_ms.is_some() {
+        return Err(ApiError::BadRequest(
+            "wait_ms is only supported together with after".to_string(),
+        ));
+    }
+
+    if let Some(entry_types) = entry_types {
+        return build_filtered_log_history_page(
+            deployment,
+            execution_process,
+            limit,
+            query.cursor,
+            &entry_types,
+        )
+        .await;
+    }
+
     let page = deployment
         .container()
         .log_history_page(execution_process, channel, limit, query.cursor)
         .await?;
 
-    let entries = page
-        .entries
+    let entries = decode_log_entries(execution_process, page.entries);
+    let next_cursor = entries.first().map(|entry| entry.entry_index);
+
+    Ok(LogHistoryPage {
+        entries,
+        next_cursor,
+        has_more: page.has_more,
+        history_truncated: page.history_truncated,
+    })
+}
+
+fn parse_entry_type_filter(raw: &str) -> Result<Vec<&'static str>, ApiError> {
+    let mut types = Vec::new();
+    for name in raw
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        let Some(known) = NORMALIZED_ENTRY_TYPES.iter().find(|known| **known == name) else {
+            return Err(ApiError::BadRequest(format!(
+                "unknown normalized entry type '{name}'"
+            )));
+        };
+        types.push(*known);
+    }
+    if types.is_empty() {
+        return Err(ApiError::BadRequest("types must not be empty".to_string()));
+    }
+    Ok(types)
+}
+
+fn normalized_entry_type_name(entry_type: &NormalizedEntryType) -> &'static str {
+    match entry_type {
+        NormalizedEntryType::UserMessage => "user_message",
+        NormalizedEntryType::UserFeedback { .. } => "user_feedback",
+        NormalizedEntryType::AssistantMessage => "assistant_message",
+        NormalizedEntryType::ToolUse { .. } => "tool_use",
+        NormalizedEntryType::SystemMessage => "system_message",
+        NormalizedEntryType::ErrorMessage { .. } => "error_message",
+        NormalizedEntryType::Thinking => "thinking",
+        NormalizedEntryType::Loading => "loading",
+        NormalizedEntryType::NextAction { .. } => "next_action",
+    }
+}
+
+/// Newest `limit` normalized entries of the given types older than `cursor`.
+///
+/// History is scanned backwards in full-size batches until the page fills, history runs out or
+/// [`MAX_FILTERED_SCAN_ENTRIES`] have been examined. `next_cursor` is the oldest entry covered by
+/// the response (not necessarily a returned one), so passing it back continues the scan without
+/// skipping or repeating matches.
+async fn build_filtered_log_history_page(
+    deployment: &DeploymentImpl,
+    execution_process: &ExecutionProcess,
+    limit: usize,
+    cursor: Option<i64>,
+    entry_types: &[&str],
+) -> Result<LogHistoryPage, ApiError> {
+    let mut batches = Vec::new();
+    let mut matched = 0usize;
+    let mut scanned = 0usize;
+    let mut scan_cursor = cursor;
+    let mut history_truncated = false;
+
+    let has_more = loop {
+        let page = deployment
+            .container()
+            .log_history_page(
+                execution_process,
+                LogEntryChannel::Normalized,
+                MAX_HISTORY_PAGE_SIZE,
+                scan_cursor,
+            )
+            .await?;
+        history_truncated |= page.history_truncated;
+        let Some(oldest) = page.entries.first().map(|entry| entry.entry_index as i64) else {
+            break false;
+        };
+        scanned += page.entries.len();
+
+        let mut batch = decode_log_entries(execution_process, page.entries)
+            .into_iter()
+            .filter(|indexed| {
+                matches!(
+                    &indexed.entry,
+                    PatchType::NormalizedEntry(entry)
+                        if entry_types.contains(&normalized_entry_type_name(&entry.entry_type))
+                )
+            })
+            .collect::<Vec<_>>();
+        let needed = limit - matched;
+        if batch.len() >= needed {
+            // Keep the newest matches; older ones in this batch are served by the next page.
+            let skipped = batch.len() > needed;
+            let batch = batch.split_off(batch.len() - needed);
+            scan_cursor = batch.first().map(|entry| entry.entry_index);
+            batches.push(batch);
+            break skipped || page.has_more;
+        }
+
+        matched += batch.len();
+        batches.push(batch);
+        let stalled = scan_cursor == Some(oldest);
+        scan_cursor = Some(oldest);
+        if !page.has_more || stalled {
+            break false;
+        }
+        if scanned >= MAX_FILTERED_SCAN_ENTRIES {
+            break true;
+        }
+    };
+
+    let entries = batches.into_iter().rev().flatten().collect();
+    Ok(LogHistoryPage {
+        entries,
+        next_cursor: scan_cursor,
+        has_more,
+        history_truncated,
+    })
+}
+
+/// Entries newer than `after`, optionally waiting up to `wait` for the first one to arrive.
+///
+/// `has_more` reports that the page was filled, i.e. newer entries may already be waiting.
+async fn build_log_page_after(
+    deployment: &DeploymentImpl,
+    execution_process: &ExecutionProcess,
+    channel: LogEntryChannel,
+    limit: usize,
+    after: i64,
+    wait: Duration,
+) -> Result<LogHistoryPage, ApiError> {
+    // Subscribe before the first read so an entry landing in between still wakes us up.
+    let mut events =
+        if !wait.is_zero() && execution_process.status == ExecutionProcessStatus::Running {
+            deployment
+                .container()
+                .get_msg_store_by_id(&execution_process.id)
+                .await
+                .map(|store| match channel {
+                    LogEntryChannel::Raw => store.subscribe_raw_entries(),
+                    LogEntryChannel::Normalized => store.subscribe_normalized_entries(),
+                })
+        } else {
+            None
+        };
+
+    let (mut entries, mut history_truncated) = deployment
+        .container()
+        .log_history_after(execution_process, channel, limit, after)
+        .await?;
+    if entries.is_empty()
+        && let Some(events) = events.as_mut()
+        && wait_for_log_entry_after(events, after, wait).await
+    {
+        (entries, history_truncated) = deployment
+            .container()
+            .log_history_after(execution_process, channel, limit, after)
+            .await?;
+    }
+
+    let has_more = entries.len() >= limit;
+    Ok(LogHistoryPage {
+        entries: decode_log_entries(execution_process, entries),
+        next_cursor: None,
+        has_more,
+        history_truncated,
+    })
+}
+
+/// Returns `false` if `wait` elapses before an entry past `after` arrives or the stream ends.
+async fn wait_for_log_entry_after(
+    events: &mut tokio::sync::broadcast::Receiver<LogEntryEvent>,
+    after: i64,
+    wait: Duration,
+) -> bool {
+    let wait_for_entry = async {
+        loop {
+            match events.recv().await {
+                Ok(
+                    LogEntryEvent::Append { entry_index, .. }
+                    | LogEntryEvent::Replace { entry_index, .. },
+                ) => {
+                    if i64::try_from(entry_index).unwrap_or(i64::MAX) > after {
+                        return;
+                    }
+                }
+                // A finished stream or a lagged receiver: let the caller re-read history.
+                Ok(LogEntryEvent::Finished) | Err(_) => return,
+            }
+        }
+    };
+    tokio::time::timeout(wait, wait_for_entry).await.is_ok()
+}
+
+fn decode_log_entries(
+    execution_process: &ExecutionProcess,
+    entries: Vec<LogEntrySnapshot>,
+) -> Vec<IndexedLogEntry> {
+    entries
         .into_iter()
         .filter_map(
             |entry| match PatchType::deserialize(entry.entry_json.as_ref()) {
@@ -184,16 +481,234 @@ async fn build_log_history_page(
                 }
             },
         )
-        .collect::<Vec<_>>();
+        .collect()
+}
 
-    let next_cursor = entries.first().map(|entry| entry.entry_index);
+/// Stream of full-history pages, oldest first, read one page ahead of the consumer so exports do
+/// not hold the whole history in memory.
+type LogPageStream =
+    futures_util::stream::BoxStream<'static, Result<Vec<IndexedLogEntry>, ApiError>>;
 
-    Ok(LogHistoryPage {
-        entries,
-        next_cursor,
-        has_more: page.has_more,
-        history_truncated: page.history_truncated,
-    })
+/// Reads the first page of `channel` up front, so failures surface before the response starts and
+/// the truncation flag is known, and returns it with a stream over every page.
+async fn stream_full_log_history(
+    deployment: DeploymentImpl,
+    execution_process: ExecutionProcess,
+    channel: LogEntryChannel,
+    page_size: usize,
+) -> Result<(bool, LogPageStream), ApiError> {
+    let first = build_log_page_after(
+        &deployment,
+        &execution_process,
+        channel,
+        page_size,
+        -1,
+        Duration::ZERO,
+    )
+    .await?;
+    let history_truncated = first.history_truncated;
+
+    let pages = futures_util::stream::try_unfold(Some(first), move |page| {
+        let deployment = deployment.clone();
+        let execution_process = execution_process.clone();
+        async move {
+            let Some(page) = page else {
+                return Ok(None);
+            };
+            let next = match page.entries.last() {
+                Some(last) if page.has_more => Some(
+                    build_log_page_after(
+                        &deployment,
+                        &execution_process,
+                        channel,
+                        page_size,
+                        last.entry_index,
+                        Duration::ZERO,
+                    )
+                    .await?,
+                ),
+                _ => None,
+            };
+            Ok(Some((page.entries, next)))
+        }
+    });
+    Ok((history_truncated, pages.boxed()))
+}
+
+fn markdown_transcript_header(execution_process_id: Uuid, history_truncated: bool) -> String {
+    let mut out = format!("# Execution process {execution_process_id}\n");
+    if history_truncated {
+        out.push_str(
+            "\n> **Note:** history was truncated; the oldest entries are missing from this \
+             transcript.\n",
+        );
+    }
+    out
+}
+
+fn render_markdown_entries(entries: &[IndexedLogEntry]) -> String {
+    let mut out = String::new();
+    for indexed in entries {
+        let PatchType::NormalizedEntry(entry) = &indexed.entry else {
+            continue;
+        };
+        let heading = match &entry.entry_type {
+            NormalizedEntryType::UserMessage => "User".to_string(),
+            NormalizedEntryType::UserFeedback { denied_tool } => {
+                format!("User feedback ({denied_tool})")
+            }
+            NormalizedEntryType::AssistantMessage => "Assistant".to_string(),
+            NormalizedEntryType::Thinking => "Thinking".to_string(),
+            NormalizedEntryType::ToolUse { tool_name, .. } => format!("Tool: {tool_name}"),
+            NormalizedEntryType::SystemMessage => "System".to_string(),
+            NormalizedEntryType::ErrorMessage { .. } => "Error".to_string(),
+            NormalizedEntryType::Loading | NormalizedEntryType::NextAction { .. } => continue,
+        };
+        out.push_str(&format!("\n## {heading}\n\n{}\n", entry.content.trim()));
+    }
+    out
+}
+
+/// Renders a page of the JSON export's `entries` array; `first` tells whether this page opens it.
+fn render_json_entries(entries: &[IndexedLogEntry], first: &mut bool) -> Result<String, ApiError> {
+    let mut out = String::new();
+    for entry in entries {
+        let separator = if std::mem::take(first) {
+            "\n    "
+        } else {
+            ",\n    "
+        };
+        out.push_str(separator);
+        out.push_str(
+            &serde_json::to_string(entry)
+                .map_err(|err| ApiError::Internal(format!("Failed to encode logs: {err}")))?,
+        );
+    }
+    Ok(out)
+}
+
+fn attachment_response(content_type: &'static str, filename: String, body: Body) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// Turns rendered chunks into a response body; an error mid-stream aborts the response.
+fn chunked_body(
+    head: String,
+    chunks: impl futures_util::Stream<Item = Result<String, ApiError>> + Send + 'static,
+    tail: &'static str,
+) -> Body {
+    let chunks = futures_util::stream::iter([Ok(head)])
+        .chain(chunks)
+        .chain(futures_util::stream::iter([Ok(tail.to_string())]))
+        .map_err(|err| std::io::Error::other(err.to_string()));
+    Body::from_stream(chunks)
+}
+
+pub async fn export_normalized_logs(
+    Extension(execution_process): Extension<ExecutionProcess>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<LogExportQuery>,
+) -> Result<Response, ApiError> {
+    let execution_process_id = execution_process.id;
+    let (history_truncated, pages) = stream_full_log_history(
+        deployment,
+        execution_process,
+        LogEntryChannel::Normalized,
+        MAX_HISTORY_PAGE_SIZE,
+    )
+    .await?;
+
+    let response = match query.format {
+        LogExportFormat::Md => attachment_response(
+            "text/markdown; charset=utf-8",
+            format!("execution-{execution_process_id}.md"),
+            chunked_body(
+                markdown_transcript_header(execution_process_id, history_truncated),
+                pages.map_ok(|entries| render_markdown_entries(&entries)),
+                "",
+            ),
+        ),
+        LogExportFormat::Json => {
+            let head = format!(
+                "{{\n  \"execution_process_id\": \"{execution_process_id}\",\n  \"history_truncated\": {history_truncated},\n  \"entries\": ["
+            );
+            let mut first = true;
+            let entries = pages.and_then(move |entries| {
+                let rendered = render_json_entries(&entries, &mut first);
+                async move { rendered }
+            });
+            attachment_response(
+                "application/json",
+                format!("execution-{execution_process_id}.json"),
+                chunked_body(head, entries, "\n  ]\n}\n"),
+            )
+        }
+    };
+    Ok(response)
+}
+
+/// First line of the raw JSONL export: `{"truncated":true}` when the oldest entries were evicted,
+/// so consumers can tell the log is incomplete; nothing otherwise.
+fn raw_log_export_head(history_truncated: bool) -> String {
+    if history_truncated {
+        "{\"truncated\":true}\n".to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// Renders raw stdout/stderr entries as JSONL, one `{entry_index, stream, content}` object per
+/// line, in entry order.
+fn render_raw_log_jsonl(entries: &[IndexedLogEntry]) -> Result<String, ApiError> {
+    let mut out = String::new();
+    for indexed in entries {
+        let (stream, content) = match &indexed.entry {
+            PatchType::Stdout(content) => ("stdout", content),
+            PatchType::Stderr(content) => ("stderr", content),
+            PatchType::NormalizedEntry(_) | PatchType::Diff(_) => continue,
+        };
+        out.push_str(
+            &serde_json::to_string(&RawLogExportLine {
+                entry_index: indexed.entry_index,
+                stream,
+                content,
+            })
+            .map_err(|err| ApiError::Internal(format!("Failed to encode logs: {err}")))?,
+        );
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+pub async fn export_raw_logs(
+    Extension(execution_process): Extension<ExecutionProcess>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<Response, ApiError> {
+    let execution_process_id = execution_process.id;
+    let (history_truncated, pages) = stream_full_log_history(
+        deployment,
+        execution_process,
+        LogEntryChannel::Raw,
+        MAX_HISTORY_PAGE_SIZE,
+    )
+    .await?;
+
+    let lines = pages.and_then(|entries| async move { render_raw_log_jsonl(&entries) });
+    Ok(attachment_response(
+        "application/x-ndjson",
+        format!("execution-{execution_process_id}-raw.jsonl"),
+        chunked_body(raw_log_export_head(history_truncated), lines, ""),
+    ))
 }
 
 pub async fn stream_raw_logs_v2_ws(
@@ -391,6 +906,50 @@ pub async fn stop_execution_process(
     Ok(ResponseJson(ApiResponse::success(())))
 }
 
+pub async fn renormalize_execution_process_logs(
+    Extension(execution_process): Extension<ExecutionProcess>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<RenormalizeLogsResponse>>, ApiError> {
+    if execution_process.status == ExecutionProcessStatus::Running {
+        return Err(ApiError::Conflict(
+            "Cannot renormalize logs of a running execution process".to_string(),
+        ));
+    }
+    if matches!(
+        execution_process.executor_action().typ(),
+        ExecutorActionType::ScriptRequest(_)
+    ) {
+        return Err(ApiError::BadRequest(
+            "Script execution processes have no normalized logs".to_string(),
+        ));
+    }
+    // Raw rows are numbered from 0, so a later first row means retention trimmed the oldest
+    // output and the rebuilt entries would silently lose it. Without raw rows there is nothing
+    // to rebuild from, and renormalizing would only wipe the stored entries.
+    let raw_stats = ExecutionProcessLogEntry::stats(
+        &deployment.db().pool,
+        execution_process.id,
+        LogEntryChannel::Raw,
+    )
+    .await?;
+    if raw_stats.is_none_or(|stats| stats.min_index > 0) {
+        return Err(ApiError::Conflict(
+            "Raw logs of this execution process are missing or were trimmed; renormalizing would \
+             drop entries"
+                .to_string(),
+        ));
+    }
+
+    let entries = deployment
+        .container()
+        .renormalize_log_entries(&execution_process)
+        .await?;
+
+    Ok(ResponseJson(ApiResponse::success(
+        RenormalizeLogsResponse { entries },
+    )))
+}
+
 pub async fn stream_execution_processes_ws(
     ws: WebSocketUpgrade,
     State(deployment): State<DeploymentImpl>,
@@ -533,6 +1092,9 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/repo-states", get(get_execution_process_repo_states))
         .route("/raw-logs/v2", get(get_raw_logs_v2))
         .route("/normalized-logs/v2", get(get_normalized_logs_v2))
+        .route("/normalized-logs/export", get(export_normalized_logs))
+        .route("/raw-logs/export", get(export_raw_logs))
+        .route("/renormalize", post(renormalize_execution_process_logs))
         .layer(from_fn_with_state(
             deployment.clone(),
             load_execution_process_middleware::<DeploymentImpl>,
@@ -549,42 +1111,84 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
 
 #[cfg(test)]
 mod tests {
+    use std::{sync::Arc, time::Duration};
+
     use app_runtime::Deployment;
     use axum::{
         body::{Body, to_bytes},
-        http::{Request, StatusCode},
+        http::{HeaderMap, Request, StatusCode, header},
     };
+    use chrono::Utc;
     use db::models::{
-        execution_process::{CreateExecutionProcess, ExecutionProcessRunReason},
+        execution_process::{
+            CreateExecutionProcess, ExecutionProcess, ExecutionProcessFailureKind,
+            ExecutionProcessRunReason, ExecutionProcessStatus,
+        },
+        execution_process_log_entries::{ExecutionProcessLogEntry, LogEntryRow},
         project::{CreateProject, Project},
         session::{CreateSession, Session},
         task::{CreateTask, Task},
         workspace::{CreateWorkspace, Workspace},
     };
-    use executors_protocol::actions::{
-        ExecutorAction, ExecutorActionType,
-        script::{ScriptContext, ScriptRequest, ScriptRequestLanguage},
+    use execution::container::ContainerService;
+    use executors_protocol::{
+        BaseCodingAgent, ExecutorProfileId,
+        actions::{
+            ExecutorAction, ExecutorActionType,
+            coding_agent_initial::CodingAgentInitialRequest,
+            script::{ScriptContext, ScriptRequest, ScriptRequestLanguage},
+        },
     };
+    use futures_util::TryStreamExt;
+    use logs_store::MsgStore;
     use test_support::{TempRoot, TestDb, TestEnvGuard};
     use tower::ServiceExt;
+    use utils_core::{
+        approvals::{ApprovalRequest, ApprovalResponse, ApprovalStatus, CreateApprovalRequest},
+        log_entries::LogEntryChannel,
+    };
     use uuid::Uuid;
 
+    use super::{markdown_transcript_header, raw_log_export_head, stream_full_log_history};
     use crate::{DeploymentImpl, http};
 
-    #[tokio::test]
-    async fn execution_process_api_does_not_expose_script_contents() {
-        let temp_root = TempRoot::new("vk-test-");
-        let db = TestDb::sqlite_file(&temp_root);
-        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+    fn script_action() -> ExecutorAction {
+        ExecutorAction::new(
+            ExecutorActionType::ScriptRequest(ScriptRequest {
+                script: "true".to_string(),
+                language: ScriptRequestLanguage::Bash,
+                context: ScriptContext::SetupScript,
+                working_dir: None,
+            }),
+            None,
+        )
+    }
 
-        let deployment = DeploymentImpl::new().await.unwrap();
+    fn coding_agent_action() -> ExecutorAction {
+        ExecutorAction::new(
+            ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
+                prompt: "hi".to_string(),
+                executor_profile_id: ExecutorProfileId::new(BaseCodingAgent::Codex),
+                working_dir: None,
+                image_paths: None,
+                model: None,
+                reasoning_effort: None,
+            }),
+            None,
+        )
+    }
+
+    async fn create_execution_process(
+        deployment: &DeploymentImpl,
+        executor_action: ExecutorAction,
+    ) -> Uuid {
         let pool = &deployment.db().pool;
 
         let project_id = Uuid::new_v4();
         Project::create(
             pool,
             &CreateProject {
-                name: "Redaction".to_string(),
+                name: "Export".to_string(),
                 repositories: Vec::new(),
             },
             project_id,
@@ -614,33 +1218,23 @@ mod tests {
         .await
         .unwrap();
 
-        let session_id = Uuid::new_v4();
         let session = Session::create(
             pool,
             &CreateSession {
                 executor: Some("test".to_string()),
             },
-            session_id,
+            Uuid::new_v4(),
             workspace_id,
         )
         .await
         .unwrap();
 
-        let secret = "sekrit-value-123";
         let process_id = Uuid::new_v4();
         db::models::execution_process::ExecutionProcess::create(
             pool,
             &CreateExecutionProcess {
                 session_id: session.id,
-                executor_action: ExecutorAction::new(
-                    ExecutorActionType::ScriptRequest(ScriptRequest {
-                        script: format!("echo {secret}"),
-                        language: ScriptRequestLanguage::Bash,
-                        context: ScriptContext::SetupScript,
-                        working_dir: None,
-                    }),
-                    None,
-                ),
+                executor_action,
                 run_reason: ExecutionProcessRunReason::CodingAgent,
             },
             process_id,
@@ -649,32 +1243,941 @@ mod tests {
         .await
         .unwrap();
 
-        let app = http::router(deployment);
-        let response = app
-            .oneshot(
-                Request::builder()
-                    .uri(format!("/api/execution-processes/{process_id}"))
-                    .body(Body::empty())
-                    .unwrap(),
-            )
+        process_id
+    }
+
+    async fn insert_entries(
+        deployment: &DeploymentImpl,
+        process_id: Uuid,
+        channel: LogEntryChannel,
+        entries: &[serde_json::Value],
+    ) {
+        let rows = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| LogEntryRow {
+                entry_index: index as i64,
+                entry_json: entry.to_string(),
+            })
+            .collect::<Vec<_>>();
+        ExecutionProcessLogEntry::upsert_entries(&deployment.db().pool, process_id, channel, &rows)
             .await
             .unwrap();
-        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    async fn create_execution_process_with_entries(
+        deployment: &DeploymentImpl,
+        channel: LogEntryChannel,
+        entries: &[serde_json::Value],
+    ) -> Uuid {
+        let process_id = create_execution_process(deployment, script_action()).await;
+        insert_entries(deployment, process_id, channel, entries).await;
+        process_id
+    }
+
+    fn normalized(entry_type: serde_json::Value, content: &str) -> serde_json::Value {
+        serde_json::json!({
+            "type": "NORMALIZED_ENTRY",
+            "content": { "timestamp": null, "entry_type": entry_type, "content": content },
+        })
+    }
 
+    async fn get(deployment: &DeploymentImpl, uri: String) -> (StatusCode, HeaderMap, String) {
+        let response = http::router(deployment.clone())
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
         let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
-        let body_str = String::from_utf8_lossy(&body);
-        assert!(!body_str.contains(secret));
+        (status, headers, String::from_utf8(body.to_vec()).unwrap())
+    }
 
-        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    #[tokio::test]
+    async fn normalized_log_export_renders_markdown_and_json() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let process_id = create_execution_process_with_entries(
+            &deployment,
+            LogEntryChannel::Normalized,
+            &[
+                normalized(serde_json::json!({ "type": "user_message" }), "Fix the bug"),
+                normalized(
+                    serde_json::json!({ "type": "thinking" }),
+                    "Looking at parser",
+                ),
+                normalized(
+                    serde_json::json!({
+                        "type": "tool_use",
+                        "tool_name": "bash",
+                        "action_type": { "action": "command_run", "command": "cargo test" },
+                        "status": { "status": "success" },
+                    }),
+                    "cargo test",
+                ),
+                normalized(serde_json::json!({ "type": "assistant_message" }), "Done."),
+            ],
+        )
+        .await;
+
+        let (status, headers, body) = get(
+            &deployment,
+            format!("/api/execution-processes/{process_id}/normalized-logs/export?format=md"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
         assert_eq!(
-            json.pointer("/data/executor_action/typ/type")
-                .and_then(|v| v.as_str()),
-            Some("ScriptRequest")
+            headers.get(header::CONTENT_TYPE).unwrap(),
+            "text/markdown; charset=utf-8"
         );
         assert_eq!(
-            json.pointer("/data/executor_action/typ/script")
-                .and_then(|v| v.as_str()),
-            Some("<redacted>")
+            headers.get(header::CONTENT_DISPOSITION).unwrap(),
+            format!("attachment; filename=\"execution-{process_id}.md\"").as_str()
+        );
+        assert!(body.starts_with(&format!("# Execution process {process_id}\n")));
+        assert!(!body.contains("history was truncated"));
+        let user = body.find("## User\n\nFix the bug").expect("user section");
+        let thinking = body
+            .find("## Thinking\n\nLooking at parser")
+            .expect("thinking");
+        let tool = body
+            .find("## Tool: bash\n\ncargo test")
+            .expect("tool section");
+        let assistant = body
+            .find("## Assistant\n\nDone.")
+            .expect("assistant section");
+        assert!(user < thinking && thinking < tool && tool < assistant);
+
+        let (status, headers, body) = get(
+            &deployment,
+            format!("/api/execution-processes/{process_id}/normalized-logs/export?format=json"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            headers.get(header::CONTENT_TYPE).unwrap(),
+            "application/json"
         );
+        assert_eq!(
+            headers.get(header::CONTENT_DISPOSITION).unwrap(),
+            format!("attachment; filename=\"execution-{process_id}.json\"").as_str()
+        );
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["execution_process_id"], process_id.to_string());
+        assert_eq!(json["history_truncated"], false);
+        let entries = json["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries[0]["entry_index"], 0);
+        assert_eq!(entries[3]["entry"]["content"]["content"], "Done.");
+    }
+
+    #[tokio::test]
+    async fn raw_log_export_preserves_stream_content_and_order() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let chunks = [
+            ("STDOUT", "{\"method\":\"codex/event\"}\n"),
+            ("STDERR", "warning: slow\n"),
+            ("STDOUT", "partial "),
+            ("STDOUT", "line\n"),
+        ];
+        let process_id = create_execution_process_with_entries(
+            &deployment,
+            LogEntryChannel::Raw,
+            &chunks
+                .iter()
+                .map(|(kind, content)| serde_json::json!({ "type": kind, "content": content }))
+                .collect::<Vec<_>>(),
+        )
+        .await;
+
+        let (status, headers, body) = get(
+            &deployment,
+            format!("/api/execution-processes/{process_id}/raw-logs/export"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            headers.get(header::CONTENT_TYPE).unwrap(),
+            "application/x-ndjson"
+        );
+        assert_eq!(
+            headers.get(header::CONTENT_DISPOSITION).unwrap(),
+            format!("attachment; filename=\"execution-{process_id}-raw.jsonl\"").as_str()
+        );
+
+        let lines = body
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), chunks.len());
+        for (index, (line, (kind, content))) in lines.iter().zip(chunks.iter()).enumerate() {
+            assert_eq!(line["entry_index"], index as i64);
+            assert_eq!(line["stream"], kind.to_ascii_lowercase());
+            assert_eq!(line["content"], *content);
+        }
+
+        let stdout: String = lines
+            .iter()
+            .filter(|line| line["stream"] == "stdout")
+            .map(|line| line["content"].as_str().unwrap())
+            .collect();
+        assert_eq!(stdout, "{\"method\":\"codex/event\"}\npartial line\n");
+    }
+
+    #[tokio::test]
+    async fn raw_logs_after_returns_only_newer_entries() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let process_id = create_execution_process_with_entries(
+            &deployment,
+            LogEntryChannel::Raw,
+            &["one\n", "two\n", "three\n", "four\n"]
+                .iter()
+                .map(|content| serde_json::json!({ "type": "STDOUT", "content": content }))
+                .collect::<Vec<_>>(),
+        )
+        .await;
+
+        let (status, _, body) = get(
+            &deployment,
+            format!("/api/execution-processes/{process_id}/raw-logs/v2?after=1"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let entries = json["data"]["entries"].as_array().unwrap();
+        let indices = entries
+            .iter()
+            .map(|entry| entry["entry_index"].as_i64().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(indices, vec![2, 3]);
+        assert_eq!(entries[0]["entry"]["content"], "three\n");
+
+        let (status, _, _) = get(
+            &deployment,
+            format!("/api/execution-processes/{process_id}/raw-logs/v2?after=1&cursor=3"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn normalized_log_page_filters_by_entry_type_with_stable_cursors() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let error = |content: &str| {
+            normalized(
+                serde_json::json!({ "type": "error_message", "error_type": { "type": "other" } }),
+                content,
+            )
+        };
+        let process_id = create_execution_process_with_entries(
+            &deployment,
+            LogEntryChannel::Normalized,
+            &[
+                normalized(serde_json::json!({ "type": "user_message" }), "Fix the bug"),
+                error("first failure"),
+                normalized(serde_json::json!({ "type": "thinking" }), "Retrying"),
+                error("second failure"),
+                normalized(serde_json::json!({ "type": "assistant_message" }), "Still"),
+                error("third failure"),
+                normalized(serde_json::json!({ "type": "assistant_message" }), "Done."),
+            ],
+        )
+        .await;
+        let base = format!("/api/execution-processes/{process_id}/normalized-logs/v2");
+        let page_of = |body: &str| {
+            let json: serde_json::Value = serde_json::from_str(body).unwrap();
+            let data = json["data"].clone();
+            let entries = data["entries"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|entry| {
+                    assert_eq!(
+                        entry["entry"]["content"]["entry_type"]["type"],
+                        "error_message"
+                    );
+                    (
+                        entry["entry_index"].as_i64().unwrap(),
+                        entry["entry"]["content"]["content"]
+                            .as_str()
+                            .unwrap()
+                            .to_string(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            (
+                entries,
+                data["next_cursor"].as_i64(),
+                data["has_more"].as_bool().unwrap(),
+            )
+        };
+
+        let (status, _, body) =
+            get(&deployment, format!("{base}?types=error_message&limit=2")).await;
+        assert_eq!(status, StatusCode::OK);
+        let (entries, next_cursor, has_more) = page_of(&body);
+        assert_eq!(
+            entries,
+            vec![
+                (3, "second failure".to_string()),
+                (5, "third failure".to_string())
+            ]
+        );
+        assert_eq!(next_cursor, Some(3));
+        assert!(has_more);
+
+        let (status, _, body) = get(
+            &deployment,
+            format!("{base}?types=error_message&limit=2&cursor=3"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (entries, next_cursor, has_more) = page_of(&body);
+        assert_eq!(entries, vec![(1, "first failure".to_string())]);
+        assert_eq!(next_cursor, Some(0));
+        assert!(!has_more);
+
+        for query in ["types=bogus", "types=error_message&after=0"] {
+            let (status, _, _) = get(&deployment, format!("{base}?{query}")).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{query}");
+        }
+        let (status, _, _) = get(
+            &deployment,
+            format!("/api/execution-processes/{process_id}/raw-logs/v2?types=error_message"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn normalized_log_page_is_gzip_compressed_when_accepted() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let entries = (0..20)
+            .map(|index| {
+                normalized(
+                    serde_json::json!({ "type": "assistant_message" }),
+                    &format!("Progress update number {index}"),
+                )
+            })
+            .collect::<Vec<_>>();
+        let process_id = create_execution_process_with_entries(
+            &deployment,
+            LogEntryChannel::Normalized,
+            &entries,
+        )
+        .await;
+
+        let response = http::router(deployment.clone())
+            .oneshot(
+                Request::builder()
+                    .uri(format!(
+                        "/api/execution-processes/{process_id}/normalized-logs/v2"
+                    ))
+                    .header(header::ACCEPT_ENCODING, "gzip")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_ENCODING).unwrap(),
+            "gzip"
+        );
+
+        let compressed = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let mut decoded = String::new();
+        std::io::Read::read_to_string(
+            &mut flate2::read::GzDecoder::new(compressed.as_ref()),
+            &mut decoded,
+        )
+        .unwrap();
+        assert!(compressed.len() < decoded.len());
+        let json: serde_json::Value = serde_json::from_str(&decoded).unwrap();
+        assert_eq!(json["data"]["entries"].as_array().unwrap().len(), 20);
+    }
+
+    #[tokio::test]
+    async fn raw_logs_long_poll_returns_when_entry_arrives() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let process_id = create_execution_process(&deployment, script_action()).await;
+        let store = Arc::new(MsgStore::new());
+        store.push_stdout("first\n");
+        deployment
+            .container()
+            .msg_stores()
+            .write()
+            .await
+            .insert(process_id, store.clone());
+
+        let producer = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            store.push_stdout("second\n");
+        });
+
+        let started = std::time::Instant::now();
+        let (status, _, body) = get(
+            &deployment,
+            format!("/api/execution-processes/{process_id}/raw-logs/v2?after=0&wait_ms=10000"),
+        )
+        .await;
+        producer.await.unwrap();
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(
+            started.elapsed() < Duration::from_secs(5),
+            "long-poll should return as soon as an entry arrives"
+        );
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let entries = json["data"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["entry_index"], 1);
+        assert_eq!(entries[0]["entry"]["content"], "second\n");
+    }
+
+    #[tokio::test]
+    async fn full_log_history_streams_every_page_in_order() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let entries: Vec<_> = (0..5)
+            .map(|index| {
+                normalized(
+                    serde_json::json!({ "type": "thinking" }),
+                    &index.to_string(),
+                )
+            })
+            .collect();
+        let process_id = create_execution_process_with_entries(
+            &deployment,
+            LogEntryChannel::Normalized,
+            &entries,
+        )
+        .await;
+        let process = ExecutionProcess::find_by_id(&deployment.db().pool, process_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let (history_truncated, pages) =
+            stream_full_log_history(deployment, process, LogEntryChannel::Normalized, 2)
+                .await
+                .unwrap();
+        assert!(!history_truncated);
+        let pages: Vec<Vec<i64>> = pages
+            .map_ok(|page| page.iter().map(|entry| entry.entry_index).collect())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn markdown_transcript_notes_truncation() {
+        let body = markdown_transcript_header(Uuid::nil(), true);
+        assert!(body.contains("history was truncated"));
+    }
+
+    #[test]
+    fn raw_log_export_flags_truncation_on_its_first_line() {
+        let head = raw_log_export_head(true);
+        let first: serde_json::Value = serde_json::from_str(head.lines().next().unwrap()).unwrap();
+        assert_eq!(first, serde_json::json!({ "truncated": true }));
+        assert!(raw_log_export_head(false).is_empty());
+    }
+
+    #[tokio::test]
+    async fn execution_process_api_does_not_expose_script_contents() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &CreateProject {
+                name: "Redaction".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(project_id, "T".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let workspace_id = Uuid::new_v4();
+        Workspace::create(
+            pool,
+            &CreateWorkspace {
+                branch: "main".to_string(),
+                agent_working_dir: None,
+            },
+            workspace_id,
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let session_id = Uuid::new_v4();
+        let session = Session::create(
+            pool,
+            &CreateSession {
+                executor: Some("test".to_string()),
+            },
+            session_id,
+            workspace_id,
+        )
+        .await
+        .unwrap();
+
+        let secret = "sekrit-value-123";
+        let process_id = Uuid::new_v4();
+        db::models::execution_process::ExecutionProcess::create(
+            pool,
+            &CreateExecutionProcess {
+                session_id: session.id,
+                executor_action: ExecutorAction::new(
+                    ExecutorActionType::ScriptRequest(ScriptRequest {
+                        script: format!("echo {secret}"),
+                        language: ScriptRequestLanguage::Bash,
+                        context: ScriptContext::SetupScript,
+                        working_dir: None,
+                    }),
+                    None,
+                ),
+                run_reason: ExecutionProcessRunReason::CodingAgent,
+            },
+            process_id,
+            &[],
+        )
+        .await
+        .unwrap();
+
+        let app = http::router(deployment);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/execution-processes/{process_id}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body_str = String::from_utf8_lossy(&body);
+        assert!(!body_str.contains(secret));
+
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            json.pointer("/data/executor_action/typ/type")
+                .and_then(|v| v.as_str()),
+            Some("ScriptRequest")
+        );
+        assert_eq!(
+            json.pointer("/data/executor_action/typ/script")
+                .and_then(|v| v.as_str()),
+            Some("<redacted>")
+        );
+    }
+
+    #[tokio::test]
+    async fn execution_timeout_kills_running_process_and_records_failure() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let running_id = create_execution_process(&deployment, coding_agent_action()).await;
+        let killed = deployment
+            .container()
+            .enforce_execution_timeout(running_id, Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert!(killed);
+
+        let process = ExecutionProcess::find_by_id(pool, running_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, ExecutionProcessStatus::Killed);
+        assert_eq!(
+            process.failure_kind,
+            Some(ExecutionProcessFailureKind::TimedOut)
+        );
+
+        let finished_id = create_execution_process(&deployment, coding_agent_action()).await;
+        ExecutionProcess::update_completion(
+            pool,
+            finished_id,
+            ExecutionProcessStatus::Completed,
+            Some(0),
+        )
+        .await
+        .unwrap();
+        let killed = deployment
+            .container()
+            .enforce_execution_timeout(finished_id, Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert!(!killed);
+
+        let process = ExecutionProcess::find_by_id(pool, finished_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, ExecutionProcessStatus::Completed);
+        assert_eq!(process.failure_kind, None);
+    }
+
+    #[tokio::test]
+    async fn idle_timeout_kills_quiet_process_but_not_active_one() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let quiet_id = create_execution_process(&deployment, coding_agent_action()).await;
+        let killed = deployment
+            .container()
+            .enforce_execution_idle_timeout(quiet_id, Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert!(killed);
+
+        let process = ExecutionProcess::find_by_id(pool, quiet_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, ExecutionProcessStatus::Killed);
+        assert_eq!(
+            process.failure_kind,
+            Some(ExecutionProcessFailureKind::IdleTimeout)
+        );
+
+        // Output keeps pushing the deadline out; the process finishes before going idle.
+        let active_id = create_execution_process(&deployment, coding_agent_action()).await;
+        let heartbeat_pool = pool.clone();
+        let heartbeat = tokio::spawn(async move {
+            for _ in 0..10 {
+                ExecutionProcess::record_activity(&heartbeat_pool, active_id, Utc::now())
+                    .await
+                    .unwrap();
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            ExecutionProcess::update_completion(
+                &heartbeat_pool,
+                active_id,
+                ExecutionProcessStatus::Completed,
+                Some(0),
+            )
+            .await
+            .unwrap();
+        });
+        let killed = deployment
+            .container()
+            .enforce_execution_idle_timeout(active_id, Duration::from_millis(100))
+            .await
+            .unwrap();
+        heartbeat.await.unwrap();
+        assert!(!killed);
+
+        let process = ExecutionProcess::find_by_id(pool, active_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, ExecutionProcessStatus::Completed);
+        assert_eq!(process.failure_kind, None);
+    }
+
+    #[tokio::test]
+    async fn idle_timeout_waits_while_an_approval_is_open() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let process_id = create_execution_process(&deployment, coding_agent_action()).await;
+        let request = ApprovalRequest::from_create(
+            CreateApprovalRequest {
+                tool_name: "Bash".to_string(),
+                tool_input: serde_json::json!({ "command": "rm -rf build" }),
+                tool_call_id: "call-1".to_string(),
+            },
+            process_id,
+        );
+        let (approval, _waiter) = deployment
+            .approvals()
+            .create_with_waiter(pool, request)
+            .await
+            .unwrap();
+
+        let waited = tokio::time::timeout(
+            Duration::from_millis(300),
+            deployment
+                .container()
+                .enforce_execution_idle_timeout(process_id, Duration::from_millis(50)),
+        )
+        .await;
+        assert!(waited.is_err());
+        let process = ExecutionProcess::find_by_id(pool, process_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, ExecutionProcessStatus::Running);
+
+        deployment
+            .approvals()
+            .respond(
+                pool,
+                &approval.id,
+                ApprovalResponse {
+                    execution_process_id: process_id,
+                    status: ApprovalStatus::Approved,
+                },
+            )
+            .await
+            .unwrap();
+        let killed = deployment
+            .container()
+            .enforce_execution_idle_timeout(process_id, Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert!(killed);
+    }
+
+    #[tokio::test]
+    async fn renormalize_rebuilds_normalized_entries_from_raw_logs() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let process_id = create_execution_process(&deployment, coding_agent_action()).await;
+        let launch_error = serde_json::json!({ "LaunchError": { "error": "boom" } });
+        insert_entries(
+            &deployment,
+            process_id,
+            LogEntryChannel::Raw,
+            &[serde_json::json!({ "type": "STDOUT", "content": format!("{launch_error}\n") })],
+        )
+        .await;
+        insert_entries(
+            &deployment,
+            process_id,
+            LogEntryChannel::Normalized,
+            &[
+                normalized(serde_json::json!({ "type": "assistant_message" }), "stale"),
+                normalized(
+                    serde_json::json!({ "type": "assistant_message" }),
+                    "stale too",
+                ),
+            ],
+        )
+        .await;
+
+        let uri = format!("/api/execution-processes/{process_id}/renormalize");
+        let response = http::router(deployment.clone())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let pool = &deployment.db().pool;
+        ExecutionProcess::update_completion(
+            pool,
+            process_id,
+            ExecutionProcessStatus::Completed,
+            Some(0),
+        )
+        .await
+        .unwrap();
+
+        let response = http::router(deployment.clone())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json.pointer("/data/entries"), Some(&serde_json::json!(1)));
+
+        let rows = ExecutionProcessLogEntry::fetch_after(
+            pool,
+            process_id,
+            LogEntryChannel::Normalized,
+            10,
+            -1,
+        )
+        .await
+        .unwrap();
+        assert_eq!(rows.len(), 1);
+        let entry: serde_json::Value = serde_json::from_str(&rows[0].entry_json).unwrap();
+        assert_eq!(
+            entry.pointer("/content/entry_type/type"),
+            Some(&serde_json::json!("error_message"))
+        );
+        assert_eq!(
+            entry.pointer("/content/content"),
+            Some(&serde_json::json!("boom"))
+        );
+
+        // Raw history trimmed by retention no longer starts at index 0 and is refused.
+        ExecutionProcessLogEntry::delete_channel(pool, process_id, LogEntryChannel::Raw)
+            .await
+            .unwrap();
+        ExecutionProcessLogEntry::upsert_entries(
+            pool,
+            process_id,
+            LogEntryChannel::Raw,
+            &[LogEntryRow {
+                entry_index: 3,
+                entry_json: serde_json::json!({ "type": "STDOUT", "content": "tail\n" })
+                    .to_string(),
+            }],
+        )
+        .await
+        .unwrap();
+        let response = http::router(deployment.clone())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let rows = ExecutionProcessLogEntry::fetch_after(
+            pool,
+            process_id,
+            LogEntryChannel::Normalized,
+            10,
+            -1,
+        )
+        .await
+        .unwrap();
+        assert_eq!(rows.len(), 1, "stored entries must be kept");
+    }
+
+    #[tokio::test]
+    async fn renormalize_keeps_stored_entries_without_usable_raw_logs() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let process_id = create_execution_process(&deployment, coding_agent_action()).await;
+        ExecutionProcess::update_completion(
+            pool,
+            process_id,
+            ExecutionProcessStatus::Completed,
+            Some(0),
+        )
+        .await
+        .unwrap();
+        insert_entries(
+            &deployment,
+            process_id,
+            LogEntryChannel::Normalized,
+            &[normalized(
+                serde_json::json!({ "type": "assistant_message" }),
+                "kept",
+            )],
+        )
+        .await;
+        let router_deployment = &deployment;
+        let renormalize = move || async move {
+            http::router(router_deployment.clone())
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("/api/execution-processes/{process_id}/renormalize"))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap()
+                .status()
+        };
+        let normalized_count = move || async move {
+            ExecutionProcessLogEntry::fetch_after(
+                pool,
+                process_id,
+                LogEntryChannel::Normalized,
+                10,
+                -1,
+            )
+            .await
+            .unwrap()
+            .len()
+        };
+
+        // No raw rows at all.
+        assert_eq!(renormalize().await, StatusCode::CONFLICT);
+        assert_eq!(normalized_count().await, 1);
+
+        // More raw output than the in-memory log history holds.
+        let chunk = "x".repeat(1024 * 1024);
+        let raw = (0..9)
+            .map(|_| serde_json::json!({ "type": "STDOUT", "content": chunk }))
+            .collect::<Vec<_>>();
+        insert_entries(&deployment, process_id, LogEntryChannel::Raw, &raw).await;
+        assert_eq!(renormalize().await, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(normalized_count().await, 1);
     }
 }
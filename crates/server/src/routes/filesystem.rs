@@ -75,8 +75,12 @@ pub(crate) async fn allowed_workspace_roots<D>(deployment: &D) -> Result<Vec<Pat
 where
     D: FilesystemRouteDeps,
 {
-    let configured_workspace_dir = deployment.config_state().read().await.workspace_dir.clone();
+    let (configured_workspace_dir, worktree_root) = {
+        let config = deployment.config_state().read().await;
+        (config.workspace_dir.clone(), config.worktree_base_dir())
+    };
     let mut candidates: Vec<PathBuf> = vec![WorkspaceManager::get_workspace_base_dir()];
+    candidates.extend(worktree_root);
 
     if let Some(workspace_dir) = configured_workspace_dir {
         let workspace_path = PathBuf::from(workspace_dir);
@@ -71,6 +71,8 @@ pub fn request_hash<T: Serialize>(payload: &T) -> Result<String, ApiError> {
     Ok(format!("{digest:x}"))
 }
 
+/// Runs `execute` at most once per `(scope, key)`. The first successful response is stored and a
+/// retry with the same key and payload gets that stored response back instead of re-executing.
 pub async fn idempotent_success<T, F, Fut>(
     db: &db::DbPool,
     scope: &'static str,
@@ -132,19 +134,7 @@ where
             }
 
             match record.state.as_str() {
-                db::models::idempotency::IDEMPOTENCY_STATE_COMPLETED => {
-                    let Some(response_json) = record.response_json else {
-                        return Err(ApiError::Internal(
-                            "Idempotency record is completed but has no stored response"
-                                .to_string(),
-                        ));
-                    };
-                    let response: ApiResponse<T> =
-                        serde_json::from_str(&response_json).map_err(|e| {
-                            ApiError::Internal(format!("Failed to parse stored response: {e}"))
-                        })?;
-                    Ok(ResponseJson(response))
-                }
+                db::models::idempotency::IDEMPOTENCY_STATE_COMPLETED => stored_response(record),
                 db::models::idempotency::IDEMPOTENCY_STATE_IN_PROGRESS => Err(ApiError::Conflict(
                     "Request with this idempotency key is in progress. Retry shortly.".to_string(),
                 )),
@@ -155,3 +145,104 @@ where
         }
     }
 }
+
+/// The stored response of a completed request with this `(scope, key)` and payload, if any. For
+/// handlers that load what the first request removed, so a retry would fail before reaching
+/// `idempotent_success`.
+pub async fn stored_success<T: DeserializeOwned>(
+    db: &db::DbPool,
+    scope: &'static str,
+    key: Option<&str>,
+    request_hash: &str,
+) -> Result<Option<ResponseJson<ApiResponse<T>>>, ApiError> {
+    let Some(key) = key else {
+        return Ok(None);
+    };
+    match db::models::idempotency::find_by_scope_key(db, scope, key).await? {
+        Some(record)
+            if record.request_hash == request_hash
+                && record.state == db::models::idempotency::IDEMPOTENCY_STATE_COMPLETED =>
+        {
+            stored_response(record).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+fn stored_response<T: DeserializeOwned>(
+    record: db::models::idempotency::IdempotencyKey,
+) -> Result<ResponseJson<ApiResponse<T>>, ApiError> {
+    let Some(response_json) = record.response_json else {
+        return Err(ApiError::Internal(
+            "Idempotency record is completed but has no stored response".to_string(),
+        ));
+    };
+    let response: ApiResponse<T> = serde_json::from_str(&response_json)
+        .map_err(|e| ApiError::Internal(format!("Failed to parse stored response: {e}")))?;
+    Ok(ResponseJson(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use app_runtime::Deployment;
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header},
+    };
+    use config::ProjectConfig;
+    use db::models::task::Task;
+    use test_support::{TempRoot, TestDb, TestEnvGuard};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::DeploymentImpl;
+
+    #[tokio::test]
+    async fn keyed_retry_replays_stored_response_without_re_executing() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let project_id = Uuid::new_v4();
+        deployment.config().write().await.projects.push(
+            serde_json::from_value::<ProjectConfig>(
+                serde_json::json!({ "id": project_id, "name": "Replay" }),
+            )
+            .unwrap(),
+        );
+
+        let create = || {
+            crate::http::router(deployment.clone()).oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/tasks")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .header(super::IDEMPOTENCY_KEY_HEADER, "create-1")
+                    .body(Body::from(
+                        serde_json::json!({ "project_id": project_id, "title": "Replay me" })
+                            .to_string(),
+                    ))
+                    .unwrap(),
+            )
+        };
+
+        let mut bodies = Vec::new();
+        for _ in 0..2 {
+            let response = create().await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            bodies.push(to_bytes(response.into_body(), usize::MAX).await.unwrap());
+        }
+
+        assert_eq!(bodies[0], bodies[1]);
+        let tasks = Task::find_by_project_id_with_attempt_status(&deployment.db().pool, project_id)
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        let created: serde_json::Value = serde_json::from_slice(&bodies[0]).unwrap();
+        assert_eq!(
+            created.pointer("/data/id").and_then(|id| id.as_str()),
+            Some(tasks[0].task.id.to_string().as_str())
+        );
+    }
+}
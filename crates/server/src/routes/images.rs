@@ -1,11 +1,11 @@
-use std::path::Path as StdPath;
+use std::{io::SeekFrom, path::Path as StdPath};
 
 use app_runtime::Deployment;
 use axum::{
     Router,
     body::Body,
     extract::{DefaultBodyLimit, Multipart, Path, Query, State},
-    http::{StatusCode, header},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
     response::{Json as ResponseJson, Response},
     routing::{delete, get, post},
 };
@@ -17,9 +17,12 @@ use db::{
         task::Task,
     },
 };
-use execution::image::ImageError;
+use execution::image::{ImageError, image_max_bytes};
 use serde::{Deserialize, Serialize};
-use tokio::fs::File;
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncSeekExt},
+};
 use tokio_util::io::ReaderStream;
 use ts_rs::TS;
 use utils_core::response::ApiResponse;
@@ -29,7 +32,7 @@ use crate::{DeploymentImpl, error::ApiError};
 
 pub(crate) const IMAGE_FILE_CACHE_CONTROL: &str = "private, max-age=31536000, immutable";
 
-pub(crate) fn build_image_file_response(
+fn build_image_file_response(
     body: Body,
     content_type: &str,
     content_length: u64,
@@ -39,6 +42,7 @@ pub(crate) fn build_image_file_response(
         .header(header::CONTENT_TYPE, content_type)
         .header(header::CONTENT_LENGTH, content_length)
         .header(header::CACHE_CONTROL, IMAGE_FILE_CACHE_CONTROL)
+        .header(header::ACCEPT_RANGES, "bytes")
         .header("X-Content-Type-Options", "nosniff")
         .body(body)
         .map_err(|e| ApiError::Image(ImageError::ResponseBuildError(e.to_string())))?;
@@ -46,6 +50,95 @@ pub(crate) fn build_image_file_response(
     Ok(response)
 }
 
+#[derive(Debug, PartialEq, Eq)]
+enum ByteRange {
+    /// Inclusive `start..=end` slice of the file.
+    Satisfiable {
+        start: u64,
+        end: u64,
+    },
+    Unsatisfiable,
+}
+
+/// Parses a single `bytes=` range against a file of `len` bytes. Returns `None` when the header
+/// should be ignored (malformed, another unit, or multiple ranges) and the full body served.
+fn parse_byte_range(value: &str, len: u64) -> Option<ByteRange> {
+    let spec = value.trim().strip_prefix("bytes=")?.trim();
+    if spec.contains(',') {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = (start.trim(), end.trim());
+
+    if start.is_empty() {
+        let suffix = end.parse::<u64>().ok()?;
+        if suffix == 0 || len == 0 {
+            return Some(ByteRange::Unsatisfiable);
+        }
+        return Some(ByteRange::Satisfiable {
+            start: len.saturating_sub(suffix),
+            end: len - 1,
+        });
+    }
+
+    let start = start.parse::<u64>().ok()?;
+    let end = if end.is_empty() {
+        None
+    } else {
+        Some(end.parse::<u64>().ok()?)
+    };
+    if end.is_some_and(|end| end < start) {
+        return None;
+    }
+    if start >= len {
+        return Some(ByteRange::Unsatisfiable);
+    }
+    Some(ByteRange::Satisfiable {
+        start,
+        end: end.map_or(len - 1, |end| end.min(len - 1)),
+    })
+}
+
+/// Streams an opened image file, answering a single `Range` request with 206 (or 416 when it
+/// lies past the end). Without a usable range the full file is returned with 200.
+pub(crate) async fn image_file_response(
+    mut file: File,
+    len: u64,
+    content_type: &str,
+    headers: &HeaderMap,
+) -> Result<Response, ApiError> {
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| parse_byte_range(value, len));
+
+    match range {
+        None => build_image_file_response(
+            Body::from_stream(ReaderStream::new(file)),
+            content_type,
+            len,
+        ),
+        Some(ByteRange::Unsatisfiable) => Response::builder()
+            .status(StatusCode::RANGE_NOT_SATISFIABLE)
+            .header(header::CONTENT_RANGE, format!("bytes */{len}"))
+            .body(Body::empty())
+            .map_err(|e| ApiError::Image(ImageError::ResponseBuildError(e.to_string()))),
+        Some(ByteRange::Satisfiable { start, end }) => {
+            file.seek(SeekFrom::Start(start)).await?;
+            let part_len = end - start + 1;
+            let body = Body::from_stream(ReaderStream::new(file.take(part_len)));
+            let mut response = build_image_file_response(body, content_type, part_len)?;
+            *response.status_mut() = StatusCode::PARTIAL_CONTENT;
+            let content_range = HeaderValue::from_str(&format!("bytes {start}-{end}/{len}"))
+                .map_err(|e| ApiError::Image(ImageError::ResponseBuildError(e.to_string())))?;
+            response
+                .headers_mut()
+                .insert(header::CONTENT_RANGE, content_range);
+            Ok(response)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct ImageResponse {
     pub id: Uuid,
@@ -153,6 +246,7 @@ pub async fn upload_task_image(
 pub async fn serve_image(
     Path(image_id): Path<Uuid>,
     State(deployment): State<DeploymentImpl>,
+    headers: HeaderMap,
 ) -> Result<Response, ApiError> {
     let image_service = deployment.image();
     let image = image_service
@@ -164,15 +258,12 @@ pub async fn serve_image(
     let file = File::open(&file_path).await?;
     let metadata = file.metadata().await?;
 
-    let stream = ReaderStream::new(file);
-    let body = Body::from_stream(stream);
-
     let content_type = image
         .mime_type
         .as_deref()
         .unwrap_or("application/octet-stream");
 
-    build_image_file_response(body, content_type, metadata.len())
+    image_file_response(file, metadata.len(), content_type, &headers).await
 }
 
 pub async fn delete_image(
@@ -256,19 +347,28 @@ pub async fn get_task_image_metadata(
     })))
 }
 
+/// Headroom above the image size limit for multipart framing.
+const MULTIPART_OVERHEAD_BYTES: usize = 64 * 1024;
+
+/// Request body limit for image uploads, derived from the configured image size limit so that
+/// oversized images reach `ImageService` and get the standard error envelope.
+pub(crate) fn upload_body_limit() -> DefaultBodyLimit {
+    match image_max_bytes() {
+        Some(max) => DefaultBodyLimit::max(max as usize + MULTIPART_OVERHEAD_BYTES),
+        None => DefaultBodyLimit::disable(),
+    }
+}
+
 pub fn routes() -> Router<DeploymentImpl> {
     Router::new()
-        .route(
-            "/upload",
-            post(upload_image).layer(DefaultBodyLimit::max(20 * 1024 * 1024)), // 20MB limit
-        )
+        .route("/upload", post(upload_image).layer(upload_body_limit()))
         .route("/{id}/file", get(serve_image))
         .route("/{id}", delete(delete_image))
         .route("/task/{task_id}", get(get_task_images))
         .route("/task/{task_id}/metadata", get(get_task_image_metadata))
         .route(
             "/task/{task_id}/upload",
-            post(upload_task_image).layer(DefaultBodyLimit::max(20 * 1024 * 1024)),
+            post(upload_task_image).layer(upload_body_limit()),
         )
 }
 
@@ -279,7 +379,9 @@ mod tests {
         body::{Body, to_bytes},
         http::{Request, StatusCode, header},
     };
-    use test_support::TestEnv;
+    use db::models::image::Image;
+    use execution::image::{IMAGE_MAX_BYTES_ENV, IMAGE_MAX_DIMENSION_ENV};
+    use test_support::{EnvVarGuard, TestEnv};
     use tower::ServiceExt;
     use uuid::Uuid;
 
@@ -309,18 +411,21 @@ mod tests {
         (boundary, body)
     }
 
-    #[tokio::test]
-    async fn svg_upload_is_rejected() {
-        let (_env_guard, deployment) = setup_deployment().await;
-        let app = http::router(deployment);
-
-        let (boundary, body) = multipart_body(
-            "evil.svg",
-            "image/svg+xml",
-            br#"<svg xmlns="http://www.w3.org/2000/svg"></svg>"#,
-        );
+    /// PNG signature plus an IHDR chunk; enough for upload validation, not a decodable image.
+    fn png_bytes(width: u32, height: u32) -> Vec<u8> {
+        let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+        data.extend_from_slice(&13u32.to_be_bytes());
+        data.extend_from_slice(b"IHDR");
+        data.extend_from_slice(&width.to_be_bytes());
+        data.extend_from_slice(&height.to_be_bytes());
+        data.extend_from_slice(&[8, 6, 0, 0, 0]);
+        data.extend_from_slice(&[0; 4]);
+        data
+    }
 
-        let response = app
+    async fn upload(app: &axum::Router, filename: &str, data: &[u8]) -> axum::response::Response {
+        let (boundary, body) = multipart_body(filename, "image/png", data);
+        app.clone()
             .oneshot(
                 Request::builder()
                     .method("POST")
@@ -333,19 +438,28 @@ mod tests {
                     .unwrap(),
             )
             .await
-            .unwrap();
+            .unwrap()
+    }
 
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    async fn error_message(response: axum::response::Response) -> String {
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], false);
+        json["message"].as_str().unwrap_or_default().to_string()
     }
 
     #[tokio::test]
-    async fn uploaded_images_are_not_publicly_cacheable_and_nosniff() {
+    async fn svg_upload_is_rejected() {
         let (_env_guard, deployment) = setup_deployment().await;
         let app = http::router(deployment);
 
-        let (boundary, body) = multipart_body("ok.png", "image/png", b"not-a-real-png");
+        let (boundary, body) = multipart_body(
+            "evil.svg",
+            "image/svg+xml",
+            br#"<svg xmlns="http://www.w3.org/2000/svg"></svg>"#,
+        );
+
         let response = app
-            .clone()
             .oneshot(
                 Request::builder()
                     .method("POST")
@@ -360,6 +474,15 @@ mod tests {
             .await
             .unwrap();
 
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn uploaded_images_are_not_publicly_cacheable_and_nosniff() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        let app = http::router(deployment);
+
+        let response = upload(&app, "ok.png", &png_bytes(1, 1)).await;
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
@@ -395,4 +518,157 @@ mod tests {
             Some("nosniff")
         );
     }
+
+    #[tokio::test]
+    async fn image_file_honors_byte_ranges() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        let app = http::router(deployment);
+
+        let data = png_bytes(1, 1);
+        let len = data.len();
+        let response = upload(&app, "ok.png", &data).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let image_id = json
+            .pointer("/data/id")
+            .and_then(|v| v.as_str())
+            .expect("upload response should include image id");
+
+        let request = |range: &str| {
+            Request::builder()
+                .uri(format!("/api/images/{image_id}/file"))
+                .header(header::RANGE, range)
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let response = app.clone().oneshot(request("bytes=4-7")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::CONTENT_RANGE)
+                .and_then(|v| v.to_str().ok()),
+            Some(format!("bytes 4-7/{len}").as_str())
+        );
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&body[..], &data[4..8]);
+
+        let response = app.clone().oneshot(request("bytes=-3")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&body[..], &data[len - 3..]);
+
+        let response = app.oneshot(request("bytes=100-")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::CONTENT_RANGE)
+                .and_then(|v| v.to_str().ok()),
+            Some(format!("bytes */{len}").as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn identical_uploads_share_one_stored_image() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        let app = http::router(deployment.clone());
+
+        let mut ids = Vec::new();
+        for filename in ["first.png", "second.png"] {
+            let response = upload(&app, filename, &png_bytes(2, 2)).await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            ids.push(
+                json.pointer("/data/id")
+                    .and_then(|v| v.as_str())
+                    .expect("upload response should include image id")
+                    .to_string(),
+            );
+        }
+        assert_eq!(ids[0], ids[1]);
+
+        let stored = Image::find_orphaned_images(&deployment.db().pool)
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), 1);
+        assert!(deployment.image().get_absolute_path(&stored[0]).exists());
+    }
+
+    #[tokio::test]
+    async fn oversized_upload_is_rejected_with_413() {
+        let _limits = EnvVarGuard::set(IMAGE_MAX_BYTES_ENV, "16");
+        let (_env_guard, deployment) = setup_deployment().await;
+        let app = http::router(deployment);
+
+        let response = upload(&app, "big.png", &png_bytes(1, 1)).await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert!(error_message(response).await.contains("too large"));
+    }
+
+    #[tokio::test]
+    async fn oversized_dimensions_are_rejected_with_413() {
+        let _limits = EnvVarGuard::set(IMAGE_MAX_DIMENSION_ENV, "100");
+        let (_env_guard, deployment) = setup_deployment().await;
+        let app = http::router(deployment);
+
+        let response = upload(&app, "wide.png", &png_bytes(101, 1)).await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert!(error_message(response).await.contains("101x1"));
+
+        let response = upload(&app, "ok.png", &png_bytes(100, 100)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn spoofed_content_type_is_rejected_with_415() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        let app = http::router(deployment);
+
+        let response = upload(&app, "fake.png", b"<html><script>alert(1)</script></html>").await;
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert!(error_message(response).await.contains("do not match"));
+
+        let mut gif = b"GIF89a".to_vec();
+        gif.extend_from_slice(&[1, 0, 1, 0]);
+        let response = upload(&app, "gif-as.png", &gif).await;
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn exif_metadata_is_stripped_on_upload() {
+        let (_env_guard, deployment) = setup_deployment().await;
+        let app = http::router(deployment);
+
+        let clean = png_bytes(1, 1);
+        let mut with_exif = clean.clone();
+        with_exif.extend_from_slice(&6u32.to_be_bytes());
+        with_exif.extend_from_slice(b"eXIfMM\0*GP");
+        with_exif.extend_from_slice(&[0; 4]);
+
+        let response = upload(&app, "photo.png", &with_exif).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["size_bytes"], clean.len());
+        let image_id = json["data"]["id"].as_str().unwrap();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/images/{image_id}/file"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&body[..], &clean[..]);
+    }
 }
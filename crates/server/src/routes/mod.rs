@@ -1,3 +1,4 @@
+pub mod admin;
 pub mod approvals;
 pub mod archived_kanbans;
 pub mod config;
@@ -10,12 +11,14 @@ pub(crate) mod idempotency;
 pub mod images;
 pub mod milestones;
 pub mod projects;
+pub mod release_notes;
 pub mod repo;
 pub mod scratch;
 pub mod sessions;
+pub mod sounds;
 pub mod tags;
 pub mod task_attempts;
+pub mod task_deletion;
 pub mod tasks;
 pub mod translation;
-
-pub(crate) mod task_deletion;
+pub mod webhooks;
@@ -9,7 +9,7 @@ use axum::{
         ws::{Message, WebSocket, WebSocketUpgrade},
     },
     http::StatusCode,
-    middleware::{Next, from_fn_with_state},
+    middleware::{Next, from_fn, from_fn_with_state},
     response::{IntoResponse, Json as ResponseJson, Response},
     routing::get,
 };
@@ -26,7 +26,7 @@ use ts_rs::TS;
 use utils_core::response::ApiResponse;
 use uuid::Uuid;
 
-use crate::{DeploymentImpl, error::ApiError};
+use crate::{DeploymentImpl, error::ApiError, middleware::conditional_get};
 
 const WS_PING_INTERVAL: Duration = Duration::from_secs(30);
 
@@ -614,7 +614,12 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         ));
 
     let projects_router = Router::new()
-        .route("/", get(get_projects).post(create_project))
+        .route(
+            "/",
+            get(get_projects)
+                .layer(from_fn(conditional_get::etag_middleware))
+                .post(create_project),
+        )
         .route(
             "/{project_id}/repositories/{repo_id}",
             get(get_project_repository)
@@ -636,6 +641,7 @@ pub struct ProjectPublic {
     pub dev_script_working_dir: Option<String>,
     pub default_agent_working_dir: Option<String>,
     pub git_no_verify_override: Option<bool>,
+    pub default_executor_profile: Option<executors::profile::ExecutorProfileId>,
     pub scheduler_max_concurrent: i32,
     pub scheduler_max_retries: i32,
     pub default_continuation_turns: i32,
@@ -719,6 +725,7 @@ pub(crate) fn project_public_from_config(project: &config::ProjectConfig) -> Opt
         dev_script_working_dir: project.dev_script_working_dir.clone(),
         default_agent_working_dir: project.default_agent_working_dir.clone(),
         git_no_verify_override: project.git_no_verify_override,
+        default_executor_profile: project.default_executor_profile.clone(),
         scheduler_max_concurrent: project.scheduler_max_concurrent,
         scheduler_max_retries: project.scheduler_max_retries,
         default_continuation_turns: project.default_continuation_turns,
@@ -0,0 +1,177 @@
+use std::{cmp::Ordering, sync::LazyLock};
+
+use app_runtime::Deployment;
+use axum::{
+    Router,
+    extract::{Query, State},
+    response::Json as ResponseJson,
+    routing::get,
+};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utils_core::{response::ApiResponse, version::APP_VERSION};
+
+use crate::{DeploymentImpl, error::ApiError};
+
+const RELEASE_NOTES_YAML: &str = include_str!("../../../../docs/release-notes.yaml");
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ReleaseNotesEntry {
+    pub version: String,
+    #[serde(default)]
+    pub highlights: Vec<String>,
+    #[serde(default)]
+    pub fixes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseNotesFile {
+    releases: Vec<ReleaseNotesEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReleaseNotesQuery {
+    /// Exclusive lower bound; defaults to the stored `last_app_version`.
+    pub since: Option<String>,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct ReleaseNotesResponse {
+    pub current_version: String,
+    pub since: Option<String>,
+    /// Newest first.
+    pub releases: Vec<ReleaseNotesEntry>,
+}
+
+static RELEASE_NOTES: LazyLock<Vec<ReleaseNotesEntry>> = LazyLock::new(
+    || match serde_yaml::from_str::<ReleaseNotesFile>(RELEASE_NOTES_YAML) {
+        Ok(file) => file.releases,
+        Err(err) => {
+            tracing::error!(error = %err, "Failed to parse embedded release notes");
+            Vec::new()
+        }
+    },
+);
+
+fn parse_version(value: &str) -> Option<Vec<u64>> {
+    value
+        .trim()
+        .trim_start_matches('v')
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+fn compare_versions(a: &[u64], b: &[u64]) -> Ordering {
+    let len = a.len().max(b.len());
+    (0..len)
+        .map(|i| {
+            a.get(i)
+                .copied()
+                .unwrap_or(0)
+                .cmp(&b.get(i).copied().unwrap_or(0))
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Entries with `since < version <= current`, newest first. Entries with unparseable versions
+/// are skipped.
+fn releases_between(
+    releases: &[ReleaseNotesEntry],
+    since: Option<&[u64]>,
+    current: &[u64],
+) -> Vec<ReleaseNotesEntry> {
+    let mut selected: Vec<(Vec<u64>, ReleaseNotesEntry)> = releases
+        .iter()
+        .filter_map(|entry| Some((parse_version(&entry.version)?, entry.clone())))
+        .filter(|(version, _)| {
+            compare_versions(version, current).is_le()
+                && since.is_none_or(|since| compare_versions(version, since).is_gt())
+        })
+        .collect();
+    selected.sort_by(|(a, _), (b, _)| compare_versions(b, a));
+    selected.into_iter().map(|(_, entry)| entry).collect()
+}
+
+pub async fn get_release_notes(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ReleaseNotesQuery>,
+) -> Result<ResponseJson<ApiResponse<ReleaseNotesResponse>>, ApiError> {
+    let since = match query.since.map(|value| value.trim().to_string()) {
+        Some(value) if !value.is_empty() => Some(value),
+        _ => deployment.config().read().await.last_app_version.clone(),
+    };
+    let since_version = match since.as_deref() {
+        Some(value) => Some(parse_version(value).ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "Invalid version '{value}': expected dotted numbers such as 0.0.140"
+            ))
+        })?),
+        None => None,
+    };
+    let current = parse_version(APP_VERSION).unwrap_or_default();
+
+    Ok(ResponseJson(ApiResponse::success(ReleaseNotesResponse {
+        current_version: APP_VERSION.to_string(),
+        releases: releases_between(&RELEASE_NOTES, since_version.as_deref(), &current),
+        since,
+    })))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route("/release-notes", get(get_release_notes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(version: &str) -> ReleaseNotesEntry {
+        ReleaseNotesEntry {
+            version: version.to_string(),
+            highlights: vec![format!("Changes in {version}")],
+            fixes: Vec::new(),
+        }
+    }
+
+    fn versions(entries: &[ReleaseNotesEntry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.version.as_str()).collect()
+    }
+
+    #[test]
+    fn releases_between_returns_range_newest_first() {
+        let releases = vec![
+            entry("0.0.9"),
+            entry("0.0.12"),
+            entry("0.0.10"),
+            entry("0.0.13"),
+            entry("not-a-version"),
+        ];
+        let current = parse_version("0.0.12").unwrap();
+
+        let since = parse_version("0.0.9").unwrap();
+        assert_eq!(
+            versions(&releases_between(&releases, Some(&since), &current)),
+            vec!["0.0.12", "0.0.10"]
+        );
+
+        assert_eq!(
+            versions(&releases_between(&releases, None, &current)),
+            vec!["0.0.12", "0.0.10", "0.0.9"]
+        );
+
+        let since = parse_version("0.0.12").unwrap();
+        assert!(releases_between(&releases, Some(&since), &current).is_empty());
+    }
+
+    #[test]
+    fn embedded_release_notes_parse() {
+        let file = serde_yaml::from_str::<ReleaseNotesFile>(RELEASE_NOTES_YAML).unwrap();
+        assert!(
+            file.releases
+                .iter()
+                .all(|entry| parse_version(&entry.version).is_some())
+        );
+    }
+}
@@ -146,7 +146,7 @@ mod tests {
 
     use app_runtime::Deployment;
     use axum::{
-        body::Body,
+        body::{Body, to_bytes},
         http::{Request, StatusCode, header},
     };
     use serde_json::json;
@@ -366,4 +366,51 @@ mod tests {
             assert_eq!(response.status(), StatusCode::FORBIDDEN);
         }
     }
+
+    #[tokio::test]
+    async fn repo_branches_lists_local_branches_with_default_marked() {
+        let (_env_guard, deployment, workspace_dir) = setup().await;
+
+        let repo = deployment
+            .repo()
+            .init_repo(
+                &deployment.db().pool,
+                deployment.git(),
+                &workspace_dir.to_string_lossy(),
+                "branches-repo",
+            )
+            .await
+            .unwrap();
+        repos::git::GitCli::new()
+            .git(&repo.path, ["branch", "feature"])
+            .unwrap();
+
+        let app = http::router(deployment);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/repos/{}/branches", repo.id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let branches = json["data"].as_array().expect("branches");
+        let branch = |name: &str| {
+            branches
+                .iter()
+                .find(|branch| branch["name"] == name)
+                .unwrap_or_else(|| panic!("missing branch {name}"))
+        };
+
+        assert_eq!(branches.len(), 2);
+        assert_eq!(branch("main")["is_default"], true);
+        assert_eq!(branch("main")["is_current"], true);
+        assert_eq!(branch("feature")["is_default"], false);
+        assert_eq!(branch("feature")["is_remote"], false);
+    }
 }
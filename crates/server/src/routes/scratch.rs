@@ -10,7 +10,9 @@ use axum::{
     response::{IntoResponse, Json as ResponseJson},
     routing::get,
 };
-use db::models::scratch::{CreateScratch, Scratch, ScratchType, UpdateScratch};
+use db::models::scratch::{
+    CreateScratch, DEFAULT_SCRATCH_MAX_BYTES, Scratch, ScratchType, UpdateScratch,
+};
 use futures_util::{SinkExt, StreamExt, TryStreamExt};
 use logs_axum::SequencedLogMsgAxumExt;
 use serde::Deserialize;
@@ -20,6 +22,38 @@ use uuid::Uuid;
 use crate::{DeploymentImpl, error::ApiError};
 
 const WS_PING_INTERVAL: Duration = Duration::from_secs(30);
+const SCRATCH_MAX_BYTES_ENV: &str = "VK_SCRATCH_MAX_BYTES";
+
+/// Maximum serialized scratch payload size; `None` disables the limit.
+fn scratch_max_bytes() -> Option<usize> {
+    let raw = match std::env::var(SCRATCH_MAX_BYTES_ENV) {
+        Ok(value) => value,
+        Err(std::env::VarError::NotPresent) => return Some(DEFAULT_SCRATCH_MAX_BYTES),
+        Err(err) => {
+            tracing::warn!(error = %err, "Failed to read {SCRATCH_MAX_BYTES_ENV}; using default");
+            return Some(DEFAULT_SCRATCH_MAX_BYTES);
+        }
+    };
+
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        tracing::warn!("{SCRATCH_MAX_BYTES_ENV} is set but empty; using default");
+        return Some(DEFAULT_SCRATCH_MAX_BYTES);
+    }
+
+    match trimmed.parse::<i64>() {
+        Ok(value) if value <= 0 => None,
+        Ok(value) => Some(value as usize),
+        Err(err) => {
+            tracing::warn!(
+                value = trimmed,
+                error = %err,
+                "Invalid {SCRATCH_MAX_BYTES_ENV}; using default"
+            );
+            Some(DEFAULT_SCRATCH_MAX_BYTES)
+        }
+    }
+}
 
 /// Path parameters for scratch routes with composite key
 #[derive(Deserialize)]
@@ -64,6 +98,9 @@ pub async fn create_scratch(
         .payload
         .validate_type(scratch_type)
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    if let Some(max_bytes) = scratch_max_bytes() {
+        payload.payload.validate_size(max_bytes)?;
+    }
 
     let scratch = Scratch::create(&deployment.db().pool, id, &payload).await?;
     Ok(ResponseJson(ApiResponse::success(scratch)))
@@ -88,6 +125,9 @@ pub async fn update_scratch(
         .payload
         .validate_type(scratch_type)
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    if let Some(max_bytes) = scratch_max_bytes() {
+        payload.payload.validate_size(max_bytes)?;
+    }
 
     // Upsert: creates if not exists, updates if exists
     let scratch = Scratch::update(&deployment.db().pool, id, &scratch_type, &payload).await?;
@@ -23,6 +23,7 @@ use db::{
     },
 };
 use execution::container::{ContainerService, normalize_and_resolve_workspace_working_dir};
+use executors::executors::codex::ReasoningEffort;
 use executors_protocol::{
     ExecutorProfileId,
     actions::{
@@ -205,6 +206,12 @@ pub struct CreateFollowUpAttempt {
     pub retry_process_id: Option<Uuid>,
     pub force_when_dirty: Option<bool>,
     pub perform_git_reset: Option<bool>,
+    /// Optional model override for this run only; the session's executor profile is unchanged.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Optional reasoning effort override, applied by executors that support it (Codex).
+    #[serde(default)]
+    pub reasoning_effort: Option<ReasoningEffort>,
 }
 
 pub async fn follow_up(
@@ -308,6 +315,16 @@ pub async fn follow_up(
                     .await?;
 
             let prompt = payload.prompt.clone();
+            let model_override = payload
+                .model
+                .as_deref()
+                .map(str::trim)
+                .filter(|model| !model.is_empty())
+                .map(str::to_string);
+            let reasoning_effort_override = payload
+                .reasoning_effort
+                .as_ref()
+                .map(|effort| effort.as_ref().to_string());
 
             let config = deployment.config().read().await;
             let project_config = config
@@ -363,6 +380,8 @@ pub async fn follow_up(
                     executor_profile_id: executor_profile_id.clone(),
                     working_dir: working_dir.clone(),
                     image_paths: image_paths.clone(),
+                    model: model_override.clone(),
+                    reasoning_effort: reasoning_effort_override.clone(),
                 })
             } else {
                 ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
@@ -370,6 +389,8 @@ pub async fn follow_up(
                     executor_profile_id: executor_profile_id.clone(),
                     working_dir,
                     image_paths,
+                    model: model_override,
+                    reasoning_effort: reasoning_effort_override,
                 })
             };
 
@@ -1,7 +1,11 @@
 use app_runtime::Deployment;
 use axum::{
-    Extension, Json, Router, extract::State, http::HeaderMap, middleware::from_fn_with_state,
-    response::Json as ResponseJson, routing::get,
+    Extension, Json, Router,
+    extract::State,
+    http::HeaderMap,
+    middleware::from_fn_with_state,
+    response::Json as ResponseJson,
+    routing::{get, post},
 };
 use db::models::{scratch::DraftFollowUpData, session::Session};
 use execution::queued_message::QueueStatus;
@@ -18,7 +22,7 @@ pub struct QueueMessageRequest {
     pub variant: Option<String>,
 }
 
-/// Queue a follow-up message to be executed when the current execution finishes
+/// Queue a follow-up message behind any already queued; messages run in order as executions finish
 pub async fn queue_message(
     Extension(session): Extension<Session>,
     State(deployment): State<DeploymentImpl>,
@@ -33,29 +37,42 @@ pub async fn queue_message(
         variant: payload.variant,
     };
 
-    let queued = match key {
-        Some(key) => deployment
-            .queued_message_service()
-            .queue_message_idempotent(session.id, key, hash, data)
-            .map_err(|_| ApiError::Conflict("Idempotency key conflict".to_string()))?,
-        None => deployment
-            .queued_message_service()
-            .queue_message(session.id, data),
-    };
+    let queue = deployment.queued_message_service();
+    match key {
+        Some(key) => {
+            queue
+                .queue_message_idempotent(session.id, key, hash, data)
+                .map_err(|_| ApiError::Conflict("Idempotency key conflict".to_string()))?;
+        }
+        None => {
+            queue.queue_message(session.id, data);
+        }
+    }
 
-    Ok(ResponseJson(ApiResponse::success(QueueStatus::Queued {
-        message: queued,
-    })))
+    Ok(ResponseJson(ApiResponse::success(
+        queue.get_status(session.id),
+    )))
 }
 
-/// Cancel a queued follow-up message
+/// Cancel the next queued follow-up message, keeping any queued behind it
 pub async fn cancel_queued_message(
     Extension(session): Extension<Session>,
     State(deployment): State<DeploymentImpl>,
 ) -> Result<ResponseJson<ApiResponse<QueueStatus>>, ApiError> {
-    deployment
-        .queued_message_service()
-        .cancel_queued(session.id);
+    let queue = deployment.queued_message_service();
+    queue.cancel_queued(session.id);
+
+    Ok(ResponseJson(ApiResponse::success(
+        queue.get_status(session.id),
+    )))
+}
+
+/// Clear every queued follow-up message for the session
+pub async fn clear_queued_messages(
+    Extension(session): Extension<Session>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<QueueStatus>>, ApiError> {
+    deployment.queued_message_service().clear_queue(session.id);
 
     Ok(ResponseJson(ApiResponse::success(QueueStatus::Empty)))
 }
@@ -76,8 +93,9 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
             "/",
             get(get_queue_status)
                 .post(queue_message)
-                .delete(cancel_queued_message),
+                .delete(clear_queued_messages),
         )
+        .route("/cancel-next", post(cancel_queued_message))
         .layer(from_fn_with_state(
             deployment.clone(),
             load_session_middleware::<DeploymentImpl>,
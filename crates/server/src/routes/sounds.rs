@@ -0,0 +1,294 @@
+use axum::{
+    Router,
+    body::Body,
+    extract::{DefaultBodyLimit, Multipart, Path},
+    http::{StatusCode, header},
+    response::{Json as ResponseJson, Response},
+    routing::get,
+};
+use config::{custom_sound_path, custom_sounds_dir, is_valid_custom_sound_name};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utils_core::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// Largest accepted custom sound upload.
+pub const CUSTOM_SOUND_MAX_BYTES: usize = 5 * 1024 * 1024;
+
+/// Headroom above the sound size limit for multipart framing.
+const MULTIPART_OVERHEAD_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct CustomSound {
+    /// File name to reference as `{ custom: <name> }` in `notifications.event_sounds`.
+    pub name: String,
+    pub size_bytes: u64,
+    pub url: String,
+}
+
+impl CustomSound {
+    fn new(name: String, size_bytes: u64) -> Self {
+        let url = format!("/api/sounds/custom/{name}");
+        Self {
+            name,
+            size_bytes,
+            url,
+        }
+    }
+}
+
+/// Only WAV is accepted: it is the one format every platform player we shell out to
+/// (`afplay`, `paplay`/`aplay`, PowerShell `SoundPlayer`) understands.
+fn validate_sound(data: &[u8]) -> Result<(), ApiError> {
+    if data.len() > CUSTOM_SOUND_MAX_BYTES {
+        return Err(ApiError::BadRequest(format!(
+            "Sound file is too large: {} bytes (max {CUSTOM_SOUND_MAX_BYTES})",
+            data.len()
+        )));
+    }
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        return Err(ApiError::BadRequest(
+            "Unsupported sound format: only WAV files are accepted".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Derives the stored `<stem>.wav` name from an uploaded file name.
+fn sound_name_from_filename(filename: &str) -> Option<String> {
+    let base = filename.rsplit(['/', '\\']).next().unwrap_or(filename);
+    let stem = base
+        .rsplit_once('.')
+        .map_or(base, |(stem, _)| stem)
+        .trim_start_matches('.');
+    let stem: String = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .take(64)
+        .collect();
+    let name = format!("{stem}.wav");
+    is_valid_custom_sound_name(&name).then_some(name)
+}
+
+fn resolve_sound_path(name: &str) -> Result<std::path::PathBuf, ApiError> {
+    custom_sound_path(name)
+        .filter(|path| path.is_file())
+        .ok_or_else(|| ApiError::NotFound(format!("Custom sound '{name}' not found")))
+}
+
+pub async fn list_custom_sounds() -> Result<ResponseJson<ApiResponse<Vec<CustomSound>>>, ApiError> {
+    let mut sounds = Vec::new();
+    let mut entries = match tokio::fs::read_dir(custom_sounds_dir()).await {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(ResponseJson(ApiResponse::success(sounds)));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    while let Some(entry) = entries.next_entry().await? {
+        let Ok(name) = entry.file_name().into_string() else {
+            continue;
+        };
+        let metadata = entry.metadata().await?;
+        if metadata.is_file() && is_valid_custom_sound_name(&name) {
+            sounds.push(CustomSound::new(name, metadata.len()));
+        }
+    }
+    sounds.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(ResponseJson(ApiResponse::success(sounds)))
+}
+
+/// Stores the `sound` field of a multipart upload. Re-uploading a name replaces the file.
+pub async fn upload_custom_sound(
+    mut multipart: Multipart,
+) -> Result<ResponseJson<ApiResponse<CustomSound>>, ApiError> {
+    while let Some(field) = multipart.next_field().await? {
+        if field.name() != Some("sound") {
+            continue;
+        }
+        let filename = field.file_name().unwrap_or("sound.wav").to_string();
+        let name = sound_name_from_filename(&filename)
+            .ok_or_else(|| ApiError::BadRequest(format!("Invalid sound file name '{filename}'")))?;
+        let data = field.bytes().await?;
+        validate_sound(&data)?;
+
+        let dir = custom_sounds_dir();
+        tokio::fs::create_dir_all(&dir).await?;
+        tokio::fs::write(dir.join(&name), &data).await?;
+        return Ok(ResponseJson(ApiResponse::success(CustomSound::new(
+            name,
+            data.len() as u64,
+        ))));
+    }
+
+    Err(ApiError::BadRequest(
+        "Missing 'sound' file field".to_string(),
+    ))
+}
+
+pub async fn serve_custom_sound(Path(name): Path<String>) -> Result<Response, ApiError> {
+    let data = tokio::fs::read(resolve_sound_path(&name)?).await?;
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "audio/wav")
+        .header(header::CONTENT_LENGTH, data.len())
+        .header("X-Content-Type-Options", "nosniff")
+        .body(Body::from(data))
+        .map_err(|err| ApiError::Internal(format!("Failed to build sound response: {err}")))
+}
+
+pub async fn delete_custom_sound(
+    Path(name): Path<String>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    tokio::fs::remove_file(resolve_sound_path(&name)?).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/sounds/custom",
+            get(list_custom_sounds)
+                .post(upload_custom_sound)
+                .layer(DefaultBodyLimit::max(
+                    CUSTOM_SOUND_MAX_BYTES + MULTIPART_OVERHEAD_BYTES,
+                )),
+        )
+        .route(
+            "/sounds/custom/{name}",
+            get(serve_custom_sound).delete(delete_custom_sound),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use app_runtime::Deployment;
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header},
+    };
+    use config::{NotificationSound, SoundFile};
+    use test_support::TestEnv;
+    use tower::ServiceExt;
+    use utils_core::notifications::NotificationEvent;
+    use uuid::Uuid;
+
+    use super::sound_name_from_filename;
+    use crate::{DeploymentImpl, http};
+
+    fn wav_bytes() -> Vec<u8> {
+        let mut data = b"RIFF".to_vec();
+        data.extend_from_slice(&36u32.to_le_bytes());
+        data.extend_from_slice(b"WAVEfmt ");
+        data.extend_from_slice(&[0; 24]);
+        data
+    }
+
+    fn upload_request(filename: &str, data: &[u8]) -> Request<Body> {
+        let boundary = format!("vk-boundary-{}", Uuid::new_v4());
+        let mut body = Vec::new();
+        body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+        body.extend_from_slice(
+            format!("Content-Disposition: form-data; name=\"sound\"; filename=\"{filename}\"\r\n")
+                .as_bytes(),
+        );
+        body.extend_from_slice(b"Content-Type: audio/wav\r\n\r\n");
+        body.extend_from_slice(data);
+        body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+        Request::builder()
+            .method("POST")
+            .uri("/api/sounds/custom")
+            .header(
+                header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={boundary}"),
+            )
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[test]
+    fn sound_names_are_sanitized() {
+        assert_eq!(
+            sound_name_from_filename("../My Chime.WAV").as_deref(),
+            Some("My-Chime.wav")
+        );
+        assert_eq!(sound_name_from_filename(".wav"), None);
+    }
+
+    #[tokio::test]
+    async fn registered_custom_sound_is_selectable_and_served() {
+        let _env_guard = TestEnv::new("vk-test-");
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let app = http::router(deployment.clone());
+
+        let response = app
+            .clone()
+            .oneshot(upload_request("chime.wav", &wav_bytes()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            json.pointer("/data/url").and_then(|v| v.as_str()),
+            Some("/api/sounds/custom/chime.wav")
+        );
+
+        let notifications = {
+            let mut config = deployment.config().write().await;
+            config.notifications.event_sounds.task_failed = Some(NotificationSound::Custom {
+                custom: "chime.wav".to_string(),
+            });
+            config.notifications.clone()
+        };
+        let selected = notifications.sound_for(NotificationEvent::TaskFailed);
+        let path = selected.get_path(&SoundFile::CowMooing).await.unwrap();
+        assert_eq!(std::fs::read(path).unwrap(), wav_bytes());
+        assert!(matches!(
+            notifications.sound_for(NotificationEvent::TaskCompleted),
+            NotificationSound::Builtin(SoundFile::CowMooing)
+        ));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/sounds/custom/chime.wav")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "audio/wav"
+        );
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body.as_ref(), wav_bytes().as_slice());
+    }
+
+    #[tokio::test]
+    async fn non_wav_upload_is_rejected() {
+        let _env_guard = TestEnv::new("vk-test-");
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let app = http::router(deployment);
+
+        let response = app
+            .oneshot(upload_request(
+                "song.mp3",
+                b"ID3\x04\x00\x00\x00\x00\x00\x00",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(!config::custom_sounds_dir().join("song.wav").exists());
+    }
+}
@@ -1,10 +1,14 @@
 use chrono::{DateTime, Utc};
 use db::models::{merge::Merge, session::Session, workspace::Workspace};
+use executors::executors::codex::ReasoningEffort;
 use executors_protocol::ExecutorProfileId;
-use repos::git::ConflictOp;
+use repos::git::{ConflictOp, WorktreeFileStatus};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
-use utils_core::diff::DiffSummary;
+use utils_core::{
+    approvals::ApprovalStatus,
+    diff::{DiffChangeKind, DiffSummary},
+};
 use uuid::Uuid;
 
 #[derive(Debug, Deserialize, Serialize, TS)]
@@ -14,6 +18,29 @@ pub struct RebaseTaskAttemptRequest {
     pub new_base_branch: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Serialize, TS)]
+pub struct RebaseOntoBaseRequest {
+    pub repo_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, TS)]
+pub struct RebaseOntoBaseResponse {
+    pub base_branch: String,
+    /// New head of the attempt branch; `None` when it already contained the base branch.
+    pub head_sha: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(tag = "type", rename_all = "snake_case")]
+pub enum RebaseOntoBaseError {
+    MergeConflicts {
+        message: String,
+        base_branch: String,
+        conflicted_files: Vec<String>,
+    },
+}
+
 #[derive(Debug, Deserialize, Serialize, TS)]
 pub struct AbortConflictsRequest {
     pub repo_id: Uuid,
@@ -30,6 +57,8 @@ pub enum GitOperationError {
 #[derive(Debug, Deserialize)]
 pub struct TaskAttemptQuery {
     pub task_id: Option<Uuid>,
+    /// Only return attempts carrying this label.
+    pub label: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,6 +96,15 @@ pub enum AttemptChangesBlockedReason {
     ThresholdExceeded,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AttemptRepoChangesSummary {
+    pub repo_name: String,
+    pub file_count: usize,
+    pub added: usize,
+    pub deleted: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskAttemptChangesResponse {
@@ -74,6 +112,9 @@ pub struct TaskAttemptChangesResponse {
     pub blocked: bool,
     pub blocked_reason: Option<AttemptChangesBlockedReason>,
     pub files: Vec<String>,
+    /// Per-repo breakdown of `summary`; repos whose stats failed are omitted.
+    #[serde(default)]
+    pub repos: Vec<AttemptRepoChangesSummary>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -86,11 +127,92 @@ pub enum AttemptArtifactBlockedReason {
     ThresholdExceeded,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AttemptRepoGitStatus {
+    pub repo_id: Uuid,
+    pub repo_name: String,
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub status: WorktreeFileStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AttemptGitStatusResponse {
+    pub repos: Vec<AttemptRepoGitStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CompareAttemptsQuery {
+    /// Attempt of the same task whose branch is compared against this attempt's branch.
+    pub other_attempt_id: Uuid,
+}
+
+/// A file that differs between two attempts, described as a change from this attempt's branch
+/// to the other attempt's branch.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AttemptComparisonFile {
+    pub repo_name: String,
+    pub path: String,
+    /// Previous path for renames and copies.
+    pub old_path: Option<String>,
+    pub change: DiffChangeKind,
+    pub additions: Option<usize>,
+    pub deletions: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct CompareAttemptsResponse {
+    pub attempt_id: Uuid,
+    pub other_attempt_id: Uuid,
+    pub files: Vec<AttemptComparisonFile>,
+    /// Repos attached to only one of the two attempts; they are not compared.
+    pub skipped_repos: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AttemptContentSearchQuery {
+    /// Regular expression matched against each line.
+    pub pattern: String,
+    /// Glob (gitignore syntax) restricting which workspace paths are scanned, e.g. `*.rs` or
+    /// `<repo>/src/**` (paths start with the repo name).
+    pub path_glob: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AttemptContentMatch {
+    /// Repo-prefixed path (`<repo>/<path in repo>`).
+    pub path: String,
+    /// 1-based line number.
+    pub line_number: usize,
+    pub line: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AttemptContentSearchResponse {
+    pub matches: Vec<AttemptContentMatch>,
+    pub files_scanned: usize,
+    #[ts(type = "number")]
+    pub bytes_scanned: u64,
+    /// Set when the match, byte or time budget stopped the scan early, or a file was skipped for
+    /// being too large to scan.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptFileEncoding {
+    #[default]
+    Utf8,
+    Base64,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AttemptFileQuery {
     pub path: Option<String>,
     pub start: Option<u64>,
     pub max_bytes: Option<usize>,
+    /// `base64` returns raw bytes (including binary files) instead of UTF-8 text.
+    pub encoding: Option<AttemptFileEncoding>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -104,6 +226,82 @@ pub struct AttemptFileResponse {
     pub bytes: usize,
     pub total_bytes: Option<u64>,
     pub content: Option<String>,
+    /// Set when the file looks binary; `content` is then omitted rather than lossy-decoded.
+    #[serde(default)]
+    pub binary: bool,
+    pub mime_type: Option<String>,
+    /// Encoding of `content`; `None` when no content is returned.
+    pub encoding: Option<AttemptFileEncoding>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct RevertAttemptFileRequest {
+    /// Repo-prefixed path (`<repo>/<path in repo>`).
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct RevertAttemptFileResponse {
+    pub path: String,
+    /// True when the file was not committed and has been deleted instead of restored.
+    pub removed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AttemptBlameQuery {
+    /// Repo-prefixed path (`<repo>/<path in repo>`).
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AttemptBlameLine {
+    /// 1-based line number in the working-tree file.
+    pub line_number: usize,
+    /// Commit that last touched the line; `None` for uncommitted lines.
+    pub commit: Option<String>,
+    pub author_name: Option<String>,
+    pub author_email: Option<String>,
+    pub authored_at: Option<DateTime<Utc>>,
+    pub summary: Option<String>,
+    /// True when the line is uncommitted or comes from a commit made on the attempt branch.
+    pub attempt_origin: bool,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AttemptBlameResponse {
+    pub path: String,
+    pub lines: Vec<AttemptBlameLine>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(rename_all = "snake_case")]
+pub enum ApprovalDecision {
+    Approved,
+    Denied,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct RespondAllApprovalsRequest {
+    pub decision: ApprovalDecision,
+    /// Sent with every denial; ignored when approving.
+    pub denial_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ApprovalBatchResult {
+    pub approval_id: String,
+    pub tool_name: String,
+    /// Final status; differs from the decision when someone else answered first.
+    pub status: Option<ApprovalStatus>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct RespondAllApprovalsResponse {
+    /// One entry per approval that was pending when the call started, oldest first.
+    pub results: Vec<ApprovalBatchResult>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -125,6 +323,27 @@ pub struct AttemptPatchResponse {
     pub patch: Option<String>,
 }
 
+#[derive(Debug, Deserialize, TS)]
+pub struct ApplyAttemptPatchRequest {
+    /// Unified diff with repo-prefixed paths (`a/<repo>/<path>`), as produced by `patch.diff`.
+    pub patch: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AttemptPatchConflict {
+    pub repo: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ApplyAttemptPatchResponse {
+    /// False when any repo rejected its part of the patch; nothing is applied in that case.
+    pub applied: bool,
+    /// Repo-prefixed paths touched by the patch.
+    pub files: Vec<String>,
+    pub conflicts: Vec<AttemptPatchConflict>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct WorkspaceWithSession {
     #[serde(flatten)]
@@ -145,12 +364,46 @@ pub struct CreateTaskAttemptBody {
     pub repos: Vec<WorkspaceRepoInput>,
     #[serde(default)]
     pub prompt_preset: Option<TaskAttemptPromptPreset>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Optional reasoning effort override, applied by executors that support it (Codex).
+    #[serde(default)]
+    pub reasoning_effort: Option<ReasoningEffort>,
 }
 
-#[derive(Debug, Serialize, Deserialize, TS)]
+#[derive(Debug, Deserialize, Serialize, TS)]
+pub struct UpdateTaskAttemptLabelsRequest {
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct WorkspaceRepoInput {
     pub repo_id: Uuid,
     pub target_branch: String,
+    /// Ref to create `target_branch` from when it does not exist yet (e.g. `main`, `origin/main`).
+    #[serde(default)]
+    #[ts(optional)]
+    pub create_from: Option<String>,
+    /// Sparse-checkout patterns (gitignore syntax); only matching paths are materialized in the
+    /// attempt worktree. Omit or leave empty for a full checkout.
+    #[serde(default)]
+    #[ts(optional)]
+    pub sparse_paths: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct MissingTargetBranch {
+    pub repo_id: Uuid,
+    pub repo_name: String,
+    pub target_branch: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(tag = "type", rename_all = "snake_case")]
+pub enum CreateTaskAttemptError {
+    /// One entry per repo whose `target_branch` exists neither locally nor on a remote.
+    TargetBranchNotFound { repos: Vec<MissingTargetBranch> },
 }
 
 #[derive(Debug, Deserialize, Serialize, TS)]
@@ -166,6 +419,31 @@ pub struct MergeTaskAttemptRequest {
     pub repo_id: Uuid,
 }
 
+#[derive(Debug, Deserialize, Serialize, TS)]
+pub struct ApplyTaskAttemptRequest {
+    pub repo_id: Uuid,
+    /// Local branch that receives the attempt's changes as a single commit.
+    pub target_branch: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, TS)]
+pub struct ApplyTaskAttemptResponse {
+    pub target_branch: String,
+    /// `None` when the target branch already contained every change.
+    pub commit_sha: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(tag = "type", rename_all = "snake_case")]
+pub enum ApplyTaskAttemptError {
+    MergeConflicts {
+        message: String,
+        target_branch: String,
+        conflicted_files: Vec<String>,
+    },
+}
+
 #[derive(Debug, Deserialize, Serialize, TS)]
 pub struct PushTaskAttemptRequest {
     pub repo_id: Uuid,
@@ -269,11 +547,24 @@ pub struct TaskAttemptStatusResponse {
     pub attempt_id: Uuid,
     pub task_id: Uuid,
     pub workspace_branch: String,
+    pub labels: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub latest_session_id: Option<Uuid>,
     pub latest_execution_process_id: Option<Uuid>,
     pub state: AttemptState,
+    /// Latest of the process's last normalized output and its last state change.
     pub last_activity_at: Option<DateTime<Utc>>,
     pub failure_summary: Option<String>,
+    /// The running process is blocked on an approval request nobody has answered yet.
+    #[serde(default)]
+    pub waiting_on_approval: bool,
+    /// Tool awaiting approval when `waiting_on_approval` is set (oldest request first).
+    pub pending_approval_tool: Option<String>,
+    /// A target branch has commits the attempt branch does not contain.
+    #[serde(default)]
+    pub base_diverged: bool,
+    /// Merging the attempt branch into a target branch would conflict.
+    #[serde(default)]
+    pub has_conflicts: bool,
 }
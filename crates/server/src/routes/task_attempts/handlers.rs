@@ -1,21 +1,25 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     io::{Read, Seek, SeekFrom},
     path::{Path, PathBuf},
+    time::Duration,
 };
 
 use app_runtime::Deployment;
 use axum::{
     Extension, Json,
+    body::Body,
     extract::{Query, State},
-    http::{HeaderMap, StatusCode},
-    response::Json as ResponseJson,
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Json as ResponseJson, Response},
 };
+use base64::{Engine as _, prelude::BASE64_STANDARD};
 #[cfg(test)]
 use db::models::milestone::{MilestoneGraph, MilestoneNode};
 use db::{
     DbErr,
     models::{
+        approval as approval_model,
         execution_process::{
             ExecutionProcess, ExecutionProcessPublic, ExecutionProcessRunReason,
             ExecutionProcessStatus,
@@ -41,13 +45,20 @@ use executors_protocol::actions::{
     ExecutorAction, ExecutorActionType,
     script::{ScriptContext, ScriptRequest, ScriptRequestLanguage},
 };
-use repos::git::{
-    ConflictOp, DiffContentPolicy, GitBranchType, GitCliError, GitMergeOptions, GitService,
-    GitServiceError, WorktreeDiffPlan,
+use repos::{
+    content_search::{self, ContentSearchError, ContentSearchLimits},
+    git::{
+        ApplyBranchOutcome, ConflictOp, DiffContentPolicy, DiffTarget, GitBranchType, GitCliError,
+        GitMergeOptions, GitService, GitServiceError, RebaseOntoBaseOutcome, RevertFileOutcome,
+        WorktreeDiffPlan,
+    },
+    patch_files,
 };
 use tasks::orchestration::{self, CreateTaskAttemptInput};
+use tokio_util::sync::CancellationToken;
 use utils_core::{
-    diff::{DiffSummary, create_unified_diff},
+    approvals::{ApprovalResponse, ApprovalStatus},
+    diff::{DiffChangeKind, DiffSummary, create_unified_diff},
     response::ApiResponse,
     text::truncate_to_char_boundary,
 };
@@ -144,16 +155,32 @@ pub async fn get_task_attempts(
     Query(query): Query<TaskAttemptQuery>,
 ) -> Result<ResponseJson<ApiResponse<Vec<Workspace>>>, ApiError> {
     let pool = &deployment.db().pool;
-    let workspaces = Workspace::fetch_all(pool, query.task_id).await?;
+    let workspaces = filter_by_label(
+        Workspace::fetch_all(pool, query.task_id).await?,
+        query.label.as_deref(),
+    );
     Ok(ResponseJson(ApiResponse::success(workspaces)))
 }
 
+fn filter_by_label(workspaces: Vec<Workspace>, label: Option<&str>) -> Vec<Workspace> {
+    match label.map(str::trim).filter(|label| !label.is_empty()) {
+        Some(label) => workspaces
+            .into_iter()
+            .filter(|workspace| workspace.has_label(label))
+            .collect(),
+        None => workspaces,
+    }
+}
+
 pub async fn get_task_attempts_with_latest_session(
     State(deployment): State<DeploymentImpl>,
     Query(query): Query<TaskAttemptQuery>,
 ) -> Result<ResponseJson<ApiResponse<Vec<WorkspaceWithSession>>>, ApiError> {
     let pool = &deployment.db().pool;
-    let workspaces = Workspace::fetch_all(pool, query.task_id).await?;
+    let workspaces = filter_by_label(
+        Workspace::fetch_all(pool, query.task_id).await?,
+        query.label.as_deref(),
+    );
     let workspace_ids: Vec<Uuid> = workspaces.iter().map(|workspace| workspace.id).collect();
     let sessions_by_workspace = Session::find_latest_by_workspace_ids(pool, &workspace_ids).await?;
 
@@ -291,18 +318,31 @@ pub async fn get_task_attempt_status(
     let last_activity_at = latest_process
         .as_ref()
         .map(|process| {
-            if let Some(completed_at) = process.completed_at {
-                completed_at.max(process.updated_at)
-            } else {
-                process.updated_at
-            }
+            let changed_at = match process.completed_at {
+                Some(completed_at) => completed_at.max(process.updated_at),
+                None => process.updated_at,
+            };
+            process
+                .last_activity_at
+                .map_or(changed_at, |output_at| output_at.max(changed_at))
         })
         .or_else(|| latest_session.as_ref().map(|session| session.updated_at));
 
+    let pending_approval = match latest_process.as_ref() {
+        Some(process) if state == AttemptState::Running => {
+            approval_model::find_open_by_execution_process(pool, process.id).await?
+        }
+        _ => None,
+    };
+
+    let (base_diverged, has_conflicts) =
+        base_branch_conflict_flags(&deployment, &workspace).await?;
+
     let status = TaskAttemptStatusResponse {
         attempt_id: workspace.id,
         task_id: workspace.task_id,
         workspace_branch: workspace.branch,
+        labels: workspace.labels,
         created_at: workspace.created_at,
         updated_at: workspace.updated_at,
         latest_session_id: latest_session.as_ref().map(|session| session.id),
@@ -310,11 +350,160 @@ pub async fn get_task_attempt_status(
         state,
         last_activity_at,
         failure_summary,
+        waiting_on_approval: pending_approval.is_some(),
+        pending_approval_tool: pending_approval.map(|approval| approval.tool_name),
+        base_diverged,
+        has_conflicts,
     };
 
     Ok(ResponseJson(ApiResponse::success(status)))
 }
 
+/// `(base_diverged, has_conflicts)` across every repo of the attempt.
+///
+/// Best-effort: a repo whose branches cannot be compared (e.g. the attempt branch was never
+/// created) is logged and treated as clean so the status endpoint keeps working.
+async fn base_branch_conflict_flags(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+) -> Result<(bool, bool), ApiError> {
+    let repos = WorkspaceRepo::find_repos_with_target_branch_for_workspace(
+        &deployment.db().pool,
+        workspace.id,
+    )
+    .await?;
+
+    let mut base_diverged = false;
+    let mut has_conflicts = false;
+    for RepoWithTargetBranch {
+        repo,
+        target_branch,
+        ..
+    } in repos
+    {
+        let git = deployment.git().clone();
+        let repo_path = repo.path.clone();
+        let workspace_branch = workspace.branch.clone();
+        match run_git_operation(git, move |git| {
+            git.get_base_conflict_status(&repo_path, &workspace_branch, &target_branch)
+        })
+        .await
+        {
+            Ok(status) => {
+                base_diverged |= status.base_diverged();
+                has_conflicts |= status.has_conflicts();
+            }
+            Err(err) => tracing::warn!(
+                workspace_id = %workspace.id,
+                repo = %repo.name,
+                error = %err,
+                "Failed to check attempt branch against its base"
+            ),
+        }
+    }
+
+    Ok((base_diverged, has_conflicts))
+}
+
+pub async fn update_task_attempt_labels(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<UpdateTaskAttemptLabelsRequest>,
+) -> Result<ResponseJson<ApiResponse<Workspace>>, ApiError> {
+    let workspace =
+        Workspace::set_labels(&deployment.db().pool, workspace.id, &payload.labels).await?;
+    Ok(ResponseJson(ApiResponse::success(workspace)))
+}
+
+/// Page size used when collecting an attempt's pending approvals.
+const PENDING_APPROVALS_PAGE: u64 = 200;
+
+/// Answers every approval of `attempt_id` that is pending when the call starts.
+///
+/// The pending set is snapshotted up front, so approvals created while the batch runs are left
+/// for the caller to review. Approvals answered concurrently keep their existing status, which
+/// is reported back instead of being overwritten.
+pub async fn respond_to_pending_approvals(
+    deployment: &DeploymentImpl,
+    attempt_id: Uuid,
+    request: RespondAllApprovalsRequest,
+    responded_by_client_id: Option<String>,
+) -> Result<RespondAllApprovalsResponse, ApiError> {
+    let pool = &deployment.db().pool;
+    let approvals = deployment.approvals();
+
+    let mut pending = Vec::new();
+    let mut cursor = None;
+    loop {
+        let (page, next_cursor) = approvals
+            .list_approvals_by_attempt(
+                pool,
+                attempt_id,
+                Some("pending"),
+                PENDING_APPROVALS_PAGE,
+                cursor,
+            )
+            .await
+            .map_err(|err| ApiError::Internal(format!("Failed to list approvals: {err}")))?;
+        let full_page = page.len() as u64 == PENDING_APPROVALS_PAGE;
+        pending.extend(page);
+        if !full_page {
+            break;
+        }
+        cursor = next_cursor;
+    }
+    pending.reverse();
+
+    let status = match request.decision {
+        ApprovalDecision::Approved => ApprovalStatus::Approved,
+        ApprovalDecision::Denied => ApprovalStatus::Denied {
+            reason: request.denial_reason,
+        },
+    };
+    let mut results = Vec::with_capacity(pending.len());
+    for approval in pending {
+        let outcome = approvals
+            .respond_with_client_id(
+                pool,
+                &approval.id,
+                ApprovalResponse {
+                    execution_process_id: approval.execution_process_id,
+                    status: status.clone(),
+                },
+                responded_by_client_id.clone(),
+            )
+            .await;
+        let (status, error) = match outcome {
+            Ok((status, _)) => (Some(status), None),
+            Err(err) => {
+                tracing::warn!(
+                    approval_id = %approval.id,
+                    error = %err,
+                    "Failed to respond to approval in batch"
+                );
+                (None, Some(err.to_string()))
+            }
+        };
+        results.push(ApprovalBatchResult {
+            approval_id: approval.id,
+            tool_name: approval.tool_name,
+            status,
+            error,
+        });
+    }
+
+    Ok(RespondAllApprovalsResponse { results })
+}
+
+pub async fn respond_all_task_attempt_approvals(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<RespondAllApprovalsRequest>,
+) -> Result<ResponseJson<ApiResponse<RespondAllApprovalsResponse>>, ApiError> {
+    let response = respond_to_pending_approvals(&deployment, workspace.id, request, None).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
 pub async fn get_task_attempt_changes(
     Extension(workspace): Extension<Workspace>,
     State(deployment): State<DeploymentImpl>,
@@ -356,6 +545,7 @@ pub async fn get_task_attempt_changes(
                     blocked: true,
                     blocked_reason: Some(AttemptChangesBlockedReason::SummaryFailed),
                     files: Vec::new(),
+                    repos: Vec::new(),
                 };
                 return Ok(ResponseJson(ApiResponse::success(response)));
             }
@@ -413,6 +603,7 @@ pub async fn get_task_attempt_changes(
             blocked,
             blocked_reason,
             files: Vec::new(),
+            repos: Vec::new(),
         };
         return Ok(ResponseJson(ApiResponse::success(response)));
     }
@@ -420,6 +611,7 @@ pub async fn get_task_attempt_changes(
     let mut summary = DiffSummary::default();
     let mut summary_failed = false;
     let mut plans: Vec<(String, WorktreeDiffPlan)> = Vec::new();
+    let mut repo_summaries: Vec<AttemptRepoChangesSummary> = Vec::new();
 
     for (repo, worktree_path, base_commit) in &repo_inputs {
         match deployment
@@ -442,6 +634,12 @@ pub async fn get_task_attempt_changes(
                     summary.deleted = summary.deleted.saturating_add(repo_summary.deleted);
                     summary.total_bytes =
                         summary.total_bytes.saturating_add(repo_summary.total_bytes);
+                    repo_summaries.push(AttemptRepoChangesSummary {
+                        repo_name: repo.name.clone(),
+                        file_count: repo_summary.file_count,
+                        added: repo_summary.added,
+                        deleted: repo_summary.deleted,
+                    });
                 }
                 plans.push((repo.name.clone(), plan));
             }
@@ -487,18 +685,158 @@ pub async fn get_task_attempt_changes(
         blocked,
         blocked_reason,
         files,
+        repos: repo_summaries,
     };
 
     Ok(ResponseJson(ApiResponse::success(response)))
 }
 
+pub async fn get_task_attempt_git_status(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<AttemptGitStatusResponse>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let workspace_root = match workspace
+        .container_ref
+        .as_ref()
+        .map(PathBuf::from)
+        .filter(|path| path.exists())
+    {
+        Some(path) => path,
+        None => PathBuf::from(
+            deployment
+                .container()
+                .ensure_container_exists(&workspace)
+                .await?,
+        ),
+    };
+    let canonical_root = std::fs::canonicalize(&workspace_root).map_err(ApiError::Io)?;
+
+    let repositories = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+    let mut repos = Vec::with_capacity(repositories.len());
+    for repo in repositories {
+        let Ok(worktree_path) = std::fs::canonicalize(workspace_root.join(&repo.name)) else {
+            tracing::warn!(
+                workspace_id = %workspace.id,
+                repo = %repo.name,
+                "Skipping git status for missing worktree"
+            );
+            continue;
+        };
+        if !worktree_path.starts_with(&canonical_root) {
+            tracing::warn!(
+                workspace_id = %workspace.id,
+                repo = %repo.name,
+                "Skipping git status for worktree outside the workspace"
+            );
+            continue;
+        }
+
+        let git = deployment.git().clone();
+        let status =
+            run_git_operation(git, move |git| git.get_worktree_file_status(&worktree_path)).await?;
+        repos.push(AttemptRepoGitStatus {
+            repo_id: repo.id,
+            repo_name: repo.name,
+            status,
+        });
+    }
+
+    Ok(ResponseJson(ApiResponse::success(
+        AttemptGitStatusResponse { repos },
+    )))
+}
+
+/// Lists files that differ between the committed branches of two attempts of the same task.
+/// Uncommitted worktree changes are not included.
+pub async fn compare_task_attempts(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<CompareAttemptsQuery>,
+) -> Result<ResponseJson<ApiResponse<CompareAttemptsResponse>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let other = Workspace::find_by_id(pool, query.other_attempt_id)
+        .await?
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("Attempt {} not found", query.other_attempt_id))
+        })?;
+    if other.task_id != workspace.task_id {
+        return Err(ApiError::BadRequest(
+            "Only attempts of the same task can be compared".to_string(),
+        ));
+    }
+
+    let repos = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+    let other_repos = WorkspaceRepo::find_repos_for_workspace(pool, other.id).await?;
+    let repo_ids: HashSet<Uuid> = repos.iter().map(|repo| repo.id).collect();
+    let other_repo_ids: HashSet<Uuid> = other_repos.iter().map(|repo| repo.id).collect();
+    let mut skipped_repos: Vec<String> = other_repos
+        .into_iter()
+        .filter(|repo| !repo_ids.contains(&repo.id))
+        .map(|repo| repo.name)
+        .collect();
+
+    let mut files = Vec::new();
+    for repo in repos {
+        if !other_repo_ids.contains(&repo.id) {
+            skipped_repos.push(repo.name);
+            continue;
+        }
+
+        let git = deployment.git().clone();
+        let repo_path = repo.path.clone();
+        let base_branch = workspace.branch.clone();
+        let branch_name = other.branch.clone();
+        let diffs = run_git_operation(git, move |git| {
+            git.get_diffs(
+                DiffTarget::Branch {
+                    repo_path: &repo_path,
+                    branch_name: &branch_name,
+                    base_branch: &base_branch,
+                },
+                None,
+                DiffContentPolicy::OmitContents,
+            )
+        })
+        .await?;
+
+        files.extend(diffs.into_iter().map(|diff| {
+            let path = GitService::diff_path(&diff);
+            let old_path = match diff.change {
+                DiffChangeKind::Renamed | DiffChangeKind::Copied => diff.old_path,
+                _ => None,
+            };
+            AttemptComparisonFile {
+                repo_name: repo.name.clone(),
+                path,
+                old_path,
+                change: diff.change,
+                additions: diff.additions,
+                deletions: diff.deletions,
+            }
+        }));
+    }
+
+    Ok(ResponseJson(ApiResponse::success(
+        CompareAttemptsResponse {
+            attempt_id: workspace.id,
+            other_attempt_id: other.id,
+            files,
+            skipped_repos,
+        },
+    )))
+}
+
+/// Most bytes of an attempt file returned by one request; blame refuses larger files outright.
+const ATTEMPT_FILE_HARD_MAX_BYTES: usize = 512 * 1024;
+
 pub async fn get_task_attempt_file(
     Extension(workspace): Extension<Workspace>,
     State(deployment): State<DeploymentImpl>,
     Query(query): Query<AttemptFileQuery>,
 ) -> Result<ResponseJson<ApiResponse<AttemptFileResponse>>, ApiError> {
     const DEFAULT_MAX_BYTES: usize = 64 * 1024;
-    const HARD_MAX_BYTES: usize = 512 * 1024;
 
     let path = query
         .path
@@ -508,8 +846,9 @@ pub async fn get_task_attempt_file(
         .ok_or_else(|| ApiError::BadRequest("path is required".to_string()))?;
 
     let start = query.start.unwrap_or(0);
+    let encoding = query.encoding.unwrap_or_default();
     let requested_max_bytes = query.max_bytes.unwrap_or(DEFAULT_MAX_BYTES);
-    if requested_max_bytes > HARD_MAX_BYTES {
+    if requested_max_bytes > ATTEMPT_FILE_HARD_MAX_BYTES {
         return Ok(ResponseJson(ApiResponse::success(AttemptFileResponse {
             path: path.to_string(),
             blocked: true,
@@ -519,6 +858,9 @@ pub async fn get_task_attempt_file(
             bytes: 0,
             total_bytes: None,
             content: None,
+            binary: false,
+            mime_type: None,
+            encoding: None,
         })));
     }
 
@@ -553,6 +895,9 @@ pub async fn get_task_attempt_file(
             bytes: 0,
             total_bytes: None,
             content: None,
+            binary: false,
+            mime_type: None,
+            encoding: None,
         })));
     }
 
@@ -575,11 +920,37 @@ pub async fn get_task_attempt_file(
             bytes: 0,
             total_bytes: None,
             content: None,
+            binary: false,
+            mime_type: None,
+            encoding: None,
         })));
     }
 
     let meta = std::fs::metadata(&canonical_file).map_err(ApiError::Io)?;
     let total_bytes = meta.len();
+
+    // Classify from the head of the file so every page of the same file agrees.
+    let mut file = std::fs::File::open(&canonical_file).map_err(ApiError::Io)?;
+    let mut probe = vec![0u8; BINARY_SNIFF_BYTES.min(total_bytes as usize)];
+    let probe_len = file.read(&mut probe).map_err(ApiError::Io)?;
+    probe.truncate(probe_len);
+    let mime_type = detect_binary_mime_type(&probe, &rel_path);
+    let binary = mime_type.is_some();
+    if binary && encoding == AttemptFileEncoding::Utf8 {
+        return Ok(ResponseJson(ApiResponse::success(AttemptFileResponse {
+            path: path.to_string(),
+            blocked: false,
+            blocked_reason: None,
+            truncated: false,
+            start,
+            bytes: 0,
+            total_bytes: Some(total_bytes),
+            content: None,
+            binary,
+            mime_type,
+            encoding: None,
+        })));
+    }
     if start >= total_bytes {
         return Ok(ResponseJson(ApiResponse::success(AttemptFileResponse {
             path: path.to_string(),
@@ -590,18 +961,24 @@ pub async fn get_task_attempt_file(
             bytes: 0,
             total_bytes: Some(total_bytes),
             content: Some(String::new()),
+            binary,
+            mime_type,
+            encoding: Some(encoding),
         })));
     }
 
     let read_len = requested_max_bytes.min((total_bytes - start) as usize);
-    let mut file = std::fs::File::open(&canonical_file).map_err(ApiError::Io)?;
     file.seek(SeekFrom::Start(start)).map_err(ApiError::Io)?;
     let mut buf = vec![0u8; read_len];
     let n = file.read(&mut buf).map_err(ApiError::Io)?;
     buf.truncate(n);
 
     let truncated = (start as u128).saturating_add(n as u128) < (total_bytes as u128);
-    let content = String::from_utf8_lossy(&buf).into_owned();
+    // Size caps apply to the raw bytes read, not to the encoded output.
+    let content = match encoding {
+        AttemptFileEncoding::Utf8 => String::from_utf8_lossy(&buf).into_owned(),
+        AttemptFileEncoding::Base64 => BASE64_STANDARD.encode(&buf),
+    };
 
     Ok(ResponseJson(ApiResponse::success(AttemptFileResponse {
         path: path.to_string(),
@@ -612,56 +989,27 @@ pub async fn get_task_attempt_file(
         bytes: n,
         total_bytes: Some(total_bytes),
         content: Some(content),
+        binary,
+        mime_type,
+        encoding: Some(encoding),
     })))
 }
 
-pub async fn get_task_attempt_patch(
+/// Grep the attempt workspace for lines matching a regular expression.
+///
+/// Ignored files, `.git` directories, binary files and symlinks are skipped. The scan is capped
+/// by match count, bytes read and wall-clock time, and stops as soon as the request is dropped.
+pub async fn search_task_attempt_content(
     Extension(workspace): Extension<Workspace>,
     State(deployment): State<DeploymentImpl>,
-    Json(request): Json<AttemptPatchRequest>,
-) -> Result<ResponseJson<ApiResponse<AttemptPatchResponse>>, ApiError> {
-    const DEFAULT_MAX_BYTES: usize = 200 * 1024;
-    const HARD_MAX_BYTES: usize = 2 * 1024 * 1024;
-    const MAX_PATHS: usize = 100;
-
-    if request.paths.is_empty() {
-        return Err(ApiError::BadRequest("paths must not be empty".to_string()));
-    }
-
-    if request.paths.len() > MAX_PATHS {
-        return Ok(ResponseJson(ApiResponse::success(AttemptPatchResponse {
-            blocked: true,
-            blocked_reason: Some(AttemptArtifactBlockedReason::TooManyPaths),
-            truncated: false,
-            bytes: 0,
-            paths: request.paths,
-            patch: None,
-        })));
-    }
+    Query(query): Query<AttemptContentSearchQuery>,
+) -> Result<ResponseJson<ApiResponse<AttemptContentSearchResponse>>, ApiError> {
+    const SEARCH_TIME_BUDGET: Duration = Duration::from_secs(10);
 
-    let max_bytes = request.max_bytes.unwrap_or(DEFAULT_MAX_BYTES);
-    if max_bytes > HARD_MAX_BYTES {
-        return Ok(ResponseJson(ApiResponse::success(AttemptPatchResponse {
-            blocked: true,
-            blocked_reason: Some(AttemptArtifactBlockedReason::SizeExceeded),
-            truncated: false,
-            bytes: 0,
-            paths: request.paths,
-            patch: None,
-        })));
+    if query.pattern.is_empty() {
+        return Err(ApiError::BadRequest("pattern is required".to_string()));
     }
 
-    let pool = &deployment.db().pool;
-    let guard_preset = deployment.config().read().await.diff_preview_guard.clone();
-
-    let workspace_repos = WorkspaceRepo::find_by_workspace_id(pool, workspace.id).await?;
-    let target_branches: HashMap<_, _> = workspace_repos
-        .iter()
-        .map(|wr| (wr.repo_id, wr.target_branch.clone()))
-        .collect();
-
-    let repositories = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
-
     let workspace_root = match workspace
         .container_ref
         .as_ref()
@@ -677,45 +1025,401 @@ pub async fn get_task_attempt_patch(
         ),
     };
 
-    let mut repo_plans: Vec<(String, WorktreeDiffPlan)> = Vec::new();
-    let mut failed_plans: std::collections::HashSet<String> = std::collections::HashSet::new();
-    let mut summary = DiffSummary::default();
-    let mut summary_failed = false;
+    let cancel = CancellationToken::new();
+    // Dropping the request (client disconnect) cancels the blocking scan.
+    let _cancel_on_drop = cancel.clone().drop_guard();
+    let cancel_after_budget = cancel.clone();
+    let budget_timer = tokio::spawn(async move {
+        tokio::time::sleep(SEARCH_TIME_BUDGET).await;
+        cancel_after_budget.cancel();
+    });
 
-    for repo in repositories {
-        let worktree_path = workspace_root.join(&repo.name);
-        let branch = &workspace.branch;
+    let pattern = query.pattern;
+    let path_glob = query.path_glob;
+    let outcome = tokio::task::spawn_blocking(move || {
+        content_search::search_content(
+            &workspace_root,
+            &pattern,
+            path_glob.as_deref(),
+            ContentSearchLimits::default(),
+            &cancel,
+        )
+    })
+    .await
+    .map_err(|e| ApiError::Internal(format!("Content search task failed: {e}")))?;
+    budget_timer.abort();
 
-        let Some(target_branch) = target_branches.get(&repo.id) else {
-            summary_failed = true;
-            continue;
-        };
+    let outcome = outcome.map_err(|e| match e {
+        ContentSearchError::InvalidPattern(_) | ContentSearchError::InvalidGlob(_) => {
+            ApiError::BadRequest(e.to_string())
+        }
+        ContentSearchError::RootNotFound(_) => ApiError::NotFound(e.to_string()),
+    })?;
 
-        let base_commit = match deployment
-            .git()
-            .get_base_commit(&repo.path, branch, target_branch)
-        {
-            Ok(commit) => commit,
-            Err(_) => {
-                summary_failed = true;
-                continue;
-            }
-        };
+    Ok(ResponseJson(ApiResponse::success(
+        AttemptContentSearchResponse {
+            matches: outcome
+                .matches
+                .into_iter()
+                .map(|found| AttemptContentMatch {
+                    path: found.path,
+                    line_number: found.line_number,
+                    line: found.line,
+                })
+                .collect(),
+            files_scanned: outcome.files_scanned,
+            bytes_scanned: outcome.bytes_scanned,
+            truncated: outcome.truncated,
+        },
+    )))
+}
 
-        match deployment
-            .git()
-            .get_worktree_diff_plan(&worktree_path, &base_commit, None)
-        {
-            Ok(plan) => {
-                if plan.stats_error().is_some() {
-                    summary_failed = true;
-                } else {
-                    let repo_summary = plan.summary();
-                    summary.file_count = summary.file_count.saturating_add(repo_summary.file_count);
-                    summary.added = summary.added.saturating_add(repo_summary.added);
-                    summary.deleted = summary.deleted.saturating_add(repo_summary.deleted);
-                    summary.total_bytes =
-                        summary.total_bytes.saturating_add(repo_summary.total_bytes);
+/// Undo the attempt's changes to a single file, restoring its content at the point the attempt
+/// branch forked from the repo's target branch.
+///
+/// `path` is prefixed with the repo name (`<repo>/<path in repo>`), matching the paths returned
+/// by the changes and file endpoints. Files that did not exist at the fork point are deleted.
+pub async fn revert_task_attempt_file(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<RevertAttemptFileRequest>,
+) -> Result<ResponseJson<ApiResponse<RevertAttemptFileResponse>>, ApiError> {
+    let pool = &deployment.db().pool;
+    if ExecutionProcess::has_running_non_dev_server_processes_for_workspace(pool, workspace.id)
+        .await?
+    {
+        return Err(ApiError::Conflict(
+            "Attempt has running processes. Stop them before reverting files.".to_string(),
+        ));
+    }
+
+    let path = request.path.trim();
+    let (repo, worktree_path, repo_rel_path) =
+        resolve_attempt_repo_file(&deployment, &workspace, path).await?;
+    let target_branch = WorkspaceRepo::find_by_workspace_and_repo_id(pool, workspace.id, repo.id)
+        .await?
+        .map(|workspace_repo| workspace_repo.target_branch)
+        .ok_or_else(|| ApiError::NotFound("Workspace repo not found".to_string()))?;
+
+    let git = deployment.git().clone();
+    let outcome = run_git_operation(git, move |git| {
+        git.revert_file(&worktree_path, &target_branch, &repo_rel_path)
+    })
+    .await?;
+    let removed = match outcome {
+        RevertFileOutcome::Restored => false,
+        RevertFileOutcome::Removed => true,
+        RevertFileOutcome::NotFound => {
+            return Err(ApiError::NotFound("File does not exist".to_string()));
+        }
+    };
+
+    Ok(ResponseJson(ApiResponse::success(
+        RevertAttemptFileResponse {
+            path: path.to_string(),
+            removed,
+        },
+    )))
+}
+
+/// Blame each line of an attempt file, flagging lines that originate from the attempt.
+///
+/// A line is attempt-origin when it is not committed yet or was committed on the attempt branch
+/// after it forked from the repo's target branch. Files larger than the file endpoint's per-request
+/// cap are rejected rather than blamed.
+pub async fn get_task_attempt_blame(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<AttemptBlameQuery>,
+) -> Result<ResponseJson<ApiResponse<AttemptBlameResponse>>, ApiError> {
+    let path = query.path.trim();
+    let (repo, worktree_path, repo_rel_path) =
+        resolve_attempt_repo_file(&deployment, &workspace, path).await?;
+    let file_path = worktree_path.join(&repo_rel_path);
+    if !file_path.is_file() {
+        return Err(ApiError::NotFound("File does not exist".to_string()));
+    }
+    // Untracked content is read from disk, so a symlink must not lead out of the worktree.
+    let canonical_file = std::fs::canonicalize(&file_path).map_err(ApiError::Io)?;
+    if !canonical_file.starts_with(&worktree_path) {
+        return Err(ApiError::BadRequest(
+            "path resolves outside the repository worktree".to_string(),
+        ));
+    }
+    let file_bytes = std::fs::metadata(&canonical_file)
+        .map_err(ApiError::Io)?
+        .len();
+    if file_bytes > ATTEMPT_FILE_HARD_MAX_BYTES as u64 {
+        return Err(ApiError::BadRequest(format!(
+            "File is too large to blame ({file_bytes} bytes, max {ATTEMPT_FILE_HARD_MAX_BYTES})"
+        )));
+    }
+    let target_branch =
+        WorkspaceRepo::find_by_workspace_and_repo_id(&deployment.db().pool, workspace.id, repo.id)
+            .await?
+            .map(|workspace_repo| workspace_repo.target_branch)
+            .ok_or_else(|| ApiError::NotFound("Workspace repo not found".to_string()))?;
+
+    let git = deployment.git().clone();
+    let (entries, attempt_commits) = run_git_operation(git, move |git| {
+        let entries = git.blame_file(&worktree_path, &repo_rel_path)?;
+        let attempt_commits = git
+            .commits_between(&worktree_path, &target_branch, "HEAD")?
+            .into_iter()
+            .collect::<HashSet<_>>();
+        Ok((entries, attempt_commits))
+    })
+    .await?;
+
+    let lines = entries
+        .into_iter()
+        .map(|entry| {
+            let uncommitted = entry.is_uncommitted();
+            AttemptBlameLine {
+                line_number: entry.line_number,
+                attempt_origin: uncommitted || attempt_commits.contains(&entry.commit),
+                commit: (!uncommitted).then_some(entry.commit),
+                author_name: (!uncommitted).then_some(entry.author_name),
+                author_email: (!uncommitted).then_some(entry.author_email),
+                authored_at: (!uncommitted)
+                    .then(|| chrono::DateTime::from_timestamp(entry.author_time, 0))
+                    .flatten(),
+                summary: (!uncommitted).then_some(entry.summary),
+                content: entry.line,
+            }
+        })
+        .collect();
+
+    Ok(ResponseJson(ApiResponse::success(AttemptBlameResponse {
+        path: path.to_string(),
+        lines,
+    })))
+}
+
+/// Resolve a repo-prefixed attempt path (`<repo>/<path in repo>`) to the repo, its worktree and
+/// the path inside that worktree, rejecting anything that escapes the workspace.
+async fn resolve_attempt_repo_file(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+    path: &str,
+) -> Result<(Repo, PathBuf, String), ApiError> {
+    let pool = &deployment.db().pool;
+    if path.is_empty() {
+        return Err(ApiError::BadRequest("path is required".to_string()));
+    }
+
+    let rel_path = PathBuf::from(path);
+    let mut components = Vec::new();
+    for component in rel_path.components() {
+        match component {
+            std::path::Component::Normal(part) => components.push(part.to_string_lossy()),
+            std::path::Component::CurDir => {}
+            _ => {
+                return Err(ApiError::BadRequest(
+                    "path must be relative to the workspace and must not contain ..".to_string(),
+                ));
+            }
+        }
+    }
+    let Some((repo_name, repo_path)) = components.split_first() else {
+        return Err(ApiError::BadRequest("path is required".to_string()));
+    };
+    if repo_path.is_empty() {
+        return Err(ApiError::BadRequest(
+            "path must name a file inside a repo (<repo>/<path>)".to_string(),
+        ));
+    }
+
+    let repositories = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+    let Some(repo) = repositories
+        .into_iter()
+        .find(|repo| repo.name == *repo_name)
+    else {
+        return Err(ApiError::BadRequest(format!(
+            "'{repo_name}' is not a repository of this attempt"
+        )));
+    };
+
+    let workspace_root = match workspace
+        .container_ref
+        .as_ref()
+        .map(PathBuf::from)
+        .filter(|path| path.exists())
+    {
+        Some(path) => path,
+        None => PathBuf::from(
+            deployment
+                .container()
+                .ensure_container_exists(workspace)
+                .await?,
+        ),
+    };
+    let canonical_root = std::fs::canonicalize(&workspace_root).map_err(ApiError::Io)?;
+    let worktree_path =
+        std::fs::canonicalize(workspace_root.join(&repo.name)).map_err(ApiError::Io)?;
+    if !worktree_path.starts_with(&canonical_root) {
+        return Err(ApiError::BadRequest(
+            "Repository worktree is outside the workspace".to_string(),
+        ));
+    }
+
+    let repo_rel_path = repo_path.join("/");
+    let file_path = worktree_path.join(&repo_rel_path);
+    if file_path.is_dir() {
+        return Err(ApiError::BadRequest("Path is not a file".to_string()));
+    }
+    if let Some(parent) = file_path.parent()
+        && let Ok(canonical_parent) = std::fs::canonicalize(parent)
+        && !canonical_parent.starts_with(&worktree_path)
+    {
+        return Err(ApiError::BadRequest(
+            "path resolves outside the repository worktree".to_string(),
+        ));
+    }
+
+    Ok((repo, worktree_path, repo_rel_path))
+}
+
+const BINARY_SNIFF_BYTES: usize = 8 * 1024;
+
+/// Returns the mime type when `head` looks like binary content, `None` for text.
+fn detect_binary_mime_type(head: &[u8], path: &Path) -> Option<String> {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"\x1f\x8b", "application/gzip"),
+        (b"\x7fELF", "application/x-executable"),
+    ];
+
+    if let Some((_, mime)) = SIGNATURES
+        .iter()
+        .find(|(signature, _)| head.starts_with(signature))
+    {
+        return Some((*mime).to_string());
+    }
+    if head.len() >= 12 && head.starts_with(b"RIFF") && &head[8..12] == b"WEBP" {
+        return Some("image/webp".to_string());
+    }
+
+    let binary = head.contains(&0)
+        || match std::str::from_utf8(head) {
+            Ok(_) => false,
+            // A multi-byte character cut off by the sniff window is still text.
+            Err(err) => err.error_len().is_some(),
+        };
+    binary.then(|| {
+        mime_guess::from_path(path)
+            .first_raw()
+            .unwrap_or("application/octet-stream")
+            .to_string()
+    })
+}
+
+pub async fn get_task_attempt_patch(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<AttemptPatchRequest>,
+) -> Result<ResponseJson<ApiResponse<AttemptPatchResponse>>, ApiError> {
+    const DEFAULT_MAX_BYTES: usize = 200 * 1024;
+    const HARD_MAX_BYTES: usize = 2 * 1024 * 1024;
+    const MAX_PATHS: usize = 100;
+
+    if request.paths.is_empty() {
+        return Err(ApiError::BadRequest("paths must not be empty".to_string()));
+    }
+
+    if request.paths.len() > MAX_PATHS {
+        return Ok(ResponseJson(ApiResponse::success(AttemptPatchResponse {
+            blocked: true,
+            blocked_reason: Some(AttemptArtifactBlockedReason::TooManyPaths),
+            truncated: false,
+            bytes: 0,
+            paths: request.paths,
+            patch: None,
+        })));
+    }
+
+    let max_bytes = request.max_bytes.unwrap_or(DEFAULT_MAX_BYTES);
+    if max_bytes > HARD_MAX_BYTES {
+        return Ok(ResponseJson(ApiResponse::success(AttemptPatchResponse {
+            blocked: true,
+            blocked_reason: Some(AttemptArtifactBlockedReason::SizeExceeded),
+            truncated: false,
+            bytes: 0,
+            paths: request.paths,
+            patch: None,
+        })));
+    }
+
+    let pool = &deployment.db().pool;
+    let guard_preset = deployment.config().read().await.diff_preview_guard.clone();
+
+    let workspace_repos = WorkspaceRepo::find_by_workspace_id(pool, workspace.id).await?;
+    let target_branches: HashMap<_, _> = workspace_repos
+        .iter()
+        .map(|wr| (wr.repo_id, wr.target_branch.clone()))
+        .collect();
+
+    let repositories = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+
+    let workspace_root = match workspace
+        .container_ref
+        .as_ref()
+        .map(PathBuf::from)
+        .filter(|path| path.exists())
+    {
+        Some(path) => path,
+        None => PathBuf::from(
+            deployment
+                .container()
+                .ensure_container_exists(&workspace)
+                .await?,
+        ),
+    };
+
+    let mut repo_plans: Vec<(String, WorktreeDiffPlan)> = Vec::new();
+    let mut failed_plans: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut summary = DiffSummary::default();
+    let mut summary_failed = false;
+
+    for repo in repositories {
+        let worktree_path = workspace_root.join(&repo.name);
+        let branch = &workspace.branch;
+
+        let Some(target_branch) = target_branches.get(&repo.id) else {
+            summary_failed = true;
+            continue;
+        };
+
+        let base_commit = match deployment
+            .git()
+            .get_base_commit(&repo.path, branch, target_branch)
+        {
+            Ok(commit) => commit,
+            Err(_) => {
+                summary_failed = true;
+                continue;
+            }
+        };
+
+        match deployment
+            .git()
+            .get_worktree_diff_plan(&worktree_path, &base_commit, None)
+        {
+            Ok(plan) => {
+                if plan.stats_error().is_some() {
+                    summary_failed = true;
+                } else {
+                    let repo_summary = plan.summary();
+                    summary.file_count = summary.file_count.saturating_add(repo_summary.file_count);
+                    summary.added = summary.added.saturating_add(repo_summary.added);
+                    summary.deleted = summary.deleted.saturating_add(repo_summary.deleted);
+                    summary.total_bytes =
+                        summary.total_bytes.saturating_add(repo_summary.total_bytes);
                 }
                 repo_plans.push((repo.name.clone(), plan));
             }
@@ -854,31 +1558,277 @@ pub async fn get_task_attempt_patch(
     })))
 }
 
-#[cfg(test)]
-fn resolve_executor_profile_id(
-    milestone_node: &MilestoneNode,
-    fallback: ExecutorProfileId,
-) -> ExecutorProfileId {
-    milestone_node
-        .executor_profile_id
-        .clone()
-        .unwrap_or(fallback)
-}
-
-#[cfg(test)]
-async fn resolve_topology_base_branches(
-    pool: &db::DbPool,
-    graph: &MilestoneGraph,
-    node_id: &str,
-) -> Result<Option<HashMap<Uuid, String>>, ApiError> {
-    let node_key = node_id.trim();
-    if node_key.is_empty() {
-        return Err(ApiError::BadRequest(
-            "Milestone node id cannot be empty".to_string(),
-        ));
-    }
+/// Download every repo's changes against its target branch as a single `git apply`-able patch.
+///
+/// Unlike [`get_task_attempt_patch`] this is not guarded or size-capped. Every repo's patch is
+/// generated before the response starts, so a git failure is reported as an error instead of a
+/// truncated download. Paths are prefixed with the repo name (`a/<repo>/<path>`), so the file
+/// applies with `git apply -p2` inside a repo checkout.
+pub async fn download_task_attempt_patch(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<Response, ApiError> {
+    let pool = &deployment.db().pool;
+    let workspace_repos = WorkspaceRepo::find_by_workspace_id(pool, workspace.id).await?;
+    let target_branches: HashMap<_, _> = workspace_repos
+        .iter()
+        .map(|wr| (wr.repo_id, wr.target_branch.clone()))
+        .collect();
+    let repositories = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
 
-    let mut selected_workspace: Option<(Uuid, chrono::DateTime<chrono::Utc>)> = None;
+    let workspace_root = match workspace
+        .container_ref
+        .as_ref()
+        .map(PathBuf::from)
+        .filter(|path| path.exists())
+    {
+        Some(path) => path,
+        None => PathBuf::from(
+            deployment
+                .container()
+                .ensure_container_exists(&workspace)
+                .await?,
+        ),
+    };
+    let canonical_root = std::fs::canonicalize(&workspace_root).map_err(ApiError::Io)?;
+
+    let mut repo_plans = Vec::with_capacity(repositories.len());
+    for repo in repositories {
+        let worktree_path =
+            std::fs::canonicalize(workspace_root.join(&repo.name)).map_err(ApiError::Io)?;
+        if !worktree_path.starts_with(&canonical_root) {
+            return Err(ApiError::BadRequest(
+                "Repository worktree is outside the workspace".to_string(),
+            ));
+        }
+        let target_branch = target_branches
+            .get(&repo.id)
+            .ok_or_else(|| ApiError::NotFound("Workspace repo not found".to_string()))?;
+        let base_commit =
+            deployment
+                .git()
+                .get_base_commit(&repo.path, &workspace.branch, target_branch)?;
+        repo_plans.push((format!("{}/", repo.name), worktree_path, base_commit));
+    }
+
+    let patch = run_git_operation(deployment.git().clone(), move |git| {
+        let mut patch = Vec::new();
+        for (prefix, worktree_path, base_commit) in repo_plans {
+            patch.extend(git.worktree_patch(&worktree_path, &base_commit, &prefix)?);
+        }
+        Ok(patch)
+    })
+    .await?;
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                "text/x-diff; charset=utf-8".to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"attempt-{}.diff\"", workspace.id),
+            ),
+        ],
+        Body::from(patch),
+    )
+        .into_response())
+}
+
+/// Apply an uploaded patch (repo-prefixed paths, as produced by [`download_task_attempt_patch`])
+/// to the attempt's worktrees.
+///
+/// Every path is validated against the attempt's repos before git runs, and each repo's part is
+/// dry-run first: if any repo reports conflicts nothing is applied and the conflicts are returned.
+pub async fn apply_task_attempt_patch(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<ApplyAttemptPatchRequest>,
+) -> Result<ResponseJson<ApiResponse<ApplyAttemptPatchResponse>>, ApiError> {
+    let files = patch_files::split_patch_files(&request.patch)
+        .map_err(|err| ApiError::BadRequest(err.to_string()))?;
+
+    let pool = &deployment.db().pool;
+    let repositories = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+
+    let mut patches_by_repo: Vec<(String, String)> = Vec::new();
+    let mut touched = Vec::new();
+    for file in files {
+        if file.creates_symlink {
+            return Err(ApiError::BadRequest(
+                "patch must not create symlinks".to_string(),
+            ));
+        }
+        let mut section_repo: Option<&str> = None;
+        for path in &file.paths {
+            let repo_name = patch_path_repo(path)?;
+            if !repositories.iter().any(|repo| repo.name == repo_name) {
+                return Err(ApiError::BadRequest(format!(
+                    "'{repo_name}' is not a repository of this attempt"
+                )));
+            }
+            if section_repo.is_some_and(|name| name != repo_name) {
+                return Err(ApiError::BadRequest(
+                    "a patch section must not span repositories".to_string(),
+                ));
+            }
+            section_repo = Some(repo_name);
+        }
+        let Some(repo_name) = section_repo else {
+            continue;
+        };
+        match patches_by_repo
+            .iter_mut()
+            .find(|(name, _)| *name == repo_name)
+        {
+            Some((_, patch)) => patch.push_str(&file.text),
+            None => patches_by_repo.push((repo_name.to_string(), file.text.clone())),
+        }
+        touched.extend(file.paths.iter().cloned());
+    }
+
+    let workspace_root = match workspace
+        .container_ref
+        .as_ref()
+        .map(PathBuf::from)
+        .filter(|path| path.exists())
+    {
+        Some(path) => path,
+        None => PathBuf::from(
+            deployment
+                .container()
+                .ensure_container_exists(&workspace)
+                .await?,
+        ),
+    };
+    let canonical_root = std::fs::canonicalize(&workspace_root).map_err(ApiError::Io)?;
+    let mut targets = Vec::with_capacity(patches_by_repo.len());
+    for (repo_name, patch) in patches_by_repo {
+        let worktree_path =
+            std::fs::canonicalize(workspace_root.join(&repo_name)).map_err(ApiError::Io)?;
+        if !worktree_path.starts_with(&canonical_root) {
+            return Err(ApiError::BadRequest(
+                "Repository worktree is outside the workspace".to_string(),
+            ));
+        }
+        targets.push((repo_name, worktree_path, patch));
+    }
+
+    // `git apply` may read more out of a section than the splitter does (e.g. a plain `---`/`+++`
+    // section after a hunk), so also validate every path git itself reports.
+    let git = deployment.git().clone();
+    let (targets, reported) = match run_git_operation(git, move |git| {
+        let reported = targets
+            .iter()
+            .map(|(_, worktree_path, patch)| git.patch_targets(worktree_path, patch.as_bytes(), 1))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((targets, reported))
+    })
+    .await
+    {
+        Ok(result) => result,
+        Err(GitServiceError::GitCLI(GitCliError::CommandFailed(message))) => {
+            return Err(ApiError::BadRequest(format!("invalid patch: {message}")));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    for ((repo_name, _, _), reported) in targets.iter().zip(reported) {
+        if reported.creates_symlink {
+            return Err(ApiError::BadRequest(
+                "patch must not create symlinks".to_string(),
+            ));
+        }
+        for path in reported.paths {
+            if patch_path_repo(&path)? != repo_name {
+                return Err(ApiError::BadRequest(format!(
+                    "patch path '{path}' is not inside repository '{repo_name}'"
+                )));
+            }
+            touched.push(path);
+        }
+    }
+    touched.sort();
+    touched.dedup();
+
+    let git = deployment.git().clone();
+    let conflicts = run_git_operation(git, move |git| {
+        let mut conflicts = Vec::new();
+        for (repo_name, worktree_path, patch) in &targets {
+            for message in git.check_patch(worktree_path, patch.as_bytes(), 2)? {
+                conflicts.push(AttemptPatchConflict {
+                    repo: repo_name.clone(),
+                    message,
+                });
+            }
+        }
+        if conflicts.is_empty() {
+            for (_, worktree_path, patch) in &targets {
+                git.apply_patch(worktree_path, patch.as_bytes(), 2)?;
+            }
+        }
+        Ok(conflicts)
+    })
+    .await?;
+
+    Ok(ResponseJson(ApiResponse::success(
+        ApplyAttemptPatchResponse {
+            applied: conflicts.is_empty(),
+            files: touched,
+            conflicts,
+        },
+    )))
+}
+
+/// Repo name of a patch path (`<repo>/<path in repo>`), rejecting paths that could escape it.
+fn patch_path_repo(path: &str) -> Result<&str, ApiError> {
+    let invalid = || {
+        ApiError::BadRequest(format!(
+            "patch path '{path}' must stay inside a repository of the workspace"
+        ))
+    };
+    let mut components = Path::new(path).components();
+    let Some(std::path::Component::Normal(repo_name)) = components.next() else {
+        return Err(invalid());
+    };
+    let mut has_file = false;
+    for component in components {
+        if !matches!(component, std::path::Component::Normal(_)) {
+            return Err(invalid());
+        }
+        has_file = true;
+    }
+    if !has_file {
+        return Err(invalid());
+    }
+    repo_name.to_str().ok_or_else(invalid)
+}
+
+#[cfg(test)]
+fn resolve_executor_profile_id(
+    milestone_node: &MilestoneNode,
+    fallback: ExecutorProfileId,
+) -> ExecutorProfileId {
+    milestone_node
+        .executor_profile_id
+        .clone()
+        .unwrap_or(fallback)
+}
+
+#[cfg(test)]
+async fn resolve_topology_base_branches(
+    pool: &db::DbPool,
+    graph: &MilestoneGraph,
+    node_id: &str,
+) -> Result<Option<HashMap<Uuid, String>>, ApiError> {
+    let node_key = node_id.trim();
+    if node_key.is_empty() {
+        return Err(ApiError::BadRequest(
+            "Milestone node id cannot be empty".to_string(),
+        ));
+    }
+
+    let mut selected_workspace: Option<(Uuid, chrono::DateTime<chrono::Utc>)> = None;
 
     for edge in &graph.edges {
         if edge.to.trim() != node_key {
@@ -979,12 +1929,154 @@ fn blocked_predecessors(graph: &MilestoneGraph, node_id: &str) -> Result<Vec<Str
     Ok(blocked)
 }
 
+/// Creates `target_branch` from `create_from` when the branch does not exist yet. A no-op when the
+/// branch already exists locally or as a remote-tracking branch. Returns whether it was created.
+pub async fn create_target_branch_if_missing(
+    deployment: &DeploymentImpl,
+    repo_id: Uuid,
+    target_branch: &str,
+    create_from: &str,
+) -> Result<bool, ApiError> {
+    let repo = Repo::find_by_id(&deployment.db().pool, repo_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Repo {repo_id} not found")))?;
+
+    let git = deployment.git().clone();
+    let repo_path = repo.path.clone();
+    let branch = target_branch.to_string();
+    let base = create_from.trim().to_string();
+    run_git_operation(git, move |git| {
+        if git.check_branch_exists(&repo_path, &branch)? {
+            return Ok(false);
+        }
+        git.ensure_local_branch_from_base(&repo_path, &branch, &base)?;
+        Ok(true)
+    })
+    .await
+    .map_err(|err| match err {
+        GitServiceError::BranchNotFound(base) => ApiError::BadRequest(format!(
+            "create_from ref '{base}' not found in repo {}",
+            repo.name
+        )),
+        GitServiceError::InvalidBranchName(name) => {
+            ApiError::BadRequest(format!("Invalid target_branch '{name}'"))
+        }
+        other => ApiError::GitService(other),
+    })
+}
+
+fn requested_create_from(repo: &WorkspaceRepoInput) -> Option<&str> {
+    repo.create_from
+        .as_deref()
+        .map(str::trim)
+        .filter(|create_from| !create_from.is_empty())
+}
+
+/// Creates the target branches that `repos` ask for via `create_from`. Returns the branches that
+/// were actually created so a failed start can remove them with `delete_created_target_branches`;
+/// if one fails, those created before it are removed here.
+pub async fn create_requested_target_branches(
+    deployment: &DeploymentImpl,
+    repos: &[WorkspaceRepoInput],
+) -> Result<Vec<(Uuid, String)>, ApiError> {
+    let mut created = Vec::new();
+    for repo in repos {
+        let Some(create_from) = requested_create_from(repo) else {
+            continue;
+        };
+        match create_target_branch_if_missing(
+            deployment,
+            repo.repo_id,
+            &repo.target_branch,
+            create_from,
+        )
+        .await
+        {
+            Ok(true) => created.push((repo.repo_id, repo.target_branch.clone())),
+            Ok(false) => {}
+            Err(err) => {
+                delete_created_target_branches(deployment, &created).await;
+                return Err(err);
+            }
+        }
+    }
+    Ok(created)
+}
+
+/// Best-effort removal of branches returned by `create_requested_target_branches`.
+pub async fn delete_created_target_branches(
+    deployment: &DeploymentImpl,
+    created: &[(Uuid, String)],
+) {
+    for (repo_id, branch) in created {
+        let repo = match Repo::find_by_id(&deployment.db().pool, *repo_id).await {
+            Ok(Some(repo)) => repo,
+            Ok(None) => continue,
+            Err(err) => {
+                tracing::warn!(repo_id = %repo_id, error = %err, "Failed to load repo");
+                continue;
+            }
+        };
+        let repo_path = repo.path.clone();
+        let branch_name = branch.clone();
+        if let Err(err) = run_git_operation(deployment.git().clone(), move |git| {
+            git.delete_local_branch(&repo_path, &branch_name)
+        })
+        .await
+        {
+            tracing::warn!(
+                repo_id = %repo_id,
+                branch = %branch,
+                error = %err,
+                "Failed to delete target branch created for a failed attempt start"
+            );
+        }
+    }
+}
+
+/// Returns the requested target branches that exist neither as a local nor a remote-tracking
+/// branch, so attempt start can reject typos before any worktree is created.
+pub async fn find_missing_target_branches(
+    deployment: &DeploymentImpl,
+    repos: &[CreateWorkspaceRepo],
+) -> Result<Vec<MissingTargetBranch>, ApiError> {
+    let pool = &deployment.db().pool;
+    let mut missing = Vec::new();
+    for input in repos {
+        let repo = Repo::find_by_id(pool, input.repo_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("Repo {} not found", input.repo_id)))?;
+
+        let git = deployment.git().clone();
+        let repo_path = repo.path.clone();
+        let target_branch = input.target_branch.clone();
+        let exists = run_git_operation(git, move |git| {
+            git.check_branch_exists(&repo_path, &target_branch)
+        })
+        .await?;
+        if !exists {
+            missing.push(MissingTargetBranch {
+                repo_id: repo.id,
+                repo_name: repo.name,
+                target_branch: input.target_branch.clone(),
+            });
+        }
+    }
+    Ok(missing)
+}
+
 #[axum::debug_handler]
 pub async fn create_task_attempt(
     State(deployment): State<DeploymentImpl>,
     headers: HeaderMap,
     Json(payload): Json<CreateTaskAttemptBody>,
-) -> Result<ResponseJson<ApiResponse<Workspace>>, ApiError> {
+) -> Result<
+    (
+        StatusCode,
+        ResponseJson<ApiResponse<Workspace, CreateTaskAttemptError>>,
+    ),
+    ApiError,
+> {
     if payload.repos.is_empty() {
         return Err(ApiError::BadRequest(
             "At least one repository is required".to_string(),
@@ -995,10 +2087,38 @@ pub async fn create_task_attempt(
         .require_coding_agent(&payload.executor_profile_id)
         .map_err(|err| ApiError::BadRequest(err.to_string()))?;
 
+    let workspace_repos: Vec<CreateWorkspaceRepo> = payload
+        .repos
+        .iter()
+        .map(|repo| CreateWorkspaceRepo {
+            repo_id: repo.repo_id,
+            target_branch: repo.target_branch.clone(),
+            sparse_paths: repo.sparse_paths.clone().unwrap_or_default(),
+        })
+        .collect();
+    // Branches requested with `create_from` are only created once the rest of the request has
+    // been validated, right before the attempt starts.
+    let existing_repos: Vec<CreateWorkspaceRepo> = payload
+        .repos
+        .iter()
+        .zip(&workspace_repos)
+        .filter(|(input, _)| requested_create_from(input).is_none())
+        .map(|(_, repo)| repo.clone())
+        .collect();
+    let missing = find_missing_target_branches(&deployment, &existing_repos).await?;
+    if !missing.is_empty() {
+        return Ok((
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ApiResponse::error_with_data(
+                CreateTaskAttemptError::TargetBranchNotFound { repos: missing },
+            )),
+        ));
+    }
+
     let key = crate::routes::idempotency::idempotency_key(&headers);
     let hash = crate::routes::idempotency::request_hash(&payload)?;
 
-    crate::routes::idempotency::idempotent_success(
+    let ResponseJson(response) = crate::routes::idempotency::idempotent_success(
         &deployment.db().pool,
         "create_task_attempt",
         key,
@@ -1047,25 +2167,32 @@ pub async fn create_task_attempt(
             .await?;
 
             let agent_working_dir = project_config.default_agent_working_dir.clone();
-            let workspace = orchestration::create_task_attempt(
+            let created_branches =
+                create_requested_target_branches(&deployment, &payload.repos).await?;
+            let workspace = match orchestration::create_task_attempt(
                 &runtime,
                 &deployment.db().pool,
                 &CreateTaskAttemptInput {
                     task_id: payload.task_id,
                     executor_profile_id: payload.executor_profile_id.clone(),
-                    repos: payload
-                        .repos
-                        .iter()
-                        .map(|repo| CreateWorkspaceRepo {
-                            repo_id: repo.repo_id,
-                            target_branch: repo.target_branch.clone(),
-                        })
-                        .collect(),
+                    repos: workspace_repos.clone(),
                     prompt_override,
+                    reasoning_effort: payload
+                        .reasoning_effort
+                        .as_ref()
+                        .map(|effort| effort.as_ref().to_string()),
                     agent_working_dir,
+                    labels: payload.labels.clone(),
                 },
             )
-            .await?;
+            .await
+            {
+                Ok(workspace) => workspace,
+                Err(err) => {
+                    delete_created_target_branches(&deployment, &created_branches).await;
+                    return Err(err.into());
+                }
+            };
 
             tracing::info!(
                 "Created and started attempt {} for task {}",
@@ -1075,7 +2202,15 @@ pub async fn create_task_attempt(
             Ok(workspace)
         },
     )
-    .await
+    .await?;
+    let workspace = response
+        .into_data()
+        .ok_or_else(|| ApiError::Internal("Attempt creation returned no workspace".to_string()))?;
+
+    Ok((
+        StatusCode::OK,
+        ResponseJson(ApiResponse::success(workspace)),
+    ))
 }
 
 #[cfg(test)]
@@ -1212,29 +2347,8 @@ pub async fn merge_task_attempt(
             "Milestone entry tasks cannot be merged".to_string(),
         ));
     }
-    let task_uuid_str = task.id.to_string();
-    let first_uuid_section = task_uuid_str.split('-').next().unwrap_or(&task_uuid_str);
-
-    let mut commit_message = format!("{} (vibe-kanban {})", task.title, first_uuid_section);
-
-    // Add description on next line if it exists
-    if let Some(description) = &task.description
-        && !description.trim().is_empty()
-    {
-        commit_message.push_str("\n\n");
-        commit_message.push_str(description);
-    }
-
-    let global_no_verify = deployment.config().read().await.git_no_verify;
-    let no_verify = deployment
-        .config()
-        .read()
-        .await
-        .projects
-        .iter()
-        .find(|project| project.id == Some(task.project_id))
-        .and_then(|project| project.git_no_verify_override)
-        .unwrap_or(global_no_verify);
+    let commit_message = attempt_commit_message(&task);
+    let no_verify = resolve_git_no_verify(&deployment, task.project_id).await;
     let git = deployment.git().clone();
     let repo_path = repo.path.clone();
     let workspace_branch = workspace.branch.clone();
@@ -1289,28 +2403,143 @@ pub async fn merge_task_attempt(
     Ok(ResponseJson(ApiResponse::success(())))
 }
 
-pub async fn push_task_attempt_branch(
-    Extension(workspace): Extension<Workspace>,
-    State(deployment): State<DeploymentImpl>,
-    Json(request): Json<PushTaskAttemptRequest>,
-) -> Result<(StatusCode, ResponseJson<ApiResponse<(), PushError>>), ApiError> {
-    let pool = &deployment.db().pool;
-
-    let workspace_repo =
-        WorkspaceRepo::find_by_workspace_and_repo_id(pool, workspace.id, request.repo_id)
-            .await?
-            .ok_or(RepoError::NotFound)?;
+fn attempt_commit_message(task: &Task) -> String {
+    let task_uuid_str = task.id.to_string();
+    let first_uuid_section = task_uuid_str.split('-').next().unwrap_or(&task_uuid_str);
 
-    let repo = Repo::find_by_id(pool, workspace_repo.repo_id)
-        .await?
-        .ok_or(RepoError::NotFound)?;
+    let mut commit_message = format!("{} (vibe-kanban {})", task.title, first_uuid_section);
 
-    let container_ref = deployment
-        .container()
-        .ensure_container_exists(&workspace)
-        .await?;
-    let workspace_path = Path::new(&container_ref);
-    let worktree_path = workspace_path.join(&repo.name);
+    // Add description on next line if it exists
+    if let Some(description) = &task.description
+        && !description.trim().is_empty()
+    {
+        commit_message.push_str("\n\n");
+        commit_message.push_str(description);
+    }
+    commit_message
+}
+
+async fn resolve_git_no_verify(deployment: &DeploymentImpl, project_id: Uuid) -> bool {
+    let config = deployment.config().read().await;
+    config
+        .projects
+        .iter()
+        .find(|project| project.id == Some(project_id))
+        .and_then(|project| project.git_no_verify_override)
+        .unwrap_or(config.git_no_verify)
+}
+
+/// Squash the attempt branch onto an arbitrary local branch without the PR/merge bookkeeping.
+///
+/// Unlike `merge_task_attempt` this neither records a merge nor marks the task done, and
+/// conflicts are reported back instead of being left in a worktree.
+pub async fn apply_task_attempt(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<ApplyTaskAttemptRequest>,
+) -> Result<ResponseJson<ApiResponse<ApplyTaskAttemptResponse, ApplyTaskAttemptError>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let target_branch = request.target_branch.trim().to_string();
+    if target_branch.is_empty() {
+        return Err(ApiError::BadRequest(
+            "target_branch is required".to_string(),
+        ));
+    }
+    if target_branch == workspace.branch {
+        return Err(ApiError::BadRequest(
+            "target_branch must differ from the attempt branch".to_string(),
+        ));
+    }
+
+    let workspace_repo =
+        WorkspaceRepo::find_by_workspace_and_repo_id(pool, workspace.id, request.repo_id)
+            .await?
+            .ok_or(RepoError::NotFound)?;
+    let repo = Repo::find_by_id(pool, workspace_repo.repo_id)
+        .await?
+        .ok_or(RepoError::NotFound)?;
+    let task = workspace
+        .parent_task(pool)
+        .await?
+        .ok_or(ApiError::Workspace(WorkspaceError::TaskNotFound))?;
+
+    let commit_message = attempt_commit_message(&task);
+    let no_verify = resolve_git_no_verify(&deployment, task.project_id).await;
+    let git = deployment.git().clone();
+    let repo_path = repo.path.clone();
+    let workspace_branch = workspace.branch.clone();
+    let apply_target = target_branch.clone();
+    let outcome = run_git_operation(git, move |git| {
+        git.apply_branch_to_local_branch(
+            &repo_path,
+            &workspace_branch,
+            &apply_target,
+            &commit_message,
+            GitMergeOptions::new(no_verify),
+        )
+    })
+    .await?;
+
+    match outcome {
+        ApplyBranchOutcome::Applied { commit_sha } => {
+            tracing::info!(
+                workspace_id = %workspace.id,
+                target_branch = %target_branch,
+                commit_sha = %commit_sha,
+                "Applied task attempt to local branch"
+            );
+            Ok(ResponseJson(ApiResponse::success(
+                ApplyTaskAttemptResponse {
+                    target_branch,
+                    commit_sha: Some(commit_sha),
+                },
+            )))
+        }
+        ApplyBranchOutcome::UpToDate => Ok(ResponseJson(ApiResponse::success(
+            ApplyTaskAttemptResponse {
+                target_branch,
+                commit_sha: None,
+            },
+        ))),
+        ApplyBranchOutcome::Conflicts { conflicted_files } => Ok(ResponseJson(
+            ApiResponse::error_with_data(ApplyTaskAttemptError::MergeConflicts {
+                message: format!(
+                    "Applying '{}' to '{}' conflicts in {} file(s); '{}' was left unchanged",
+                    workspace.branch,
+                    target_branch,
+                    conflicted_files.len(),
+                    target_branch
+                ),
+                target_branch,
+                conflicted_files,
+            }),
+        )),
+    }
+}
+
+pub async fn push_task_attempt_branch(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<PushTaskAttemptRequest>,
+) -> Result<(StatusCode, ResponseJson<ApiResponse<(), PushError>>), ApiError> {
+    let pool = &deployment.db().pool;
+
+    let workspace_repo =
+        WorkspaceRepo::find_by_workspace_and_repo_id(pool, workspace.id, request.repo_id)
+            .await?
+            .ok_or(RepoError::NotFound)?;
+
+    let repo = Repo::find_by_id(pool, workspace_repo.repo_id)
+        .await?
+        .ok_or(RepoError::NotFound)?;
+
+    let container_ref = deployment
+        .container()
+        .ensure_container_exists(&workspace)
+        .await?;
+    let workspace_path = Path::new(&container_ref);
+    let worktree_path = workspace_path.join(&repo.name);
 
     let git = deployment.git().clone();
     let workspace_branch = workspace.branch.clone();
@@ -1866,6 +3095,76 @@ pub async fn rebase_task_attempt(
     Ok(ResponseJson(ApiResponse::success(())))
 }
 
+/// Rebase the attempt branch onto the latest tip of its target branch.
+///
+/// Conflicts abort the rebase and are reported, so the worktree is never left mid-rebase.
+pub async fn rebase_task_attempt_onto_base(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<RebaseOntoBaseRequest>,
+) -> Result<ResponseJson<ApiResponse<RebaseOntoBaseResponse, RebaseOntoBaseError>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    if ExecutionProcess::has_running_non_dev_server_processes_for_workspace(pool, workspace.id)
+        .await?
+    {
+        return Err(ApiError::Conflict(
+            "Attempt has running processes. Stop them before rebasing.".to_string(),
+        ));
+    }
+
+    let workspace_repo =
+        WorkspaceRepo::find_by_workspace_and_repo_id(pool, workspace.id, payload.repo_id)
+            .await?
+            .ok_or(RepoError::NotFound)?;
+    let repo = Repo::find_by_id(pool, workspace_repo.repo_id)
+        .await?
+        .ok_or(RepoError::NotFound)?;
+
+    let container_ref = deployment
+        .container()
+        .ensure_container_exists(&workspace)
+        .await?;
+    let worktree_path = Path::new(&container_ref).join(&repo.name);
+
+    let git = deployment.git().clone();
+    let repo_path = repo.path.clone();
+    let base_branch = workspace_repo.target_branch.clone();
+    let workspace_branch = workspace.branch.clone();
+    let outcome = run_git_operation(git, move |git| {
+        git.rebase_onto_base(&repo_path, &worktree_path, &base_branch, &workspace_branch)
+    })
+    .await?;
+
+    let base_branch = workspace_repo.target_branch;
+    match outcome {
+        RebaseOntoBaseOutcome::Rebased { head_sha } => {
+            Ok(ResponseJson(ApiResponse::success(RebaseOntoBaseResponse {
+                base_branch,
+                head_sha: Some(head_sha),
+            })))
+        }
+        RebaseOntoBaseOutcome::UpToDate => {
+            Ok(ResponseJson(ApiResponse::success(RebaseOntoBaseResponse {
+                base_branch,
+                head_sha: None,
+            })))
+        }
+        RebaseOntoBaseOutcome::Conflicts { conflicted_files } => Ok(ResponseJson(
+            ApiResponse::error_with_data(RebaseOntoBaseError::MergeConflicts {
+                message: format!(
+                    "Rebasing '{}' onto '{}' conflicts in {} file(s); the rebase was aborted",
+                    workspace.branch,
+                    base_branch,
+                    conflicted_files.len()
+                ),
+                base_branch,
+                conflicted_files,
+            }),
+        )),
+    }
+}
+
 #[axum::debug_handler]
 pub async fn abort_conflicts_task_attempt(
     Extension(workspace): Extension<Workspace>,
@@ -2313,15 +3612,17 @@ pub async fn get_task_attempt_repos(
 
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashSet, path::Path};
+    use std::{collections::HashSet, ffi::OsStr, path::Path, sync::Arc};
 
     use app_runtime::Deployment;
     use axum::{
         Extension, Json,
+        body::to_bytes,
         extract::{Query, State},
-        http::StatusCode,
+        http::{StatusCode, header},
         response::Json as ResponseJson,
     };
+    use base64::{Engine as _, prelude::BASE64_STANDARD};
     use chrono::Utc;
     use config::DiffPreviewGuardPreset;
     use db::models::{
@@ -2342,7 +3643,10 @@ mod tests {
         workspace_repo::{CreateWorkspaceRepo, WorkspaceRepo},
     };
     use db_migration::Migrator;
-    use execution::container::LocalContainerService;
+    use execution::container::{
+        ContainerService, LocalContainerService, RepoStartupStatus, WorkspaceStartupProgress,
+    };
+    use executors::logs::{NormalizedEntry, NormalizedEntryType, utils::ConversationPatch};
     use executors_protocol::{
         BaseCodingAgent, ExecutorProfileId,
         actions::{
@@ -2350,28 +3654,42 @@ mod tests {
             script::{ScriptContext, ScriptRequest, ScriptRequestLanguage},
         },
     };
+    use json_patch::PatchOperation;
+    use logs_protocol::LogMsg;
+    use logs_store::MsgStore;
     use repos::{
-        git::{GitService, GitServiceError},
+        git::{GitCli, GitService, GitServiceError},
         workspace_manager::WorkspaceManager,
     };
     use sea_orm::Database;
     use sea_orm_migration::MigratorTrait;
     use test_support::{TempRoot, TestDb, TestEnvGuard};
     use tokio::time::Duration;
+    use utils_core::approvals::{ApprovalResponse, ApprovalStatus};
     use uuid::Uuid;
 
     use super::{
-        AttemptChangesBlockedReason, AttemptChangesQuery, AttemptPatchRequest, AttemptState,
-        CreateTaskAttemptBody, RenameBranchError, RenameBranchRequest, WorkspaceRepoInput,
-        blocked_predecessors, cleanup_failed_attempt_start, create_task_attempt,
-        get_task_attempt_changes, get_task_attempt_patch, get_task_attempt_status,
-        normalize_dev_server_working_dir, rename_branch, resolve_executor_profile_id,
-        resolve_topology_base_branches, run_git_operation, validate_dev_server_script,
+        ApplyAttemptPatchRequest, ApprovalDecision, AttemptBlameQuery, AttemptChangesBlockedReason,
+        AttemptChangesQuery, AttemptFileEncoding, AttemptFileQuery, AttemptPatchRequest,
+        AttemptState, CompareAttemptsQuery, CreateTaskAttemptBody, CreateTaskAttemptError,
+        RenameBranchError, RenameBranchRequest, RespondAllApprovalsRequest,
+        RevertAttemptFileRequest, WorkspaceRepoInput, apply_task_attempt_patch,
+        blocked_predecessors, cleanup_failed_attempt_start, compare_task_attempts,
+        create_task_attempt, download_task_attempt_patch, get_task_attempt_blame,
+        get_task_attempt_changes, get_task_attempt_file, get_task_attempt_git_status,
+        get_task_attempt_patch, get_task_attempt_status, normalize_dev_server_working_dir,
+        rename_branch, resolve_executor_profile_id, resolve_topology_base_branches,
+        respond_all_task_attempt_approvals, revert_task_attempt_file, run_git_operation,
+        validate_dev_server_script,
     };
     use crate::{
         DeploymentImpl,
         error::ApiError,
         routes::tasks::{CreateAndStartTaskRequest, create_task_and_start},
+        test_fixtures::{
+            create_attempt_with_worktree, create_attempt_with_worktrees, create_pending_approval,
+            create_running_attempt, create_workspace_with_repo,
+        },
     };
 
     fn node(id: &str, status: TaskStatus) -> MilestoneNode {
@@ -2440,37 +3758,6 @@ mod tests {
         task_id
     }
 
-    async fn create_workspace_with_repo(
-        db: &sea_orm::DatabaseConnection,
-        task_id: Uuid,
-        repo_id: Uuid,
-        workspace_branch: &str,
-        target_branch: &str,
-    ) -> Workspace {
-        let workspace = Workspace::create(
-            db,
-            &CreateWorkspace {
-                branch: workspace_branch.to_string(),
-                agent_working_dir: None,
-            },
-            Uuid::new_v4(),
-            task_id,
-        )
-        .await
-        .unwrap();
-        WorkspaceRepo::create_many(
-            db,
-            workspace.id,
-            &[CreateWorkspaceRepo {
-                repo_id,
-                target_branch: target_branch.to_string(),
-            }],
-        )
-        .await
-        .unwrap();
-        workspace
-    }
-
     fn list_dir_names(path: &Path) -> HashSet<String> {
         std::fs::read_dir(path)
             .map(|entries| {
@@ -2648,6 +3935,99 @@ mod tests {
         assert!(branches.is_none());
     }
 
+    #[tokio::test]
+    async fn create_task_attempt_rejects_missing_target_branch_per_repo() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let repo_path = temp_root.join("repo");
+        GitService::new()
+            .initialize_repo_with_main_branch(&repo_path)
+            .unwrap();
+        let repo = Repo::find_or_create(&deployment.db().pool, &repo_path, "Repo")
+            .await
+            .unwrap();
+
+        let project_id = Uuid::new_v4();
+        let task_id = Uuid::new_v4();
+        Task::create(
+            &deployment.db().pool,
+            &CreateTask::from_title_description(project_id, "Typo task".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let (status, ResponseJson(response)) = create_task_attempt(
+            State(deployment.clone()),
+            axum::http::HeaderMap::new(),
+            Json(CreateTaskAttemptBody {
+                task_id,
+                executor_profile_id: ExecutorProfileId::new(BaseCodingAgent::FakeAgent),
+                repos: vec![WorkspaceRepoInput {
+                    repo_id: repo.id,
+                    target_branch: "mian".to_string(),
+                    create_from: None,
+                    sparse_paths: None,
+                }],
+                prompt_preset: None,
+                labels: Vec::new(),
+                reasoning_effort: None,
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        match response.error_data() {
+            Some(CreateTaskAttemptError::TargetBranchNotFound { repos }) => {
+                assert_eq!(repos.len(), 1);
+                assert_eq!(repos[0].repo_id, repo.id);
+                assert_eq!(repos[0].target_branch, "mian");
+            }
+            other => panic!("expected target_branch_not_found, got {other:?}"),
+        }
+
+        let workspaces = Workspace::fetch_all(&deployment.db().pool, Some(task_id))
+            .await
+            .unwrap();
+        assert!(workspaces.is_empty());
+    }
+
+    #[tokio::test]
+    async fn create_target_branch_if_missing_branches_from_given_ref() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let repo_path = temp_root.join("repo");
+        let git = GitService::new();
+        git.initialize_repo_with_main_branch(&repo_path).unwrap();
+        let base_oid = git.get_head_oid(&repo_path).unwrap();
+        GitCli::new()
+            .commit_allow_empty(&repo_path, "Second commit")
+            .unwrap();
+        let repo = Repo::find_or_create(&deployment.db().pool, &repo_path, "Repo")
+            .await
+            .unwrap();
+
+        super::create_target_branch_if_missing(&deployment, repo.id, "release", &base_oid)
+            .await
+            .unwrap();
+
+        assert_eq!(git.get_branch_oid(&repo_path, "release").unwrap(), base_oid);
+        assert_ne!(git.get_branch_oid(&repo_path, "main").unwrap(), base_oid);
+
+        let err =
+            super::create_target_branch_if_missing(&deployment, repo.id, "hotfix", "no-such-ref")
+                .await
+                .unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(_)));
+    }
+
     #[tokio::test]
     async fn start_failure_cleans_up_records_for_attempt_and_create_start() {
         let temp_root = TempRoot::new("vk-test-");
@@ -2705,9 +4085,13 @@ mod tests {
             executor_profile_id: ExecutorProfileId::new(BaseCodingAgent::FakeAgent),
             repos: vec![WorkspaceRepoInput {
                 repo_id,
-                target_branch: "main".to_string(),
+                target_branch: "release".to_string(),
+                create_from: Some("main".to_string()),
+                sparse_paths: None,
             }],
             prompt_preset: None,
+            labels: Vec::new(),
+            reasoning_effort: None,
         };
 
         let attempt_result = create_task_attempt(
@@ -2731,6 +4115,12 @@ mod tests {
 
         let after_attempt_dirs = list_dir_names(&worktree_base);
         assert_eq!(baseline_dirs, after_attempt_dirs);
+        // The requested target branch is only created once the request has been validated.
+        assert!(
+            !GitService::new()
+                .check_branch_exists(&repo_path, "release")
+                .unwrap()
+        );
 
         let create_start_payload = CreateAndStartTaskRequest {
             task: CreateTask::from_title_description(
@@ -2742,6 +4132,8 @@ mod tests {
             repos: vec![WorkspaceRepoInput {
                 repo_id,
                 target_branch: "main".to_string(),
+                create_from: None,
+                sparse_paths: None,
             }],
         };
 
@@ -2790,6 +4182,8 @@ mod tests {
             repos: vec![WorkspaceRepoInput {
                 repo_id,
                 target_branch: "main".to_string(),
+                create_from: None,
+                sparse_paths: None,
             }],
         };
 
@@ -2822,6 +4216,7 @@ mod tests {
             container_ref: None,
             branch: "old-branch".to_string(),
             agent_working_dir: None,
+            labels: Vec::new(),
             setup_completed_at: None,
             latest_hook_run: None,
             after_prepare_hook_status: None,
@@ -2853,40 +4248,169 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn cleanup_skips_status_restore_when_running_attempt_exists() {
+    async fn attempt_file_reports_binary_with_mime_type_instead_of_text() {
         let temp_root = TempRoot::new("vk-test-");
         let db = TestDb::sqlite_file(&temp_root);
         let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
 
         let deployment = DeploymentImpl::new().await.unwrap();
 
-        let project_id = Uuid::new_v4();
-        Project::create(
-            &deployment.db().pool,
-            &CreateProject {
-                name: "Running attempt project".to_string(),
-                repositories: Vec::new(),
-            },
-            project_id,
+        let workspace_dir = temp_root.join("attempt-file-workspace");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png.extend_from_slice(&[0, 0, 0, 13, b'I', b'H', b'D', b'R', 0xff, 0xfe]);
+        std::fs::write(workspace_dir.join("logo.png"), &png).unwrap();
+        std::fs::write(workspace_dir.join("notes.txt"), "héllo\n").unwrap();
+
+        let workspace = Workspace {
+            id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            container_ref: Some(workspace_dir.to_string_lossy().to_string()),
+            branch: "attempt-file".to_string(),
+            agent_working_dir: None,
+            labels: Vec::new(),
+            setup_completed_at: None,
+            latest_hook_run: None,
+            after_prepare_hook_status: None,
+            after_prepare_hook_ran_at: None,
+            after_prepare_hook_error_summary: None,
+            before_cleanup_hook_status: None,
+            before_cleanup_hook_ran_at: None,
+            before_cleanup_hook_error_summary: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        let ResponseJson(response) = get_task_attempt_file(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Query(AttemptFileQuery {
+                path: Some("logo.png".to_string()),
+                start: None,
+                max_bytes: None,
+                encoding: None,
+            }),
         )
         .await
         .unwrap();
-
-        let task_id = Uuid::new_v4();
-        let task = Task::create(
-            &deployment.db().pool,
-            &CreateTask::from_title_description(
-                project_id,
-                "Running attempt task".to_string(),
-                None,
-            ),
-            task_id,
+        let file = response.into_data().expect("file response");
+        assert!(!file.blocked);
+        assert!(file.binary);
+        assert_eq!(file.mime_type.as_deref(), Some("image/png"));
+        assert_eq!(file.content, None);
+        assert_eq!(file.total_bytes, Some(png.len() as u64));
+
+        let ResponseJson(response) = get_task_attempt_file(
+            Extension(workspace),
+            State(deployment),
+            Query(AttemptFileQuery {
+                path: Some("notes.txt".to_string()),
+                start: None,
+                max_bytes: None,
+                encoding: None,
+            }),
         )
         .await
         .unwrap();
+        let file = response.into_data().expect("file response");
+        assert!(!file.binary);
+        assert_eq!(file.mime_type, None);
+        assert_eq!(file.content.as_deref(), Some("héllo\n"));
+    }
 
-        let running_workspace = Workspace::create(
-            &deployment.db().pool,
+    #[tokio::test]
+    async fn attempt_file_round_trips_binary_bytes_as_base64() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let workspace_dir = temp_root.join("attempt-file-base64-workspace");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png.extend_from_slice(&[0, 0, 0, 13, b'I', b'H', b'D', b'R', 0x00, 0xff, 0xfe, 0x80]);
+        std::fs::write(workspace_dir.join("logo.png"), &png).unwrap();
+
+        let workspace = Workspace {
+            id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            container_ref: Some(workspace_dir.to_string_lossy().to_string()),
+            branch: "attempt-file-base64".to_string(),
+            agent_working_dir: None,
+            labels: Vec::new(),
+            setup_completed_at: None,
+            latest_hook_run: None,
+            after_prepare_hook_status: None,
+            after_prepare_hook_ran_at: None,
+            after_prepare_hook_error_summary: None,
+            before_cleanup_hook_status: None,
+            before_cleanup_hook_ran_at: None,
+            before_cleanup_hook_error_summary: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        let ResponseJson(response) = get_task_attempt_file(
+            Extension(workspace),
+            State(deployment),
+            Query(AttemptFileQuery {
+                path: Some("logo.png".to_string()),
+                start: None,
+                max_bytes: None,
+                encoding: Some(AttemptFileEncoding::Base64),
+            }),
+        )
+        .await
+        .unwrap();
+        let file = response.into_data().expect("file response");
+        assert!(file.binary);
+        assert_eq!(file.mime_type.as_deref(), Some("image/png"));
+        assert_eq!(file.encoding, Some(AttemptFileEncoding::Base64));
+        assert_eq!(file.bytes, png.len());
+        assert!(!file.truncated);
+
+        let decoded = BASE64_STANDARD
+            .decode(file.content.expect("base64 content"))
+            .unwrap();
+        assert_eq!(decoded, png);
+    }
+
+    #[tokio::test]
+    async fn cleanup_skips_status_restore_when_running_attempt_exists() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            &deployment.db().pool,
+            &CreateProject {
+                name: "Running attempt project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let task_id = Uuid::new_v4();
+        let task = Task::create(
+            &deployment.db().pool,
+            &CreateTask::from_title_description(
+                project_id,
+                "Running attempt task".to_string(),
+                None,
+            ),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let running_workspace = Workspace::create(
+            &deployment.db().pool,
             &CreateWorkspace {
                 branch: "running".to_string(),
                 agent_working_dir: None,
@@ -2956,6 +4480,203 @@ mod tests {
         assert_eq!(task_after.status, TaskStatus::InReview);
     }
 
+    #[tokio::test]
+    async fn attempt_status_flags_conflicts_once_base_branch_advances() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            &deployment.db().pool,
+            &CreateProject {
+                name: "Base conflict project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let repo_path = temp_root.join("base-conflict-repo");
+        let git_service = GitService::new();
+        git_service
+            .initialize_repo_with_main_branch(&repo_path)
+            .unwrap();
+        std::fs::write(repo_path.join("shared.txt"), "base\n").unwrap();
+        git_service.commit(&repo_path, "base").unwrap();
+        let repo = Repo::find_or_create(&deployment.db().pool, &repo_path, "base-conflict-repo")
+            .await
+            .unwrap();
+
+        let task_id = Uuid::new_v4();
+        Task::create(
+            &deployment.db().pool,
+            &CreateTask::from_title_description(project_id, "Base conflict task".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let branch_name = "base-conflict-attempt".to_string();
+        let git = GitCli::new();
+        git.git(&repo_path, ["checkout", "-b", branch_name.as_str()])
+            .unwrap();
+        std::fs::write(repo_path.join("shared.txt"), "attempt\n").unwrap();
+        git_service.commit(&repo_path, "attempt edit").unwrap();
+        git.git(&repo_path, ["checkout", "main"]).unwrap();
+
+        let workspace = Workspace::create(
+            &deployment.db().pool,
+            &CreateWorkspace {
+                branch: branch_name,
+                agent_working_dir: None,
+            },
+            Uuid::new_v4(),
+            task_id,
+        )
+        .await
+        .unwrap();
+        WorkspaceRepo::create_many(
+            &deployment.db().pool,
+            workspace.id,
+            &[CreateWorkspaceRepo {
+                repo_id: repo.id,
+                target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
+            }],
+        )
+        .await
+        .unwrap();
+
+        let ResponseJson(response) =
+            get_task_attempt_status(Extension(workspace.clone()), State(deployment.clone()))
+                .await
+                .unwrap();
+        let status = response.into_data().expect("status should be present");
+        assert!(!status.base_diverged);
+        assert!(!status.has_conflicts);
+
+        // Advancing main with an unrelated file diverges the base without conflicting.
+        std::fs::write(repo_path.join("other.txt"), "other\n").unwrap();
+        git_service.commit(&repo_path, "unrelated").unwrap();
+        let ResponseJson(response) =
+            get_task_attempt_status(Extension(workspace.clone()), State(deployment.clone()))
+                .await
+                .unwrap();
+        let status = response.into_data().expect("status should be present");
+        assert!(status.base_diverged);
+        assert!(!status.has_conflicts);
+
+        std::fs::write(repo_path.join("shared.txt"), "main\n").unwrap();
+        git_service.commit(&repo_path, "conflicting").unwrap();
+        let ResponseJson(response) =
+            get_task_attempt_status(Extension(workspace), State(deployment))
+                .await
+                .unwrap();
+        let status = response.into_data().expect("status should be present");
+        assert!(status.base_diverged);
+        assert!(status.has_conflicts);
+    }
+
+    #[tokio::test]
+    async fn compare_attempts_reports_files_that_differ_between_branches() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &CreateProject {
+                name: "Compare project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let repo_path = temp_root.join("compare-repo");
+        let git_service = GitService::new();
+        git_service
+            .initialize_repo_with_main_branch(&repo_path)
+            .unwrap();
+        std::fs::write(repo_path.join("shared.txt"), "base\n").unwrap();
+        std::fs::write(repo_path.join("same.txt"), "same\n").unwrap();
+        git_service.commit(&repo_path, "base").unwrap();
+        let repo = Repo::find_or_create(pool, &repo_path, "compare-repo")
+            .await
+            .unwrap();
+
+        let git = GitCli::new();
+        for (branch, content, extra_file) in [
+            ("compare-a", "approach a\n", "a.txt"),
+            ("compare-b", "approach b\n", "b.txt"),
+        ] {
+            git.git(&repo_path, ["checkout", "-b", branch]).unwrap();
+            std::fs::write(repo_path.join("shared.txt"), content).unwrap();
+            std::fs::write(repo_path.join(extra_file), "extra\n").unwrap();
+            git_service.commit(&repo_path, branch).unwrap();
+            git.git(&repo_path, ["checkout", "main"]).unwrap();
+        }
+
+        let task_id = create_task(pool, project_id, "Compare task").await;
+        let workspace_a =
+            create_workspace_with_repo(pool, task_id, repo.id, "compare-a", "main").await;
+        let workspace_b =
+            create_workspace_with_repo(pool, task_id, repo.id, "compare-b", "main").await;
+
+        let ResponseJson(response) = compare_task_attempts(
+            Extension(workspace_a.clone()),
+            State(deployment.clone()),
+            Query(CompareAttemptsQuery {
+                other_attempt_id: workspace_b.id,
+            }),
+        )
+        .await
+        .unwrap();
+        let comparison = response.into_data().expect("comparison should be present");
+        assert!(comparison.skipped_repos.is_empty());
+
+        let mut changes: Vec<(String, String)> = comparison
+            .files
+            .iter()
+            .map(|file| {
+                assert_eq!(file.repo_name, "compare-repo");
+                (file.path.clone(), format!("{:?}", file.change))
+            })
+            .collect();
+        changes.sort();
+        assert_eq!(
+            changes,
+            vec![
+                ("a.txt".to_string(), "Deleted".to_string()),
+                ("b.txt".to_string(), "Added".to_string()),
+                ("shared.txt".to_string(), "Modified".to_string()),
+            ]
+        );
+
+        let other_task_id = create_task(pool, project_id, "Other task").await;
+        let other_workspace =
+            create_workspace_with_repo(pool, other_task_id, repo.id, "compare-b", "main").await;
+        let err = compare_task_attempts(
+            Extension(workspace_a),
+            State(deployment.clone()),
+            Query(CompareAttemptsQuery {
+                other_attempt_id: other_workspace.id,
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(_)));
+    }
+
     #[tokio::test]
     async fn attempt_status_reports_idle_running_failed_and_ignores_devserver() {
         let temp_root = TempRoot::new("vk-test-");
@@ -3093,6 +4814,249 @@ mod tests {
         assert!(status.last_activity_at.is_some());
     }
 
+    #[tokio::test]
+    async fn normalized_entries_advance_attempt_last_activity_at() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &CreateProject {
+                name: "Activity project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+        let task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(project_id, "Activity task".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+        let workspace = Workspace::create(
+            pool,
+            &CreateWorkspace {
+                branch: "attempt-activity".to_string(),
+                agent_working_dir: None,
+            },
+            Uuid::new_v4(),
+            task_id,
+        )
+        .await
+        .unwrap();
+        let session = Session::create(
+            pool,
+            &CreateSession { executor: None },
+            Uuid::new_v4(),
+            workspace.id,
+        )
+        .await
+        .unwrap();
+        let process_id = Uuid::new_v4();
+        ExecutionProcess::create(
+            pool,
+            &CreateExecutionProcess {
+                session_id: session.id,
+                executor_action: ExecutorAction::new(
+                    ExecutorActionType::ScriptRequest(ScriptRequest {
+                        script: "true".to_string(),
+                        language: ScriptRequestLanguage::Bash,
+                        context: ScriptContext::SetupScript,
+                        working_dir: None,
+                    }),
+                    None,
+                ),
+                run_reason: ExecutionProcessRunReason::SetupScript,
+            },
+            process_id,
+            &[],
+        )
+        .await
+        .unwrap();
+
+        let ResponseJson(response) =
+            get_task_attempt_status(Extension(workspace.clone()), State(deployment.clone()))
+                .await
+                .unwrap();
+        let before = response.into_data().unwrap().last_activity_at.unwrap();
+        let process = ExecutionProcess::find_by_id(pool, process_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(process.last_activity_at.is_none());
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let store = Arc::new(MsgStore::new());
+        deployment
+            .container()
+            .msg_stores()
+            .write()
+            .await
+            .insert(process_id, store.clone());
+        let writer = deployment
+            .container()
+            .spawn_stream_normalized_entries_to_db(&process_id);
+        for (index, content) in ["thinking", "done"].into_iter().enumerate() {
+            store.push_patch(ConversationPatch::add_normalized_entry(
+                index,
+                NormalizedEntry {
+                    timestamp: None,
+                    entry_type: NormalizedEntryType::AssistantMessage,
+                    content: content.to_string(),
+                    metadata: None,
+                },
+            ));
+        }
+        store.push_finished();
+        writer.await.unwrap();
+
+        let recorded = ExecutionProcess::find_by_id(pool, process_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .last_activity_at
+            .expect("normalized entries should record activity");
+        assert!(recorded > before);
+
+        let ResponseJson(response) =
+            get_task_attempt_status(Extension(workspace), State(deployment.clone()))
+                .await
+                .unwrap();
+        assert_eq!(
+            response.into_data().unwrap().last_activity_at,
+            Some(recorded)
+        );
+    }
+
+    #[tokio::test]
+    async fn attempt_status_reports_pending_approval_until_answered() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+        let (workspace, process_id) = create_running_attempt(pool, "attempt-approval").await;
+
+        let ResponseJson(response) =
+            get_task_attempt_status(Extension(workspace.clone()), State(deployment.clone()))
+                .await
+                .unwrap();
+        let status = response.into_data().unwrap();
+        assert_eq!(status.state, AttemptState::Running);
+        assert!(!status.waiting_on_approval);
+        assert!(status.pending_approval_tool.is_none());
+
+        let approval_id = create_pending_approval(&deployment, process_id, "call-1").await;
+
+        let ResponseJson(response) =
+            get_task_attempt_status(Extension(workspace.clone()), State(deployment.clone()))
+                .await
+                .unwrap();
+        let status = response.into_data().unwrap();
+        assert_eq!(status.state, AttemptState::Running);
+        assert!(status.waiting_on_approval);
+        assert_eq!(status.pending_approval_tool.as_deref(), Some("Bash"));
+
+        deployment
+            .approvals()
+            .respond(
+                pool,
+                &approval_id,
+                ApprovalResponse {
+                    execution_process_id: process_id,
+                    status: ApprovalStatus::Approved,
+                },
+            )
+            .await
+            .unwrap();
+
+        let ResponseJson(response) =
+            get_task_attempt_status(Extension(workspace), State(deployment.clone()))
+                .await
+                .unwrap();
+        let status = response.into_data().unwrap();
+        assert_eq!(status.state, AttemptState::Running);
+        assert!(!status.waiting_on_approval);
+        assert!(status.pending_approval_tool.is_none());
+    }
+
+    #[tokio::test]
+    async fn respond_all_approves_every_pending_approval_of_the_attempt() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+        let (workspace, process_id) = create_running_attempt(pool, "approve-all").await;
+        let mut pending = Vec::new();
+        for call_id in ["call-1", "call-2", "call-3"] {
+            pending.push(create_pending_approval(&deployment, process_id, call_id).await);
+        }
+        let (_, other_process_id) = create_running_attempt(pool, "approve-all-other").await;
+        let other = create_pending_approval(&deployment, other_process_id, "call-1").await;
+
+        let ResponseJson(response) = respond_all_task_attempt_approvals(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Json(RespondAllApprovalsRequest {
+                decision: ApprovalDecision::Approved,
+                denial_reason: None,
+            }),
+        )
+        .await
+        .unwrap();
+        let results = response.into_data().unwrap().results;
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| result.approval_id.clone())
+                .collect::<Vec<_>>(),
+            pending
+        );
+        assert!(results.iter().all(|result| {
+            matches!(result.status, Some(ApprovalStatus::Approved)) && result.error.is_none()
+        }));
+
+        for approval_id in &pending {
+            let approval = deployment
+                .approvals()
+                .get_approval(pool, approval_id)
+                .await
+                .unwrap();
+            assert!(matches!(approval.status, ApprovalStatus::Approved));
+        }
+        let approval = deployment
+            .approvals()
+            .get_approval(pool, &other)
+            .await
+            .unwrap();
+        assert!(matches!(approval.status, ApprovalStatus::Pending));
+
+        // Nothing is left to answer on a second call.
+        let ResponseJson(response) = respond_all_task_attempt_approvals(
+            Extension(workspace),
+            State(deployment.clone()),
+            Json(RespondAllApprovalsRequest {
+                decision: ApprovalDecision::Denied,
+                denial_reason: Some("too late".to_string()),
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(response.into_data().unwrap().results.is_empty());
+    }
+
     #[tokio::test]
     async fn attempt_changes_blocks_when_guard_exceeded_and_unblocks_when_forced() {
         let temp_root = TempRoot::new("vk-test-");
@@ -3143,69 +5107,572 @@ mod tests {
         .await
         .unwrap();
 
-        let branch_name = format!("attempt-changes-{}", Uuid::new_v4());
-        let workspace_id = Uuid::new_v4();
-        let mut workspace = Workspace::create(
-            &deployment.db().pool,
-            &CreateWorkspace {
-                branch: branch_name.clone(),
-                agent_working_dir: None,
-            },
-            workspace_id,
-            task_id,
+        let branch_name = format!("attempt-changes-{}", Uuid::new_v4());
+        let workspace_id = Uuid::new_v4();
+        let mut workspace = Workspace::create(
+            &deployment.db().pool,
+            &CreateWorkspace {
+                branch: branch_name.clone(),
+                agent_working_dir: None,
+            },
+            workspace_id,
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        WorkspaceRepo::create_many(
+            &deployment.db().pool,
+            workspace.id,
+            &[CreateWorkspaceRepo {
+                repo_id: repo.id,
+                target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
+            }],
+        )
+        .await
+        .unwrap();
+
+        let workspace_dir_name =
+            LocalContainerService::dir_name_from_workspace(&workspace.id, "Attempt changes task");
+        let workspace_dir = WorkspaceManager::get_workspace_base_dir().join(&workspace_dir_name);
+        let _container = WorkspaceManager::create_workspace(
+            &workspace_dir,
+            &[repos::workspace_manager::RepoWorkspaceInput::new(
+                repo.clone(),
+                "main".to_string(),
+            )],
+            &branch_name,
+        )
+        .await
+        .unwrap();
+
+        let worktree_path = workspace_dir.join(&repo.name);
+        for i in 0..201 {
+            std::fs::write(worktree_path.join(format!("file-{i}.txt")), "hi\n").unwrap();
+        }
+
+        workspace.container_ref = Some(workspace_dir.to_string_lossy().to_string());
+
+        let ResponseJson(response) = get_task_attempt_changes(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Query(AttemptChangesQuery { force: false }),
+        )
+        .await
+        .unwrap();
+        let changes = response.into_data().expect("changes should be present");
+        assert!(changes.blocked);
+        assert_eq!(
+            changes.blocked_reason,
+            Some(AttemptChangesBlockedReason::ThresholdExceeded)
+        );
+        assert!(changes.files.is_empty());
+
+        let ResponseJson(response) = get_task_attempt_changes(
+            Extension(workspace),
+            State(deployment),
+            Query(AttemptChangesQuery { force: true }),
+        )
+        .await
+        .unwrap();
+        let changes = response.into_data().expect("changes should be present");
+        assert!(!changes.blocked);
+        assert_eq!(changes.blocked_reason, None);
+        assert!(
+            changes.files.len() >= 201,
+            "expected files list to include created files"
+        );
+
+        WorkspaceManager::cleanup_workspace(&workspace_dir, &[repo])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn attempt_git_status_lists_untracked_files_per_repo() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let attempt =
+            create_attempt_with_worktree(&deployment, &temp_root, "git-status-repo", &[]).await;
+        std::fs::write(attempt.worktree.join("scratch.txt"), "notes\n").unwrap();
+
+        let ResponseJson(response) =
+            get_task_attempt_git_status(Extension(attempt.workspace.clone()), State(deployment))
+                .await
+                .unwrap();
+        let status = response.into_data().expect("git status should be present");
+        assert_eq!(status.repos.len(), 1);
+        let repo_status = &status.repos[0];
+        assert_eq!(repo_status.repo_id, attempt.repo.id);
+        assert_eq!(
+            repo_status.status.untracked,
+            vec!["scratch.txt".to_string()]
+        );
+        assert!(repo_status.status.staged.is_empty());
+        assert!(repo_status.status.unstaged.is_empty());
+
+        attempt.cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn revert_attempt_file_restores_committed_content() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let attempt = create_attempt_with_worktree(
+            &deployment,
+            &temp_root,
+            "revert-repo",
+            &[("notes.txt", "original\n"), ("a.txt", "base a\n")],
+        )
+        .await;
+        let workspace = attempt.workspace.clone();
+        let worktree = &attempt.worktree;
+        // Committed attempt changes are undone too, not just uncommitted edits.
+        std::fs::write(worktree.join("notes.txt"), "committed by agent\n").unwrap();
+        std::fs::write(worktree.join("added.txt"), "added by agent\n").unwrap();
+        GitService::new().commit(worktree, "agent changes").unwrap();
+        std::fs::write(worktree.join("notes.txt"), "edited by agent\n").unwrap();
+        std::fs::write(worktree.join("scratch.txt"), "scratch\n").unwrap();
+
+        let ResponseJson(response) = revert_task_attempt_file(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Json(RevertAttemptFileRequest {
+                path: "revert-repo/notes.txt".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        let reverted = response
+            .into_data()
+            .expect("revert response should be present");
+        assert!(!reverted.removed);
+        assert_eq!(
+            std::fs::read_to_string(worktree.join("notes.txt")).unwrap(),
+            "original\n"
+        );
+
+        let ResponseJson(response) = revert_task_attempt_file(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Json(RevertAttemptFileRequest {
+                path: "revert-repo/scratch.txt".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(response.into_data().unwrap().removed);
+        assert!(!worktree.join("scratch.txt").exists());
+
+        let ResponseJson(response) = revert_task_attempt_file(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Json(RevertAttemptFileRequest {
+                path: "revert-repo/added.txt".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(response.into_data().unwrap().removed);
+        assert!(!worktree.join("added.txt").exists());
+
+        // Glob characters in a path name that one file only.
+        std::fs::write(worktree.join("a.txt"), "edited a\n").unwrap();
+        std::fs::write(worktree.join("[a].txt"), "bracketed\n").unwrap();
+        let ResponseJson(response) = revert_task_attempt_file(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Json(RevertAttemptFileRequest {
+                path: "revert-repo/[a].txt".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(response.into_data().unwrap().removed);
+        assert!(!worktree.join("[a].txt").exists());
+        let err = revert_task_attempt_file(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Json(RevertAttemptFileRequest {
+                path: "revert-repo/*.txt".to_string(),
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ApiError::NotFound(_)), "{err:?}");
+        assert_eq!(
+            std::fs::read_to_string(worktree.join("a.txt")).unwrap(),
+            "edited a\n"
+        );
+        assert_eq!(
+            GitCli::new()
+                .git(worktree, ["ls-files", "--", "a.txt"])
+                .unwrap()
+                .trim(),
+            "a.txt"
+        );
+
+        for path in [
+            "revert-repo/../escape.txt",
+            "other-repo/notes.txt",
+            "/etc/passwd",
+        ] {
+            let err = revert_task_attempt_file(
+                Extension(workspace.clone()),
+                State(deployment.clone()),
+                Json(RevertAttemptFileRequest {
+                    path: path.to_string(),
+                }),
+            )
+            .await
+            .unwrap_err();
+            assert!(matches!(err, ApiError::BadRequest(_)), "{path}: {err:?}");
+        }
+
+        attempt.cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn attempt_blame_flags_agent_added_lines_as_attempt_origin() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let attempt = create_attempt_with_worktree(
+            &deployment,
+            &temp_root,
+            "blame-repo",
+            &[("notes.txt", "first\nsecond\n")],
+        )
+        .await;
+        let git_service = GitService::new();
+        let worktree = &attempt.worktree;
+        std::fs::write(
+            worktree.join("notes.txt"),
+            "first\nsecond\ncommitted by agent\n",
+        )
+        .unwrap();
+        git_service.commit(worktree, "agent edit").unwrap();
+        std::fs::write(
+            worktree.join("notes.txt"),
+            "first\nsecond\ncommitted by agent\nuncommitted by agent\n",
+        )
+        .unwrap();
+        let workspace = attempt.workspace.clone();
+
+        let ResponseJson(response) = get_task_attempt_blame(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Query(AttemptBlameQuery {
+                path: "blame-repo/notes.txt".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        let blame = response
+            .into_data()
+            .expect("blame response should be present");
+        let lines = blame
+            .lines
+            .iter()
+            .map(|line| (line.content.as_str(), line.attempt_origin))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                ("first", false),
+                ("second", false),
+                ("committed by agent", true),
+                ("uncommitted by agent", true),
+            ]
+        );
+        assert_eq!(blame.lines[2].summary.as_deref(), Some("agent edit"));
+        assert!(blame.lines[2].commit.is_some());
+        assert!(blame.lines[3].commit.is_none());
+
+        let err = get_task_attempt_blame(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Query(AttemptBlameQuery {
+                path: "blame-repo/missing.txt".to_string(),
+            }),
         )
         .await
-        .unwrap();
+        .unwrap_err();
+        assert!(matches!(err, ApiError::NotFound(_)), "{err:?}");
 
-        WorkspaceRepo::create_many(
-            &deployment.db().pool,
-            workspace.id,
-            &[CreateWorkspaceRepo {
-                repo_id: repo.id,
-                target_branch: "main".to_string(),
-            }],
+        // Files past the file endpoint's cap are refused instead of blamed line by line.
+        std::fs::write(worktree.join("huge.txt"), "x\n".repeat(300 * 1024)).unwrap();
+        let err = get_task_attempt_blame(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Query(AttemptBlameQuery {
+                path: "blame-repo/huge.txt".to_string(),
+            }),
         )
         .await
+        .unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(_)), "{err:?}");
+
+        // An untracked symlink must not expose a file outside the worktree.
+        #[cfg(unix)]
+        {
+            let outside = temp_root.join("outside-secret.txt");
+            std::fs::write(&outside, "secret\n").unwrap();
+            std::os::unix::fs::symlink(&outside, worktree.join("leak")).unwrap();
+            let err = get_task_attempt_blame(
+                Extension(workspace.clone()),
+                State(deployment.clone()),
+                Query(AttemptBlameQuery {
+                    path: "blame-repo/leak".to_string(),
+                }),
+            )
+            .await
+            .unwrap_err();
+            assert!(matches!(err, ApiError::BadRequest(_)), "{err:?}");
+        }
+
+        attempt.cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn download_attempt_patch_applies_cleanly_to_base() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let attempt = create_attempt_with_worktree(
+            &deployment,
+            &temp_root,
+            "patch-repo",
+            &[
+                ("notes.txt", "first\nsecond\n"),
+                ("obsolete.txt", "remove me\n"),
+            ],
+        )
+        .await;
+        let git_service = GitService::new();
+        let worktree = attempt.worktree.clone();
+        std::fs::write(worktree.join("notes.txt"), "first\nsecond\ncommitted\n").unwrap();
+        std::fs::remove_file(worktree.join("obsolete.txt")).unwrap();
+        git_service.commit(&worktree, "agent edit").unwrap();
+        std::fs::write(
+            worktree.join("notes.txt"),
+            "first\nsecond\ncommitted\nuncommitted\n",
+        )
         .unwrap();
+        std::fs::create_dir_all(worktree.join("src")).unwrap();
+        std::fs::write(worktree.join("src/new.rs"), "fn added() {}\n").unwrap();
+        std::fs::write(worktree.join("logo.bin"), [0u8, 1, 2, 255]).unwrap();
+        let workspace = attempt.workspace.clone();
 
-        let workspace_dir_name =
-            LocalContainerService::dir_name_from_workspace(&workspace.id, "Attempt changes task");
-        let workspace_dir = WorkspaceManager::get_workspace_base_dir().join(&workspace_dir_name);
-        let _container = WorkspaceManager::create_workspace(
-            &workspace_dir,
-            &[repos::workspace_manager::RepoWorkspaceInput::new(
-                repo.clone(),
-                "main".to_string(),
-            )],
-            &branch_name,
+        let response =
+            download_task_attempt_patch(Extension(workspace.clone()), State(deployment.clone()))
+                .await
+                .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_DISPOSITION).unwrap(),
+            format!("attachment; filename=\"attempt-{}.diff\"", workspace.id).as_str()
+        );
+        let patch = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let patch_text = String::from_utf8_lossy(&patch);
+        assert!(patch_text.contains("diff --git a/patch-repo/notes.txt b/patch-repo/notes.txt"));
+        assert!(patch_text.contains("b/patch-repo/src/new.rs"));
+
+        // Apply the download to a fresh checkout of the base branch.
+        let checkout = temp_root.join("patch-checkout");
+        let patch_file = temp_root.join("attempt.diff");
+        std::fs::write(&patch_file, &patch).unwrap();
+        let git = GitCli::new();
+        git.git(
+            temp_root.path(),
+            [
+                OsStr::new("clone"),
+                attempt.repo_path.as_os_str(),
+                checkout.as_os_str(),
+            ],
+        )
+        .unwrap();
+        git.git(
+            &checkout,
+            [
+                OsStr::new("apply"),
+                OsStr::new("-p2"),
+                patch_file.as_os_str(),
+            ],
         )
-        .await
         .unwrap();
 
-        let worktree_path = workspace_dir.join(&repo.name);
-        for i in 0..201 {
-            std::fs::write(worktree_path.join(format!("file-{i}.txt")), "hi\n").unwrap();
+        for path in ["notes.txt", "src/new.rs", "logo.bin"] {
+            assert_eq!(
+                std::fs::read(checkout.join(path)).unwrap(),
+                std::fs::read(worktree.join(path)).unwrap(),
+                "{path}"
+            );
         }
+        assert!(!checkout.join("obsolete.txt").exists());
 
-        workspace.container_ref = Some(workspace_dir.to_string_lossy().to_string());
+        attempt.cleanup().await;
+    }
 
-        let ResponseJson(response) = get_task_attempt_changes(
+    #[tokio::test]
+    async fn apply_attempt_patch_applies_clean_patch_and_rejects_escaping_paths() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let attempt = create_attempt_with_worktree(
+            &deployment,
+            &temp_root,
+            "apply-repo",
+            &[("notes.txt", "first\nsecond\n")],
+        )
+        .await;
+        let worktree = attempt.worktree.clone();
+        let workspace = attempt.workspace.clone();
+
+        let patch = "\
+diff --git a/apply-repo/notes.txt b/apply-repo/notes.txt
+--- a/apply-repo/notes.txt
++++ b/apply-repo/notes.txt
+@@ -1,2 +1,3 @@
+ first
+ second
++human tweak
+diff --git a/apply-repo/docs/new.md b/apply-repo/docs/new.md
+new file mode 100644
+--- /dev/null
++++ b/apply-repo/docs/new.md
+@@ -0,0 +1 @@
++# Notes
+";
+        let ResponseJson(response) = apply_task_attempt_patch(
             Extension(workspace.clone()),
             State(deployment.clone()),
-            Query(AttemptChangesQuery { force: false }),
+            Json(ApplyAttemptPatchRequest {
+                patch: patch.to_string(),
+            }),
         )
         .await
         .unwrap();
-        let changes = response.into_data().expect("changes should be present");
-        assert!(changes.blocked);
+        let applied = response
+            .into_data()
+            .expect("apply response should be present");
+        assert!(applied.applied);
+        assert!(applied.conflicts.is_empty());
         assert_eq!(
-            changes.blocked_reason,
-            Some(AttemptChangesBlockedReason::ThresholdExceeded)
+            applied.files,
+            vec![
+                "apply-repo/docs/new.md".to_string(),
+                "apply-repo/notes.txt".to_string()
+            ]
+        );
+        assert_eq!(
+            std::fs::read_to_string(worktree.join("notes.txt")).unwrap(),
+            "first\nsecond\nhuman tweak\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(worktree.join("docs/new.md")).unwrap(),
+            "# Notes\n"
         );
-        assert!(changes.files.is_empty());
+
+        // Applying it again conflicts and leaves the tree as it was.
+        let ResponseJson(response) = apply_task_attempt_patch(
+            Extension(workspace.clone()),
+            State(deployment.clone()),
+            Json(ApplyAttemptPatchRequest {
+                patch: patch.to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        let rejected = response.into_data().unwrap();
+        assert!(!rejected.applied);
+        assert!(!rejected.conflicts.is_empty());
+        assert_eq!(
+            std::fs::read_to_string(worktree.join("notes.txt")).unwrap(),
+            "first\nsecond\nhuman tweak\n"
+        );
+
+        for escaping in [
+            "--- a/apply-repo/../escape.txt\n+++ b/apply-repo/../escape.txt\n@@ -0,0 +1 @@\n+x\n",
+            "--- /dev/null\n+++ b/other-repo/file.txt\n@@ -0,0 +1 @@\n+x\n",
+            "--- /dev/null\n+++ b//etc/passwd\n@@ -0,0 +1 @@\n+x\n",
+            // A plain section after a hunk of a git-style section is still applied by git.
+            concat!(
+                "diff --git a/apply-repo/docs/new.md b/apply-repo/docs/new.md\n",
+                "--- a/apply-repo/docs/new.md\n",
+                "+++ b/apply-repo/docs/new.md\n",
+                "@@ -1 +1,2 @@\n",
+                " # Notes\n",
+                "+more\n",
+                "--- a/not-a-repo/smuggled.txt\n",
+                "+++ b/not-a-repo/smuggled.txt\n",
+                "@@ -0,0 +1 @@\n",
+                "+x\n",
+            ),
+        ] {
+            let err = apply_task_attempt_patch(
+                Extension(workspace.clone()),
+                State(deployment.clone()),
+                Json(ApplyAttemptPatchRequest {
+                    patch: escaping.to_string(),
+                }),
+            )
+            .await
+            .unwrap_err();
+            assert!(
+                matches!(err, ApiError::BadRequest(_)),
+                "{escaping}: {err:?}"
+            );
+        }
+        assert!(!attempt.workspace_dir.join("escape.txt").exists());
+        assert!(!worktree.join("smuggled.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(worktree.join("docs/new.md")).unwrap(),
+            "# Notes\n"
+        );
+
+        attempt.cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn attempt_changes_breaks_down_summary_per_repo() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let attempt = create_attempt_with_worktrees(
+            &deployment,
+            &temp_root,
+            "per-repo-changes",
+            &[("repo-a", &[]), ("repo-b", &[])],
+        )
+        .await;
+        for (repo, file_count) in attempt.repos.iter().zip([2usize, 3]) {
+            let worktree_path = attempt.workspace_dir.join(&repo.name);
+            for i in 0..file_count {
+                std::fs::write(worktree_path.join(format!("file-{i}.txt")), "one\ntwo\n").unwrap();
+            }
+        }
 
         let ResponseJson(response) = get_task_attempt_changes(
-            Extension(workspace),
+            Extension(attempt.workspace.clone()),
             State(deployment),
             Query(AttemptChangesQuery { force: true }),
         )
@@ -3213,15 +5680,36 @@ mod tests {
         .unwrap();
         let changes = response.into_data().expect("changes should be present");
         assert!(!changes.blocked);
-        assert_eq!(changes.blocked_reason, None);
-        assert!(
-            changes.files.len() >= 201,
-            "expected files list to include created files"
+
+        let file_count_for = |name: &str| {
+            changes
+                .repos
+                .iter()
+                .find(|repo| repo.repo_name == name)
+                .map(|repo| repo.file_count)
+                .unwrap_or_else(|| panic!("missing per-repo summary for {name}"))
+        };
+        assert_eq!(changes.repos.len(), 2);
+        assert!(file_count_for("repo-a") > 0);
+        assert!(file_count_for("repo-b") > file_count_for("repo-a"));
+        assert_eq!(
+            changes
+                .repos
+                .iter()
+                .map(|repo| repo.file_count)
+                .sum::<usize>(),
+            changes.summary.file_count
+        );
+        assert_eq!(
+            changes.repos.iter().map(|repo| repo.added).sum::<usize>(),
+            changes.summary.added
+        );
+        assert_eq!(
+            changes.repos.iter().map(|repo| repo.deleted).sum::<usize>(),
+            changes.summary.deleted
         );
 
-        WorkspaceManager::cleanup_workspace(&workspace_dir, &[repo])
-            .await
-            .unwrap();
+        attempt.cleanup().await;
     }
 
     #[tokio::test]
@@ -3290,6 +5778,7 @@ mod tests {
             &[CreateWorkspaceRepo {
                 repo_id: repo.id,
                 target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
             }],
         )
         .await
@@ -3511,4 +6000,193 @@ mod tests {
         let result = validate_dev_server_script("npm run dev && rm -rf /");
         assert!(matches!(result, Err(ApiError::BadRequest(_))));
     }
+
+    #[tokio::test]
+    async fn container_create_uses_configured_worktree_root() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+        let worktree_root = temp_root.join("scratch-worktrees");
+        deployment.config().write().await.worktree_root =
+            Some(worktree_root.to_string_lossy().to_string());
+
+        let repo_path = temp_root.join("repo");
+        GitService::new()
+            .initialize_repo_with_main_branch(&repo_path)
+            .unwrap();
+        let repo = Repo::find_or_create(pool, &repo_path, "Repo")
+            .await
+            .unwrap();
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &CreateProject {
+                name: "Worktree root project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+        let task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(project_id, "Scratch disk".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+        let workspace = Workspace::create(
+            pool,
+            &CreateWorkspace {
+                branch: "vk/scratch-disk".to_string(),
+                agent_working_dir: None,
+            },
+            Uuid::new_v4(),
+            task_id,
+        )
+        .await
+        .unwrap();
+        WorkspaceRepo::create_many(
+            pool,
+            workspace.id,
+            &[CreateWorkspaceRepo {
+                repo_id: repo.id,
+                target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
+            }],
+        )
+        .await
+        .unwrap();
+
+        let container_ref = deployment.container().create(&workspace).await.unwrap();
+
+        let workspace_dir = Path::new(&container_ref);
+        assert_eq!(
+            workspace_dir.parent(),
+            Some(worktree_root.join(config::WORKTREE_ROOT_SUBDIR).as_path()),
+            "{container_ref} should live in the dedicated subdirectory of the worktree root"
+        );
+        assert!(workspace_dir.join(&repo.name).join(".git").is_file());
+        assert!(
+            !WorkspaceManager::get_workspace_base_dir()
+                .join(workspace_dir.file_name().unwrap())
+                .exists()
+        );
+    }
+
+    #[tokio::test]
+    async fn container_create_emits_startup_progress_for_each_repo() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let mut repos = Vec::new();
+        for name in ["frontend", "backend"] {
+            let repo_path = temp_root.join(name);
+            GitService::new()
+                .initialize_repo_with_main_branch(&repo_path)
+                .unwrap();
+            repos.push(Repo::find_or_create(pool, &repo_path, name).await.unwrap());
+        }
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &CreateProject {
+                name: "Startup progress project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+        let task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(project_id, "Two repos".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+        let workspace = Workspace::create(
+            pool,
+            &CreateWorkspace {
+                branch: "vk/two-repos".to_string(),
+                agent_working_dir: None,
+            },
+            Uuid::new_v4(),
+            task_id,
+        )
+        .await
+        .unwrap();
+        let workspace_repos: Vec<CreateWorkspaceRepo> = repos
+            .iter()
+            .map(|repo| CreateWorkspaceRepo {
+                repo_id: repo.id,
+                target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
+            })
+            .collect();
+        WorkspaceRepo::create_many(pool, workspace.id, &workspace_repos)
+            .await
+            .unwrap();
+
+        deployment.container().create(&workspace).await.unwrap();
+
+        let progress_path = format!("/workspace_startup/{}", workspace.id);
+        let snapshots: Vec<WorkspaceStartupProgress> = deployment
+            .events()
+            .msg_store()
+            .get_history()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::JsonPatch(patch) => Some(patch),
+                _ => None,
+            })
+            .flat_map(|patch| patch.0)
+            .filter_map(|op| match op {
+                PatchOperation::Add(add) if add.path.to_string() == progress_path => {
+                    Some(serde_json::from_value(add.value).unwrap())
+                }
+                _ => None,
+            })
+            .collect();
+
+        let first = snapshots.first().expect("initial progress snapshot");
+        assert_eq!(first.total, 2);
+        assert!(
+            first
+                .repos
+                .iter()
+                .all(|repo| repo.status == RepoStartupStatus::Pending)
+        );
+        for repo in &repos {
+            let statuses: Vec<RepoStartupStatus> = snapshots
+                .iter()
+                .filter_map(|snapshot| {
+                    snapshot
+                        .repos
+                        .iter()
+                        .find(|entry| entry.repo_id == repo.id)
+                        .map(|entry| entry.status)
+                })
+                .collect();
+            assert!(
+                statuses.contains(&RepoStartupStatus::Cloning),
+                "{} never reported cloning: {statuses:?}",
+                repo.name
+            );
+            assert_eq!(statuses.last(), Some(&RepoStartupStatus::Ready));
+        }
+        let last = snapshots.last().unwrap();
+        assert_eq!(last.completed, 2);
+    }
 }
@@ -3,9 +3,8 @@ use std::path::Path;
 use app_runtime::Deployment;
 use axum::{
     Extension, Router,
-    body::Body,
-    extract::{DefaultBodyLimit, Multipart, Query, Request, State},
-    http::StatusCode,
+    extract::{Multipart, Query, Request, State},
+    http::{HeaderMap, StatusCode},
     middleware::{Next, from_fn_with_state},
     response::{Json as ResponseJson, Response},
     routing::{get, post},
@@ -14,7 +13,6 @@ use db::models::{task::Task, workspace::Workspace};
 use execution::{container::ContainerService, image::ImageError};
 use serde::Deserialize;
 use tokio::fs::File;
-use tokio_util::io::ReaderStream;
 use utils_core::response::ApiResponse;
 use uuid::Uuid;
 
@@ -23,7 +21,7 @@ use crate::{
     error::ApiError,
     middleware::load_workspace_middleware,
     routes::images::{
-        ImageMetadata, ImageResponse, build_image_file_response, process_image_upload,
+        ImageMetadata, ImageResponse, image_file_response, process_image_upload, upload_body_limit,
     },
 };
 
@@ -199,6 +197,7 @@ pub async fn serve_image(
     axum::extract::Path((_id, path)): axum::extract::Path<(Uuid, String)>,
     Extension(workspace): Extension<Workspace>,
     State(deployment): State<DeploymentImpl>,
+    headers: HeaderMap,
 ) -> Result<Response, ApiError> {
     // Reject paths with .. to prevent traversal
     if path.contains("..") {
@@ -236,9 +235,6 @@ pub async fn serve_image(
         .await
         .map_err(|_| ApiError::Image(ImageError::NotFound))?;
 
-    let stream = ReaderStream::new(file);
-    let body = Body::from_stream(stream);
-
     // Determine content type from extension
     let content_type = Path::new(&path)
         .extension()
@@ -255,7 +251,7 @@ pub async fn serve_image(
         })
         .unwrap_or("application/octet-stream");
 
-    build_image_file_response(body, content_type, metadata.len())
+    image_file_response(file, metadata.len(), content_type, &headers).await
 }
 
 /// Middleware to load Workspace for routes with wildcard path params.
@@ -277,10 +273,7 @@ async fn load_workspace_with_wildcard(
 pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
     let metadata_router = Router::new()
         .route("/metadata", get(get_image_metadata))
-        .route(
-            "/upload",
-            post(upload_image).layer(DefaultBodyLimit::max(20 * 1024 * 1024)), // 20MB limit
-        )
+        .route("/upload", post(upload_image).layer(upload_body_limit()))
         .layer(from_fn_with_state(
             deployment.clone(),
             load_workspace_middleware::<DeploymentImpl>,
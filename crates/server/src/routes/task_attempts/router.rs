@@ -1,18 +1,22 @@
 use axum::{
     Router,
     middleware::from_fn_with_state,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 
 use super::{
-    abort_conflicts_task_attempt, change_target_branch, create_task_attempt,
-    force_push_task_attempt_branch, get_task_attempt, get_task_attempt_branch_status,
-    get_task_attempt_changes, get_task_attempt_children, get_task_attempt_file,
-    get_task_attempt_patch, get_task_attempt_repos, get_task_attempt_status, get_task_attempts,
+    abort_conflicts_task_attempt, apply_task_attempt, apply_task_attempt_patch,
+    change_target_branch, compare_task_attempts, create_task_attempt, download_task_attempt_patch,
+    force_push_task_attempt_branch, get_task_attempt, get_task_attempt_blame,
+    get_task_attempt_branch_status, get_task_attempt_changes, get_task_attempt_children,
+    get_task_attempt_file, get_task_attempt_git_status, get_task_attempt_patch,
+    get_task_attempt_repos, get_task_attempt_status, get_task_attempts,
     get_task_attempts_latest_summaries, get_task_attempts_with_latest_session, images,
     merge_task_attempt, push_task_attempt_branch, rebase_task_attempt,
-    remove_task_attempt_worktree, rename_branch, run_agent_setup, run_cleanup_script,
-    run_setup_script, start_dev_server, stop_task_attempt_execution, ws,
+    rebase_task_attempt_onto_base, remove_task_attempt_worktree, rename_branch,
+    respond_all_task_attempt_approvals, revert_task_attempt_file, run_agent_setup,
+    run_cleanup_script, run_setup_script, search_task_attempt_content, start_dev_server,
+    stop_task_attempt_execution, update_task_attempt_labels, ws,
 };
 use crate::{DeploymentImpl, middleware::load_workspace_middleware};
 
@@ -20,9 +24,21 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
     let task_attempt_id_router = Router::new()
         .route("/", get(get_task_attempt))
         .route("/status", get(get_task_attempt_status))
+        .route("/labels", put(update_task_attempt_labels))
+        .route(
+            "/approvals/respond-all",
+            post(respond_all_task_attempt_approvals),
+        )
         .route("/changes", get(get_task_attempt_changes))
+        .route("/compare", get(compare_task_attempts))
         .route("/file", get(get_task_attempt_file))
+        .route("/file/revert", post(revert_task_attempt_file))
+        .route("/blame", get(get_task_attempt_blame))
+        .route("/search", get(search_task_attempt_content))
+        .route("/git-status", get(get_task_attempt_git_status))
         .route("/patch", post(get_task_attempt_patch))
+        .route("/patch.diff", get(download_task_attempt_patch))
+        .route("/patch/apply", post(apply_task_attempt_patch))
         .route("/run-agent-setup", post(run_agent_setup))
         .route("/start-dev-server", post(start_dev_server))
         .route("/run-setup-script", post(run_setup_script))
@@ -30,9 +46,11 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/branch-status", get(get_task_attempt_branch_status))
         .route("/diff/ws", get(ws::stream_task_attempt_diff_ws))
         .route("/merge", post(merge_task_attempt))
+        .route("/apply", post(apply_task_attempt))
         .route("/push", post(push_task_attempt_branch))
         .route("/push/force", post(force_push_task_attempt_branch))
         .route("/rebase", post(rebase_task_attempt))
+        .route("/rebase/onto-base", post(rebase_task_attempt_onto_base))
         .route("/conflicts/abort", post(abort_conflicts_task_attempt))
         .route("/children", get(get_task_attempt_children))
         .route("/stop", post(stop_task_attempt_execution))
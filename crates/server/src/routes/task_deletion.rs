@@ -7,6 +7,29 @@ use tasks::task_deletion as domain;
 
 use crate::{DeploymentImpl, error::ApiError, task_runtime::DeploymentTaskRuntime};
 
+pub const TASK_RECOVERY_WINDOW_ENV: &str = "VK_TASK_RECOVERY_WINDOW_SECS";
+const DEFAULT_TASK_RECOVERY_WINDOW_SECS: i64 = 60 * 60 * 24 * 7;
+
+/// How long a deleted task stays restorable. `None` (window set to 0 or less) deletes
+/// immediately.
+pub fn recovery_window() -> Option<chrono::Duration> {
+    let secs = match std::env::var(TASK_RECOVERY_WINDOW_ENV) {
+        Ok(raw) => match raw.trim().parse::<i64>() {
+            Ok(value) => value,
+            Err(err) => {
+                tracing::warn!(
+                    value = raw.trim(),
+                    error = %err,
+                    "Invalid {TASK_RECOVERY_WINDOW_ENV}; using default"
+                );
+                DEFAULT_TASK_RECOVERY_WINDOW_SECS
+            }
+        },
+        Err(_) => DEFAULT_TASK_RECOVERY_WINDOW_SECS,
+    };
+    (secs > 0).then(|| chrono::Duration::seconds(secs))
+}
+
 pub async fn delete_task_with_cleanup(
     deployment: &DeploymentImpl,
     task: Task,
@@ -18,6 +41,31 @@ pub async fn delete_task_with_cleanup(
         .map_err(ApiError::from)
 }
 
+/// Deletes a task through the recovery window when one is configured, otherwise immediately.
+pub async fn delete_task_recoverable(
+    deployment: &DeploymentImpl,
+    task: Task,
+) -> Result<(), ApiError> {
+    if recovery_window().is_none() {
+        return delete_task_with_cleanup(deployment, task, DeleteTaskMode::CascadeMilestone).await;
+    }
+    let runtime = DeploymentTaskRuntime::new(deployment.container());
+    domain::soft_delete_task(&runtime, &deployment.db().pool, task)
+        .await
+        .map_err(ApiError::from)
+}
+
+pub async fn prune_deleted_tasks_once(
+    deployment: &DeploymentImpl,
+    window: chrono::Duration,
+) -> Result<u64, ApiError> {
+    let runtime = DeploymentTaskRuntime::new(deployment.container());
+    let cutoff = chrono::Utc::now() - window;
+    domain::prune_deleted_tasks(&runtime, &deployment.db().pool, cutoff)
+        .await
+        .map_err(ApiError::from)
+}
+
 pub async fn delete_milestone_with_cleanup(
     deployment: &DeploymentImpl,
     milestone: Milestone,
@@ -43,13 +91,20 @@ fn topo_sorted_task_ids(graph: &MilestoneGraph) -> Vec<uuid::Uuid> {
 
 #[cfg(test)]
 mod tests {
-    use db::models::milestone::{
-        MilestoneEdge, MilestoneGraph, MilestoneNode, MilestoneNodeBaseStrategy, MilestoneNodeKind,
-        MilestoneNodeLayout,
+    use app_runtime::Deployment;
+    use db::models::{
+        milestone::{
+            MilestoneEdge, MilestoneGraph, MilestoneNode, MilestoneNodeBaseStrategy,
+            MilestoneNodeKind, MilestoneNodeLayout,
+        },
+        project::{CreateProject, Project},
+        task::{CreateTask, Task},
     };
+    use test_support::{TempRoot, TestDb, TestEnvGuard};
     use uuid::Uuid;
 
-    use super::topo_sorted_task_ids;
+    use super::{prune_deleted_tasks_once, topo_sorted_task_ids};
+    use crate::DeploymentImpl;
 
     fn node(id: &str) -> (MilestoneNode, Uuid) {
         let task_id = Uuid::new_v4();
@@ -89,4 +144,58 @@ mod tests {
         let idx_b = order.iter().position(|id| *id == task_id_b).unwrap();
         assert!(idx_a < idx_b);
     }
+
+    #[tokio::test]
+    async fn prune_removes_deleted_tasks_only_after_recovery_window() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            pool,
+            &CreateProject {
+                name: "Prune".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+        let task_id = Uuid::new_v4();
+        Task::create(
+            pool,
+            &CreateTask::from_title_description(project_id, "Deleted".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+        Task::soft_delete(pool, task_id).await.unwrap();
+
+        let removed = prune_deleted_tasks_once(&deployment, chrono::Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(removed, 0);
+        Task::restore_deleted(
+            pool,
+            task_id,
+            chrono::Utc::now() - chrono::Duration::hours(1),
+        )
+        .await
+        .expect("task is still recoverable");
+        Task::soft_delete(pool, task_id).await.unwrap();
+
+        let removed = prune_deleted_tasks_once(&deployment, chrono::Duration::zero())
+            .await
+            .unwrap();
+        assert_eq!(removed, 1);
+        assert!(
+            db::models::ids::task_id_by_uuid(pool, task_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
 }
@@ -1,11 +1,11 @@
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 use anyhow;
 use app_runtime::Deployment;
 use axum::{
     Extension, Json, Router,
     extract::{
-        Query, State,
+        Path, Query, State,
         ws::{Message, WebSocket, WebSocketUpgrade},
     },
     http::{HeaderMap, StatusCode},
@@ -13,12 +13,14 @@ use axum::{
     response::{IntoResponse, Json as ResponseJson},
     routing::{delete, get, post, put},
 };
+use chrono::{DateTime, Utc};
 use db::models::{
     image::TaskImage,
     task::{
         CreateTask, Task, TaskLineageSummary, TaskUpdateParams, TaskWithAttemptStatus, UpdateTask,
     },
-    workspace_repo::CreateWorkspaceRepo,
+    task_schedule::{TaskSchedule, TaskScheduleRepo, UpsertTaskSchedule},
+    workspace_repo::{CreateWorkspaceRepo, WorkspaceRepo},
 };
 use events::EventError;
 use executors::profile::ExecutorConfigs;
@@ -26,7 +28,10 @@ use executors_protocol::ExecutorProfileId;
 use futures_util::{SinkExt, StreamExt, TryStreamExt};
 use logs_axum::SequencedLogMsgAxumExt;
 use serde::{Deserialize, Serialize};
-use tasks::orchestration::{self, CreateAndStartTaskInput};
+use tasks::{
+    orchestration::{self, CreateAndStartTaskInput},
+    schedule::CronSchedule,
+};
 use ts_rs::TS;
 use utils_core::response::ApiResponse;
 use uuid::Uuid;
@@ -35,7 +40,10 @@ use crate::{
     DeploymentImpl,
     error::ApiError,
     middleware::load_task_middleware,
-    routes::{task_attempts::WorkspaceRepoInput, task_deletion},
+    routes::{
+        task_attempts::{self, WorkspaceRepoInput},
+        task_deletion,
+    },
     task_runtime::DeploymentTaskRuntime,
 };
 
@@ -255,12 +263,15 @@ pub async fn create_task_and_start(
         .map(|repo| CreateWorkspaceRepo {
             repo_id: repo.repo_id,
             target_branch: repo.target_branch.clone(),
+            sparse_paths: repo.sparse_paths.clone().unwrap_or_default(),
         })
         .collect();
 
     let agent_working_dir = project_config.default_agent_working_dir.clone();
 
-    let task = orchestration::create_task_and_start(
+    let created_branches =
+        task_attempts::create_requested_target_branches(&deployment, &payload.repos).await?;
+    let task = match orchestration::create_task_and_start(
         &runtime,
         &deployment.db().pool,
         &CreateAndStartTaskInput {
@@ -270,17 +281,31 @@ pub async fn create_task_and_start(
             agent_working_dir,
         },
     )
-    .await?;
+    .await
+    {
+        Ok(task) => task,
+        Err(err) => {
+            task_attempts::delete_created_target_branches(&deployment, &created_branches).await;
+            return Err(err.into());
+        }
+    };
 
     Ok(ResponseJson(ApiResponse::success(task)))
 }
 
+#[derive(Debug, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(tag = "type", rename_all = "snake_case")]
+pub enum UpdateTaskError {
+    StaleWrite { current_updated_at: DateTime<Utc> },
+}
+
 pub async fn update_task(
     Extension(existing_task): Extension<Task>,
     State(deployment): State<DeploymentImpl>,
 
     Json(payload): Json<UpdateTask>,
-) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+) -> Result<(StatusCode, ResponseJson<ApiResponse<Task, UpdateTaskError>>), ApiError> {
     if existing_task.archived_kanban_id.is_some() {
         return Err(ApiError::Conflict(
             "Task is archived. Restore it before editing.".to_string(),
@@ -298,7 +323,7 @@ pub async fn update_task(
         .parent_workspace_id
         .or(existing_task.parent_workspace_id);
 
-    let task = Task::update(
+    let task = match Task::update(
         &deployment.db().pool,
         existing_task.id,
         TaskUpdateParams {
@@ -308,55 +333,272 @@ pub async fn update_task(
             status,
             parent_workspace_id,
             continuation_turns_override: payload.continuation_turns_override,
+            expected_updated_at: payload.expected_updated_at,
         },
     )
-    .await?;
+    .await
+    {
+        Ok(task) => task,
+        Err(db::DbErr::RecordNotUpdated) => {
+            let current = Task::find_by_id(&deployment.db().pool, existing_task.id)
+                .await?
+                .ok_or_else(|| ApiError::NotFound("Task not found".to_string()))?;
+            return Ok((
+                StatusCode::CONFLICT,
+                ResponseJson(ApiResponse::error_with_data(UpdateTaskError::StaleWrite {
+                    current_updated_at: current.updated_at,
+                })),
+            ));
+        }
+        Err(err) => return Err(err.into()),
+    };
 
     if let Some(image_ids) = &payload.image_ids {
         TaskImage::delete_by_task_id(&deployment.db().pool, task.id).await?;
         TaskImage::associate_many_dedup(&deployment.db().pool, task.id, image_ids).await?;
     }
 
-    Ok(ResponseJson(ApiResponse::success(task)))
+    Ok((StatusCode::OK, ResponseJson(ApiResponse::success(task))))
 }
 
+/// Loads the task itself instead of going through `load_task_middleware`: a keyed retry of a
+/// delete that already succeeded finds no task and must get the stored response, not a 404.
 pub async fn delete_task(
-    Extension(task): Extension<Task>,
     State(deployment): State<DeploymentImpl>,
+    Path(task_id): Path<Uuid>,
+    headers: HeaderMap,
 ) -> Result<(StatusCode, ResponseJson<ApiResponse<()>>), ApiError> {
+    let pool = &deployment.db().pool;
+    let key = crate::routes::idempotency::idempotency_key(&headers);
+    let hash = crate::routes::idempotency::request_hash(&task_id)?;
+    if let Some(response) =
+        crate::routes::idempotency::stored_success(pool, "delete_task", key.as_deref(), &hash)
+            .await?
+    {
+        return Ok((StatusCode::ACCEPTED, response));
+    }
+
+    let task = Task::find_by_id(pool, task_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Task not found".to_string()))?;
     if task.archived_kanban_id.is_some() {
         return Err(ApiError::Conflict(
             "Task is archived. Delete its archive to remove it.".to_string(),
         ));
     }
-    task_deletion::delete_task_with_cleanup(
-        &deployment,
-        task,
-        task_deletion::DeleteTaskMode::CascadeMilestone,
+
+    let response =
+        crate::routes::idempotency::idempotent_success(pool, "delete_task", key, hash, || async {
+            task_deletion::delete_task_recoverable(&deployment, task).await
+        })
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, response))
+}
+
+#[derive(Debug, Serialize, Deserialize, TS)]
+pub struct MoveTaskRequest {
+    pub target_project_id: Uuid,
+}
+
+/// Moves a task to another project. Attempts move with the task and keep their worktrees, so
+/// the move is refused while any of them is running or when they use repos the target project
+/// does not have. Milestone tasks stay with their milestone's project.
+pub async fn move_task(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<MoveTaskRequest>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    if task.archived_kanban_id.is_some() {
+        return Err(ApiError::Conflict(
+            "Task is archived. Restore it before moving.".to_string(),
+        ));
+    }
+    if task.milestone_id.is_some() {
+        return Err(ApiError::Conflict(
+            "Task belongs to a milestone and cannot be moved to another project.".to_string(),
+        ));
+    }
+    if task.project_id == payload.target_project_id {
+        return Ok(ResponseJson(ApiResponse::success(task)));
+    }
+
+    let target = {
+        let config = deployment.config().read().await;
+        config
+            .projects
+            .iter()
+            .find(|project| project.id == Some(payload.target_project_id))
+            .map(|project| {
+                let repo_paths = project
+                    .repos
+                    .iter()
+                    .map(|repo| PathBuf::from(repo.path.trim()))
+                    .collect::<Vec<_>>();
+                (project.name.clone(), repo_paths)
+            })
+    };
+    let Some((project_name, target_repo_paths)) = target else {
+        return Err(ApiError::BadRequest(
+            "Target project not found in projects config.".to_string(),
+        ));
+    };
+
+    let tx = deployment.db().pool.begin().await?;
+    if Task::has_running_attempts(&tx, task.id).await? {
+        return Err(ApiError::Conflict(
+            "Task has a running attempt. Stop it before moving the task.".to_string(),
+        ));
+    }
+
+    let foreign_repos = WorkspaceRepo::find_unique_repos_for_task(&tx, task.id)
+        .await?
+        .into_iter()
+        .filter(|repo| !target_repo_paths.contains(&repo.path))
+        .map(|repo| repo.display_name)
+        .collect::<Vec<_>>();
+    if !foreign_repos.is_empty() {
+        return Err(ApiError::Conflict(format!(
+            "Task has attempts on repos that are not in the target project: {}",
+            foreign_repos.join(", ")
+        )));
+    }
+
+    db::models::project::Project::find_or_create_minimal(
+        &tx,
+        payload.target_project_id,
+        &project_name,
     )
     .await?;
 
-    Ok((StatusCode::ACCEPTED, ResponseJson(ApiResponse::success(()))))
+    let moved = Task::move_to_project(&tx, task.id, payload.target_project_id).await?;
+    // Schedules pin repos of the source project and would fail to start in the target.
+    TaskSchedule::delete_by_task_id(&tx, moved.id).await?;
+    tx.commit().await?;
+
+    Ok(ResponseJson(ApiResponse::success(moved)))
 }
 
-pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
-    let task_actions_router = Router::new()
-        .route("/", put(update_task))
-        .route("/", delete(delete_task));
+/// Restores a soft-deleted task while its recovery window is still open.
+pub async fn restore_task(
+    State(deployment): State<DeploymentImpl>,
+    Path(task_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    let Some(window) = task_deletion::recovery_window() else {
+        return Err(ApiError::NotFound("Deleted task not found".to_string()));
+    };
+    let deleted_after = Utc::now() - window;
+    let task = Task::restore_deleted(&deployment.db().pool, task_id, deleted_after)
+        .await
+        .map_err(|err| match err {
+            db::DbErr::Custom(message) => ApiError::Conflict(message),
+            err => ApiError::Database(err),
+        })?;
+    Ok(ResponseJson(ApiResponse::success(task)))
+}
 
+#[derive(Debug, Deserialize, TS)]
+pub struct UpsertTaskScheduleRequest {
+    pub cron: String,
+    pub executor_profile_id: ExecutorProfileId,
+    pub repos: Vec<WorkspaceRepoInput>,
+    pub enabled: Option<bool>,
+}
+
+pub async fn get_task_schedule(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<TaskSchedule>>>, ApiError> {
+    let schedule = TaskSchedule::find_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(schedule)))
+}
+
+pub async fn upsert_task_schedule(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<UpsertTaskScheduleRequest>,
+) -> Result<ResponseJson<ApiResponse<TaskSchedule>>, ApiError> {
+    if task.archived_kanban_id.is_some() {
+        return Err(ApiError::Conflict(
+            "Task is archived. Restore it before scheduling attempts.".to_string(),
+        ));
+    }
+    if payload.repos.is_empty() {
+        return Err(ApiError::BadRequest(
+            "At least one repository is required".to_string(),
+        ));
+    }
+    ExecutorConfigs::get_cached()
+        .require_coding_agent(&payload.executor_profile_id)
+        .map_err(|err| ApiError::BadRequest(err.to_string()))?;
+
+    let cron =
+        CronSchedule::parse(&payload.cron).map_err(|err| ApiError::BadRequest(err.to_string()))?;
+    let enabled = payload.enabled.unwrap_or(true);
+    let next_run_at = if enabled {
+        Some(cron.next_after(Utc::now()).ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "Cron expression '{}' never fires",
+                payload.cron.trim()
+            ))
+        })?)
+    } else {
+        None
+    };
+
+    let schedule = TaskSchedule::upsert(
+        &deployment.db().pool,
+        task.id,
+        &UpsertTaskSchedule {
+            cron: payload.cron.trim().to_string(),
+            executor_profile_id: payload.executor_profile_id,
+            repos: payload
+                .repos
+                .into_iter()
+                .map(|repo| TaskScheduleRepo {
+                    repo_id: repo.repo_id,
+                    target_branch: repo.target_branch,
+                })
+                .collect(),
+            enabled,
+            next_run_at,
+        },
+    )
+    .await?;
+
+    Ok(ResponseJson(ApiResponse::success(schedule)))
+}
+
+pub async fn delete_task_schedule(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    TaskSchedule::delete_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
     let task_id_router = Router::new()
-        .route("/", get(get_task))
+        .route("/", get(get_task).put(update_task))
         .route("/lineage", get(get_task_lineage))
-        .merge(task_actions_router)
+        .route("/move", post(move_task))
+        .route(
+            "/schedule",
+            get(get_task_schedule)
+                .put(upsert_task_schedule)
+                .delete(delete_task_schedule),
+        )
         .layer(from_fn_with_state(
             deployment.clone(),
             load_task_middleware::<DeploymentImpl>,
-        ));
+        ))
+        .merge(Router::new().route("/", delete(delete_task)));
 
     let inner = Router::new()
         .route("/", get(get_tasks).post(create_task))
         .route("/stream/ws", get(stream_tasks_ws))
         .route("/create-and-start", post(create_task_and_start))
+        .route("/{task_id}/restore", post(restore_task))
         .nest("/{task_id}", task_id_router);
 
     // mount under /projects/:project_id/tasks
@@ -366,17 +608,64 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
 #[cfg(test)]
 mod tests {
     use app_runtime::Deployment;
-    use axum::{Extension, Json, extract::State, http::HeaderValue};
+    use axum::{
+        Extension, Json,
+        body::Body,
+        extract::State,
+        http::{HeaderValue, Request, StatusCode},
+    };
     use db::models::{
+        execution_process::{CreateExecutionProcess, ExecutionProcess, ExecutionProcessRunReason},
         project::{CreateProject, Project},
-        task::{CreateTask, Task},
+        repo::Repo,
+        session::{CreateSession, Session},
+        task::{CreateTask, Task, UpdateTask},
+        workspace::{CreateWorkspace, Workspace},
+        workspace_repo::{CreateWorkspaceRepo, WorkspaceRepo},
+    };
+    use executors_protocol::actions::{
+        ExecutorAction, ExecutorActionType,
+        script::{ScriptContext, ScriptRequest, ScriptRequestLanguage},
     };
     use test_support::{TempRoot, TestDb, TestEnvGuard};
+    use tower::ServiceExt;
     use uuid::Uuid;
 
-    use super::{create_task, get_task_lineage};
+    use super::{
+        MoveTaskRequest, UpdateTaskError, create_task, get_task_lineage, move_task, update_task,
+    };
     use crate::DeploymentImpl;
 
+    async fn deployment_with_two_projects(temp_root: &TempRoot) -> (DeploymentImpl, Uuid, Uuid) {
+        let source_project_id = Uuid::new_v4();
+        let target_project_id = Uuid::new_v4();
+        let repo_path = temp_root.join("repo");
+        std::fs::create_dir_all(&repo_path).unwrap();
+        std::fs::write(
+            temp_root.join("vk-config").join("config.yaml"),
+            format!(
+                r#"projects:
+  - id: {source_project_id}
+    name: Source
+    repos:
+      - path: {repo_path}
+  - id: {target_project_id}
+    name: Target
+    repos:
+      - path: {repo_path}
+"#,
+                repo_path = repo_path.to_string_lossy()
+            ),
+        )
+        .unwrap();
+
+        let deployment = DeploymentImpl::new().await.unwrap();
+        Project::find_or_create_minimal(&deployment.db().pool, source_project_id, "Source")
+            .await
+            .unwrap();
+        (deployment, source_project_id, target_project_id)
+    }
+
     fn idempotency_headers(key: &'static str) -> axum::http::HeaderMap {
         let mut headers = axum::http::HeaderMap::new();
         headers.insert("Idempotency-Key", HeaderValue::from_static(key));
@@ -615,4 +904,365 @@ mod tests {
 
         assert!(matches!(err, crate::error::ApiError::Conflict(_)));
     }
+
+    #[tokio::test]
+    async fn update_task_rejects_stale_expected_updated_at() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let deployment = DeploymentImpl::new().await.unwrap();
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            &deployment.db().pool,
+            &CreateProject {
+                name: "Stale writes".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let task = Task::create(
+            &deployment.db().pool,
+            &CreateTask::from_title_description(project_id, "Original".to_string(), None),
+            Uuid::new_v4(),
+        )
+        .await
+        .unwrap();
+
+        let update = |title: &str, expected_updated_at| UpdateTask {
+            title: Some(title.to_string()),
+            description: None,
+            status: None,
+            parent_workspace_id: None,
+            image_ids: None,
+            continuation_turns_override: None,
+            expected_updated_at,
+        };
+
+        let (status, Json(response)) = update_task(
+            Extension(task.clone()),
+            State(deployment.clone()),
+            Json(update(
+                "Stale",
+                Some(task.updated_at - chrono::Duration::seconds(1)),
+            )),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, axum::http::StatusCode::CONFLICT);
+        assert!(matches!(
+            response.error_data(),
+            Some(UpdateTaskError::StaleWrite { current_updated_at })
+                if *current_updated_at == task.updated_at
+        ));
+        let unchanged = Task::find_by_id(&deployment.db().pool, task.id)
+            .await
+            .unwrap()
+            .expect("task should exist");
+        assert_eq!(unchanged.title, "Original");
+
+        let (status, Json(response)) = update_task(
+            Extension(task.clone()),
+            State(deployment.clone()),
+            Json(update("Fresh", Some(task.updated_at))),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, axum::http::StatusCode::OK);
+        let fresh = response.into_data().expect("task should be present");
+        assert_eq!(fresh.title, "Fresh");
+
+        // The loaded task still carries the old updated_at, as it would for a request that read
+        // the row before a concurrent write; the conditional UPDATE must still reject it.
+        let (status, Json(response)) = update_task(
+            Extension(task.clone()),
+            State(deployment.clone()),
+            Json(update("Racing", Some(task.updated_at))),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, axum::http::StatusCode::CONFLICT);
+        assert!(matches!(
+            response.error_data(),
+            Some(UpdateTaskError::StaleWrite { current_updated_at })
+                if *current_updated_at == fresh.updated_at
+        ));
+        let stored = Task::find_by_id(&deployment.db().pool, task.id)
+            .await
+            .unwrap()
+            .expect("task should exist");
+        assert_eq!(stored.title, "Fresh");
+    }
+
+    #[tokio::test]
+    async fn repeated_delete_with_same_key_replays_success() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let (deployment, project_id, _) = deployment_with_two_projects(&temp_root).await;
+
+        let task = Task::create(
+            &deployment.db().pool,
+            &CreateTask::from_title_description(project_id, "Obsolete".to_string(), None),
+            Uuid::new_v4(),
+        )
+        .await
+        .unwrap();
+
+        let delete = |key: Option<&'static str>| {
+            let mut request = Request::builder()
+                .method("DELETE")
+                .uri(format!("/api/tasks/{}", task.id));
+            if let Some(key) = key {
+                request = request.header("Idempotency-Key", key);
+            }
+            crate::http::router(deployment.clone()).oneshot(request.body(Body::empty()).unwrap())
+        };
+
+        let first = delete(Some("delete-1")).await.unwrap();
+        assert_eq!(first.status(), StatusCode::ACCEPTED);
+        assert!(
+            Task::find_by_id(&deployment.db().pool, task.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let retry = delete(Some("delete-1")).await.unwrap();
+        assert_eq!(retry.status(), StatusCode::ACCEPTED);
+
+        let unkeyed = delete(None).await.unwrap();
+        assert_eq!(unkeyed.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn move_task_reassigns_task_without_attempts() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let (deployment, source_project_id, target_project_id) =
+            deployment_with_two_projects(&temp_root).await;
+
+        let task = Task::create(
+            &deployment.db().pool,
+            &CreateTask::from_title_description(source_project_id, "Misfiled".to_string(), None),
+            Uuid::new_v4(),
+        )
+        .await
+        .unwrap();
+
+        let Json(response) = move_task(
+            Extension(task.clone()),
+            State(deployment.clone()),
+            Json(MoveTaskRequest { target_project_id }),
+        )
+        .await
+        .unwrap();
+        let moved = response.into_data().expect("task should be present");
+        assert_eq!(moved.id, task.id);
+        assert_eq!(moved.project_id, target_project_id);
+
+        let stored = Task::find_by_id(&deployment.db().pool, task.id)
+            .await
+            .unwrap()
+            .expect("task should exist");
+        assert_eq!(stored.project_id, target_project_id);
+        assert!(
+            Task::find_by_project_id_with_attempt_status(&deployment.db().pool, source_project_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn move_task_with_attempt_requires_its_repos_in_the_target_project() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let source_project_id = Uuid::new_v4();
+        let shared_project_id = Uuid::new_v4();
+        let other_project_id = Uuid::new_v4();
+        let repo_path = temp_root.join("repo");
+        let other_repo_path = temp_root.join("other-repo");
+        std::fs::create_dir_all(&repo_path).unwrap();
+        std::fs::create_dir_all(&other_repo_path).unwrap();
+        std::fs::write(
+            temp_root.join("vk-config").join("config.yaml"),
+            format!(
+                r#"projects:
+  - id: {source_project_id}
+    name: Source
+    repos:
+      - path: {repo_path}
+  - id: {shared_project_id}
+    name: Shared
+    repos:
+      - path: {other_repo_path}
+      - path: {repo_path}
+  - id: {other_project_id}
+    name: Other
+    repos:
+      - path: {other_repo_path}
+"#,
+                repo_path = repo_path.to_string_lossy(),
+                other_repo_path = other_repo_path.to_string_lossy()
+            ),
+        )
+        .unwrap();
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let pool = &deployment.db().pool;
+        Project::find_or_create_minimal(pool, source_project_id, "Source")
+            .await
+            .unwrap();
+
+        let task = Task::create(
+            pool,
+            &CreateTask::from_title_description(source_project_id, "Attempted".to_string(), None),
+            Uuid::new_v4(),
+        )
+        .await
+        .unwrap();
+        let attempt_id = Uuid::new_v4();
+        Workspace::create(
+            pool,
+            &CreateWorkspace {
+                branch: "attempted".to_string(),
+                agent_working_dir: None,
+            },
+            attempt_id,
+            task.id,
+        )
+        .await
+        .unwrap();
+        let repo = Repo::find_or_create(pool, &repo_path, "repo")
+            .await
+            .unwrap();
+        WorkspaceRepo::create_many(
+            pool,
+            attempt_id,
+            &[CreateWorkspaceRepo {
+                repo_id: repo.id,
+                target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
+            }],
+        )
+        .await
+        .unwrap();
+
+        let err = move_task(
+            Extension(task.clone()),
+            State(deployment.clone()),
+            Json(MoveTaskRequest {
+                target_project_id: other_project_id,
+            }),
+        )
+        .await
+        .expect_err("expected conflict");
+        assert!(matches!(err, crate::error::ApiError::Conflict(_)));
+        let stored = Task::find_by_id(pool, task.id)
+            .await
+            .unwrap()
+            .expect("task should exist");
+        assert_eq!(stored.project_id, source_project_id);
+        assert!(
+            Project::find_by_id(pool, other_project_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let Json(response) = move_task(
+            Extension(task.clone()),
+            State(deployment.clone()),
+            Json(MoveTaskRequest {
+                target_project_id: shared_project_id,
+            }),
+        )
+        .await
+        .unwrap();
+        let moved = response.into_data().expect("task should be present");
+        assert_eq!(moved.project_id, shared_project_id);
+        let attempts = Workspace::fetch_all(pool, Some(task.id)).await.unwrap();
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].id, attempt_id);
+    }
+
+    #[tokio::test]
+    async fn move_task_is_blocked_while_an_attempt_is_running() {
+        let temp_root = TempRoot::new("vk-test-");
+        let db = TestDb::sqlite_file(&temp_root);
+        let _env_guard = TestEnvGuard::new(temp_root.path(), db.url().to_string());
+        let (deployment, source_project_id, target_project_id) =
+            deployment_with_two_projects(&temp_root).await;
+        let pool = &deployment.db().pool;
+
+        let task = Task::create(
+            pool,
+            &CreateTask::from_title_description(source_project_id, "Busy".to_string(), None),
+            Uuid::new_v4(),
+        )
+        .await
+        .unwrap();
+
+        let attempt_id = Uuid::new_v4();
+        Workspace::create(
+            pool,
+            &CreateWorkspace {
+                branch: "busy".to_string(),
+                agent_working_dir: None,
+            },
+            attempt_id,
+            task.id,
+        )
+        .await
+        .unwrap();
+        let session_id = Uuid::new_v4();
+        Session::create(
+            pool,
+            &CreateSession { executor: None },
+            session_id,
+            attempt_id,
+        )
+        .await
+        .unwrap();
+        ExecutionProcess::create(
+            pool,
+            &CreateExecutionProcess {
+                session_id,
+                executor_action: ExecutorAction::new(
+                    ExecutorActionType::ScriptRequest(ScriptRequest {
+                        script: "sleep 60".to_string(),
+                        language: ScriptRequestLanguage::Bash,
+                        context: ScriptContext::SetupScript,
+                        working_dir: None,
+                    }),
+                    None,
+                ),
+                run_reason: ExecutionProcessRunReason::CodingAgent,
+            },
+            Uuid::new_v4(),
+            &[],
+        )
+        .await
+        .unwrap();
+
+        let err = move_task(
+            Extension(task.clone()),
+            State(deployment.clone()),
+            Json(MoveTaskRequest { target_project_id }),
+        )
+        .await
+        .expect_err("expected conflict");
+        assert!(matches!(err, crate::error::ApiError::Conflict(_)));
+
+        let stored = Task::find_by_id(pool, task.id)
+            .await
+            .unwrap()
+            .expect("task should exist");
+        assert_eq!(stored.project_id, source_project_id);
+    }
 }
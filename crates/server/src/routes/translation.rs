@@ -1,5 +1,15 @@
-use axum::{Json, Router, response::Json as ResponseJson, routing::post};
+use std::collections::BTreeSet;
+
+use app_runtime::Deployment;
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use config::ProxyConfig;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use utils_core::response::ApiResponse;
 
 use crate::{DeploymentImpl, error::ApiError};
@@ -11,6 +21,39 @@ const OPENAI_API_BASE: &str = "OPENAI_API_BASE";
 const OPENAI_API_KEY: &str = "OPENAI_API_KEY";
 const OPENAI_DEFAULT_MODEL: &str = "OPENAI_DEFAULT_MODEL";
 
+/// Language every other UI catalog is checked against.
+const BASE_CATALOG_LANG: &str = "en";
+
+/// `(namespace, json)` pairs of a UI catalog.
+type Catalog = [(&'static str, &'static str)];
+
+macro_rules! ui_catalog {
+    ($lang:literal: $($namespace:literal),+) => {
+        &[$((
+            $namespace,
+            include_str!(concat!(
+                "../../../../frontend/src/i18n/locales/",
+                $lang,
+                "/",
+                $namespace,
+                ".json"
+            )),
+        )),+]
+    };
+}
+
+/// Frontend i18n catalogs, keyed by i18next language code.
+const UI_CATALOGS: &[(&str, &Catalog)] = &[
+    (
+        "en",
+        ui_catalog!("en": "common", "projects", "settings", "tasks"),
+    ),
+    (
+        "zh-Hans",
+        ui_catalog!("zh-Hans": "common", "projects", "settings", "tasks"),
+    ),
+];
+
 #[derive(Debug, Deserialize)]
 pub struct TranslationRequest {
     pub text: String,
@@ -67,12 +110,99 @@ struct LlmConfig {
     model: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct MissingTranslationsQuery {
+    pub lang: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MissingTranslationsResponse {
+    pub base_lang: String,
+    pub lang: String,
+    /// `namespace:dotted.key` entries present in the base catalog but not in `lang`, sorted.
+    pub missing: Vec<String>,
+}
+
 pub fn router() -> Router<DeploymentImpl> {
-    Router::new().route("/translation", post(translate))
+    Router::new()
+        .route("/translation", post(translate))
+        .route("/translation/missing", get(missing_translations))
+}
+
+async fn missing_translations(
+    Query(query): Query<MissingTranslationsQuery>,
+) -> Result<ResponseJson<ApiResponse<MissingTranslationsResponse>>, ApiError> {
+    let find = |lang: &str| {
+        UI_CATALOGS
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(lang.trim()))
+    };
+    let Some((lang, catalog)) = find(&query.lang) else {
+        let supported: Vec<&str> = UI_CATALOGS.iter().map(|(code, _)| *code).collect();
+        return Err(ApiError::BadRequest(format!(
+            "Unknown UI language '{}': expected one of {}",
+            query.lang,
+            supported.join(", ")
+        )));
+    };
+    let (_, base) = find(BASE_CATALOG_LANG).expect("base catalog is embedded");
+
+    Ok(ResponseJson(ApiResponse::success(
+        MissingTranslationsResponse {
+            base_lang: BASE_CATALOG_LANG.to_string(),
+            lang: lang.to_string(),
+            missing: missing_catalog_keys(base, catalog)?,
+        },
+    )))
+}
+
+fn catalog_keys(catalog: &Catalog) -> Result<BTreeSet<String>, ApiError> {
+    fn collect(prefix: String, value: &Value, keys: &mut BTreeSet<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    collect(format!("{prefix}.{key}"), child, keys);
+                }
+            }
+            _ => {
+                keys.insert(prefix);
+            }
+        }
+    }
+
+    let mut keys = BTreeSet::new();
+    for (namespace, json) in catalog {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|err| ApiError::Internal(format!("Invalid '{namespace}' catalog: {err}")))?;
+        let Value::Object(map) = value else {
+            continue;
+        };
+        for (key, child) in &map {
+            collect(format!("{namespace}:{key}"), child, &mut keys);
+        }
+    }
+    Ok(keys)
+}
+
+fn missing_catalog_keys(base: &Catalog, target: &Catalog) -> Result<Vec<String>, ApiError> {
+    let target_keys = catalog_keys(target)?;
+    Ok(catalog_keys(base)?
+        .into_iter()
+        .filter(|key| !target_keys.contains(key))
+        .collect())
 }
 
 async fn translate(
+    State(deployment): State<DeploymentImpl>,
     Json(payload): Json<TranslationRequest>,
+) -> Result<ResponseJson<ApiResponse<TranslationResponse>>, ApiError> {
+    let proxy = deployment.config().read().await.proxy.clone();
+    translate_with_proxy(&proxy, payload).await
+}
+
+async fn translate_with_proxy(
+    proxy: &ProxyConfig,
+    payload: TranslationRequest,
 ) -> Result<ResponseJson<ApiResponse<TranslationResponse>>, ApiError> {
     if payload.text.trim().is_empty() {
         return Err(ApiError::BadRequest(
@@ -80,9 +210,26 @@ async fn translate(
         ));
     }
 
+    let system_prompt = build_system_prompt(&payload.source_lang, &payload.target_lang);
+    let translated_text =
+        chat_completion(proxy, "Translation", system_prompt, payload.text, 0.2).await?;
+
+    Ok(ResponseJson(ApiResponse::success(TranslationResponse {
+        translated_text,
+    })))
+}
+
+/// Sends a single system + user exchange to the configured OpenAI-compatible endpoint and
+/// returns the first non-empty reply. `purpose` prefixes error messages.
+pub(crate) async fn chat_completion(
+    proxy: &ProxyConfig,
+    purpose: &str,
+    system_prompt: String,
+    user_content: String,
+    temperature: f32,
+) -> Result<String, ApiError> {
     let config = resolve_llm_config()?;
     let url = format_openai_url(&config.base_url);
-    let system_prompt = build_system_prompt(&payload.source_lang, &payload.target_lang);
 
     let request_body = OpenAiChatRequest {
         model: config.model,
@@ -93,20 +240,21 @@ async fn translate(
             },
             OpenAiMessage {
                 role: "user".to_string(),
-                content: payload.text,
+                content: user_content,
             },
         ],
-        temperature: 0.2,
+        temperature,
     };
 
-    let client = reqwest::Client::new();
+    let client = crate::proxy::build_http_client(proxy)
+        .map_err(|err| ApiError::BadRequest(format!("Invalid proxy configuration: {}", err)))?;
     let response = client
         .post(url)
         .bearer_auth(config.api_key)
         .json(&request_body)
         .send()
         .await
-        .map_err(|err| ApiError::BadRequest(format!("Translation request failed: {}", err)))?;
+        .map_err(|err| ApiError::BadRequest(format!("{purpose} request failed: {}", err)))?;
 
     if !response.status().is_success() {
         let status = response.status();
@@ -115,7 +263,7 @@ async fn translate(
             .unwrap_or_else(|| body.trim().to_string())
             .trim()
             .to_string();
-        let fallback = format!("Translation failed with status {}", status);
+        let fallback = format!("{purpose} failed with status {}", status);
         let message = if message.is_empty() {
             fallback
         } else {
@@ -127,19 +275,14 @@ async fn translate(
     let data = response
         .json::<OpenAiChatResponse>()
         .await
-        .map_err(|err| ApiError::BadRequest(format!("Translation response invalid: {}", err)))?;
+        .map_err(|err| ApiError::BadRequest(format!("{purpose} response invalid: {}", err)))?;
 
-    let translated_text = data
-        .choices
+    data.choices
         .iter()
         .find_map(|choice| choice.message.as_ref()?.content.as_ref())
         .map(|text| text.to_string())
         .filter(|text| !text.trim().is_empty())
-        .ok_or_else(|| ApiError::BadRequest("Translation unavailable".to_string()))?;
-
-    Ok(ResponseJson(ApiResponse::success(TranslationResponse {
-        translated_text,
-    })))
+        .ok_or_else(|| ApiError::BadRequest(format!("{purpose} unavailable")))
 }
 
 fn resolve_llm_config() -> Result<LlmConfig, ApiError> {
@@ -202,7 +345,8 @@ mod tests {
 
     use super::{
         KANBAN_OPENAI_API_BASE, KANBAN_OPENAI_API_KEY, KANBAN_OPENAI_DEFAULT_MODEL, ResponseJson,
-        TranslationRequest, build_system_prompt, format_openai_url, translate,
+        TranslationRequest, UI_CATALOGS, build_system_prompt, catalog_keys, format_openai_url,
+        missing_catalog_keys, translate_with_proxy,
     };
 
     static ENV_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
@@ -270,7 +414,9 @@ mod tests {
             target_lang: "fr".to_string(),
         };
 
-        let ResponseJson(api_response) = translate(Json(payload)).await.unwrap();
+        let ResponseJson(api_response) = translate_with_proxy(&Default::default(), payload)
+            .await
+            .unwrap();
         assert!(api_response.is_success());
         assert_eq!(
             api_response.into_data().unwrap().translated_text,
@@ -310,4 +456,35 @@ mod tests {
         assert!(prompt.contains("en"));
         assert!(prompt.contains("zh-CN"));
     }
+
+    #[test]
+    fn missing_catalog_keys_lists_keys_absent_from_target() {
+        let base = [
+            (
+                "common",
+                r#"{ "buttons": { "save": "Save", "cancel": "Cancel" }, "title": "Board" }"#,
+            ),
+            ("tasks", r#"{ "empty": "No tasks" }"#),
+        ];
+        let incomplete = [
+            (
+                "common",
+                r#"{ "buttons": { "save": "保存" }, "extra": "多余" }"#,
+            ),
+            ("tasks", r#"{}"#),
+        ];
+
+        assert_eq!(
+            missing_catalog_keys(&base, &incomplete).unwrap(),
+            vec!["common:buttons.cancel", "common:title", "tasks:empty"]
+        );
+        assert!(missing_catalog_keys(&base, &base).unwrap().is_empty());
+    }
+
+    #[test]
+    fn embedded_ui_catalogs_parse() {
+        for (_, catalog) in UI_CATALOGS {
+            assert!(!catalog_keys(catalog).unwrap().is_empty());
+        }
+    }
 }
@@ -0,0 +1,332 @@
+use axum::{
+    Json, Router,
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, HeaderValue},
+    response::Json as ResponseJson,
+    routing::post,
+};
+use db::{
+    models::task::{CreateTask, Task},
+    types::TaskCreatedByKind,
+};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use utils_core::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// Header carrying `sha256=<hex HMAC-SHA256 of "<timestamp>.<delivery id>.<raw body>">`, keyed by
+/// `access_control.webhook_secret`.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-vk-signature";
+/// Unix time (seconds) at which the sender signed the request.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "x-vk-timestamp";
+/// Sender-chosen id of the delivery; a redelivery with the same id returns the task created by
+/// the first one instead of creating another.
+pub const WEBHOOK_DELIVERY_HEADER: &str = "x-vk-delivery";
+/// Signed requests older (or further in the future) than this are rejected as replays.
+const WEBHOOK_TIMESTAMP_TOLERANCE_SECS: i64 = 5 * 60;
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookCreateTaskRequest {
+    pub project_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if value.len() % 2 != 0 {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(value.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn signature_is_valid(
+    secret: &str,
+    signature: &str,
+    timestamp: &str,
+    delivery_id: &str,
+    body: &[u8],
+) -> bool {
+    let Some(expected) = signature
+        .trim()
+        .strip_prefix("sha256=")
+        .and_then(decode_hex)
+    else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(delivery_id.as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// Creates a task on behalf of an external system (e.g. CI). Authenticated only by the signature
+/// over the timestamp, delivery id and body, so callers never need the API token. Stale
+/// timestamps are rejected and redeliveries are answered from the first delivery's result.
+pub async fn create_task_from_webhook(
+    State(deployment): State<DeploymentImpl>,
+    mut headers: HeaderMap,
+    body: Bytes,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    let secret = {
+        let config = deployment.config().read().await;
+        config
+            .access_control
+            .webhook_secret
+            .as_deref()
+            .map(str::trim)
+            .filter(|secret| !secret.is_empty())
+            .map(str::to_string)
+    };
+    let Some(secret) = secret else {
+        return Err(ApiError::NotFound(
+            "Inbound webhooks are disabled. Set access_control.webhook_secret in config.yaml."
+                .to_string(),
+        ));
+    };
+
+    let (Some(signature), Some(timestamp), Some(delivery_id)) = (
+        header_str(&headers, WEBHOOK_SIGNATURE_HEADER),
+        header_str(&headers, WEBHOOK_TIMESTAMP_HEADER),
+        header_str(&headers, WEBHOOK_DELIVERY_HEADER),
+    ) else {
+        tracing::warn!(reason = "missing_headers", "Rejected inbound webhook");
+        return Err(ApiError::Forbidden(
+            "Webhook signature, timestamp and delivery headers are required".to_string(),
+        ));
+    };
+    if !signature_is_valid(&secret, signature, timestamp, delivery_id, &body) {
+        tracing::warn!(reason = "signature_mismatch", "Rejected inbound webhook");
+        return Err(ApiError::Forbidden("Invalid webhook signature".to_string()));
+    }
+    let fresh = timestamp.parse::<i64>().is_ok_and(|signed_at| {
+        (chrono::Utc::now().timestamp() - signed_at).abs() <= WEBHOOK_TIMESTAMP_TOLERANCE_SECS
+    });
+    if !fresh {
+        tracing::warn!(reason = "stale_timestamp", "Rejected inbound webhook");
+        return Err(ApiError::Forbidden(
+            "Webhook timestamp is outside the allowed window".to_string(),
+        ));
+    }
+
+    // Dedupe through the create_task idempotency store, keyed by the signed delivery id.
+    let idempotency_key = HeaderValue::from_str(&format!("webhook:{delivery_id}"))
+        .map_err(|_| ApiError::BadRequest("Invalid webhook delivery id".to_string()))?;
+    headers.insert(
+        crate::routes::idempotency::IDEMPOTENCY_KEY_HEADER,
+        idempotency_key,
+    );
+
+    let payload: WebhookCreateTaskRequest = serde_json::from_slice(&body)
+        .map_err(|err| ApiError::BadRequest(format!("Invalid webhook payload: {err}")))?;
+    let mut create =
+        CreateTask::from_title_description(payload.project_id, payload.title, payload.description);
+    create.created_by_kind = Some(TaskCreatedByKind::Webhook);
+
+    crate::routes::tasks::create_task(State(deployment), headers, Json(create)).await
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route("/webhooks/tasks", post(create_task_from_webhook))
+}
+
+#[cfg(test)]
+mod tests {
+    use app_runtime::Deployment;
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header},
+    };
+    use config::{AccessControlMode, ProjectConfig};
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+    use test_support::TestEnv;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use super::{WEBHOOK_DELIVERY_HEADER, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER};
+    use crate::{DeploymentImpl, http};
+
+    fn sign(secret: &str, timestamp: i64, delivery_id: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("{timestamp}.{delivery_id}.").as_bytes());
+        mac.update(body);
+        let digest: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        format!("sha256={digest}")
+    }
+
+    async fn setup_deployment() -> (TestEnv, DeploymentImpl, Uuid) {
+        let env_guard = TestEnv::new("vk-test-");
+        let deployment = DeploymentImpl::new().await.unwrap();
+        let project_id = Uuid::new_v4();
+        {
+            let mut config = deployment.config().write().await;
+            // The webhook must work without the API token.
+            config.access_control.mode = AccessControlMode::Token;
+            config.access_control.token = Some("sekrit".to_string());
+            config.access_control.allow_localhost_bypass = false;
+            config.access_control.webhook_secret = Some("hook-secret".to_string());
+            config.projects.push(
+                serde_json::from_value::<ProjectConfig>(
+                    serde_json::json!({ "id": project_id, "name": "Webhooks" }),
+                )
+                .unwrap(),
+            );
+        }
+
+        (env_guard, deployment, project_id)
+    }
+
+    fn webhook_request(
+        body: &[u8],
+        timestamp: i64,
+        delivery_id: &str,
+        signature: &str,
+    ) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri("/api/webhooks/tasks")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_SIGNATURE_HEADER, signature)
+            .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+            .header(WEBHOOK_DELIVERY_HEADER, delivery_id)
+            .body(Body::from(body.to_vec()))
+            .unwrap()
+    }
+
+    fn signed_request(body: &[u8], timestamp: i64, delivery_id: &str) -> Request<Body> {
+        let signature = sign("hook-secret", timestamp, delivery_id, body);
+        webhook_request(body, timestamp, delivery_id, &signature)
+    }
+
+    #[tokio::test]
+    async fn signed_webhook_creates_task() {
+        let (_env_guard, deployment, project_id) = setup_deployment().await;
+        let app = http::router(deployment);
+
+        let body = serde_json::json!({ "project_id": project_id, "title": "Fix flaky CI" })
+            .to_string()
+            .into_bytes();
+        let response = app
+            .oneshot(signed_request(
+                &body,
+                chrono::Utc::now().timestamp(),
+                "delivery-1",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            json.pointer("/data/title").and_then(|v| v.as_str()),
+            Some("Fix flaky CI")
+        );
+        assert_eq!(
+            json.pointer("/data/created_by_kind")
+                .and_then(|v| v.as_str()),
+            Some("webhook")
+        );
+    }
+
+    #[tokio::test]
+    async fn webhook_with_invalid_signature_is_rejected() {
+        let (_env_guard, deployment, project_id) = setup_deployment().await;
+        let app = http::router(deployment.clone());
+
+        let body = serde_json::json!({ "project_id": project_id, "title": "Forged" })
+            .to_string()
+            .into_bytes();
+        let now = chrono::Utc::now().timestamp();
+        let stale = now - 10 * 60;
+        let requests = [
+            webhook_request(
+                &body,
+                now,
+                "delivery-1",
+                &sign("wrong-secret", now, "delivery-1", &body),
+            ),
+            webhook_request(&body, now, "delivery-1", "sha256=not-hex"),
+            // Signed for another delivery id, so it cannot be reused to dodge deduplication.
+            webhook_request(
+                &body,
+                now,
+                "delivery-2",
+                &sign("hook-secret", now, "delivery-1", &body),
+            ),
+            signed_request(&body, stale, "delivery-1"),
+        ];
+        for request in requests {
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+
+        let tasks = db::models::task::Task::find_by_project_id_with_attempt_status(
+            &deployment.db().pool,
+            project_id,
+        )
+        .await
+        .unwrap();
+        assert!(tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn redelivered_webhook_returns_the_first_task() {
+        let (_env_guard, deployment, project_id) = setup_deployment().await;
+        let app = http::router(deployment.clone());
+
+        let body = serde_json::json!({ "project_id": project_id, "title": "Nightly failed" })
+            .to_string()
+            .into_bytes();
+        let mut task_ids = Vec::new();
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(signed_request(
+                    &body,
+                    chrono::Utc::now().timestamp(),
+                    "delivery-1",
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            task_ids.push(json.pointer("/data/id").cloned().unwrap());
+        }
+        assert_eq!(task_ids[0], task_ids[1]);
+
+        let tasks = db::models::task::Task::find_by_project_id_with_attempt_status(
+            &deployment.db().pool,
+            project_id,
+        )
+        .await
+        .unwrap();
+        assert_eq!(tasks.len(), 1);
+    }
+}
@@ -31,9 +31,15 @@ where
         workspace: &Workspace,
         executor_profile_id: ExecutorProfileId,
         prompt_override: Option<String>,
+        reasoning_effort: Option<String>,
     ) -> Result<(), String> {
         self.container
-            .start_workspace(workspace, executor_profile_id, prompt_override)
+            .start_workspace(
+                workspace,
+                executor_profile_id,
+                prompt_override,
+                reasoning_effort,
+            )
             .await
             .map(|_| ())
             .map_err(|err| err.to_string())
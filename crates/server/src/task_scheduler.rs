@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use app_runtime::Deployment;
+use chrono::Utc;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::{DeploymentImpl, task_runtime::DeploymentTaskRuntime};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+pub fn spawn(deployment: DeploymentImpl) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let shutdown = deployment.shutdown_token();
+        if let Err(err) = run_loop(deployment, shutdown).await {
+            warn!(error = %err, "task scheduler stopped with error");
+        }
+    })
+}
+
+async fn run_loop(deployment: DeploymentImpl, shutdown: CancellationToken) -> Result<()> {
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        tokio::select! {
+            _ = shutdown.cancelled() => {
+                info!("task scheduler shutting down");
+                return Ok(());
+            }
+            _ = interval.tick() => {
+                if let Err(err) = poll_once(&deployment).await {
+                    warn!(error = %err, "task scheduler poll failed");
+                }
+            }
+        }
+    }
+}
+
+async fn poll_once(deployment: &DeploymentImpl) -> Result<()> {
+    let runtime = DeploymentTaskRuntime::new(deployment.container());
+    tasks::schedule::run_due_schedules(&runtime, &deployment.db().pool, Utc::now()).await?;
+    Ok(())
+}
@@ -0,0 +1,328 @@
+//! Database and worktree fixtures shared by the route and MCP tool tests.
+
+use std::path::PathBuf;
+
+use app_runtime::Deployment;
+use db::models::{
+    execution_process::{CreateExecutionProcess, ExecutionProcess, ExecutionProcessRunReason},
+    project::{CreateProject, Project},
+    project_repo::ProjectRepo,
+    repo::Repo,
+    session::{CreateSession, Session},
+    task::{CreateTask, Task},
+    workspace::{CreateWorkspace, Workspace},
+    workspace_repo::{CreateWorkspaceRepo, WorkspaceRepo},
+};
+use execution::container::LocalContainerService;
+use executors_protocol::actions::{
+    ExecutorAction, ExecutorActionType,
+    script::{ScriptContext, ScriptRequest, ScriptRequestLanguage},
+};
+use repos::{
+    git::GitService,
+    workspace_manager::{RepoWorkspaceInput, WorkspaceManager},
+};
+use test_support::TempRoot;
+use utils_core::approvals::{ApprovalRequest, CreateApprovalRequest};
+use uuid::Uuid;
+
+use crate::DeploymentImpl;
+
+pub(crate) async fn create_workspace_with_repo(
+    db: &db::DbPool,
+    task_id: Uuid,
+    repo_id: Uuid,
+    workspace_branch: &str,
+    target_branch: &str,
+) -> Workspace {
+    let workspace = Workspace::create(
+        db,
+        &CreateWorkspace {
+            branch: workspace_branch.to_string(),
+            agent_working_dir: None,
+        },
+        Uuid::new_v4(),
+        task_id,
+    )
+    .await
+    .unwrap();
+    WorkspaceRepo::create_many(
+        db,
+        workspace.id,
+        &[CreateWorkspaceRepo {
+            repo_id,
+            target_branch: target_branch.to_string(),
+            sparse_paths: Vec::new(),
+        }],
+    )
+    .await
+    .unwrap();
+    workspace
+}
+
+/// An attempt whose repos are each checked out into a real worktree, branched off `main`.
+pub(crate) struct AttemptWithWorktrees {
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub task_id: Uuid,
+    pub repos: Vec<Repo>,
+    /// `container_ref` is set both here and in the database.
+    pub workspace: Workspace,
+    pub workspace_dir: PathBuf,
+}
+
+impl AttemptWithWorktrees {
+    pub(crate) async fn cleanup(&self) {
+        WorkspaceManager::cleanup_workspace(&self.workspace_dir, &self.repos)
+            .await
+            .unwrap();
+    }
+}
+
+/// [`AttemptWithWorktrees`] with a single repo.
+pub(crate) struct AttemptWithWorktree {
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub task_id: Uuid,
+    pub repo: Repo,
+    pub repo_path: PathBuf,
+    /// `container_ref` is set both here and in the database.
+    pub workspace: Workspace,
+    pub workspace_dir: PathBuf,
+    /// The repo's checkout inside `workspace_dir`.
+    pub worktree: PathBuf,
+}
+
+impl AttemptWithWorktree {
+    pub(crate) async fn cleanup(&self) {
+        WorkspaceManager::cleanup_workspace(&self.workspace_dir, std::slice::from_ref(&self.repo))
+            .await
+            .unwrap();
+    }
+}
+
+/// Creates a project, task and attempt named after `name` around one new git repo per entry of
+/// `repos` under `temp_root`, each with its files committed on `main`, and creates the attempt's
+/// worktrees.
+pub(crate) async fn create_attempt_with_worktrees(
+    deployment: &DeploymentImpl,
+    temp_root: &TempRoot,
+    name: &str,
+    repos: &[(&str, &[(&str, &str)])],
+) -> AttemptWithWorktrees {
+    let pool = &deployment.db().pool;
+
+    let project_id = Uuid::new_v4();
+    let project_name = format!("{name} project");
+    Project::create(
+        pool,
+        &CreateProject {
+            name: project_name.clone(),
+            repositories: Vec::new(),
+        },
+        project_id,
+    )
+    .await
+    .unwrap();
+
+    let git_service = GitService::new();
+    let mut attempt_repos = Vec::new();
+    for (repo_name, base_files) in repos {
+        let repo_path = temp_root.join(repo_name);
+        git_service
+            .initialize_repo_with_main_branch(&repo_path)
+            .unwrap();
+        for (path, contents) in *base_files {
+            let path = repo_path.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+        if !base_files.is_empty() {
+            git_service.commit(&repo_path, "add base files").unwrap();
+        }
+        let repo = Repo::find_or_create(pool, &repo_path, repo_name)
+            .await
+            .unwrap();
+        ProjectRepo::create(pool, project_id, repo.id)
+            .await
+            .unwrap();
+        attempt_repos.push(repo);
+    }
+
+    let task_id = Uuid::new_v4();
+    let task_title = format!("{name} task");
+    Task::create(
+        pool,
+        &CreateTask::from_title_description(project_id, task_title.clone(), None),
+        task_id,
+    )
+    .await
+    .unwrap();
+
+    let branch = format!("{name}-{}", Uuid::new_v4());
+    let mut workspace = Workspace::create(
+        pool,
+        &CreateWorkspace {
+            branch: branch.clone(),
+            agent_working_dir: None,
+        },
+        Uuid::new_v4(),
+        task_id,
+    )
+    .await
+    .unwrap();
+    let workspace_repos: Vec<_> = attempt_repos
+        .iter()
+        .map(|repo| CreateWorkspaceRepo {
+            repo_id: repo.id,
+            target_branch: "main".to_string(),
+            sparse_paths: Vec::new(),
+        })
+        .collect();
+    WorkspaceRepo::create_many(pool, workspace.id, &workspace_repos)
+        .await
+        .unwrap();
+
+    let workspace_dir = WorkspaceManager::get_workspace_base_dir().join(
+        LocalContainerService::dir_name_from_workspace(&workspace.id, &task_title),
+    );
+    let inputs: Vec<_> = attempt_repos
+        .iter()
+        .map(|repo| RepoWorkspaceInput::new(repo.clone(), "main".to_string()))
+        .collect();
+    WorkspaceManager::create_workspace(&workspace_dir, &inputs, &branch)
+        .await
+        .unwrap();
+    let container_ref = workspace_dir.to_string_lossy().to_string();
+    Workspace::update_container_ref(pool, workspace.id, &container_ref)
+        .await
+        .unwrap();
+    workspace.container_ref = Some(container_ref);
+
+    AttemptWithWorktrees {
+        project_id,
+        project_name,
+        task_id,
+        repos: attempt_repos,
+        workspace,
+        workspace_dir,
+    }
+}
+
+/// Single-repo [`create_attempt_with_worktrees`], named after the repo.
+pub(crate) async fn create_attempt_with_worktree(
+    deployment: &DeploymentImpl,
+    temp_root: &TempRoot,
+    repo_name: &str,
+    base_files: &[(&str, &str)],
+) -> AttemptWithWorktree {
+    let AttemptWithWorktrees {
+        project_id,
+        project_name,
+        task_id,
+        mut repos,
+        workspace,
+        workspace_dir,
+    } = create_attempt_with_worktrees(deployment, temp_root, repo_name, &[(repo_name, base_files)])
+        .await;
+    let repo = repos.remove(0);
+    let worktree = workspace_dir.join(&repo.name);
+    AttemptWithWorktree {
+        project_id,
+        project_name,
+        task_id,
+        repo,
+        repo_path: temp_root.join(repo_name),
+        workspace,
+        workspace_dir,
+        worktree,
+    }
+}
+
+/// Creates an attempt whose setup script process is still running; returns the attempt and the
+/// process id approvals can be attached to.
+pub(crate) async fn create_running_attempt(pool: &db::DbPool, branch: &str) -> (Workspace, Uuid) {
+    let project_id = Uuid::new_v4();
+    Project::create(
+        pool,
+        &CreateProject {
+            name: "Approvals project".to_string(),
+            repositories: Vec::new(),
+        },
+        project_id,
+    )
+    .await
+    .unwrap();
+    let task_id = Uuid::new_v4();
+    Task::create(
+        pool,
+        &CreateTask::from_title_description(project_id, "Approvals task".to_string(), None),
+        task_id,
+    )
+    .await
+    .unwrap();
+    let workspace = Workspace::create(
+        pool,
+        &CreateWorkspace {
+            branch: branch.to_string(),
+            agent_working_dir: None,
+        },
+        Uuid::new_v4(),
+        task_id,
+    )
+    .await
+    .unwrap();
+    let session = Session::create(
+        pool,
+        &CreateSession { executor: None },
+        Uuid::new_v4(),
+        workspace.id,
+    )
+    .await
+    .unwrap();
+    let process_id = Uuid::new_v4();
+    ExecutionProcess::create(
+        pool,
+        &CreateExecutionProcess {
+            session_id: session.id,
+            executor_action: ExecutorAction::new(
+                ExecutorActionType::ScriptRequest(ScriptRequest {
+                    script: "true".to_string(),
+                    language: ScriptRequestLanguage::Bash,
+                    context: ScriptContext::SetupScript,
+                    working_dir: None,
+                }),
+                None,
+            ),
+            run_reason: ExecutionProcessRunReason::SetupScript,
+        },
+        process_id,
+        &[],
+    )
+    .await
+    .unwrap();
+
+    (workspace, process_id)
+}
+
+/// Registers a pending `Bash` approval for `process_id` and returns its id.
+pub(crate) async fn create_pending_approval(
+    deployment: &DeploymentImpl,
+    process_id: Uuid,
+    tool_call_id: &str,
+) -> String {
+    let request = ApprovalRequest::from_create(
+        CreateApprovalRequest {
+            tool_name: "Bash".to_string(),
+            tool_input: serde_json::json!({ "command": format!("echo {tool_call_id}") }),
+            tool_call_id: tool_call_id.to_string(),
+        },
+        process_id,
+    );
+    let (approval, _waiter) = deployment
+        .approvals()
+        .create_with_waiter(&deployment.db().pool, request)
+        .await
+        .unwrap();
+    approval.id
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use config::Config;
+use execution::turn_summary::TurnSummarizer;
+use tokio::sync::RwLock;
+use utils_core::text::truncate_to_char_boundary;
+
+use crate::routes::translation::chat_completion;
+
+const TURN_SUMMARY_PROMPT_MAX_LEN: usize = 4_000;
+
+const TURN_SUMMARY_SYSTEM_PROMPT: &str = "You summarize one turn of a coding agent session. \
+Reply with a single plain-text line (at most 120 characters) describing what the agent did or \
+concluded. No markdown, no preamble.";
+
+/// Summarizes finished turns through the OpenAI-compatible endpoint used for translation.
+pub struct LlmTurnSummarizer {
+    config: Arc<RwLock<Config>>,
+}
+
+impl LlmTurnSummarizer {
+    pub fn new(config: Arc<RwLock<Config>>) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl TurnSummarizer for LlmTurnSummarizer {
+    async fn summarize(&self, prompt: Option<&str>, last_message: &str) -> anyhow::Result<String> {
+        let proxy = self.config.read().await.proxy.clone();
+        let mut user_content = String::new();
+        if let Some(prompt) = prompt.map(str::trim).filter(|prompt| !prompt.is_empty()) {
+            user_content.push_str("User request:\n");
+            user_content.push_str(truncate_to_char_boundary(
+                prompt,
+                TURN_SUMMARY_PROMPT_MAX_LEN,
+            ));
+            user_content.push_str("\n\n");
+        }
+        user_content.push_str("Agent's final message:\n");
+        user_content.push_str(last_message);
+
+        chat_completion(
+            &proxy,
+            "Turn summary",
+            TURN_SUMMARY_SYSTEM_PROMPT.to_string(),
+            user_content,
+            0.2,
+        )
+        .await
+        .map_err(|err| anyhow::anyhow!(err.to_string()))
+    }
+}
@@ -6,7 +6,7 @@ use executors_core::approvals::{ExecutorApprovalError, ExecutorApprovalService};
 use serde_json::Value;
 use utils_core::{
     approvals::{ApprovalRequest, ApprovalStatus, CreateApprovalRequest},
-    notifications::SharedNotifier,
+    notifications::{NotificationEvent, SharedNotifier},
 };
 use uuid::Uuid;
 
@@ -62,7 +62,8 @@ impl ExecutorApprovalService for ExecutorApprovalBridge {
 
         // Play notification sound when approval is needed
         self.notification_service
-            .notify(
+            .notify_event(
+                NotificationEvent::ApprovalNeeded,
                 "Approval Needed",
                 &format!("Tool '{}' requires approval", tool_name),
             )
@@ -610,6 +610,7 @@ mod tests {
             &[CreateWorkspaceRepo {
                 repo_id: repo.id,
                 target_branch: "main".to_string(),
+                sparse_paths: Vec::new(),
             }],
         )
         .await
@@ -679,6 +680,7 @@ mod tests {
             metadata: Some(
                 serde_json::to_value(ToolCallMetadata {
                     tool_call_id: id.to_string(),
+                    ..Default::default()
                 })
                 .unwrap(),
             ),
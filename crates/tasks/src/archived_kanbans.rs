@@ -108,6 +108,7 @@ pub async fn archive_project_kanban<R: TaskRuntime + Sync>(
     let mut selected = task::Entity::find()
         .filter(task::Column::ProjectId.eq(project_row_id))
         .filter(task::Column::ArchivedKanbanId.is_null())
+        .filter(task::Column::DeletedAt.is_null())
         .filter(task::Column::Status.is_in(payload.statuses.clone()))
         .all(pool)
         .await?;
@@ -2,5 +2,6 @@ pub mod approvals;
 pub mod archived_kanbans;
 pub mod orchestration;
 pub mod runtime;
+pub mod schedule;
 pub mod task_deletion;
 pub mod turn_continuation;
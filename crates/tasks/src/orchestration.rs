@@ -32,7 +32,9 @@ pub struct CreateTaskAttemptInput {
     pub executor_profile_id: ExecutorProfileId,
     pub repos: Vec<CreateWorkspaceRepo>,
     pub prompt_override: Option<String>,
+    pub reasoning_effort: Option<String>,
     pub agent_working_dir: Option<String>,
+    pub labels: Vec<String>,
 }
 
 #[derive(Debug, Error)]
@@ -128,7 +130,7 @@ pub async fn create_task_and_start<R: TaskRuntime + Sync>(
     tx.commit().await?;
 
     if let Err(err) = runtime
-        .start_workspace(&workspace, input.executor_profile_id.clone(), None)
+        .start_workspace(&workspace, input.executor_profile_id.clone(), None, None)
         .await
     {
         if is_blocking_after_prepare_hook_error(&err) {
@@ -179,7 +181,7 @@ pub async fn create_task_attempt<R: TaskRuntime + Sync>(
         .await;
 
     let tx = db.begin().await?;
-    let workspace = Workspace::create(
+    let mut workspace = Workspace::create(
         &tx,
         &CreateWorkspace {
             branch: git_branch_name,
@@ -189,6 +191,9 @@ pub async fn create_task_attempt<R: TaskRuntime + Sync>(
         input.task_id,
     )
     .await?;
+    if !input.labels.is_empty() {
+        workspace = Workspace::set_labels(&tx, workspace.id, &input.labels).await?;
+    }
 
     WorkspaceRepo::create_many(&tx, workspace.id, &attempt_plan.repos).await?;
     if matches!(original_task_status, TaskStatus::Todo) {
@@ -201,6 +206,7 @@ pub async fn create_task_attempt<R: TaskRuntime + Sync>(
             &workspace,
             attempt_plan.executor_profile_id,
             input.prompt_override.clone(),
+            input.reasoning_effort.clone(),
         )
         .await
     {
@@ -436,6 +442,7 @@ async fn resolve_attempt_plan(
                 .cloned()
                 .or_else(|| baseline_ref.clone())
                 .unwrap_or_else(|| repo.target_branch.clone()),
+            sparse_paths: repo.sparse_paths.clone(),
         })
         .collect();
 
@@ -591,6 +598,7 @@ mod tests {
             _workspace: &Workspace,
             _executor_profile_id: ExecutorProfileId,
             _prompt_override: Option<String>,
+            _reasoning_effort: Option<String>,
         ) -> Result<(), String> {
             Ok(())
         }
@@ -649,9 +657,12 @@ mod tests {
                 repos: vec![CreateWorkspaceRepo {
                     repo_id: repo.id,
                     target_branch: "main".to_string(),
+                    sparse_paths: Vec::new(),
                 }],
                 prompt_override: None,
+                reasoning_effort: None,
                 agent_working_dir: None,
+                labels: Vec::new(),
             },
         )
         .await
@@ -661,6 +672,77 @@ mod tests {
         assert_eq!(task.status, TaskStatus::InProgress);
     }
 
+    #[tokio::test]
+    async fn create_task_attempt_stores_labels_for_filtering() {
+        let db = setup_db().await;
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            &db,
+            &CreateProject {
+                name: "Test project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+
+        let repo = Repo::find_or_create(&db, Path::new("/tmp/vk-test-labels-repo"), "Repo")
+            .await
+            .unwrap();
+
+        let task_id = Uuid::new_v4();
+        Task::create(
+            &db,
+            &CreateTask::from_title_description(project_id, "Test task".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        let runtime = NoopRuntime;
+        let mut attempt_ids = Vec::new();
+        for labels in [
+            vec![
+                " experiment ".to_string(),
+                "nightly".to_string(),
+                "nightly".to_string(),
+            ],
+            vec!["production".to_string()],
+        ] {
+            let workspace = create_task_attempt(
+                &runtime,
+                &db,
+                &CreateTaskAttemptInput {
+                    task_id,
+                    executor_profile_id: ExecutorProfileId::new(BaseCodingAgent::FakeAgent),
+                    repos: vec![CreateWorkspaceRepo {
+                        repo_id: repo.id,
+                        target_branch: "main".to_string(),
+                        sparse_paths: Vec::new(),
+                    }],
+                    prompt_override: None,
+                    reasoning_effort: None,
+                    agent_working_dir: None,
+                    labels,
+                },
+            )
+            .await
+            .unwrap();
+            attempt_ids.push(workspace.id);
+        }
+
+        let workspaces = Workspace::fetch_all(&db, Some(task_id)).await.unwrap();
+        let experiments: Vec<&Workspace> = workspaces
+            .iter()
+            .filter(|workspace| workspace.has_label("experiment"))
+            .collect();
+        assert_eq!(experiments.len(), 1);
+        assert_eq!(experiments[0].id, attempt_ids[0]);
+        assert_eq!(experiments[0].labels, vec!["experiment", "nightly"]);
+    }
+
     #[tokio::test]
     async fn create_task_and_start_marks_todo_task_in_progress() {
         let db = setup_db().await;
@@ -691,6 +773,7 @@ mod tests {
                 repos: vec![CreateWorkspaceRepo {
                     repo_id: repo.id,
                     target_branch: "main".to_string(),
+                    sparse_paths: Vec::new(),
                 }],
                 agent_working_dir: None,
             },
@@ -12,6 +12,7 @@ pub trait TaskRuntime {
         workspace: &Workspace,
         executor_profile_id: ExecutorProfileId,
         prompt_override: Option<String>,
+        reasoning_effort: Option<String>,
     ) -> Result<(), String>;
 
     async fn delete_workspace_container(&self, workspace: &Workspace) -> Result<(), String>;
@@ -0,0 +1,496 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc};
+use db::models::{
+    task_schedule::{TaskSchedule, TaskScheduleRunOutcome},
+    workspace_repo::CreateWorkspaceRepo,
+};
+use thiserror::Error;
+
+use crate::{
+    orchestration::{CreateTaskAttemptInput, TasksError, create_task_attempt},
+    runtime::TaskRuntime,
+};
+
+/// How far ahead `next_after` searches before giving up on an expression that never matches
+/// (e.g. `0 0 31 2 *`).
+const MAX_LOOKAHEAD_YEARS: i32 = 5;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Invalid cron expression '{expr}': {reason}")]
+pub struct CronParseError {
+    pub expr: String,
+    pub reason: String,
+}
+
+/// A five-field cron expression (`minute hour day-of-month month day-of-week`) evaluated in UTC.
+///
+/// Supports `*`, lists (`1,15`), ranges (`1-5`), steps (`*/15`, `0-30/10`) and the
+/// `@hourly`, `@daily`/`@nightly`/`@midnight`, `@weekly`, `@monthly` and `@yearly` macros.
+/// Day-of-week accepts 0-7 where both 0 and 7 mean Sunday.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<Self, CronParseError> {
+        let err = |reason: String| CronParseError {
+            expr: expr.to_string(),
+            reason,
+        };
+
+        let trimmed = expr.trim();
+        let expanded = match trimmed.to_ascii_lowercase().as_str() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@nightly" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            _ if trimmed.starts_with('@') => return Err(err("unknown macro".to_string())),
+            _ => trimmed,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(err(format!("expected 5 fields, got {}", fields.len())));
+        }
+
+        let minutes = parse_field(fields[0], 0, 59).map_err(&err)?;
+        let hours = parse_field(fields[1], 0, 23).map_err(&err)?;
+        let days_of_month = parse_field(fields[2], 1, 31).map_err(&err)?;
+        let months = parse_field(fields[3], 1, 12).map_err(&err)?;
+        let mut days_of_week = parse_field(fields[4], 0, 7).map_err(&err)?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            day_of_month_restricted: fields[2] != "*",
+            day_of_week_restricted: fields[4] != "*",
+        })
+    }
+
+    /// The first matching minute strictly after `after`, or `None` when the expression does not
+    /// match within the lookahead window.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit_year = start.year() + MAX_LOOKAHEAD_YEARS;
+        let mut candidate = start;
+
+        while candidate.year() <= limit_year {
+            if !bit(self.months, candidate.month()) {
+                let (year, month) = if candidate.month() == 12 {
+                    (candidate.year() + 1, 1)
+                } else {
+                    (candidate.year(), candidate.month() + 1)
+                };
+                candidate = start_of_day(NaiveDate::from_ymd_opt(year, month, 1)?);
+                continue;
+            }
+            if !self.day_matches(candidate) {
+                candidate = start_of_day(candidate.date_naive().succ_opt()?);
+                continue;
+            }
+            if !bit(self.hours, candidate.hour()) {
+                candidate = candidate.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if !bit(self.minutes, candidate.minute()) {
+                candidate += Duration::minutes(1);
+                continue;
+            }
+            return Some(candidate);
+        }
+
+        None
+    }
+
+    fn day_matches(&self, at: DateTime<Utc>) -> bool {
+        let dom = bit(self.days_of_month, at.day());
+        let dow = bit(self.days_of_week, at.weekday().num_days_from_sunday());
+        // Classic cron: when both day fields are restricted, either one matching is enough.
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => dom || dow,
+            (true, false) => dom,
+            (false, true) => dow,
+            (false, false) => true,
+        }
+    }
+}
+
+fn bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+}
+
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, String> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("invalid step in '{part}'"))?;
+                if step == 0 {
+                    return Err(format!("step must be positive in '{part}'"));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start, min, max)?, parse_value(end, min, max)?)
+        } else {
+            let value = parse_value(range, min, max)?;
+            // `5/15` means "from 5 to the end of the range, every 15".
+            let end = if part.contains('/') { max } else { value };
+            (value, end)
+        };
+        if start > end {
+            return Err(format!("range start exceeds end in '{part}'"));
+        }
+
+        let mut value = start;
+        while value <= end {
+            mask |= 1 << value;
+            value += step;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(raw: &str, min: u32, max: u32) -> Result<u32, String> {
+    let value: u32 = raw.parse().map_err(|_| format!("invalid value '{raw}'"))?;
+    if value < min || value > max {
+        return Err(format!("value {value} out of range {min}-{max}"));
+    }
+    Ok(value)
+}
+
+/// Start an attempt for every schedule that is due at `now`.
+///
+/// A schedule whose task still has running processes is skipped for this slot; either way the
+/// schedule advances to its next occurrence so a missed slot is not replayed repeatedly.
+pub async fn run_due_schedules<R: TaskRuntime + Sync>(
+    runtime: &R,
+    db: &db::DbPool,
+    now: DateTime<Utc>,
+) -> Result<Vec<(TaskSchedule, TaskScheduleRunOutcome)>, TasksError> {
+    let due = TaskSchedule::find_due(db, now).await?;
+    let mut results = Vec::with_capacity(due.len());
+
+    for schedule in due {
+        let next_run_at = CronSchedule::parse(&schedule.cron)
+            .ok()
+            .and_then(|cron| cron.next_after(now));
+        let outcome = run_schedule(runtime, db, &schedule).await;
+
+        match &outcome {
+            TaskScheduleRunOutcome::Started { workspace_id } => tracing::info!(
+                task_id = %schedule.task_id,
+                workspace_id = %workspace_id,
+                "Started scheduled task attempt"
+            ),
+            TaskScheduleRunOutcome::Skipped { reason } => tracing::info!(
+                task_id = %schedule.task_id,
+                reason = %reason,
+                "Skipped scheduled task attempt"
+            ),
+            TaskScheduleRunOutcome::Failed { error } => tracing::warn!(
+                task_id = %schedule.task_id,
+                error = %error,
+                "Scheduled task attempt failed"
+            ),
+        }
+
+        TaskSchedule::record_run(db, schedule.task_id, now, &outcome, next_run_at).await?;
+        results.push((schedule, outcome));
+    }
+
+    Ok(results)
+}
+
+async fn run_schedule<R: TaskRuntime + Sync>(
+    runtime: &R,
+    db: &db::DbPool,
+    schedule: &TaskSchedule,
+) -> TaskScheduleRunOutcome {
+    match runtime.has_running_processes(schedule.task_id).await {
+        Ok(true) => {
+            return TaskScheduleRunOutcome::Skipped {
+                reason: "Previous attempt is still running".to_string(),
+            };
+        }
+        Ok(false) => {}
+        Err(err) => return TaskScheduleRunOutcome::Failed { error: err },
+    }
+
+    let input = CreateTaskAttemptInput {
+        task_id: schedule.task_id,
+        executor_profile_id: schedule.executor_profile_id.clone(),
+        repos: schedule
+            .repos
+            .iter()
+            .map(|repo| CreateWorkspaceRepo {
+                repo_id: repo.repo_id,
+                target_branch: repo.target_branch.clone(),
+                sparse_paths: Vec::new(),
+            })
+            .collect(),
+        prompt_override: None,
+        reasoning_effort: None,
+        agent_working_dir: None,
+        labels: Vec::new(),
+    };
+
+    match create_task_attempt(runtime, db, &input).await {
+        Ok(workspace) => TaskScheduleRunOutcome::Started {
+            workspace_id: workspace.id,
+        },
+        Err(err) => TaskScheduleRunOutcome::Failed {
+            error: err.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::Path,
+        sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    };
+
+    use async_trait::async_trait;
+    use db::models::{
+        project::{CreateProject, Project},
+        repo::Repo,
+        task::{CreateTask, Task},
+        task_schedule::{TaskScheduleRepo, UpsertTaskSchedule},
+        workspace::Workspace,
+    };
+    use executors_protocol::{BaseCodingAgent, ExecutorProfileId};
+    use sea_orm::Database;
+    use sea_orm_migration::MigratorTrait;
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CountingRuntime {
+        started: AtomicUsize,
+        busy: AtomicBool,
+    }
+
+    #[async_trait]
+    impl TaskRuntime for CountingRuntime {
+        async fn git_branch_from_workspace(&self, attempt_id: Uuid, _task_title: &str) -> String {
+            format!("attempt-{attempt_id}")
+        }
+
+        async fn start_workspace(
+            &self,
+            _workspace: &Workspace,
+            _executor_profile_id: ExecutorProfileId,
+            _prompt_override: Option<String>,
+            _reasoning_effort: Option<String>,
+        ) -> Result<(), String> {
+            self.started.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn delete_workspace_container(&self, _workspace: &Workspace) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn has_running_processes(&self, _task_id: Uuid) -> Result<bool, String> {
+            Ok(self.busy.load(Ordering::SeqCst))
+        }
+    }
+
+    fn at(raw: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(raw)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    async fn setup_scheduled_task(cron: &str, next_run_at: DateTime<Utc>) -> (db::DbPool, Uuid) {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db_migration::Migrator::up(&db, None).await.unwrap();
+
+        let project_id = Uuid::new_v4();
+        Project::create(
+            &db,
+            &CreateProject {
+                name: "Test project".to_string(),
+                repositories: Vec::new(),
+            },
+            project_id,
+        )
+        .await
+        .unwrap();
+        let repo = Repo::find_or_create(&db, Path::new("/tmp/vk-schedule-repo"), "Repo")
+            .await
+            .unwrap();
+        let task_id = Uuid::new_v4();
+        Task::create(
+            &db,
+            &CreateTask::from_title_description(project_id, "Nightly task".to_string(), None),
+            task_id,
+        )
+        .await
+        .unwrap();
+
+        TaskSchedule::upsert(
+            &db,
+            task_id,
+            &UpsertTaskSchedule {
+                cron: cron.to_string(),
+                executor_profile_id: ExecutorProfileId::new(BaseCodingAgent::FakeAgent),
+                repos: vec![TaskScheduleRepo {
+                    repo_id: repo.id,
+                    target_branch: "main".to_string(),
+                }],
+                enabled: true,
+                next_run_at: Some(next_run_at),
+            },
+        )
+        .await
+        .unwrap();
+
+        (db, task_id)
+    }
+
+    #[test]
+    fn parses_macros_lists_ranges_and_steps() {
+        assert_eq!(
+            CronSchedule::parse("@nightly").unwrap(),
+            CronSchedule::parse("0 0 * * *").unwrap()
+        );
+        assert!(CronSchedule::parse("*/15 9-17 * * 1-5").is_ok());
+        assert!(CronSchedule::parse("0 0 * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("@sometimes").is_err());
+    }
+
+    #[test]
+    fn next_after_finds_following_occurrence() {
+        let nightly = CronSchedule::parse("30 2 * * *").unwrap();
+        assert_eq!(
+            nightly.next_after(at("2026-04-02T01:00:00Z")),
+            Some(at("2026-04-02T02:30:00Z"))
+        );
+        assert_eq!(
+            nightly.next_after(at("2026-04-02T02:30:00Z")),
+            Some(at("2026-04-03T02:30:00Z"))
+        );
+
+        let weekdays = CronSchedule::parse("0 9 * * 1-5").unwrap();
+        // 2026-04-03 is a Friday; the next weekday is Monday the 6th.
+        assert_eq!(
+            weekdays.next_after(at("2026-04-03T10:00:00Z")),
+            Some(at("2026-04-06T09:00:00Z"))
+        );
+
+        let sunday = CronSchedule::parse("0 0 * * 7").unwrap();
+        assert_eq!(
+            sunday.next_after(at("2026-04-02T00:00:00Z")),
+            Some(at("2026-04-05T00:00:00Z"))
+        );
+
+        let never = CronSchedule::parse("0 0 31 2 *").unwrap();
+        assert_eq!(never.next_after(at("2026-04-02T00:00:00Z")), None);
+    }
+
+    #[tokio::test]
+    async fn due_schedule_triggers_one_attempt() {
+        let now = at("2026-04-02T00:00:30Z");
+        let (db, task_id) = setup_scheduled_task("@nightly", at("2026-04-02T00:00:00Z")).await;
+        let runtime = CountingRuntime::default();
+
+        let results = run_due_schedules(&runtime, &db, now).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0].1,
+            TaskScheduleRunOutcome::Started { .. }
+        ));
+        assert_eq!(runtime.started.load(Ordering::SeqCst), 1);
+
+        // The schedule advanced, so polling again in the same slot does nothing.
+        let results = run_due_schedules(&runtime, &db, now).await.unwrap();
+        assert!(results.is_empty());
+        assert_eq!(runtime.started.load(Ordering::SeqCst), 1);
+
+        let workspaces = Workspace::fetch_all(&db, Some(task_id)).await.unwrap();
+        assert_eq!(workspaces.len(), 1);
+
+        let schedule = TaskSchedule::find_by_task_id(&db, task_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(schedule.last_run_at, Some(now));
+        assert_eq!(schedule.last_workspace_id, Some(workspaces[0].id));
+        assert_eq!(schedule.next_run_at, Some(at("2026-04-03T00:00:00Z")));
+    }
+
+    #[tokio::test]
+    async fn due_schedule_is_skipped_while_previous_attempt_runs() {
+        let now = at("2026-04-02T00:00:30Z");
+        let (db, task_id) = setup_scheduled_task("@nightly", at("2026-04-02T00:00:00Z")).await;
+        let runtime = CountingRuntime::default();
+        runtime.busy.store(true, Ordering::SeqCst);
+
+        let results = run_due_schedules(&runtime, &db, now).await.unwrap();
+        assert!(matches!(
+            results[0].1,
+            TaskScheduleRunOutcome::Skipped { .. }
+        ));
+        assert_eq!(runtime.started.load(Ordering::SeqCst), 0);
+
+        let schedule = TaskSchedule::find_by_task_id(&db, task_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(schedule.last_run_at, None);
+        assert_eq!(schedule.next_run_at, Some(at("2026-04-03T00:00:00Z")));
+    }
+
+    #[tokio::test]
+    async fn soft_deleted_task_schedule_stops_firing() {
+        let now = at("2026-04-02T00:00:30Z");
+        let (db, task_id) = setup_scheduled_task("@nightly", at("2026-04-02T00:00:00Z")).await;
+        let runtime = CountingRuntime::default();
+
+        let task = Task::find_by_id(&db, task_id).await.unwrap().unwrap();
+        crate::task_deletion::soft_delete_task(&runtime, &db, task)
+            .await
+            .unwrap();
+
+        let results = run_due_schedules(&runtime, &db, now).await.unwrap();
+        assert!(results.is_empty());
+        assert_eq!(runtime.started.load(Ordering::SeqCst), 0);
+
+        let schedule = TaskSchedule::find_by_task_id(&db, task_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!schedule.enabled);
+        assert_eq!(schedule.next_run_at, None);
+    }
+}
@@ -6,6 +6,7 @@ use db::{
         milestone::{Milestone, MilestoneError, MilestoneGraph},
         repo::Repo,
         task::{Task, TaskKind},
+        task_schedule::TaskSchedule,
         workspace::Workspace,
     },
 };
@@ -53,6 +54,75 @@ pub async fn delete_task_with_cleanup<R: TaskRuntime + Sync>(
     delete_single_task_with_cleanup(runtime, db, task, allow_archived).await
 }
 
+/// Soft-deletes a task so it can be restored until the prune job removes it. Milestone tasks are
+/// tied into their milestone graph and keep the immediate cascading delete.
+pub async fn soft_delete_task<R: TaskRuntime + Sync>(
+    runtime: &R,
+    db: &db::DbPool,
+    task: Task,
+) -> Result<(), TasksError> {
+    if task.milestone_id.is_some() {
+        return delete_task_with_cleanup(
+            runtime,
+            db,
+            task,
+            DeleteTaskMode::CascadeMilestone,
+            false,
+        )
+        .await;
+    }
+
+    if runtime
+        .has_running_processes(task.id)
+        .await
+        .map_err(TasksError::Runtime)?
+    {
+        return Err(TasksError::Conflict(
+            "Task has running execution processes. Please wait for them to complete or stop them first.".to_string(),
+        ));
+    }
+
+    if soft_delete_task_record(db, task.id).await? == 0 {
+        return Err(TasksError::Database(DbErr::RecordNotFound(
+            "Task not found".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+/// Marks the task deleted and disables its schedule in one transaction, so a deleted task never
+/// fires again. Returns 0 when there is no live task with that id.
+pub async fn soft_delete_task_record(db: &db::DbPool, task_id: uuid::Uuid) -> Result<u64, DbErr> {
+    let tx = db.begin().await?;
+    let rows_affected = Task::soft_delete(&tx, task_id).await?;
+    if rows_affected > 0 {
+        TaskSchedule::disable_by_task_id(&tx, task_id).await?;
+    }
+    tx.commit().await?;
+    Ok(rows_affected)
+}
+
+/// Permanently removes soft-deleted tasks whose recovery window ended before `cutoff`. Returns
+/// the number of tasks removed; a task that fails to clean up is retried on the next pass.
+pub async fn prune_deleted_tasks<R: TaskRuntime + Sync>(
+    runtime: &R,
+    db: &db::DbPool,
+    cutoff: chrono::DateTime<chrono::Utc>,
+) -> Result<u64, TasksError> {
+    let mut removed = 0u64;
+    for task in Task::find_deleted_before(db, cutoff).await? {
+        let task_id = task.id;
+        match delete_single_task_with_cleanup(runtime, db, task, false).await {
+            Ok(()) => removed += 1,
+            Err(err) => {
+                tracing::warn!(task_id = %task_id, error = %err, "Failed to prune deleted task");
+            }
+        }
+    }
+    Ok(removed)
+}
+
 pub async fn delete_milestone_with_cleanup<R: TaskRuntime + Sync>(
     runtime: &R,
     db: &db::DbPool,
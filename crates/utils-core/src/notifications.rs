@@ -2,9 +2,21 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
+/// What a notification is about, so receivers can pick an event-specific sound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationEvent {
+    TaskCompleted,
+    TaskFailed,
+    ApprovalNeeded,
+}
+
 #[async_trait]
 pub trait Notifier: Send + Sync {
     async fn notify(&self, title: &str, message: &str);
+
+    async fn notify_event(&self, _event: NotificationEvent, title: &str, message: &str) {
+        self.notify(title, message).await;
+    }
 }
 
 #[derive(Debug, Default)]
@@ -436,7 +436,9 @@ pub async fn export_projects_yaml(pool: &db::DbPool) -> Result<String> {
                 "default_agent_working_dir",
                 project.id,
             ),
+            context_file: None,
             git_no_verify_override: project.git_no_verify_override,
+            default_executor_profile: None,
             scheduler_max_concurrent: project.scheduler_max_concurrent,
             scheduler_max_retries: project.scheduler_max_retries,
             default_continuation_turns: project.default_continuation_turns,
@@ -1,4 +1,9 @@
-import type { ApprovalResponse, ApprovalStatus } from 'shared/types';
+import type {
+  ApprovalResponse,
+  ApprovalStatus,
+  RespondAllApprovalsRequest,
+  RespondAllApprovalsResponse,
+} from 'shared/types';
 
 import { handleApiResponse, makeRequest } from './client';
 
@@ -17,4 +22,20 @@ export const approvalsApi = {
 
     return handleApiResponse<ApprovalStatus>(res);
   },
+
+  respondAll: async (
+    attemptId: string,
+    payload: RespondAllApprovalsRequest
+  ): Promise<RespondAllApprovalsResponse> => {
+    const res = await makeRequest(
+      `/api/task-attempts/${attemptId}/approvals/respond-all`,
+      {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify(payload),
+      }
+    );
+
+    return handleApiResponse<RespondAllApprovalsResponse>(res);
+  },
 };
@@ -3,9 +3,12 @@ import type {
   BaseCodingAgent,
   CheckEditorAvailabilityResponse,
   CliDependencyPreflightResponse,
+  ConfigExportResponse,
+  ConfigImportResponse,
   ConfigStatusResponse,
   CodexProtocolCompatibility,
   EditorType,
+  ReleaseNotesResponse,
   UserSystemInfo,
 } from 'shared/types';
 
@@ -31,6 +34,30 @@ export const configApi = {
     return handleApiResponse<ConfigStatusResponse>(response);
   },
 
+  exportConfig: async (
+    includeSecrets = false
+  ): Promise<ConfigExportResponse> => {
+    const response = await makeRequest(
+      `/api/config/export?include_secrets=${includeSecrets}`,
+      { cache: 'no-store' }
+    );
+    return handleApiResponse<ConfigExportResponse>(response);
+  },
+
+  importConfig: async (config: unknown): Promise<ConfigImportResponse> => {
+    const response = await makeRequest('/api/config/import', {
+      method: 'POST',
+      body: JSON.stringify(config),
+    });
+    return handleApiResponse<ConfigImportResponse>(response);
+  },
+
+  getReleaseNotes: async (since?: string): Promise<ReleaseNotesResponse> => {
+    const query = since ? `?since=${encodeURIComponent(since)}` : '';
+    const response = await makeRequest(`/api/release-notes${query}`);
+    return handleApiResponse<ReleaseNotesResponse>(response);
+  },
+
   checkEditorAvailability: async (
     editorType: EditorType
   ): Promise<CheckEditorAvailabilityResponse> => {
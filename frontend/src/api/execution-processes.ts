@@ -2,6 +2,8 @@ import type {
   ExecutionProcessPublic as ExecutionProcess,
   ExecutionProcessRepoState,
   LogHistoryPage,
+  NormalizedEntryType,
+  RenormalizeLogsResponse,
 } from 'shared/types';
 
 import { handleApiResponse, makeRequest } from './client';
@@ -30,13 +32,20 @@ export const executionProcessesApi = {
 
   getNormalizedLogsPage: async (
     processId: string,
-    params: { limit: number; cursor?: bigint | null }
+    params: {
+      limit: number;
+      cursor?: bigint | null;
+      types?: NormalizedEntryType['type'][];
+    }
   ): Promise<LogHistoryPage> => {
     const search = new URLSearchParams();
     search.set('limit', String(params.limit));
     if (params.cursor != null) {
       search.set('cursor', String(params.cursor));
     }
+    if (params.types?.length) {
+      search.set('types', params.types.join(','));
+    }
     const suffix = search.toString() ? `?${search.toString()}` : '';
     const response = await makeRequest(
       `/api/execution-processes/${processId}/normalized-logs/v2${suffix}`
@@ -44,6 +53,12 @@ export const executionProcessesApi = {
     return handleApiResponse<LogHistoryPage>(response);
   },
 
+  normalizedLogsExportUrl: (processId: string, format: 'md' | 'json') =>
+    `/api/execution-processes/${processId}/normalized-logs/export?format=${format}`,
+
+  rawLogsExportUrl: (processId: string) =>
+    `/api/execution-processes/${processId}/raw-logs/export`,
+
   getRepoStates: async (
     processId: string
   ): Promise<ExecutionProcessRepoState[]> => {
@@ -53,6 +68,18 @@ export const executionProcessesApi = {
     return handleApiResponse<ExecutionProcessRepoState[]>(response);
   },
 
+  renormalizeLogs: async (
+    processId: string
+  ): Promise<RenormalizeLogsResponse> => {
+    const response = await makeRequest(
+      `/api/execution-processes/${processId}/renormalize`,
+      {
+        method: 'POST',
+      }
+    );
+    return handleApiResponse<RenormalizeLogsResponse>(response);
+  },
+
   stopExecutionProcess: async (processId: string): Promise<void> => {
     const response = await makeRequest(
       `/api/execution-processes/${processId}/stop`,
@@ -13,6 +13,7 @@ export * from './config';
 export * from './tags';
 export * from './profiles';
 export * from './images';
+export * from './sounds';
 export * from './approvals';
 export * from './scratch';
 export * from './queue';
@@ -15,6 +15,14 @@ export const queueApi = {
   },
 
   cancel: async (sessionId: string): Promise<QueueStatus> => {
+    const response = await makeRequest(
+      `/api/sessions/${sessionId}/queue/cancel-next`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<QueueStatus>(response);
+  },
+
+  clear: async (sessionId: string): Promise<QueueStatus> => {
     const response = await makeRequest(`/api/sessions/${sessionId}/queue`, {
       method: 'DELETE',
     });
@@ -0,0 +1,29 @@
+import type { CustomSound } from 'shared/types';
+
+import { handleApiResponse, makeRequest } from './client';
+
+export const soundsApi = {
+  listCustom: async (): Promise<CustomSound[]> => {
+    const response = await makeRequest('/api/sounds/custom');
+    return handleApiResponse<CustomSound[]>(response);
+  },
+
+  uploadCustom: async (file: File): Promise<CustomSound> => {
+    const formData = new FormData();
+    formData.append('sound', file);
+
+    const response = await makeRequest('/api/sounds/custom', {
+      method: 'POST',
+      body: formData,
+    });
+    return handleApiResponse<CustomSound>(response);
+  },
+
+  deleteCustom: async (name: string): Promise<void> => {
+    const response = await makeRequest(
+      `/api/sounds/custom/${encodeURIComponent(name)}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<void>(response);
+  },
+};
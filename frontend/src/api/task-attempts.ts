@@ -1,23 +1,35 @@
 import type {
   AbortConflictsRequest,
+  AttemptGitStatusResponse,
+  ApplyTaskAttemptError,
+  ApplyTaskAttemptRequest,
+  ApplyTaskAttemptResponse,
   ChangeTargetBranchRequest,
   ChangeTargetBranchResponse,
+  CompareAttemptsResponse,
   CreateTaskAttemptBody,
+  CreateTaskAttemptError,
   ExecutionProcessPublic as ExecutionProcess,
   GitOperationError,
   TaskAttemptStatusResponse,
   MergeTaskAttemptRequest,
   PushError,
   PushTaskAttemptRequest,
+  RebaseOntoBaseError,
+  RebaseOntoBaseRequest,
+  RebaseOntoBaseResponse,
   RebaseTaskAttemptRequest,
   RenameBranchRequest,
   RenameBranchResponse,
   RepoBranchStatus,
   RepoWithTargetBranch,
+  RevertAttemptFileRequest,
+  RevertAttemptFileResponse,
   RunAgentSetupRequest,
   RunAgentSetupResponse,
   RunScriptError,
   TaskRelationships,
+  UpdateTaskAttemptLabelsRequest,
   Workspace,
 } from 'shared/types';
 import type { WorkspaceWithSession } from '@/types/attempt';
@@ -39,8 +51,13 @@ export const attemptsApi = {
     return handleApiResponse<TaskRelationships>(response);
   },
 
-  getAll: async (taskId: string): Promise<Workspace[]> => {
-    const response = await makeRequest(`/api/task-attempts?task_id=${taskId}`);
+  getAll: async (
+    taskId: string,
+    options?: { label?: string }
+  ): Promise<Workspace[]> => {
+    const params = new URLSearchParams({ task_id: taskId });
+    if (options?.label) params.set('label', options.label);
+    const response = await makeRequest(`/api/task-attempts?${params}`);
     return handleApiResponse<Workspace[]>(response);
   },
 
@@ -79,7 +96,7 @@ export const attemptsApi = {
       method: 'POST',
       body: JSON.stringify(data),
     });
-    return handleApiResponse<Workspace>(response);
+    return handleApiResponse<Workspace, CreateTaskAttemptError>(response);
   },
 
   stop: async (
@@ -127,6 +144,37 @@ export const attemptsApi = {
     return handleApiResponse<RepoBranchStatus[]>(response);
   },
 
+  getGitStatus: async (attemptId: string): Promise<AttemptGitStatusResponse> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/git-status`
+    );
+    return handleApiResponse<AttemptGitStatusResponse>(response);
+  },
+
+  compare: async (
+    attemptId: string,
+    otherAttemptId: string
+  ): Promise<CompareAttemptsResponse> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/compare?other_attempt_id=${otherAttemptId}`
+    );
+    return handleApiResponse<CompareAttemptsResponse>(response);
+  },
+
+  revertFile: async (
+    attemptId: string,
+    data: RevertAttemptFileRequest
+  ): Promise<RevertAttemptFileResponse> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/file/revert`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<RevertAttemptFileResponse>(response);
+  },
+
   getRepos: async (attemptId: string): Promise<RepoWithTargetBranch[]> => {
     const response = await makeRequest(`/api/task-attempts/${attemptId}/repos`);
     return handleApiResponse<RepoWithTargetBranch[]>(response);
@@ -146,6 +194,23 @@ export const attemptsApi = {
     return handleApiResponse<void>(response);
   },
 
+  apply: async (
+    attemptId: string,
+    data: ApplyTaskAttemptRequest
+  ): Promise<Result<ApplyTaskAttemptResponse, ApplyTaskAttemptError>> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/apply`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponseAsResult<
+      ApplyTaskAttemptResponse,
+      ApplyTaskAttemptError
+    >(response);
+  },
+
   push: async (
     attemptId: string,
     data: PushTaskAttemptRequest
@@ -185,6 +250,23 @@ export const attemptsApi = {
     return handleApiResponseAsResult<void, GitOperationError>(response);
   },
 
+  rebaseOntoBase: async (
+    attemptId: string,
+    data: RebaseOntoBaseRequest
+  ): Promise<Result<RebaseOntoBaseResponse, RebaseOntoBaseError>> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/rebase/onto-base`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponseAsResult<
+      RebaseOntoBaseResponse,
+      RebaseOntoBaseError
+    >(response);
+  },
+
   change_target_branch: async (
     attemptId: string,
     data: ChangeTargetBranchRequest
@@ -199,6 +281,21 @@ export const attemptsApi = {
     return handleApiResponse<ChangeTargetBranchResponse>(response);
   },
 
+  updateLabels: async (
+    attemptId: string,
+    labels: string[]
+  ): Promise<Workspace> => {
+    const payload: UpdateTaskAttemptLabelsRequest = { labels };
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/labels`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(payload),
+      }
+    );
+    return handleApiResponse<Workspace>(response);
+  },
+
   renameBranch: async (
     attemptId: string,
     newBranchName: string
@@ -3,8 +3,10 @@ import type {
   CreateTask,
   Task,
   TaskLineageSummary,
+  TaskSchedule,
   TaskWithAttemptStatus,
   UpdateTask,
+  UpsertTaskScheduleRequest,
 } from 'shared/types';
 
 import { handleApiResponse, makeRequest } from './client';
@@ -68,4 +70,27 @@ export const tasksApi = {
     });
     return handleApiResponse<void>(response);
   },
+
+  getSchedule: async (taskId: string): Promise<TaskSchedule | null> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/schedule`);
+    return handleApiResponse<TaskSchedule | null>(response);
+  },
+
+  upsertSchedule: async (
+    taskId: string,
+    data: UpsertTaskScheduleRequest
+  ): Promise<TaskSchedule> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/schedule`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<TaskSchedule>(response);
+  },
+
+  deleteSchedule: async (taskId: string): Promise<void> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/schedule`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
 };
@@ -10,6 +10,12 @@ type TranslationApiResult = {
   translated_text: string;
 };
 
+export type MissingTranslationsResult = {
+  base_lang: string;
+  lang: string;
+  missing: string[];
+};
+
 export const translationApi = {
   translate: async (
     text: string,
@@ -33,4 +39,11 @@ export const translationApi = {
 
     return translatedText;
   },
+
+  missingKeys: async (lang: string): Promise<MissingTranslationsResult> => {
+    const response = await makeRequest(
+      `/api/translation/missing?lang=${encodeURIComponent(lang)}`
+    );
+    return handleApiResponse<MissingTranslationsResult>(response);
+  },
 };
@@ -134,6 +134,8 @@ const getStatusIndicator = (entryType: NormalizedEntryType) => {
       } else {
         status_visualisation = 'error';
       }
+    } else if (entryType.action_type.result?.exit_status?.type === 'signal') {
+      status_visualisation = 'error';
     }
   }
 
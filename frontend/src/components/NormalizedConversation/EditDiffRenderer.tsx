@@ -17,6 +17,8 @@ type Props = {
   path: string;
   unifiedDiff: string;
   hasLineNumbers: boolean;
+  /** Highlight language from the backend; falls back to the path's extension. */
+  language?: string;
   expansionKey: string;
   defaultExpanded?: boolean;
   statusAppearance?: 'default' | 'denied' | 'timed_out';
@@ -65,6 +67,7 @@ function EditDiffRenderer({
   path,
   unifiedDiff,
   hasLineNumbers,
+  language,
   expansionKey,
   defaultExpanded = false,
   statusAppearance = 'default',
@@ -83,13 +86,13 @@ function EditDiffRenderer({
   const hideLineNumbersClass = hideLineNumbers ? ' edit-diff-hide-nums' : '';
 
   const diffData = useMemo(() => {
-    const lang = getHighLightLanguageFromPath(path) || 'plaintext';
+    const lang = language || getHighLightLanguageFromPath(path) || 'plaintext';
     return {
       hunks,
       oldFile: { fileName: path, fileLang: lang },
       newFile: { fileName: path, fileLang: lang },
     };
-  }, [hunks, path]);
+  }, [hunks, path, language]);
 
   const headerClass = cn(
     'flex items-center gap-1.5 text-secondary-foreground',
@@ -79,6 +79,7 @@ const FileChangeRenderer = ({
         path={path}
         unifiedDiff={change.unified_diff}
         hasLineNumbers={change.has_line_numbers}
+        language={change.language}
         expansionKey={expansionKey}
         defaultExpanded={defaultExpanded}
         statusAppearance={statusAppearance}
@@ -208,6 +208,7 @@ describe('VirtualizedList', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
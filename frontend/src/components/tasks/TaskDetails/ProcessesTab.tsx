@@ -14,6 +14,7 @@ import { useExecutionProcesses } from '@/hooks/execution-processes/useExecutionP
 import { useLogStream } from '@/hooks/execution-processes/useLogStream';
 import { ProcessLogsViewerContent } from './ProcessLogsViewer';
 import {
+  ExecutionProcessFailureKind,
   ExecutionProcessStatus,
   type ExecutionProcessPublic as ExecutionProcess,
 } from 'shared/types';
@@ -268,6 +269,18 @@ function ProcessesTab({ attemptId }: ProcessesTabProps) {
                           })}
                         </p>
                       )}
+                      {process.failure_kind ===
+                        ExecutionProcessFailureKind.timed_out && (
+                        <p className="text-xs text-destructive mt-1">
+                          {t('processes.timedOut')}
+                        </p>
+                      )}
+                      {process.failure_kind ===
+                        ExecutionProcessFailureKind.idle_timeout && (
+                        <p className="text-xs text-destructive mt-1">
+                          {t('processes.idleTimedOut')}
+                        </p>
+                      )}
                     </div>
                   </div>
                   <div className="mt-3 text-xs text-muted-foreground">
@@ -1,5 +1,6 @@
 import { Loader2, AlertCircle, Clock } from 'lucide-react';
 import { Alert, AlertDescription } from '@/components/ui/alert';
+import { Button } from '@/components/ui/button';
 import { useQuery } from '@tanstack/react-query';
 //
 import { useEffect, useMemo, useRef, useState, useCallback } from 'react';
@@ -262,9 +263,11 @@ export function TaskFollowUpSection({
   const {
     isQueued,
     queuedMessage,
+    queueDepth,
     isLoading: isQueueLoading,
     queueMessage,
     cancelQueue,
+    clearQueue,
     refresh: refreshQueueStatus,
   } = useQueueStatus(sessionId);
 
@@ -640,11 +643,24 @@ export function TaskFollowUpSection({
               <div className="flex items-center gap-2 text-sm text-muted-foreground bg-muted p-3 rounded-md border">
                 <Clock className="h-4 w-4 flex-shrink-0" />
                 <div className="font-medium">
-                  {t(
-                    'followUp.queuedMessage',
-                    'Message queued - will execute when current run finishes'
-                  )}
+                  {queueDepth > 1
+                    ? t('followUp.queuedMessages', { count: queueDepth })
+                    : t(
+                        'followUp.queuedMessage',
+                        'Message queued - will execute when current run finishes'
+                      )}
                 </div>
+                {queueDepth > 1 && (
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    className="ml-auto"
+                    disabled={isQueueLoading}
+                    onClick={() => void clearQueue()}
+                  >
+                    {t('followUp.clearQueue', 'Clear queue')}
+                  </Button>
+                )}
               </div>
             )}
 
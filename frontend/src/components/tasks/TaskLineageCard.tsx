@@ -23,6 +23,8 @@ function sourceLabel(kind: TaskWithAttemptStatus['created_by_kind']) {
       return 'MCP';
     case 'scheduler':
       return 'Scheduler';
+    case 'webhook':
+      return 'Webhook';
     case 'human_ui':
     default:
       return 'Human UI';
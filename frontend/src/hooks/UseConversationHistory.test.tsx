@@ -102,9 +102,11 @@ describe('useConversationHistory', () => {
       },
       status: ExecutionProcessStatus.completed,
       exit_code: null,
+      failure_kind: null,
       dropped: false,
       started_at: now,
       completed_at: now,
+      last_activity_at: null,
       created_at: now,
       updated_at: now,
     });
@@ -168,6 +170,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
@@ -222,9 +225,11 @@ describe('useConversationHistory', () => {
       },
       status: ExecutionProcessStatus.completed,
       exit_code: null,
+      failure_kind: null,
       dropped: false,
       started_at: now,
       completed_at: now,
+      last_activity_at: null,
       created_at: now,
       updated_at: now,
     };
@@ -276,6 +281,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
@@ -340,9 +346,11 @@ describe('useConversationHistory', () => {
       },
       status: ExecutionProcessStatus.completed,
       exit_code: null,
+      failure_kind: null,
       dropped: false,
       started_at: now,
       completed_at: now,
+      last_activity_at: null,
       created_at: now,
       updated_at: now,
     };
@@ -384,6 +392,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
@@ -427,9 +436,11 @@ describe('useConversationHistory', () => {
       },
       status: ExecutionProcessStatus.completed,
       exit_code: null,
+      failure_kind: null,
       dropped: false,
       started_at: createdAt,
       completed_at: createdAt,
+      last_activity_at: null,
       created_at: createdAt,
       updated_at: createdAt,
     });
@@ -480,6 +491,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
@@ -529,6 +541,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
@@ -581,6 +594,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
@@ -628,9 +642,11 @@ describe('useConversationHistory', () => {
       },
       status: ExecutionProcessStatus.completed,
       exit_code: null,
+      failure_kind: null,
       dropped: false,
       started_at: now,
       completed_at: now,
+      last_activity_at: null,
       created_at: now,
       updated_at: now,
     };
@@ -668,6 +684,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
@@ -731,9 +748,11 @@ describe('useConversationHistory', () => {
       },
       status: ExecutionProcessStatus.running,
       exit_code: null,
+      failure_kind: null,
       dropped: false,
       started_at: now,
       completed_at: null,
+      last_activity_at: null,
       created_at: now,
       updated_at: now,
     };
@@ -769,6 +788,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
@@ -820,9 +840,11 @@ describe('useConversationHistory', () => {
       },
       status: ExecutionProcessStatus.completed,
       exit_code: null,
+      failure_kind: null,
       dropped: false,
       started_at: createdAt,
       completed_at: createdAt,
+      last_activity_at: null,
       created_at: createdAt,
       updated_at: createdAt,
     });
@@ -882,6 +904,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
@@ -935,9 +958,11 @@ describe('useConversationHistory', () => {
       },
       status: ExecutionProcessStatus.completed,
       exit_code: null,
+      failure_kind: null,
       dropped: false,
       started_at: createdAt,
       completed_at: createdAt,
+      last_activity_at: null,
       created_at: createdAt,
       updated_at: createdAt,
     });
@@ -999,6 +1024,7 @@ describe('useConversationHistory', () => {
       container_ref: null,
       branch: 'main',
       agent_working_dir: null,
+      labels: [],
       setup_completed_at: null,
       latest_hook_run: null,
       after_prepare_hook_status: null,
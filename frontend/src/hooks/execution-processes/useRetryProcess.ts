@@ -59,6 +59,8 @@ export function useRetryProcess(
         retry_process_id: executionProcessId,
         force_when_dirty: modalResult.forceWhenDirty ?? false,
         perform_git_reset: modalResult.performGitReset ?? true,
+        model: null,
+        reasoning_effort: null,
       });
     },
     onSuccess: () => {
@@ -7,14 +7,18 @@ interface UseQueueStatusResult {
   queueStatus: QueueStatus;
   /** Whether a message is currently queued */
   isQueued: boolean;
-  /** The queued message if any */
+  /** The next queued message if any */
   queuedMessage: QueuedMessage | null;
+  /** Number of queued messages (dispatched in order) */
+  queueDepth: number;
   /** Whether an operation is in progress */
   isLoading: boolean;
   /** Queue a new message */
   queueMessage: (message: string, variant: string | null) => Promise<void>;
-  /** Cancel the queued message */
+  /** Cancel the next queued message */
   cancelQueue: () => Promise<void>;
+  /** Clear every queued message */
+  clearQueue: () => Promise<void>;
   /** Refresh the queue status from the server */
   refresh: () => Promise<void>;
 }
@@ -60,6 +64,17 @@ export function useQueueStatus(sessionId?: string): UseQueueStatusResult {
     }
   }, [sessionId]);
 
+  const clearQueue = useCallback(async () => {
+    if (!sessionId) return;
+    setIsLoading(true);
+    try {
+      const status = await queueApi.clear(sessionId);
+      setQueueStatus(status);
+    } finally {
+      setIsLoading(false);
+    }
+  }, [sessionId]);
+
   // Fetch initial status when sessionId changes
   useEffect(() => {
     if (sessionId) {
@@ -70,17 +85,21 @@ export function useQueueStatus(sessionId?: string): UseQueueStatusResult {
   }, [sessionId, refresh]);
 
   const isQueued = queueStatus.status === 'queued';
-  const queuedMessage = isQueued
-    ? (queueStatus as Extract<QueueStatus, { status: 'queued' }>).message
+  const queued = isQueued
+    ? (queueStatus as Extract<QueueStatus, { status: 'queued' }>)
     : null;
+  const queuedMessage = queued?.message ?? null;
+  const queueDepth = queued?.depth ?? 0;
 
   return {
     queueStatus,
     isQueued,
     queuedMessage,
+    queueDepth,
     isLoading,
     queueMessage,
     cancelQueue,
+    clearQueue,
     refresh,
   };
 }
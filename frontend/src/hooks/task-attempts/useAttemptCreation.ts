@@ -9,6 +9,7 @@ import { useOptimisticTasksStore } from '@/stores/useOptimisticTasksStore';
 import { taskKeys } from '@/hooks/tasks/useTask';
 import type {
   ExecutorProfileId,
+  ReasoningEffort,
   TaskAttemptPromptPreset,
   TaskWithAttemptStatus,
   WorkspaceRepoInput,
@@ -19,6 +20,8 @@ type CreateAttemptArgs = {
   profile: ExecutorProfileId;
   repos: WorkspaceRepoInput[];
   promptPreset?: TaskAttemptPromptPreset | null;
+  labels?: string[];
+  reasoningEffort?: ReasoningEffort | null;
 };
 
 type UseAttemptCreationArgs = {
@@ -33,12 +36,20 @@ export function useAttemptCreation({
   const queryClient = useQueryClient();
 
   const mutation = useMutation({
-    mutationFn: ({ profile, repos, promptPreset }: CreateAttemptArgs) =>
+    mutationFn: ({
+      profile,
+      repos,
+      promptPreset,
+      labels,
+      reasoningEffort,
+    }: CreateAttemptArgs) =>
       attemptsApi.create({
         task_id: taskId,
         executor_profile_id: profile,
         repos,
         prompt_preset: promptPreset ?? null,
+        labels: labels ?? [],
+        reasoning_effort: reasoningEffort ?? null,
       }),
     onMutate: async () => {
       const store = useOptimisticTasksStore.getState();
@@ -60,6 +60,8 @@ export function useFollowUpSend({
           retry_process_id: null,
           force_when_dirty: null,
           perform_git_reset: null,
+          model: null,
+          reasoning_effort: null,
         };
         const process: ExecutionProcess = await sessionsApi.followUp(
           sessionId,
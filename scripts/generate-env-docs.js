@@ -51,6 +51,24 @@ const ENV_VARS = [
     description:
       'When true, attempts to open the local server URL in the default browser on startup.',
   },
+  {
+    category: 'Server',
+    name: 'VK_SHUTDOWN_TIMEOUT_SECS',
+    type: 'duration-secs',
+    defaultValue: '10',
+    appliesTo: ['server'],
+    description:
+      'How long to wait for in-flight requests after a shutdown signal before forcing exit. Must be > 0.',
+  },
+  {
+    category: 'Server',
+    name: 'VK_CLEANUP_TIMEOUT_SECS',
+    type: 'duration-secs',
+    defaultValue: '15',
+    appliesTo: ['server'],
+    description:
+      'How long shutdown cleanup (stopping running execution processes) may take before forcing exit. Must be > 0.',
+  },
   {
     category: 'Storage',
     name: 'VIBE_ASSET_DIR',
@@ -120,7 +138,8 @@ const ENV_VARS = [
     type: 'bool (presence)',
     defaultValue: 'unset',
     appliesTo: ['local-deployment'],
-    description: 'When set, disables orphan workspace cleanup on disk.',
+    description:
+      'When set, disables orphan workspace cleanup on disk (the startup pass and the periodic reclaim of directories with no workspace row).',
   },
 
   {
@@ -142,6 +161,73 @@ const ENV_VARS = [
       'TTL for completed idempotency keys. Set to 0 to disable pruning.',
   },
 
+  {
+    category: 'Scratch',
+    name: 'VK_SCRATCH_MAX_BYTES',
+    type: 'int',
+    defaultValue: '262144 (256 KiB)',
+    appliesTo: ['server'],
+    description:
+      'Maximum serialized size of a single scratch payload. Larger writes are rejected with 413. Set to 0 to disable the limit.',
+  },
+  {
+    category: 'Scratch',
+    name: 'VK_SCRATCH_TTL_SECS',
+    type: 'duration-secs',
+    defaultValue: '2592000 (30d)',
+    appliesTo: ['server'],
+    description:
+      'TTL for scratches that have not been updated. Set to 0 to disable pruning.',
+  },
+
+  {
+    category: 'Events',
+    name: 'VK_EVENT_OUTBOX_TTL_SECS',
+    type: 'duration-secs',
+    defaultValue: '604800 (7d)',
+    appliesTo: ['server'],
+    description:
+      'TTL for event outbox rows after they have been published to subscribers. Unpublished rows are never pruned. Set to 0 to disable pruning.',
+  },
+
+  {
+    category: 'Tasks',
+    name: 'VK_TASK_RECOVERY_WINDOW_SECS',
+    type: 'duration-secs',
+    defaultValue: '604800 (7d)',
+    appliesTo: ['server', 'mcp_task_server'],
+    description:
+      'How long a deleted task can be restored before it is permanently removed. Set to 0 to delete tasks immediately.',
+  },
+
+  {
+    category: 'Images',
+    name: 'VK_IMAGE_MAX_BYTES',
+    type: 'int',
+    defaultValue: '20971520 (20 MiB)',
+    appliesTo: ['server'],
+    description:
+      'Maximum size of an uploaded image. Larger uploads are rejected with 413. Set to 0 to disable the limit.',
+  },
+  {
+    category: 'Images',
+    name: 'VK_IMAGE_MAX_DIMENSION',
+    type: 'int',
+    defaultValue: '16384',
+    appliesTo: ['server'],
+    description:
+      'Maximum width or height, in pixels, of an uploaded image. Larger images are rejected with 413. Set to 0 to disable the limit.',
+  },
+  {
+    category: 'Images',
+    name: 'VK_IMAGE_STRIP_EXIF',
+    type: 'bool',
+    defaultValue: 'true',
+    appliesTo: ['server'],
+    description:
+      'Remove EXIF metadata (camera details, GPS location) from uploaded JPEG, PNG, and WebP images before storing them. Set to 0/false to keep uploads byte-for-byte.',
+  },
+
   {
     category: 'Logs',
     name: 'VK_LOG_HISTORY_MAX_BYTES',
@@ -228,6 +314,68 @@ const ENV_VARS = [
     description:
       'Retention window for legacy JSONL logs. Values <= 0 disable cleanup.',
   },
+  {
+    category: 'Logs',
+    name: 'VK_RAW_LOG_MAX_BYTES_PER_PROCESS',
+    type: 'int',
+    defaultValue: '67108864 (64 MiB)',
+    appliesTo: ['local-deployment'],
+    description:
+      'Per execution process budget for stored raw log rows; the oldest rows beyond it are trimmed periodically (normalized entries are kept). 0 disables trimming.',
+  },
+  {
+    category: 'Logs',
+    name: 'VK_RAW_LOG_RETENTION_INTERVAL_SECS',
+    type: 'duration-secs',
+    defaultValue: '3600 (1h)',
+    appliesTo: ['local-deployment'],
+    description: 'Tick interval for the periodic raw log retention loop (min 10s).',
+  },
+  {
+    category: 'Logs',
+    name: 'VK_CODEX_NORMALIZE_DEBUG',
+    type: 'bool',
+    defaultValue: 'false',
+    appliesTo: ['server'],
+    description:
+      'When true, codex log normalization emits a system message (with a truncated snippet) for codex events it fails to parse instead of skipping them silently.',
+  },
+  {
+    category: 'Logs',
+    name: 'VK_CODEX_STDERR_TIME_GAP_MS',
+    type: 'int',
+    defaultValue: '2000',
+    appliesTo: ['server'],
+    description:
+      'Idle gap (milliseconds) after which codex stderr output starts a new log entry.',
+  },
+  {
+    category: 'Logs',
+    name: 'VK_CODEX_COMMAND_OUTPUT_MAX_BYTES',
+    type: 'int',
+    defaultValue: '262144',
+    appliesTo: ['server'],
+    description:
+      'Per-stream byte cap for codex command output kept in the transcript; larger output keeps only its head and tail. Set to 0 to disable.',
+  },
+  {
+    category: 'Logs',
+    name: 'VK_CODEX_COMMAND_OUTPUT_ANSI',
+    type: 'string',
+    defaultValue: 'plain',
+    appliesTo: ['server'],
+    description:
+      'How ANSI colors in codex command output are kept: `plain` strips them, `html` converts them to HTML spans with `ansi-*` classes (text is HTML-escaped), `raw` leaves the escape codes for the UI to render.',
+  },
+  {
+    category: 'Logs',
+    name: 'VK_CODEX_COMMAND_OUTPUT_FORMAT',
+    type: 'string',
+    defaultValue: 'formatted',
+    appliesTo: ['server'],
+    description:
+      'Output shown for finished codex commands: `formatted` uses codex\'s formatted output, `raw` keeps the captured stdout/stderr.',
+  },
 
   {
     category: 'Cache budgets',
@@ -407,6 +555,47 @@ const ENV_VARS = [
     description:
       'Backend base URL for the MCP task server (overrides HOST/BACKEND_PORT/port file discovery).',
   },
+  {
+    category: 'MCP',
+    name: 'VK_MCP_TAG_EXPANSION_MAX_DEPTH',
+    type: 'int',
+    defaultValue: '5',
+    appliesTo: ['server', 'mcp_task_server'],
+    description:
+      'Maximum nesting depth when expanding @tag references whose content references other tags. 0 is normalized to 1 (single pass).',
+  },
+  {
+    category: 'MCP',
+    name: 'VK_MCP_SERVER_NAME',
+    type: 'string',
+    defaultValue: 'vibe-kanban',
+    appliesTo: ['mcp_task_server'],
+    description: 'MCP serverInfo.name override for white-labeled deployments.',
+  },
+  {
+    category: 'MCP',
+    name: 'VK_MCP_SERVER_TITLE',
+    type: 'string',
+    defaultValue: 'Vibe Kanban MCP Server',
+    appliesTo: ['mcp_task_server'],
+    description: 'MCP serverInfo.title override for white-labeled deployments.',
+  },
+  {
+    category: 'MCP',
+    name: 'VK_MCP_SERVER_ICON_URL',
+    type: 'string',
+    defaultValue: 'unset',
+    appliesTo: ['mcp_task_server'],
+    description: 'Icon URL advertised in MCP serverInfo.icons (no icon when unset).',
+  },
+  {
+    category: 'MCP',
+    name: 'VK_MCP_SERVER_WEBSITE_URL',
+    type: 'string',
+    defaultValue: 'https://github.com/BloopAI/vibe-kanban',
+    appliesTo: ['mcp_task_server'],
+    description: 'MCP serverInfo.websiteUrl override for white-labeled deployments.',
+  },
 
   {
     category: 'Dev frontend',
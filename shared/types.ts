@@ -6,7 +6,7 @@
 
 export type Project = { id: string, name: string, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, git_no_verify_override: boolean | null, scheduler_max_concurrent: number, scheduler_max_retries: number, default_continuation_turns: number, mcp_auto_executor_policy_mode: ProjectMcpExecutorPolicyMode, mcp_auto_executor_policy_allow_list: Array<ProjectExecutorProfileAllowListEntry>, after_prepare_hook: WorkspaceLifecycleHookConfig | null, before_cleanup_hook: WorkspaceLifecycleHookConfig | null, remote_project_id: string | null, created_at: Date, updated_at: Date, };
 
-export type ProjectPublic = { id: string, name: string, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, git_no_verify_override: boolean | null, scheduler_max_concurrent: number, scheduler_max_retries: number, default_continuation_turns: number, mcp_auto_executor_policy_mode: ProjectMcpExecutorPolicyMode, mcp_auto_executor_policy_allow_list: Array<ProjectExecutorProfileAllowListEntry>, after_prepare_hook: WorkspaceLifecycleHookConfig | null, before_cleanup_hook: WorkspaceLifecycleHookConfig | null, remote_project_id: string | null, };
+export type ProjectPublic = { id: string, name: string, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, git_no_verify_override: boolean | null, default_executor_profile: ExecutorProfileId | null, scheduler_max_concurrent: number, scheduler_max_retries: number, default_continuation_turns: number, mcp_auto_executor_policy_mode: ProjectMcpExecutorPolicyMode, mcp_auto_executor_policy_allow_list: Array<ProjectExecutorProfileAllowListEntry>, after_prepare_hook: WorkspaceLifecycleHookConfig | null, before_cleanup_hook: WorkspaceLifecycleHookConfig | null, remote_project_id: string | null, };
 
 export type WorkspaceLifecycleHookConfig = { command: string, working_dir: string | null, failure_policy: WorkspaceLifecycleHookFailurePolicy, run_mode: WorkspaceLifecycleHookRunMode | null, };
 
@@ -42,11 +42,15 @@ export type CreateProjectRepo = { display_name: string, git_repo_path: string, }
 
 export type UpdateProjectRepo = { setup_script: string | null, cleanup_script: string | null, copy_files: string | null, parallel_setup_script: boolean | null, };
 
-export type WorkspaceRepo = { id: string, workspace_id: string, repo_id: string, target_branch: string, created_at: Date, updated_at: Date, };
+export type WorkspaceRepo = { id: string, workspace_id: string, repo_id: string, target_branch: string, 
+/**
+ * Sparse-checkout patterns for this repo's worktree; empty = full checkout.
+ */
+sparse_paths: Array<string>, created_at: Date, updated_at: Date, };
 
-export type CreateWorkspaceRepo = { repo_id: string, target_branch: string, };
+export type CreateWorkspaceRepo = { repo_id: string, target_branch: string, sparse_paths: Array<string>, };
 
-export type RepoWithTargetBranch = { target_branch: string, id: string, path: string, name: string, display_name: string, created_at: Date, updated_at: Date, };
+export type RepoWithTargetBranch = { target_branch: string, sparse_paths: Array<string>, id: string, path: string, name: string, display_name: string, created_at: Date, updated_at: Date, };
 
 export type Tag = { id: string, tag_name: string, content: string, created_at: string, updated_at: string, };
 
@@ -64,7 +68,7 @@ export type Task = { id: string, project_id: string, title: string, description:
  */
 continuation_turns_override: number | null, shared_task_id: string | null, archived_kanban_id: string | null, created_at: string, updated_at: string, };
 
-export type TaskCreatedByKind = "human_ui" | "mcp" | "scheduler" | "agent_followup" | "milestone_planner";
+export type TaskCreatedByKind = "human_ui" | "mcp" | "scheduler" | "agent_followup" | "milestone_planner" | "webhook";
 
 export type VkNextAction = "continue" | "review";
 
@@ -104,7 +108,15 @@ export type TaskLineageSummary = { origin_task: Task | null, follow_up_tasks: Ar
 
 export type CreateTask = { project_id: string, title: string, description: string | null, status: TaskStatus | null, task_kind: TaskKind | null, milestone_id: string | null, milestone_node_id: string | null, parent_workspace_id: string | null, origin_task_id: string | null, created_by_kind: TaskCreatedByKind | null, image_ids: Array<string> | null, shared_task_id: string | null, };
 
-export type UpdateTask = { title: string | null, description: string | null, status: TaskStatus | null, parent_workspace_id: string | null, image_ids: Array<string> | null, continuation_turns_override: number | null | null, };
+export type UpdateTask = { title: string | null, description: string | null, status: TaskStatus | null, parent_workspace_id: string | null, image_ids: Array<string> | null, continuation_turns_override: number | null | null, 
+/**
+ * The `updated_at` the client last observed; the update is rejected if the row changed since.
+ */
+expected_updated_at?: string, };
+
+export type TaskSchedule = { task_id: string, cron: string, executor_profile_id: ExecutorProfileId, repos: Array<TaskScheduleRepo>, enabled: boolean, next_run_at: string | null, last_run_at: string | null, last_workspace_id: string | null, last_error: string | null, created_at: string, updated_at: string, };
+
+export type TaskScheduleRepo = { repo_id: string, target_branch: string, };
 
 export type Milestone = { id: string, project_id: string, title: string, description: string | null, objective: string | null, definition_of_done: string | null, default_executor_profile_id: ExecutorProfileId | null, automation_mode: MilestoneAutomationMode, run_next_step_requested_at: string | null, status: TaskStatus, baseline_ref: string, schema_version: number, graph: MilestoneGraph, suggested_status: TaskStatus, last_plan_application: MilestonePlanApplicationSummary | null, created_at: string, updated_at: string, };
 
@@ -197,16 +209,21 @@ export type Image = { id: string, file_path: string, original_name: string, mime
 
 export type CreateImage = { file_path: string, original_name: string, mime_type: string | null, size_bytes: bigint, hash: string, };
 
-export type Workspace = { id: string, task_id: string, container_ref: string | null, branch: string, agent_working_dir: string | null, setup_completed_at: string | null, latest_hook_run: WorkspaceLifecycleHookRunSummary | null, after_prepare_hook_status: WorkspaceLifecycleHookStatus | null, after_prepare_hook_ran_at: Date | null, after_prepare_hook_error_summary: string | null, before_cleanup_hook_status: WorkspaceLifecycleHookStatus | null, before_cleanup_hook_ran_at: Date | null, before_cleanup_hook_error_summary: string | null, created_at: string, updated_at: string, };
+export type Workspace = { id: string, task_id: string, container_ref: string | null, branch: string, agent_working_dir: string | null, labels: Array<string>, setup_completed_at: string | null, latest_hook_run: WorkspaceLifecycleHookRunSummary | null, after_prepare_hook_status: WorkspaceLifecycleHookStatus | null, after_prepare_hook_ran_at: Date | null, after_prepare_hook_error_summary: string | null, before_cleanup_hook_status: WorkspaceLifecycleHookStatus | null, before_cleanup_hook_ran_at: Date | null, before_cleanup_hook_error_summary: string | null, created_at: string, updated_at: string, };
 
 export type WorkspaceLifecycleHookRunSummary = { phase: WorkspaceLifecycleHookPhase, status: WorkspaceLifecycleHookStatus, ran_at: Date, error_summary: string | null, };
 
 export type Session = { id: string, workspace_id: string, executor: string | null, created_at: string, updated_at: string, };
 
-export type ExecutionProcessPublic = { id: string, session_id: string, run_reason: ExecutionProcessRunReason, executor_action: ExecutorAction, status: ExecutionProcessStatus, exit_code: bigint | null, dropped: boolean, started_at: string, completed_at: string | null, created_at: string, updated_at: string, };
+export type ExecutionProcessPublic = { id: string, session_id: string, run_reason: ExecutionProcessRunReason, executor_action: ExecutorAction, status: ExecutionProcessStatus, exit_code: bigint | null, failure_kind: ExecutionProcessFailureKind | null, dropped: boolean, started_at: string, completed_at: string | null, last_activity_at: string | null, created_at: string, updated_at: string, };
 
 export enum ExecutionProcessStatus { running = "running", completed = "completed", failed = "failed", killed = "killed" }
 
+/**
+ * Why an execution process failed, when the status alone does not say.
+ */
+export enum ExecutionProcessFailureKind { timed_out = "timed_out", idle_timeout = "idle_timeout" }
+
 export type ExecutionProcessRunReason = "setupscript" | "cleanupscript" | "codingagent" | "devserver";
 
 export type ExecutionProcessRepoState = { id: string, execution_process_id: string, repo_id: string, before_head_commit: string | null, after_head_commit: string | null, merge_commit: string | null, created_at: Date, updated_at: Date, };
@@ -257,12 +274,74 @@ capabilities: { [key in string]?: Array<BaseAgentCapability> },
 /**
  * Resolved command source/version per executor
  */
-agent_command_resolutions: { [key in string]?: AgentCommandResolution }, executors: { [key in BaseCodingAgent]?: ExecutorConfig }, };
+agent_command_resolutions: { [key in string]?: AgentCommandResolution }, 
+/**
+ * Startup executor self-check results (empty until the check has completed)
+ */
+executor_availability: Array<ExecutorAvailability>, 
+/**
+ * Running/queued coding agent executions against `max_concurrent_executions`
+ */
+execution_capacity: ExecutionCapacity, 
+/**
+ * Effective UI language: `config.language`, or negotiated from `Accept-Language` when that
+ * is `BROWSER` (stays `BROWSER` if no supported language is accepted)
+ */
+ui_language: UiLanguage, executors: { [key in BaseCodingAgent]?: ExecutorConfig }, };
+
+/**
+ * Snapshot of the coding agent concurrency gate (`max_concurrent_executions`).
+ */
+export type ExecutionCapacity = { running: number, queued: number, limit: number | null, policy: ExecutionCapacityPolicy, };
+
+export type RepoStartupStatus = "pending" | "cloning" | "ready" | "failed";
+
+export type RepoStartupProgress = { repo_id: string, repo_name: string, status: RepoStartupStatus, };
+
+/**
+ * Snapshot pushed to the global event stream at `/workspace_startup/{workspace_id}` whenever a
+ * repo's worktree changes stage while an attempt's workspace is being created.
+ */
+export type WorkspaceStartupProgress = { workspace_id: string, repos: Array<RepoStartupProgress>, 
+/**
+ * Repos whose worktree is ready.
+ */
+completed: number, total: number, };
 
 export type Environment = { os_type: string, os_version: string, os_architecture: string, bitness: string, };
 
+export type ExecutorAvailability = { executor: BaseCodingAgent, available: boolean, availability: AvailabilityInfo, command_source: AgentCommandSource, version?: string | null, };
+
 export type ConfigStatusResponse = { config_dir: string, config_path: string, projects_path: string, projects_dir: string, secret_env_path: string, schema_path: string, projects_schema_path: string, loaded_at_unix_ms: number, last_error: string | null, dirty: boolean, };
 
+export type ConfigExportQuery = { include_secrets: boolean, };
+
+export type ConfigExportResponse = { config: Config, secrets_included: boolean, };
+
+export type ConfigImportResponse = { 
+/**
+ * Where the merged config was written; it does not replace `config.yaml`.
+ */
+path: string, 
+/**
+ * JSON pointers of redacted values that kept the current secret.
+ */
+preserved_secrets: Array<string>, };
+
+export type ReleaseNotesEntry = { version: string, highlights: Array<string>, fixes: Array<string>, };
+
+export type ReleaseNotesResponse = { current_version: string, since: string | null, 
+/**
+ * Newest first.
+ */
+releases: Array<ReleaseNotesEntry>, };
+
+export type CustomSound = { 
+/**
+ * File name to reference as `{ custom: <name> }` in `notifications.event_sounds`.
+ */
+name: string, size_bytes: bigint, url: string, };
+
 export type CheckEditorAvailabilityQuery = { editor_type: EditorType, };
 
 export type CheckEditorAvailabilityResponse = { available: boolean, };
@@ -279,7 +358,15 @@ export type ImportLlmanProfilesResponse = { path: string, imported: number, upda
 
 export type ResolveLlmanPathResponse = { path: string | null, };
 
-export type CreateFollowUpAttempt = { prompt: string, variant: string | null, retry_process_id: string | null, force_when_dirty: boolean | null, perform_git_reset: boolean | null, };
+export type CreateFollowUpAttempt = { prompt: string, variant: string | null, retry_process_id: string | null, force_when_dirty: boolean | null, perform_git_reset: boolean | null, 
+/**
+ * Optional model override for this run only; the session's executor profile is unchanged.
+ */
+model: string | null, 
+/**
+ * Optional reasoning effort override, applied by executors that support it (Codex).
+ */
+reasoning_effort: ReasoningEffort | null, };
 
 export type ChangeTargetBranchRequest = { repo_id: string, new_target_branch: string, };
 
@@ -287,6 +374,20 @@ export type ChangeTargetBranchResponse = { repo_id: string, new_target_branch: s
 
 export type MergeTaskAttemptRequest = { repo_id: string, };
 
+export type ApplyTaskAttemptRequest = { repo_id: string, 
+/**
+ * Local branch that receives the attempt's changes as a single commit.
+ */
+target_branch: string, };
+
+export type ApplyTaskAttemptResponse = { target_branch: string, 
+/**
+ * `None` when the target branch already contained every change.
+ */
+commit_sha: string | null, };
+
+export type ApplyTaskAttemptError = { "type": "merge_conflicts", message: string, target_branch: string, conflicted_files: Array<string>, };
+
 export type PushTaskAttemptRequest = { repo_id: string, };
 
 export type RenameBranchRequest = { new_branch_name: string, };
@@ -295,10 +396,143 @@ export type RenameBranchResponse = { branch: string, };
 
 export type AttemptState = "idle" | "running" | "completed" | "failed";
 
-export type TaskAttemptStatusResponse = { attempt_id: string, task_id: string, workspace_branch: string, created_at: string, updated_at: string, latest_session_id: string | null, latest_execution_process_id: string | null, state: AttemptState, last_activity_at: string | null, failure_summary: string | null, };
+export type TaskAttemptStatusResponse = { attempt_id: string, task_id: string, workspace_branch: string, labels: Array<string>, created_at: string, updated_at: string, latest_session_id: string | null, latest_execution_process_id: string | null, state: AttemptState, 
+/**
+ * Latest of the process's last normalized output and its last state change.
+ */
+last_activity_at: string | null, failure_summary: string | null, 
+/**
+ * The running process is blocked on an approval request nobody has answered yet.
+ */
+waiting_on_approval: boolean, 
+/**
+ * Tool awaiting approval when `waiting_on_approval` is set (oldest request first).
+ */
+pending_approval_tool: string | null, 
+/**
+ * A target branch has commits the attempt branch does not contain.
+ */
+base_diverged: boolean, 
+/**
+ * Merging the attempt branch into a target branch would conflict.
+ */
+has_conflicts: boolean, };
+
+export type AttemptRepoGitStatus = { repo_id: string, repo_name: string, staged: Array<WorktreeStatusEntry>, unstaged: Array<WorktreeStatusEntry>, 
+/**
+ * Untracked paths; untracked directories are collapsed to `dir/`.
+ */
+untracked: Array<string>, };
+
+export type AttemptGitStatusResponse = { repos: Array<AttemptRepoGitStatus>, };
+
+/**
+ * A file that differs between two attempts, described as a change from this attempt's branch
+ * to the other attempt's branch.
+ */
+export type AttemptComparisonFile = { repo_name: string, path: string, 
+/**
+ * Previous path for renames and copies.
+ */
+old_path: string | null, change: DiffChangeKind, additions: number | null, deletions: number | null, };
+
+export type CompareAttemptsResponse = { attempt_id: string, other_attempt_id: string, files: Array<AttemptComparisonFile>, 
+/**
+ * Repos attached to only one of the two attempts; they are not compared.
+ */
+skipped_repos: Array<string>, };
+
+export type AttemptContentMatch = { 
+/**
+ * Repo-prefixed path (`<repo>/<path in repo>`).
+ */
+path: string, 
+/**
+ * 1-based line number.
+ */
+line_number: number, line: string, };
+
+export type AttemptContentSearchResponse = { matches: Array<AttemptContentMatch>, files_scanned: number, bytes_scanned: number, 
+/**
+ * Set when the match, byte or time budget stopped the scan early, or a file was skipped for
+ * being too large to scan.
+ */
+truncated: boolean, };
+
+export type RevertAttemptFileRequest = { 
+/**
+ * Repo-prefixed path (`<repo>/<path in repo>`).
+ */
+path: string, };
+
+export type RevertAttemptFileResponse = { path: string, 
+/**
+ * True when the file was not committed and has been deleted instead of restored.
+ */
+removed: boolean, };
+
+export type AttemptBlameLine = { 
+/**
+ * 1-based line number in the working-tree file.
+ */
+line_number: number, 
+/**
+ * Commit that last touched the line; `None` for uncommitted lines.
+ */
+commit: string | null, author_name: string | null, author_email: string | null, authored_at: string | null, summary: string | null, 
+/**
+ * True when the line is uncommitted or comes from a commit made on the attempt branch.
+ */
+attempt_origin: boolean, content: string, };
+
+export type AttemptBlameResponse = { path: string, lines: Array<AttemptBlameLine>, };
+
+export type ApplyAttemptPatchRequest = { 
+/**
+ * Unified diff with repo-prefixed paths (`a/<repo>/<path>`), as produced by `patch.diff`.
+ */
+patch: string, };
+
+export type AttemptPatchConflict = { repo: string, message: string, };
+
+export type ApplyAttemptPatchResponse = { 
+/**
+ * False when any repo rejected its part of the patch; nothing is applied in that case.
+ */
+applied: boolean, 
+/**
+ * Repo-prefixed paths touched by the patch.
+ */
+files: Array<string>, conflicts: Array<AttemptPatchConflict>, };
+
+export type ApprovalDecision = "approved" | "denied";
+
+export type RespondAllApprovalsRequest = { decision: ApprovalDecision, 
+/**
+ * Sent with every denial; ignored when approving.
+ */
+denial_reason: string | null, };
+
+export type ApprovalBatchResult = { approval_id: string, tool_name: string, 
+/**
+ * Final status; differs from the decision when someone else answered first.
+ */
+status: ApprovalStatus | null, error: string | null, };
+
+export type RespondAllApprovalsResponse = { 
+/**
+ * One entry per approval that was pending when the call started, oldest first.
+ */
+results: Array<ApprovalBatchResult>, };
 
 export type CreateAndStartTaskRequest = { task: CreateTask, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, };
 
+export type MoveTaskRequest = { target_project_id: string, };
+
+export type UpdateTaskError = { "type": "stale_write", current_updated_at: string, };
+
+export type UpsertTaskScheduleRequest = { cron: string, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, enabled: boolean | null, };
+
 export type ArchiveProjectKanbanRequest = { statuses: Array<TaskStatus>, title: string | null, };
 
 export type ArchiveProjectKanbanResponse = { archived_kanban: ArchivedKanbanWithTaskCount, moved_task_count: bigint, };
@@ -321,11 +555,40 @@ export type LogHistoryPage = { entries: Array<IndexedLogEntry>, next_cursor: big
 
 export type LogStreamEvent = { "type": "append", entry_index: bigint, entry: PatchType, } | { "type": "replace", entry_index: bigint, entry: PatchType, } | { "type": "finished" };
 
-export type CreateTaskAttemptBody = { task_id: string, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, prompt_preset: TaskAttemptPromptPreset | null, };
+export type RenormalizeLogsResponse = { 
+/**
+ * Number of normalized entries written.
+ */
+entries: number, };
+
+export type CreateTaskAttemptBody = { task_id: string, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, prompt_preset: TaskAttemptPromptPreset | null, labels: Array<string>, 
+/**
+ * Optional reasoning effort override, applied by executors that support it (Codex).
+ */
+reasoning_effort: ReasoningEffort | null, };
+
+export type UpdateTaskAttemptLabelsRequest = { labels: Array<string>, };
 
 export type TaskAttemptPromptPreset = "milestone_planning";
 
-export type WorkspaceRepoInput = { repo_id: string, target_branch: string, };
+export type WorkspaceRepoInput = { repo_id: string, target_branch: string, 
+/**
+ * Ref to create `target_branch` from when it does not exist yet (e.g. `main`, `origin/main`).
+ */
+create_from?: string, 
+/**
+ * Sparse-checkout patterns (gitignore syntax); only matching paths are materialized in the
+ * attempt worktree. Omit or leave empty for a full checkout.
+ */
+sparse_paths?: Array<string>, };
+
+export type MissingTargetBranch = { repo_id: string, repo_name: string, target_branch: string, };
+
+export type CreateTaskAttemptError = { "type": "target_branch_not_found", 
+/**
+ * One entry per repo whose `target_branch` exists neither locally nor on a remote.
+ */
+repos: Array<MissingTargetBranch>, };
 
 export type RunAgentSetupRequest = { executor_profile_id: ExecutorProfileId, };
 
@@ -333,6 +596,16 @@ export type RunAgentSetupResponse = Record<string, never>;
 
 export type RebaseTaskAttemptRequest = { repo_id: string, old_base_branch: string | null, new_base_branch: string | null, };
 
+export type RebaseOntoBaseRequest = { repo_id: string, };
+
+export type RebaseOntoBaseResponse = { base_branch: string, 
+/**
+ * New head of the attempt branch; `None` when it already contained the base branch.
+ */
+head_sha: string | null, };
+
+export type RebaseOntoBaseError = { "type": "merge_conflicts", message: string, base_branch: string, conflicted_files: Array<string>, };
+
 export type AbortConflictsRequest = { repo_id: string, };
 
 export type GitOperationError = { "type": "merge_conflicts", message: string, op: ConflictOp, } | { "type": "rebase_in_progress" };
@@ -375,11 +648,18 @@ export type DirectoryListResponse = { entries: Array<DirectoryEntry>, current_pa
 
 export type ProjectRepoConfig = { path: string, display_name: string | null, setup_script: string | null, cleanup_script: string | null, copy_files: string | null, parallel_setup_script: boolean, };
 
-export type ProjectConfig = { id: string | null, remote_project_id: string | null, name: string, repos: Array<ProjectRepoConfig>, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, git_no_verify_override: boolean | null, scheduler_max_concurrent: number, scheduler_max_retries: number, default_continuation_turns: number, mcp_auto_executor_policy_mode: ProjectMcpExecutorPolicyMode, mcp_auto_executor_policy_allow_list: Array<ExecutorProfileId>, after_prepare_hook: WorkspaceLifecycleHookConfig | null, before_cleanup_hook: WorkspaceLifecycleHookConfig | null, };
+export type ProjectConfig = { id: string | null, remote_project_id: string | null, name: string, repos: Array<ProjectRepoConfig>, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, context_file: string | null, git_no_verify_override: boolean | null, default_executor_profile: ExecutorProfileId | null, scheduler_max_concurrent: number, scheduler_max_retries: number, default_continuation_turns: number, mcp_auto_executor_policy_mode: ProjectMcpExecutorPolicyMode, mcp_auto_executor_policy_allow_list: Array<ExecutorProfileId>, after_prepare_hook: WorkspaceLifecycleHookConfig | null, before_cleanup_hook: WorkspaceLifecycleHookConfig | null, };
+
+export type Config = { config_version: string, theme: ThemeMode, executor_profile: ExecutorProfileId, executor_profiles: ExecutorConfigs | null, executor_env: { [key in BaseCodingAgent]?: { [key in string]?: string } }, execution_timeout_secs: bigint | null, executor_timeout_secs: { [key in BaseCodingAgent]?: bigint }, execution_idle_timeout_secs: bigint | null, max_concurrent_executions: number | null, execution_capacity_policy: ExecutionCapacityPolicy, disclaimer_acknowledged: boolean, onboarding_acknowledged: boolean, notifications: NotificationConfig, editor: EditorConfig, github: GitHubConfig, workspace_dir: string | null, worktree_root: string | null, last_app_version: string | null, show_release_notes: boolean, language: UiLanguage, git_branch_prefix: string, git_no_verify: boolean, showcases: ShowcaseState, pr_auto_description_enabled: boolean, pr_auto_description_prompt: string | null, llman_claude_code_path: string | null, diff_preview_guard: DiffPreviewGuardPreset, access_control: AccessControlConfig, proxy: ProxyConfig, task_status_transitions: { [key in string]?: Array<string> }, turn_summary_llm_enabled: boolean, projects: Array<ProjectConfig>, };
+
+export type NotificationConfig = { sound_enabled: boolean, push_enabled: boolean, sound_file: SoundFile, event_sounds: NotificationEventSounds, };
 
-export type Config = { config_version: string, theme: ThemeMode, executor_profile: ExecutorProfileId, executor_profiles: ExecutorConfigs | null, disclaimer_acknowledged: boolean, onboarding_acknowledged: boolean, notifications: NotificationConfig, editor: EditorConfig, github: GitHubConfig, workspace_dir: string | null, last_app_version: string | null, show_release_notes: boolean, language: UiLanguage, git_branch_prefix: string, git_no_verify: boolean, showcases: ShowcaseState, pr_auto_description_enabled: boolean, pr_auto_description_prompt: string | null, llman_claude_code_path: string | null, diff_preview_guard: DiffPreviewGuardPreset, access_control: AccessControlConfig, projects: Array<ProjectConfig>, };
+export type NotificationEventSounds = { task_completed: NotificationSound | null, task_failed: NotificationSound | null, approval_needed: NotificationSound | null, };
 
-export type NotificationConfig = { sound_enabled: boolean, push_enabled: boolean, sound_file: SoundFile, };
+/**
+ * A built-in preset or a custom sound uploaded via `POST /api/sounds/custom`.
+ */
+export type NotificationSound = SoundFile | { custom: string, };
 
 export enum ThemeMode { LIGHT = "LIGHT", DARK = "DARK", SYSTEM = "SYSTEM" }
 
@@ -401,9 +681,21 @@ export enum DiffPreviewGuardPreset { SAFE = "SAFE", BALANCED = "BALANCED", RELAX
 
 export enum AccessControlMode { DISABLED = "DISABLED", TOKEN = "TOKEN" }
 
-export type AccessControlConfig = { mode: AccessControlMode, token: string | null, allow_localhost_bypass: boolean, };
+export enum ExecutionCapacityPolicy { QUEUE = "QUEUE", REJECT = "REJECT" }
 
-export type GitBranch = { name: string, is_current: boolean, is_remote: boolean, last_commit_date: Date, };
+export type AccessScope = "read" | "tasks:write" | "attempts:start" | "attempts:write" | "admin";
+
+export type ScopedAccessToken = { name: string, token: string, scopes: Array<AccessScope>, };
+
+export type AccessControlConfig = { mode: AccessControlMode, token: string | null, allow_localhost_bypass: boolean, tokens: Array<ScopedAccessToken>, webhook_secret: string | null, };
+
+export type ProxyConfig = { http_proxy: string | null, https_proxy: string | null, no_proxy: string | null, };
+
+export type GitBranch = { name: string, is_current: boolean, is_remote: boolean, 
+/**
+ * The repository's default branch (remote `HEAD`, else `main`/`master`, else current).
+ */
+is_default: boolean, last_commit_date: Date, };
 
 export type QueuedMessage = { 
 /**
@@ -419,10 +711,38 @@ data: DraftFollowUpData,
  */
 queued_at: string, };
 
-export type QueueStatus = { "status": "empty" } | { "status": "queued", message: QueuedMessage, };
+export type QueueStatus = { "status": "empty" } | { "status": "queued", 
+/**
+ * The next message to be dispatched
+ */
+message: QueuedMessage, 
+/**
+ * All queued messages in dispatch order (including `message`)
+ */
+messages: Array<QueuedMessage>, 
+/**
+ * Number of queued messages
+ */
+depth: number, };
 
 export type ConflictOp = "rebase" | "merge" | "cherry_pick" | "revert";
 
+export type WorktreeFileStatus = { staged: Array<WorktreeStatusEntry>, unstaged: Array<WorktreeStatusEntry>, 
+/**
+ * Untracked paths; untracked directories are collapsed to `dir/`.
+ */
+untracked: Array<string>, };
+
+export type WorktreeStatusEntry = { 
+/**
+ * Porcelain status letter (`M`, `A`, `D`, `R`, `C`, `T` or `U`).
+ */
+status: string, path: string, 
+/**
+ * Previous path for renames and copies.
+ */
+orig_path: string | null, };
+
 export type ExecutorAction = { typ: ExecutorActionType, next_action: ExecutorAction | null, };
 
 export type McpConfig = { servers: { [key in string]?: JsonValue }, servers_path: Array<string>, template: JsonValue, preconfigured: JsonValue, is_toml_config: boolean, };
@@ -513,7 +833,16 @@ working_dir: string | null,
 /**
  * Optional image path map keyed by prompt image src.
  */
-image_paths?: { [key in string]?: string } | null, };
+image_paths?: { [key in string]?: string } | null, 
+/**
+ * Optional model override for this run only; the executor profile is left untouched.
+ */
+model?: string | null, 
+/**
+ * Optional reasoning effort override (`low`, `medium`, `high`, `xhigh`).
+ * Only applied by executors that support it (currently Codex).
+ */
+reasoning_effort?: string | null, };
 
 export type CodingAgentFollowUpRequest = { prompt: string, session_id: string, 
 /**
@@ -524,9 +853,22 @@ executor_profile_id: ExecutorProfileId,
  * Optional relative path to execute the agent in (relative to container_ref).
  * If None, uses the container_ref directory directly.
  */
-working_dir: string | null, image_paths?: { [key in string]?: string } | null, };
+working_dir: string | null, image_paths?: { [key in string]?: string } | null, 
+/**
+ * Optional model override for this run only; the executor profile is left untouched.
+ */
+model?: string | null, 
+/**
+ * Optional reasoning effort override (`low`, `medium`, `high`, `xhigh`).
+ * Only applied by executors that support it (currently Codex).
+ */
+reasoning_effort?: string | null, };
 
-export type CommandExitStatus = { "type": "exit_code", code: number, } | { "type": "success", success: boolean, };
+export type CommandExitStatus = { "type": "exit_code", code: number, } | { "type": "success", success: boolean, } | { "type": "signal", 
+/**
+ * The command was terminated by a signal rather than exiting on its own.
+ */
+signal: number, };
 
 export type CommandRunResult = { exit_status: CommandExitStatus | null, output: string | null, };
 
@@ -542,7 +884,11 @@ unified_diff: string,
 /**
  * Whether line number in the hunks are reliable.
  */
-has_line_numbers: boolean, };
+has_line_numbers: boolean, 
+/**
+ * Syntax-highlighting language (highlight.js id) inferred from the file extension.
+ */
+language?: string, };
 
 export type ActionType = { "action": "file_read", path: string, } | { "action": "file_edit", path: string, changes: Array<FileChange>, } | { "action": "command_run", command: string, result: CommandRunResult | null, } | { "action": "search", query: string, } | { "action": "web_fetch", url: string, } | { "action": "tool", tool_name: string, arguments: JsonValue | null, result: ToolResult | null, } | { "action": "task_create", description: string, } | { "action": "plan_presentation", plan: string, } | { "action": "todo_management", todos: Array<TodoItem>, operation: string, } | { "action": "other", description: string, };
 